@@ -226,8 +226,11 @@ fn main() {
     let bindings = builder
         .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()))
         // Allowlist only the FFI surface the wrapper actually calls (PLA I/O is pure Rust, so the
-        // `read_pla`/`*_PLA`/`fprint_pla` family and the standalone `simplify`/`expand`/`irredundant`/
-        // `reduce`/`verify` passes are not exposed).
+        // `read_pla`/`*_PLA`/`fprint_pla` family and the standalone `expand`/`irredundant`/`reduce`
+        // passes are not exposed; `simplify` is reached through `guarded_simplify`, `verify`'s checks
+        // through `guarded_verify` and `guarded_covered`, `essen.c`'s test through
+        // `guarded_essential_primes`, and the cube calculus of `sharp.c`/`cofactor.c` through
+        // `guarded_set_operation`).
         .allowlist_function("espresso")
         .allowlist_function("cube_setup")
         .allowlist_function("setdown_cube")
@@ -237,6 +240,7 @@ fn main() {
         .allowlist_function("complement")
         .allowlist_function("minimize_exact")
         .allowlist_function("sf_addset")
+        .allowlist_function("cube1list")
         .allowlist_function("cube2list")
         .allowlist_function("set_clear")
         .allowlist_type("set_family_t")
@@ -273,8 +277,19 @@ fn main() {
         .allowlist_function("set_skip_make_sparse")
         .allowlist_function("guarded_espresso")
         .allowlist_function("guarded_minimize_exact")
+        .allowlist_function("guarded_signature")
         .allowlist_function("guarded_complement")
         .allowlist_function("guarded_primes")
+        .allowlist_function("guarded_simplify")
+        .allowlist_function("espresso_set_interrupt")
+        .allowlist_function("guarded_phase_assignment")
+        .allowlist_function("apply_output_phase")
+        .allowlist_function("guarded_pairing")
+        .allowlist_function("guarded_single_output")
+        .allowlist_function("guarded_verify")
+        .allowlist_function("guarded_covered")
+        .allowlist_function("guarded_essential_primes")
+        .allowlist_function("guarded_set_operation")
         // Generate good Rust types
         .derive_default(true)
         .derive_debug(true)
@@ -38,6 +38,7 @@ pcover find_canonical_cover(pset_family F1, pset_family D, pset_family R)
 	ESC = new_cover(F->count);
 	
 	while(F->count){
+		espresso_check_interrupt();
 		c = GETSET(F,--F->count);
 		RESET(c,NONESSEN);
 		extended_dc = cube2list(E,F);
@@ -684,6 +684,8 @@ extern _Thread_local struct cdata_struct cdata, temp_cdata_save;
 /* pair.c */ extern void find_optimal_pairing (pPLA PLA, int strategy);
 /* pair.c */ extern void set_pair (pPLA PLA);
 /* pair.c */ extern void set_pair1 (pPLA PLA, int adjust_labels);
+/* pair.c */ extern ppair optimal_pairing (pPLA PLA, int strategy);
+/* pair.c */ extern ppair best_pairing (pPLA PLA, int pair_strategy);
 /* primes.c */ extern pset_family primes_consensus (pset *T);
 /* reduce.c */ extern int sccc_special_cases (pset *T, pset *result);
 /* reduce.c */ extern pset_family reduce (pset_family F, pset_family D);
@@ -247,6 +247,15 @@ aux_etr_order(pset_family F, pset_family E, pset_family R, pset c, pset d)
 	}
 	if(variable_head == variable_count){
 		minterms = get_mins(d);
+		/* Make room for every sigma cube up front: sf_addset() moving
+		   COVER's cubes would leave local_dc pointing into freed memory. */
+		if(COVER->count + minterms->count > COVER->capacity){
+			COVER->capacity = COVER->count + minterms->count;
+			COVER->data = REALLOC(espresso_word, COVER->data,
+				(long) COVER->capacity * COVER->wsize);
+			free_cubelist(local_dc);
+			local_dc = cube3list(F,E,COVER);
+		}
 		foreachi_set(minterms,i,d_minterm){
 			if(cube_is_covered(local_dc,d_minterm))continue;
 			sigma_d = get_sigma(R,d_minterm);
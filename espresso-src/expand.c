@@ -75,6 +75,7 @@ pcover expand(pset_family F, pset_family R, int nonsparse)
 
     /* Try to expand each nonprime and noncovered cube */
     foreach_set(F, last, p) {
+	espresso_check_interrupt();
 	/* do not expand if PRIME or if covered by previous expansion */
 	if (! TESTP(p, PRIME) && ! TESTP(p, COVERED)) {
 
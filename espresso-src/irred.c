@@ -97,6 +97,7 @@ irred_split_cover(pset_family F, pset_family D, pset_family *E, pset_family *Rt,
     /* Split F into E and R */
     FD = cube2list(F, D);
     foreach_set(F, last, p) {
+  espresso_check_interrupt();
   if (cube_is_covered(FD, p)) {
       R = sf_addset(R, p);
   } else {
@@ -161,6 +162,7 @@ irred_derive_table(pset_family D, pset_family E, pset_family Rp)
     size_last_dominance = 0;
     i = 0;
     foreach_set(Rp, last, p) {
+  espresso_check_interrupt();
   Rp_current = SIZE(p);
   fcube_is_covered(list, p, table);
   RESET(p, REDUND);	/* can now consider this cube redundant */
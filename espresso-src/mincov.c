@@ -111,6 +111,8 @@ sm_mincov(sm_matrix *A, solution_t *select, int *weight, int lb, int bound, int
     solution_t *select1, *select2, *best, *best1, *best2, *indep;
     int pick, lb_new, debug;
 
+    espresso_check_interrupt();
+
     /* Start out with some debugging information */
     stats->nodes++;
     if (depth > stats->max_depth) stats->max_depth = depth;
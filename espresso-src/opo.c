@@ -218,7 +218,7 @@ pcover opo(pset phase, pset_family T, pset_family D, pset_family R, int first_ou
 
 pset_family opo_recur(pset_family T, pset_family D, pset select, int offset, int first, int last)
 {
-    static int level = 0;
+    static _Thread_local int level = 0;
     int middle;
     pset_family sl, sr, temp;
 
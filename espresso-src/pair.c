@@ -207,8 +207,23 @@ pcover delvar(pset_family A, int *paired)
           19,20 654,729,075
 */
 void find_optimal_pairing(pPLA PLA, int strategy)
+{
+    PLA->pair = optimal_pairing(PLA, strategy);
+    printf("# ");
+    print_pair(PLA->pair);
+
+    set_pair(PLA);
+    EXEC_S(PLA->F=espresso(PLA->F,PLA->D,PLA->R),"ESPRESSO  ",PLA->F);
+}
+
+/*
+    optimal_pairing -- the search half of find_optimal_pairing: returns
+    the chosen pairing and leaves the PLA and the cube structure as they were
+*/
+ppair optimal_pairing(pPLA PLA, int strategy)
 {
     int i, j, **cost_array;
+    ppair pair;
 
     cost_array = find_pairing_cost(PLA, strategy);
 
@@ -226,19 +241,15 @@ void find_optimal_pairing(pPLA PLA, int strategy)
     }
 
     if (cube.num_binary_vars <= 14) {
-  PLA->pair = pair_best_cost(cost_array);
+  pair = pair_best_cost(cost_array);
     } else {
-  (void) greedy_best_cost(cost_array, &(PLA->pair));
+  (void) greedy_best_cost(cost_array, &pair);
     }
-    printf("# ");
-    print_pair(PLA->pair);
 
     for(i = 0; i < cube.num_binary_vars; i++)
   FREE(cost_array[i]);
     FREE(cost_array);
-
-    set_pair(PLA);
-    EXEC_S(PLA->F=espresso(PLA->F,PLA->D,PLA->R),"ESPRESSO  ",PLA->F);
+    return pair;
 }
 
 int **find_pairing_cost(pPLA PLA, int strategy)
@@ -442,7 +453,7 @@ void find_best_cost(register ppair pair)
   4) for phase assignment
 */
 
-void pair_all(pPLA PLA, int pair_strategy)
+static void pair_all_search(pPLA PLA, int pair_strategy)
 {
     ppair pair;
     pset candidate;
@@ -460,6 +471,11 @@ void pair_all(pPLA PLA, int pair_strategy)
 
     pair_free(pair);
     set_free(candidate);
+}
+
+void pair_all(pPLA PLA, int pair_strategy)
+{
+    pair_all_search(PLA, pair_strategy);
 
     PLA->pair = best_pair;
     PLA->phase = best_phase;
@@ -480,6 +496,25 @@ void pair_all(pPLA PLA, int pair_strategy)
 }
 
 
+/*
+ *  best_pairing -- the search half of pair_all: returns the pairing that
+ *  minimized to the fewest terms and leaves the PLA and the cube structure
+ *  as they were
+ */
+ppair best_pairing(pPLA PLA, int pair_strategy)
+{
+    pair_all_search(PLA, pair_strategy);
+
+    if (best_phase != NULL) set_free(best_phase);
+    if (best_F != NULL) sf_free(best_F);
+    if (best_D != NULL) sf_free(best_D);
+    if (best_R != NULL) sf_free(best_R);
+    best_phase = NULL;
+    best_F = best_D = best_R = NULL;
+    return best_pair;
+}
+
+
 /*
  *  minimize_pair -- called as each pair is generated
  */
@@ -13,6 +13,7 @@ pcover primes_consensus(pset *T)
     register int best;
     pcover Tnew, Tl, Tr;
 
+    espresso_check_interrupt();
     if (primes_consensus_special_cases(T, &Tnew) == MAYBE) {
 	cl = new_cube();
 	cr = new_cube();
@@ -119,6 +120,7 @@ primes_consensus_merge(pset_family Tl, pset_family Tr, pset cl, pset cr)
     Tsave = sf_contain(sf_join(Tl, Tr));
 
     foreach_set(Tl, lastl, pl) {
+	espresso_check_interrupt();
 	foreach_set(Tr, lastr, pr) {
 	    if (cdist01(pl, pr) == 1) {
 		consensus(pt, pl, pr);
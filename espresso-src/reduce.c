@@ -59,6 +59,7 @@ pcover reduce(pset_family F, pset_family D)
     /* Try to reduce each cube */
     FD = cube2list(F, D);
     foreach_set(F, last, p) {
+	espresso_check_interrupt();
 	cunder = reduce_cube(FD, p);		/* reduce the cube */
 	if (setp_equal(cunder, p)) {            /* see if it actually did */
 	    SET(p, ACTIVE);	/* cube remains active */
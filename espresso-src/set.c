@@ -293,7 +293,8 @@ pset_family sf_append(pset_family A, pset_family B)
 pset_family sf_new(int num, int size)
 {
     pset_family A;
-    if (set_family_garbage == NULL) {
+    /* A tracked call must not hand out or keep families it cannot release. */
+    if (set_family_garbage == NULL || espresso_allocations_tracked()) {
   A = ALLOC(set_family_t, 1);
     } else {
   A = set_family_garbage;
@@ -320,6 +321,10 @@ pset_family sf_save(register pset_family A)
 void sf_free(pset_family A)
 {
     FREE(A->data);
+    if (espresso_allocations_tracked()) {
+  FREE(A);
+  return;
+    }
     A->next = set_family_garbage;
     set_family_garbage = A;
 }
@@ -92,6 +92,7 @@ generate_primes(pset_family F, pset_family R)
   count = 0;
   PRIMES = new_cover(F->count);
   foreach_set(F,lastc,c){
+    espresso_check_interrupt();
     BB = new_cover(R->count);
     BB->count = R->count;
     /* BB = get_blocking_matrix(R,c); */
@@ -2,6 +2,8 @@
 #include "thread_local_accessors.h"
 
 #include <setjmp.h>
+#include <stdint.h>
+#include <stdlib.h>
 #include <string.h>
 
 /*
@@ -145,30 +147,225 @@ void espresso_fatal_guard_trigger(const char* s) {
     longjmp(fatal_env, 1);
 }
 
+/*
+ * Interrupts
+ *
+ * The hook installed by espresso_set_interrupt() is polled from the
+ * checkpoints in expand, reduce, irredundant, mincov, primes and the
+ * signature loops. It only fires
+ * while a recovery point is armed, and turns into a caught fatal there.
+ */
+static _Thread_local espresso_interrupt_fn interrupt_hook = NULL;
+static _Thread_local void* interrupt_data = NULL;
+
+void espresso_set_interrupt(espresso_interrupt_fn hook, void* data) {
+    interrupt_hook = hook;
+    interrupt_data = data;
+}
+
+void espresso_check_interrupt(void) {
+    if (interrupt_hook != NULL && fatal_armed && interrupt_hook(interrupt_data)) {
+        espresso_fatal_guard_trigger("minimisation interrupted");
+    }
+}
+
+/*
+ * Allocation tracking
+ *
+ * While an interruptible call runs, every block the ALLOC/REALLOC/FREE macros
+ * hand out is recorded in an open-addressed set of pointers, so that whatever
+ * is still live when the call is abandoned can be freed. Blocks that existed
+ * before the call are never recorded (their owners keep them), except those
+ * the call adopts as its own. The set is only touched while tracking is on;
+ * if it cannot grow, tracking stops and an abandoned call leaks as before.
+ */
+#define TRACKED_TOMBSTONE ((void*) 1)
+static _Thread_local bool tracking = FALSE;
+static _Thread_local void** tracked = NULL;
+static _Thread_local size_t tracked_capacity = 0;
+static _Thread_local size_t tracked_used = 0; /* live entries and tombstones */
+
+static size_t tracked_slot(void* p) {
+    return (size_t) (((uintptr_t) p >> 4) * 0x9E3779B97F4A7C15ull) & (tracked_capacity - 1);
+}
+
+static void tracking_stop(void) {
+    free(tracked);
+    tracked = NULL;
+    tracked_capacity = tracked_used = 0;
+    tracking = FALSE;
+}
+
+static bool tracked_grow(void) {
+    void** old = tracked;
+    size_t old_capacity = tracked_capacity, i, j;
+    size_t capacity = old_capacity == 0 ? 1024 : old_capacity * 2;
+
+    tracked = calloc(capacity, sizeof(void*));
+    if (tracked == NULL) {
+        tracked = old;
+        return FALSE;
+    }
+    tracked_capacity = capacity;
+    tracked_used = 0;
+    for (i = 0; i < old_capacity; i++) {
+        if (old[i] != NULL && old[i] != TRACKED_TOMBSTONE) {
+            for (j = tracked_slot(old[i]); tracked[j] != NULL; j = (j + 1) & (capacity - 1)) {
+            }
+            tracked[j] = old[i];
+            tracked_used++;
+        }
+    }
+    free(old);
+    return TRUE;
+}
+
+static void tracked_insert(void* p) {
+    size_t i;
+    if ((tracked_used + 1) * 2 > tracked_capacity && !tracked_grow()) {
+        tracking_stop();
+        return;
+    }
+    for (i = tracked_slot(p); tracked[i] != NULL && tracked[i] != TRACKED_TOMBSTONE;
+         i = (i + 1) & (tracked_capacity - 1)) {
+    }
+    if (tracked[i] == NULL) {
+        tracked_used++;
+    }
+    tracked[i] = p;
+}
+
+/* Forget p, reporting whether it was recorded. */
+static bool tracked_remove(void* p) {
+    size_t i;
+    if (tracked_capacity == 0) {
+        return FALSE;
+    }
+    for (i = tracked_slot(p); tracked[i] != NULL; i = (i + 1) & (tracked_capacity - 1)) {
+        if (tracked[i] == p) {
+            tracked[i] = TRACKED_TOMBSTONE;
+            return TRUE;
+        }
+    }
+    return FALSE;
+}
+
+/* Start recording, adopting the set family F (consumed by the call). */
+static void tracking_start(pset_family F) {
+    tracking = TRUE;
+    tracked_insert(F->data);
+    tracked_insert(F);
+}
+
+/* Free every recorded block that is still live, then stop recording. */
+static void tracking_release(void) {
+    size_t i;
+    for (i = 0; i < tracked_capacity; i++) {
+        if (tracked[i] != NULL && tracked[i] != TRACKED_TOMBSTONE) {
+            free(tracked[i]);
+        }
+    }
+    tracking_stop();
+}
+
+void* espresso_malloc(size_t size) {
+    void* p = malloc(size);
+    if (tracking && p != NULL) {
+        tracked_insert(p);
+    }
+    return p;
+}
+
+void* espresso_realloc(void* obj, size_t size) {
+    void* p = realloc(obj, size);
+    /* A moved block keeps its owner: recorded only if the original was. */
+    if (tracking && p != NULL && p != obj && tracked_remove(obj)) {
+        tracked_insert(p);
+    }
+    return p;
+}
+
+void espresso_free(void* obj) {
+    if (tracking) {
+        (void) tracked_remove(obj);
+    }
+    free(obj);
+}
+
+int espresso_allocations_tracked(void) {
+    return tracking;
+}
+
 pset_family guarded_espresso(pset_family F, pset_family D, pset_family R,
                              const char** msg_out) {
+    bool interruptible = interrupt_hook != NULL;
     *msg_out = NULL;
     if (setjmp(fatal_env) != 0) {
         /* fatal() jumped back here; it has already disarmed the guard. */
         *msg_out = fatal_message;
+        if (interruptible) {
+            tracking_release();
+        }
         return NULL;
     }
+    if (interruptible) {
+        tracking_start(F);
+    }
     fatal_armed = TRUE;
     pset_family result = espresso(F, D, R);
     fatal_armed = FALSE;
+    tracking_stop();
     return result;
 }
 
 pset_family guarded_minimize_exact(pset_family F, pset_family D, pset_family R,
                                    int exact_cover, const char** msg_out) {
+    bool interruptible = interrupt_hook != NULL;
+    unsigned int debug_save = debug;
     *msg_out = NULL;
     if (setjmp(fatal_env) != 0) {
         *msg_out = fatal_message;
+        /* do_minimize() adjusts debug while it runs and restores it only on return. */
+        debug = debug_save;
+        if (interruptible) {
+            tracking_release();
+        }
         return NULL;
     }
+    if (interruptible) {
+        tracking_start(F);
+    }
     fatal_armed = TRUE;
     pset_family result = minimize_exact(F, D, R, exact_cover);
     fatal_armed = FALSE;
+    tracking_stop();
+    return result;
+}
+
+pset_family guarded_signature(pset_family F, pset_family D, pset_family R,
+                              const char** msg_out) {
+    bool interruptible = interrupt_hook != NULL;
+    *msg_out = NULL;
+    if (setjmp(fatal_env) != 0) {
+        *msg_out = fatal_message;
+        if (interruptible) {
+            tracking_release();
+        }
+        return NULL;
+    }
+    if (interruptible) {
+        tracking_start(F);
+    }
+    fatal_armed = TRUE;
+    /* sigma/etr are written for binary inputs; on .mv inputs they return a wrong cover. */
+    if (cube.num_binary_vars != cube.num_vars - 1) {
+        fatal("signature: multiple-valued inputs are not supported");
+    }
+    pset_family result = signature(F, D, R);
+    fatal_armed = FALSE;
+    tracking_stop();
+    /* signature() works on copies; F is ours to free, as the other trampolines consume it. */
+    sf_free(F);
     return result;
 }
 
@@ -196,3 +393,385 @@ pset_family guarded_primes(pset* T, const char** msg_out) {
     return result;
 }
 
+pset_family guarded_simplify(pset* T, const char** msg_out) {
+    *msg_out = NULL;
+    if (setjmp(fatal_env) != 0) {
+        *msg_out = fatal_message;
+        return NULL;
+    }
+    fatal_armed = TRUE;
+    pset_family result = simplify(T);
+    fatal_armed = FALSE;
+    return result;
+}
+
+
+/* Build the phase cube for the current output part from one flag per output. */
+static pset phase_from_flags(const int* positive) {
+    pset phase = set_save(cube.fullset);
+    int i;
+    for (i = 0; i < cube.part_size[cube.output]; i++) {
+        if (!positive[i]) {
+            set_remove(phase, cube.first_part[cube.output] + i);
+        }
+    }
+    return phase;
+}
+
+/*
+ * The shape of the cube structure, for the guards whose callee reshapes it:
+ * find_phase() widens the output part and the pairing searches rebuild the
+ * variables. cube_setup() also resets the sparse flags, which two-bit
+ * decoder variables clear.
+ */
+typedef struct {
+    int num_vars;
+    int num_binary_vars;
+    int* part_size;
+    int* sparse;
+} cube_shape;
+
+static void save_cube_shape(cube_shape* shape) {
+    shape->num_vars = cube.num_vars;
+    shape->num_binary_vars = cube.num_binary_vars;
+    shape->part_size = ALLOC(int, cube.num_vars);
+    shape->sparse = ALLOC(int, cube.num_vars);
+    memcpy(shape->part_size, cube.part_size, cube.num_vars * sizeof(int));
+    memcpy(shape->sparse, cube.sparse, cube.num_vars * sizeof(int));
+}
+
+static void restore_cube_shape(cube_shape* shape) {
+    if (cube.num_vars != shape->num_vars
+        || cube.num_binary_vars != shape->num_binary_vars
+        || memcmp(cube.part_size, shape->part_size, shape->num_vars * sizeof(int)) != 0) {
+        setdown_cube();
+        FREE(cube.part_size);
+        cube.num_vars = shape->num_vars;
+        cube.num_binary_vars = shape->num_binary_vars;
+        cube.part_size = shape->part_size;
+        cube_setup();
+    } else {
+        FREE(shape->part_size);
+    }
+    memcpy(cube.sparse, shape->sparse, shape->num_vars * sizeof(int));
+    FREE(shape->sparse);
+}
+
+pset_family guarded_phase_assignment(pset_family F, pset_family D, pset_family R,
+                                     int strategy, pset_family* R_out,
+                                     int* positive_out, const char** msg_out) {
+    /* phase_assignment() overrides skip_make_sparse, so it is put back along
+     * with the cube shape however the call ends. */
+    bool saved_skip_make_sparse = skip_make_sparse;
+    cube_shape shape;
+    pPLA PLA = new_PLA();
+    int i;
+
+    save_cube_shape(&shape);
+    PLA->F = F;
+    PLA->D = D;
+    PLA->R = R;
+    *msg_out = NULL;
+    *R_out = NULL;
+    if (setjmp(fatal_env) != 0) {
+        *msg_out = fatal_message;
+        skip_make_sparse = saved_skip_make_sparse;
+        restore_cube_shape(&shape);
+        /* The covers are indeterminate; the PLA shell is leaked along with them. */
+        return NULL;
+    }
+    fatal_armed = TRUE;
+    phase_assignment(PLA, strategy);
+    fatal_armed = FALSE;
+    skip_make_sparse = saved_skip_make_sparse;
+    restore_cube_shape(&shape);
+
+    for (i = 0; i < cube.part_size[cube.output]; i++) {
+        positive_out[i] = is_in_set(PLA->phase, cube.first_part[cube.output] + i) != 0;
+    }
+    pset_family result = PLA->F;
+    *R_out = PLA->R;
+    PLA->F = PLA->D = PLA->R = NULL;
+    free_PLA(PLA);
+    return result;
+}
+
+void apply_output_phase(pset_family* F, pset_family* R, const int* positive) {
+    pPLA PLA = new_PLA();
+
+    PLA->F = *F;
+    PLA->R = *R;
+    PLA->phase = phase_from_flags(positive);
+    (void) set_phase(PLA);
+    *F = PLA->F;
+    *R = PLA->R;
+    PLA->F = PLA->R = NULL;
+    free_PLA(PLA);
+}
+
+int guarded_pairing(pset_family F, pset_family D, pset_family R, int exhaustive,
+                    int strategy, int* var1_out, int* var2_out, const char** msg_out) {
+    /* Both searches reshape the cube for every candidate and replace the
+     * PLA's covers, so they work on copies. */
+    bool saved_skip_make_sparse = skip_make_sparse;
+    cube_shape shape;
+    pPLA PLA = new_PLA();
+    ppair pair;
+    int i, count;
+
+    save_cube_shape(&shape);
+    PLA->F = sf_save(F);
+    PLA->D = sf_save(D);
+    PLA->R = sf_save(R);
+    *msg_out = NULL;
+    if (setjmp(fatal_env) != 0) {
+        *msg_out = fatal_message;
+        skip_make_sparse = saved_skip_make_sparse;
+        restore_cube_shape(&shape);
+        /* The copies are indeterminate; they are leaked with the PLA shell. */
+        return -1;
+    }
+    fatal_armed = TRUE;
+    pair = exhaustive ? best_pairing(PLA, strategy) : optimal_pairing(PLA, strategy);
+    fatal_armed = FALSE;
+    skip_make_sparse = saved_skip_make_sparse;
+    restore_cube_shape(&shape);
+
+    count = pair->cnt;
+    for (i = 0; i < count; i++) {
+        var1_out[i] = pair->var1[i] - 1;
+        var2_out[i] = pair->var2[i] - 1;
+    }
+    pair_free(pair);
+    free_PLA(PLA);
+    return count;
+}
+
+pset_family guarded_single_output(pset_family F, pset_family D, pset_family R,
+                                  int both, int exact, int* positive_out,
+                                  const char** msg_out) {
+    /* Each per-output minimisation sets skip_make_sparse, so it is put back
+     * however the call ends. */
+    bool saved_skip_make_sparse = skip_make_sparse;
+    pPLA PLA = new_PLA();
+    int i;
+
+    PLA->F = F;
+    PLA->D = D;
+    PLA->R = R;
+    *msg_out = NULL;
+    if (setjmp(fatal_env) != 0) {
+        *msg_out = fatal_message;
+        skip_make_sparse = saved_skip_make_sparse;
+        /* The covers are indeterminate; the PLA shell is leaked along with them. */
+        return NULL;
+    }
+    fatal_armed = TRUE;
+    if (both) {
+        so_both_espresso(PLA, exact);
+    } else {
+        so_espresso(PLA, exact);
+    }
+    fatal_armed = FALSE;
+    skip_make_sparse = saved_skip_make_sparse;
+
+    for (i = 0; i < cube.part_size[cube.output]; i++) {
+        positive_out[i] = PLA->phase == NULL ||
+                          is_in_set(PLA->phase, cube.first_part[cube.output] + i) != 0;
+    }
+    pset_family result = PLA->F;
+    /* D and R are only cofactored, never replaced, so they stay the caller's. */
+    PLA->F = PLA->D = PLA->R = NULL;
+    free_PLA(PLA);
+    return result;
+}
+
+
+/* Whether some part of c allows no value, so that c covers no minterm. */
+static bool cube_is_vacuous(pset c) {
+    int var, i;
+    for (var = 0; var < cube.num_vars; var++) {
+        for (i = cube.first_part[var]; i <= cube.last_part[var]; i++) {
+            if (is_in_set(c, i)) {
+                break;
+            }
+        }
+        if (i > cube.last_part[var]) {
+            return TRUE;
+        }
+    }
+    return FALSE;
+}
+
+/* Narrow every part of the non-vacuous cube c to its first value, giving one of its minterms. */
+static void first_minterm(pset c, pset minterm) {
+    int var, i;
+    set_clear(minterm, cube.size);
+    for (var = 0; var < cube.num_vars; var++) {
+        for (i = cube.first_part[var]; i <= cube.last_part[var]; i++) {
+            if (is_in_set(c, i)) {
+                set_insert(minterm, i);
+                break;
+            }
+        }
+    }
+}
+
+/*
+ * Find a minterm of A outside B u C. Returns a one-cube family holding it, or
+ * NULL if B u C covers A.
+ */
+static pset_family uncovered_minterm(pset_family A, pset_family B, pset_family C) {
+    pcube p, last, *BC;
+    pset_family result = NULL;
+
+    BC = cube2list(B, C);
+    foreach_set(A, last, p) {
+        if (! cube_is_vacuous(p) && ! cube_is_covered(BC, p)) {
+            pset_family BC_family = sf_join(B, C);
+            pset_family rest = cb_sharp(p, BC_family);
+            result = sf_new(1, cube.size);
+            first_minterm(GETSET(rest, 0), GETSET(result, 0));
+            result->count = 1;
+            sf_free(rest);
+            sf_free(BC_family);
+            break;
+        }
+    }
+    free_cubelist(BC);
+    return result;
+}
+
+int guarded_verify(pset_family F, pset_family Fold, pset_family Dold,
+                   pset_family* witness_out, const char** msg_out) {
+    int status = 0;
+
+    *msg_out = NULL;
+    *witness_out = NULL;
+    if (setjmp(fatal_env) != 0) {
+        *msg_out = fatal_message;
+        /* The cube lists and partial results are leaked. */
+        return -1;
+    }
+    fatal_armed = TRUE;
+    *witness_out = uncovered_minterm(F, Fold, Dold);
+    if (*witness_out != NULL) {
+        status = 1;
+    } else {
+        *witness_out = uncovered_minterm(Fold, F, Dold);
+        if (*witness_out != NULL) {
+            status = 2;
+        }
+    }
+    fatal_armed = FALSE;
+    return status;
+}
+
+
+int guarded_covered(pset_family A, pset_family B, pset_family C,
+                    pset_family* witness_out, const char** msg_out) {
+    pset_family universe = NULL;
+
+    *msg_out = NULL;
+    *witness_out = NULL;
+    if (setjmp(fatal_env) != 0) {
+        *msg_out = fatal_message;
+        return -1;
+    }
+    fatal_armed = TRUE;
+    if (A == NULL) {
+        universe = sf_addset(new_cover(1), cube.fullset);
+        A = universe;
+    }
+    *witness_out = uncovered_minterm(A, B, C);
+    if (universe != NULL) {
+        sf_free(universe);
+    }
+    fatal_armed = FALSE;
+    return *witness_out == NULL;
+}
+
+pset_family guarded_essential_primes(pset_family F, pset_family D,
+                                     pset_family* witness_out,
+                                     const char** msg_out) {
+    pcube p, last;
+    pset_family E, W, FD, H, prime, minterm;
+
+    *msg_out = NULL;
+    *witness_out = NULL;
+    if (setjmp(fatal_env) != 0) {
+        *msg_out = fatal_message;
+        /* The partial results are leaked. */
+        return NULL;
+    }
+    fatal_armed = TRUE;
+    E = new_cover(F->count);
+    W = new_cover(F->count);
+    FD = sf_join(F, D);
+    foreach_set(F, last, p) {
+        /* The part of p other primes reach, as essen_cube() computes it. */
+        H = cb_consensus(FD, p);
+        prime = sf_addset(new_cover(1), p);
+        minterm = uncovered_minterm(prime, H, D);
+        if (minterm != NULL) {
+            E = sf_addset(E, p);
+            W = sf_addset(W, GETSET(minterm, 0));
+            sf_free(minterm);
+        }
+        sf_free(prime);
+        sf_free(H);
+    }
+    sf_free(FD);
+    fatal_armed = FALSE;
+    *witness_out = W;
+    return E;
+}
+
+/*
+ * The cofactor of A against the cube c: the cubes of A that meet c, each
+ * widened to the full part of every variable c restricts.
+ */
+static pset_family cofactor_cover(pset_family A, pset c) {
+    pcube p, *T, *Tc, *T1;
+    pset_family result = new_cover(A->count);
+
+    T = cube1list(A);
+    Tc = cofactor(T, c);
+    for (T1 = Tc + 2; (p = *T1++) != NULL; ) {
+        (void) set_or(GETSET(result, result->count++), p, Tc[0]);
+    }
+    free_cubelist(Tc);
+    free_cubelist(T);
+    return sf_contain(result);
+}
+
+pset_family guarded_set_operation(int op, pset_family A, pset_family B,
+                                  const char** msg_out) {
+    pset_family result = NULL;
+
+    *msg_out = NULL;
+    if (setjmp(fatal_env) != 0) {
+        *msg_out = fatal_message;
+        return NULL;
+    }
+    fatal_armed = TRUE;
+    switch (op) {
+    case 0:
+        result = cv_intersect(A, B);
+        break;
+    case 1:
+        result = cv_sharp(A, B);
+        break;
+    case 2:
+        result = cv_dsharp(A, B);
+        break;
+    case 3:
+        result = sf_contain(sf_join(A, B));
+        break;
+    case 4:
+        result = cofactor_cover(A, GETSET(B, 0));
+        break;
+    }
+    fatal_armed = FALSE;
+    return result;
+}
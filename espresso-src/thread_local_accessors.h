@@ -85,8 +85,128 @@ pset_family guarded_espresso(pset_family F, pset_family D, pset_family R,
                              const char** msg_out);
 pset_family guarded_minimize_exact(pset_family F, pset_family D, pset_family R,
                                    int exact_cover, const char** msg_out);
+pset_family guarded_signature(pset_family F, pset_family D, pset_family R,
+                              const char** msg_out);
 pset_family guarded_complement(pset* T, const char** msg_out);
 pset_family guarded_primes(pset* T, const char** msg_out);
+pset_family guarded_simplify(pset* T, const char** msg_out);
+
+/*
+ * Interrupts (expand.c, reduce.c, irred.c, mincov.c, primes.c)
+ *
+ * espresso_set_interrupt() installs a thread-local hook that the minimiser's
+ * main loops poll through espresso_check_interrupt(); pass NULL to remove it.
+ * When the hook returns non-zero inside an armed guard, the call is abandoned
+ * as a caught fatal with the message "minimisation interrupted".
+ *
+ * While a hook is installed, guarded_espresso() and guarded_minimize_exact()
+ * record every block allocated during the call and free whatever is still
+ * live if it is abandoned, including F (which both consume). D and R remain
+ * the caller's. Without a hook they behave exactly as before.
+ */
+typedef int (*espresso_interrupt_fn)(void* data);
+void espresso_set_interrupt(espresso_interrupt_fn hook, void* data);
+
+/*
+ * Output phase assignment (opo.c)
+ *
+ * guarded_phase_assignment() runs phase_assignment() with the given opo
+ * strategy over F, D and R, returning the minimised ON-set. F and R are
+ * consumed (the chosen phase swaps them output by output); the new OFF-set is
+ * returned through *R_out and D is left untouched. positive_out receives one
+ * flag per output, zero where the output is implemented in complement. The
+ * cube structure and skip_make_sparse are restored on both paths.
+ *
+ * apply_output_phase() swaps F and R for every output whose positive flag is
+ * zero (set_phase); it cannot fail, so it is not guarded.
+ */
+pset_family guarded_phase_assignment(pset_family F, pset_family D, pset_family R,
+                                     int strategy, pset_family* R_out,
+                                     int* positive_out, const char** msg_out);
+void apply_output_phase(pset_family* F, pset_family* R, const int* positive);
+
+/*
+ * Input bit pairing (pair.c)
+ *
+ * guarded_pairing() searches for a pairing of the binary variables into
+ * two-bit decoders: exhaustive = 0 runs find_optimal_pairing()'s estimate
+ * (strategy 0-3: algebraic, strong division, espresso, exact), exhaustive = 1
+ * pair_all()'s minimisation under every pairing (strategy 0-2: espresso,
+ * exact, phase assignment). F, D and R are borrowed. The pairs are written
+ * to var1_out/var2_out as zero-based variable indices (room for
+ * num_binary_vars / 2 entries each) and their count is returned, or -1 on a
+ * caught fatal. The cube structure and skip_make_sparse are restored on both
+ * paths.
+ */
+int guarded_pairing(pset_family F, pset_family D, pset_family R, int exhaustive,
+                    int strategy, int* var1_out, int* var2_out, const char** msg_out);
+
+/*
+ * Single-output minimisation (cvrm.c)
+ *
+ * guarded_single_output() minimises every output of F on its own, as
+ * so_espresso() does, or with both = 1 also minimises its complement and keeps
+ * the phase with fewer cubes, as so_both_espresso() does. exact = 1 minimises
+ * each one exactly rather than heuristically. Every cube of the returned cover
+ * asserts exactly one output. F is consumed; D and R are borrowed. positive_out
+ * receives one flag per output, zero where the output is implemented in
+ * complement (never for both = 0). skip_make_sparse is restored on both paths.
+ */
+pset_family guarded_single_output(pset_family F, pset_family D, pset_family R,
+                                  int both, int exact, int* positive_out,
+                                  const char** msg_out);
+
+/*
+ * Verification (verify.c)
+ *
+ * guarded_verify() makes verify()'s two checks, F within Fold u Dold and Fold
+ * within F u Dold, but reports the first failure instead of printing it. It
+ * returns 0 if both hold, 1 if some minterm of F lies outside Fold u Dold, 2 if
+ * some minterm of Fold is not covered by F u Dold, and -1 on a caught fatal.
+ * On a failure *witness_out receives a new one-cube family holding such a
+ * minterm, one value in every part (the output part names the offending
+ * output); otherwise it is NULL. F, Fold and Dold are borrowed.
+ */
+int guarded_verify(pset_family F, pset_family Fold, pset_family Dold,
+                   pset_family* witness_out, const char** msg_out);
+
+/*
+ * guarded_covered() asks whether B u C covers A, by the unate recursive
+ * tautology check of each cube's cofactor (cube_is_covered). It returns 1 if
+ * so, 0 if not, with *witness_out set as for guarded_verify() to a minterm of
+ * A outside B u C, and -1 on a caught fatal. A NULL A stands for the whole
+ * space. A, B and C are borrowed.
+ */
+int guarded_covered(pset_family A, pset_family B, pset_family C,
+                    pset_family* witness_out, const char** msg_out);
+
+/*
+ * Essential primes (essen.c)
+ *
+ * guarded_essential_primes() returns the primes of the prime cover F that are
+ * essential with respect to F u D, by essen_cube()'s test: c is essential iff
+ * consensus((F u D) # c, c) u D does not cover it. Unlike essential() it
+ * leaves F and D unchanged and ignores the NONESSEN/RELESSEN hints, so F may be
+ * any prime cover. *witness_out receives, in the same order, one minterm per
+ * essential prime that no other prime and no don't-care covers. F and D are
+ * borrowed; the result is a new family, or NULL on a caught fatal.
+ */
+pset_family guarded_essential_primes(pset_family F, pset_family D,
+                                     pset_family* witness_out,
+                                     const char** msg_out);
+
+/*
+ * Cube calculus (sharp.c, cofactor.c, contain.c)
+ *
+ * guarded_set_operation() runs one operation of the reference tool's -D
+ * commands on the covers A and B: op 0 is the intersection (cv_intersect),
+ * 1 the sharp A # B (cv_sharp), 2 the disjoint sharp (cv_dsharp), 3 the union
+ * with contained cubes removed, and 4 the cofactor of A against the first
+ * cube of B. A and B are borrowed; the result is a new family, or NULL on a
+ * caught fatal.
+ */
+pset_family guarded_set_operation(int op, pset_family A, pset_family B,
+                                  const char** msg_out);
 
 #endif /* THREAD_LOCAL_ACCESSORS_H */
 
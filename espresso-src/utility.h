@@ -1,6 +1,8 @@
 #ifndef UTILITY_H
 #define UTILITY_H
 
+#include <stddef.h>
+
 /*
  *  bare malloc/realloc wrappers, not a checked memory manager
  *	- no libmm.a: allocation failure is NOT caught here and
@@ -8,15 +10,27 @@
  *	- ALLOC/REALLOC may return NULL on allocation failure; callers
  *	  (including the Rust FFI boundary) must check the result
  *	- catch free(0) and realloc(0, size) in the macros
+ *	- the espresso_* functions are plain malloc/realloc/free, except
+ *	  that an interruptible guarded call records the blocks it
+ *	  allocates so they can be released if it is abandoned
+ *	  (thread_local_accessors.c)
  */
+extern void *espresso_malloc(size_t size);
+extern void *espresso_realloc(void *obj, size_t size);
+extern void espresso_free(void *obj);
+extern int espresso_allocations_tracked(void);
+
+/* Give up the current guarded call if its interrupt hook asks to. */
+extern void espresso_check_interrupt(void);
+
 #define NIL(type)		((type *) 0)
 #define ALLOC(type, num)	\
-    ((type *) malloc(sizeof(type) * (num)))
+    ((type *) espresso_malloc(sizeof(type) * (num)))
 #define REALLOC(type, obj, num)	\
-    (obj) ? ((type *) realloc((char *) obj, sizeof(type) * (num))) : \
-	    ((type *) malloc(sizeof(type) * (num)))
+    (obj) ? ((type *) espresso_realloc((char *) obj, sizeof(type) * (num))) : \
+	    ((type *) espresso_malloc(sizeof(type) * (num)))
 #define FREE(obj)		\
-    if ((obj)) { (void) free((char *) (obj)); (obj) = 0; }
+    if ((obj)) { espresso_free((char *) (obj)); (obj) = 0; }
 
 extern long  util_cpu_time(void);
 extern char *util_print_time (long t);
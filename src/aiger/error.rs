@@ -0,0 +1,244 @@
+//! Error types for AIGER format parsing and writing
+
+use std::fmt;
+use std::io;
+use std::sync::Arc;
+
+/// Errors related to AIGER format parsing and validation
+///
+/// These errors occur when reading an AIGER file that is malformed or describes more than a
+/// combinational and-inverter graph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AIGERError {
+    /// The first line is not an `aag`/`aig` header with five (or, for AIGER 1.9, up to nine) counts.
+    InvalidHeader {
+        /// The header line as read
+        line: Arc<str>,
+    },
+    /// The graph has latches. Only combinational graphs are read.
+    Latches {
+        /// Number of latches the header declares
+        count: u64,
+    },
+    /// The header declares AIGER 1.9 bad-state, constraint, justice or fairness properties, which
+    /// belong to a model-checking problem rather than to a function.
+    Properties,
+    /// A line in the input, output or AND-gate section is not the literals it should hold, or a
+    /// symbol table entry is malformed.
+    InvalidLine {
+        /// The line as read
+        line: Arc<str>,
+    },
+    /// A literal exceeds the maximum variable index the header declares, or an input or AND-gate
+    /// definition uses a negated or constant literal.
+    InvalidLiteral {
+        /// The offending literal
+        literal: u64,
+    },
+    /// A variable is defined twice (as two inputs, two gates, or both).
+    DuplicateDefinition {
+        /// The literal being redefined
+        literal: u64,
+    },
+    /// A gate or output reads a variable that is neither an input nor a gate.
+    UndefinedLiteral {
+        /// The undefined literal
+        literal: u64,
+    },
+    /// The AND gates form a cycle through the given gate.
+    CombinationalCycle {
+        /// A gate on the cycle
+        literal: u64,
+    },
+    /// The input ends before the sections the header declares.
+    UnexpectedEnd,
+}
+
+impl fmt::Display for AIGERError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AIGERError::InvalidHeader { line } => write!(f, "Invalid AIGER header: '{}'", line),
+            AIGERError::Latches { count } => {
+                write!(
+                    f,
+                    "AIGER graph has {} latches; only combinational graphs are read",
+                    count
+                )
+            }
+            AIGERError::Properties => {
+                write!(
+                    f,
+                    "AIGER graph declares bad-state, constraint, justice or fairness properties"
+                )
+            }
+            AIGERError::InvalidLine { line } => write!(f, "Invalid AIGER line: '{}'", line),
+            AIGERError::InvalidLiteral { literal } => write!(f, "Invalid literal {}", literal),
+            AIGERError::DuplicateDefinition { literal } => {
+                write!(f, "Literal {} is defined more than once", literal)
+            }
+            AIGERError::UndefinedLiteral { literal } => {
+                write!(f, "Literal {} is neither an input nor an AND gate", literal)
+            }
+            AIGERError::CombinationalCycle { literal } => {
+                write!(f, "AND gate {} is on a combinational cycle", literal)
+            }
+            AIGERError::UnexpectedEnd => write!(f, "AIGER data ends before its declared sections"),
+        }
+    }
+}
+
+impl std::error::Error for AIGERError {}
+
+impl From<AIGERError> for io::Error {
+    fn from(err: AIGERError) -> Self {
+        io::Error::new(io::ErrorKind::InvalidData, err)
+    }
+}
+
+/// Errors that can occur when reading AIGER format data
+///
+/// This error type is returned by the `Aig::from_aiger_*` methods.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum AIGERReadError {
+    /// AIGER format error
+    AIGER(AIGERError),
+    /// IO error during reading
+    Io(io::Error),
+}
+
+impl fmt::Display for AIGERReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AIGERReadError::AIGER(e) => write!(f, "AIGER format error: {}", e),
+            AIGERReadError::Io(e) => write!(f, "IO error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for AIGERReadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AIGERReadError::AIGER(e) => Some(e),
+            AIGERReadError::Io(e) => Some(e),
+        }
+    }
+}
+
+impl From<AIGERError> for AIGERReadError {
+    fn from(err: AIGERError) -> Self {
+        AIGERReadError::AIGER(err)
+    }
+}
+
+impl From<io::Error> for AIGERReadError {
+    fn from(err: io::Error) -> Self {
+        AIGERReadError::Io(err)
+    }
+}
+
+impl From<AIGERReadError> for io::Error {
+    fn from(err: AIGERReadError) -> Self {
+        match err {
+            AIGERReadError::Io(e) => e,
+            AIGERReadError::AIGER(e) => io::Error::new(io::ErrorKind::InvalidData, e),
+        }
+    }
+}
+
+/// Errors that can occur when writing AIGER format data
+///
+/// This error type is returned by the [`AIGERWriter`](crate::AIGERWriter) methods.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum AIGERWriteError {
+    /// The cover has multiple-valued inputs (see [`Cover::mv_layout`](crate::Cover::mv_layout)),
+    /// which an and-inverter graph's binary inputs cannot carry.
+    MultipleValuedInputs,
+    /// A name contains a line break, which the symbol table cannot hold.
+    InvalidName {
+        /// The name as given
+        name: Arc<str>,
+    },
+    /// IO error during writing
+    Io(io::Error),
+}
+
+impl fmt::Display for AIGERWriteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AIGERWriteError::MultipleValuedInputs => {
+                write!(
+                    f,
+                    "AIGER cannot express a cover with multiple-valued inputs"
+                )
+            }
+            AIGERWriteError::InvalidName { name } => {
+                write!(f, "AIGER symbol {:?} contains a line break", name)
+            }
+            AIGERWriteError::Io(e) => write!(f, "IO error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for AIGERWriteError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AIGERWriteError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for AIGERWriteError {
+    fn from(err: io::Error) -> Self {
+        AIGERWriteError::Io(err)
+    }
+}
+
+impl From<AIGERWriteError> for io::Error {
+    fn from(err: AIGERWriteError) -> Self {
+        match err {
+            AIGERWriteError::Io(e) => e,
+            e => io::Error::new(io::ErrorKind::InvalidInput, e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_aiger_error_messages() {
+        let err = AIGERError::Latches { count: 2 };
+        assert!(err.to_string().contains("2 latches"));
+        let err = AIGERError::UndefinedLiteral { literal: 8 };
+        assert!(err.to_string().contains("Literal 8"));
+        let err = AIGERError::InvalidHeader {
+            line: Arc::from("aag 1"),
+        };
+        assert!(err.to_string().contains("'aag 1'"));
+    }
+
+    #[test]
+    fn test_aiger_read_error_conversions() {
+        let read_err: AIGERReadError = AIGERError::UnexpectedEnd.into();
+        assert!(matches!(read_err, AIGERReadError::AIGER(_)));
+        let io_err: io::Error = read_err.into();
+        assert_eq!(io_err.kind(), io::ErrorKind::InvalidData);
+
+        let read_err: AIGERReadError = io::Error::new(io::ErrorKind::NotFound, "missing").into();
+        let io_err: io::Error = read_err.into();
+        assert_eq!(io_err.kind(), io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn test_aiger_write_error_to_io_error() {
+        let io_err: io::Error = AIGERWriteError::MultipleValuedInputs.into();
+        assert_eq!(io_err.kind(), io::ErrorKind::InvalidInput);
+        let write_err: AIGERWriteError = io::Error::other("disk full").into();
+        assert!(matches!(write_err, AIGERWriteError::Io(_)));
+    }
+}
@@ -0,0 +1,696 @@
+//! AIGER (And-Inverter Graph) support
+//!
+//! This module exchanges combinational logic with model checkers and logic synthesis tools in the
+//! AIGER format, in both its ASCII (`.aag`) and binary (`.aig`) forms. An [`Aig`] is an
+//! and-inverter graph: named inputs, two-input AND gates over possibly negated signals, and named
+//! outputs, each a possibly negated signal.
+//!
+//! - [`Aig::from_cover`] builds the graph of a [`Cover`]'s outputs from its cubes, and
+//!   [`Aig::from_bdds`] builds it from [`Bdd`] roots by folding their decision nodes
+//!   ([`BddNode`]) into multiplexers;
+//! - [`AIGERWriter`] writes an [`Aig`], or a named [`Cover`] directly, in either format
+//!   ([`AIGERFormat`]);
+//! - [`Aig::from_aiger_reader`] (and the `_bytes`/`_file` forms) reads a combinational AIGER file,
+//!   and [`BddBuilder::build_aig`] turns its outputs into [`Bdd`] handles, so a netlist can be checked
+//!   against a PLA with [`Bdd::equivalent_to`].
+//!
+//! Graphs are built with structural hashing and constant propagation, so equal gates are shared and
+//! trivial ones (`x & 1`, `x & !x`, …) never appear.
+//!
+//! # Quick Example
+//!
+//! ```
+//! use espresso_logic::aiger::{AIGERFormat, Aig};
+//! use espresso_logic::{bdd_builder, AIGERWriter, PlaCover, Symbol};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! // A netlist from a model checker: f = !(!a & !b), i.e. a | b.
+//! let netlist = "aag 3 2 0 1 1\n2\n4\n7\n6 5 3\ni0 a\ni1 b\no0 f\n";
+//! let aig = Aig::from_aiger_bytes(netlist.as_bytes())?;
+//!
+//! let builder = bdd_builder!();
+//! let outputs = builder.build_aig(&aig);
+//! assert_eq!(outputs[0].0, "f");
+//!
+//! // Check it against the PLA specification.
+//! let pla = ".i 2\n.o 1\n.ilb a b\n.ob f\n1- 1\n-1 1\n.e\n";
+//! let PlaCover::InputsOutputsNamed(spec) = PlaCover::<Symbol>::from_pla_string(pla)? else {
+//!     unreachable!("the PLA names its inputs and outputs")
+//! };
+//! assert!(outputs[0].1.equivalent_to(&builder.build_cover(&spec)));
+//!
+//! // And write the specification back out as a graph.
+//! let text = String::from_utf8(spec.to_aiger_bytes(AIGERFormat::Ascii)?)?;
+//! assert_eq!(text, netlist);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # What Is Read
+//!
+//! - The header `aag M I L O A` (or `aig …`); AIGER 1.9's extra counts are accepted when zero.
+//!   Latches and 1.9 properties (bad states, constraints, justice, fairness) are rejected: only a
+//!   combinational graph describes a function.
+//! - ASCII gates may appear in any order and variables may be numbered sparsely; both are
+//!   renumbered on reading. Gates no output depends on are dropped.
+//! - A header declaring more inputs, outputs and gates together than the rest of the file has
+//!   bytes is rejected, even in the binary form, whose inputs take no bytes of their own.
+//! - The symbol table names inputs (`i<k> name`) and outputs (`o<k> name`); an unnamed input or
+//!   output is called `i<k>` or `o<k>`. The comment section is skipped.
+//!
+//! Since [`BddBuilder`] resolves variables by name, inputs sharing a name are the same variable
+//! once built.
+
+pub mod error;
+
+pub use error::{AIGERError, AIGERReadError, AIGERWriteError};
+
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fs::File;
+use std::io::{BufWriter, Read, Write};
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::bdd::{Bdd, BddBuilder, BddNode, Brand, ManagerCell};
+use crate::cover::{Cover, CubeType, InputField, Label};
+use crate::Symbol;
+
+/// A signal in the graph: twice its variable index, plus one when negated. Variable 0 is the
+/// constant `false`, so literal `0` is `false` and `1` is `true`.
+type Literal = u32;
+
+/// The two encodings of an AIGER file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum AIGERFormat {
+    /// The human-readable `aag` form
+    #[default]
+    Ascii,
+    /// The compact binary `aig` form
+    Binary,
+}
+
+/// A combinational and-inverter graph
+///
+/// Variables are numbered in AIGER's reencoded order: the inputs first, then the AND gates, each
+/// gate reading only lower-numbered variables. Build one from a [`Cover`] or from [`Bdd`] roots, or
+/// read one from an AIGER file; see the [module documentation](self).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Aig {
+    inputs: Vec<Symbol>,
+    /// Gate `k` defines variable `inputs.len() + 1 + k` as the AND of two literals, the larger
+    /// first (the order the binary format encodes).
+    ands: Vec<(Literal, Literal)>,
+    outputs: Vec<Symbol>,
+    output_literals: Vec<Literal>,
+}
+
+impl Aig {
+    /// The input names, in order
+    pub fn inputs(&self) -> &[Symbol] {
+        &self.inputs
+    }
+
+    /// The output names, in order
+    pub fn outputs(&self) -> &[Symbol] {
+        &self.outputs
+    }
+
+    /// Number of AND gates in the graph
+    pub fn num_ands(&self) -> usize {
+        self.ands.len()
+    }
+
+    /// Build the graph of a cover's outputs
+    ///
+    /// Inputs and outputs take the cover's labels. Each output is the OR of its ON-set cubes, each
+    /// cube the AND of its literals; an output whose cubes cover its complement (see
+    /// [`Cover::complemented_outputs`]) is negated, so it still carries the true function.
+    /// Don't-care and OFF-set cubes are not part of the graph.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AIGERWriteError::MultipleValuedInputs`] for a cover with multiple-valued inputs.
+    pub fn from_cover<I: Label + AsRef<str>, O: Label + AsRef<str>>(
+        cover: &Cover<I, O>,
+    ) -> Result<Self, AIGERWriteError> {
+        if cover.mv_layout().is_some_and(|l| !l.mv_sizes().is_empty()) {
+            return Err(AIGERWriteError::MultipleValuedInputs);
+        }
+        let mut graph = GraphBuilder::default();
+        let inputs: Vec<Literal> = cover
+            .input_labels()
+            .iter()
+            .map(|label| graph.input(Symbol::from(label.as_ref())))
+            .collect();
+
+        let complemented = cover.complemented_outputs();
+        let mut output_literals = Vec::with_capacity(cover.num_outputs());
+        for j in 0..cover.num_outputs() {
+            let mut sum = 0;
+            for cube in cover.cubes() {
+                if cube.set != CubeType::F || !cube.asserts(j) {
+                    continue;
+                }
+                let mut term = 1;
+                for (i, field) in cube.inputs.fields().enumerate() {
+                    term = match field {
+                        InputField::Zero => graph.and(term, inputs[i] ^ 1),
+                        InputField::One => graph.and(term, inputs[i]),
+                        InputField::DontCare => term,
+                        InputField::Empty => 0,
+                    };
+                }
+                sum = graph.or(sum, term);
+            }
+            output_literals.push(if complemented.contains(&j) {
+                sum ^ 1
+            } else {
+                sum
+            });
+        }
+
+        let outputs = cover
+            .output_labels()
+            .iter()
+            .map(|label| Symbol::from(label.as_ref()))
+            .collect();
+        Ok(graph.finish(outputs, output_literals))
+    }
+
+    /// Build the graph of named [`Bdd`] roots, one output per root
+    ///
+    /// Each decision node becomes a multiplexer on its variable, `(v & high) | (!v & low)`, folded
+    /// once per node with [`Bdd::fold`], so the graph grows with the diagrams rather than with their
+    /// paths. The inputs are the roots' support variables, sorted by name.
+    pub fn from_bdds<'a, S: AsRef<str>, B: Brand + 'a, C: ManagerCell + 'a>(
+        roots: impl IntoIterator<Item = (S, &'a Bdd<B, C>)>,
+    ) -> Self {
+        let roots: Vec<(S, &Bdd<B, C>)> = roots.into_iter().collect();
+        let support: BTreeSet<Symbol> = roots
+            .iter()
+            .flat_map(|(_, root)| root.variables())
+            .collect();
+
+        let mut graph = GraphBuilder::default();
+        let inputs: HashMap<Symbol, Literal> = support
+            .into_iter()
+            .map(|name| (name.clone(), graph.input(name)))
+            .collect();
+
+        // `fold` takes a `Copy` closure, so the graph is reached through a shared `RefCell`.
+        let graph = RefCell::new(graph);
+        let mut outputs = Vec::with_capacity(roots.len());
+        let mut output_literals = Vec::with_capacity(roots.len());
+        for (name, root) in &roots {
+            let literal = root.fold(|node| match node {
+                BddNode::Terminal(value) => Literal::from(value),
+                BddNode::Decision {
+                    variable,
+                    low,
+                    high,
+                } => {
+                    let mut graph = graph.borrow_mut();
+                    let v = inputs[variable];
+                    let high = graph.and(v, high);
+                    let low = graph.and(v ^ 1, low);
+                    graph.or(high, low)
+                }
+            });
+            outputs.push(Symbol::from(name.as_ref()));
+            output_literals.push(literal);
+        }
+        graph.into_inner().finish(outputs, output_literals)
+    }
+
+    /// Read a combinational AIGER graph, in either format, from a reader
+    ///
+    /// The format is taken from the header (`aag` or `aig`).
+    pub fn from_aiger_reader<R: Read>(mut reader: R) -> Result<Self, AIGERReadError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+        Self::from_aiger_bytes(&bytes)
+    }
+
+    /// Read a combinational AIGER graph from bytes
+    pub fn from_aiger_bytes(bytes: &[u8]) -> Result<Self, AIGERReadError> {
+        Ok(parse_aiger(bytes)?)
+    }
+
+    /// Read a combinational AIGER graph from a file
+    pub fn from_aiger_file<P: AsRef<Path>>(path: P) -> Result<Self, AIGERReadError> {
+        Self::from_aiger_reader(File::open(path)?)
+    }
+}
+
+impl<B: Brand, C: ManagerCell> BddBuilder<B, C> {
+    /// Build each output of an [`Aig`] into a [`Bdd`] in this builder, paired with its name
+    ///
+    /// Inputs resolve to variables by name, like [`build_cover`](Self::build_cover), so the handles
+    /// can be compared with functions built from other sources with
+    /// [`Bdd::equivalent_to`](crate::Bdd::equivalent_to).
+    #[must_use]
+    pub fn build_aig(&self, aig: &Aig) -> Vec<(Symbol, Bdd<B, C>)> {
+        let mut nodes = Vec::with_capacity(1 + aig.inputs.len() + aig.ands.len());
+        nodes.push(self.constant(false));
+        nodes.extend(aig.inputs.iter().map(|name| self.var(name)));
+        let signal = |nodes: &[Bdd<B, C>], literal: Literal| {
+            let node = &nodes[(literal >> 1) as usize];
+            if literal & 1 == 1 {
+                !node
+            } else {
+                node.clone()
+            }
+        };
+        for &(a, b) in &aig.ands {
+            let gate = signal(&nodes, a) & signal(&nodes, b);
+            nodes.push(gate);
+        }
+        aig.outputs
+            .iter()
+            .zip(&aig.output_literals)
+            .map(|(name, &literal)| (name.clone(), signal(&nodes, literal)))
+            .collect()
+    }
+}
+
+/// Trait for types that support AIGER serialisation (writing)
+///
+/// Implemented for [`Aig`], and for every [`Cover<I, O>`](crate::Cover) whose labels are names
+/// ([`AsRef<str>`]), which is written through [`Aig::from_cover`].
+pub trait AIGERWriter {
+    /// Write this graph in the given format
+    ///
+    /// The symbol table names every input and output.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AIGERWriteError::InvalidName`] if a name contains a line break,
+    /// [`AIGERWriteError::MultipleValuedInputs`] for a cover with multiple-valued inputs, and
+    /// [`AIGERWriteError::Io`] if writing fails.
+    fn write_aiger<W: Write>(
+        &self,
+        writer: &mut W,
+        format: AIGERFormat,
+    ) -> Result<(), AIGERWriteError>;
+
+    /// Convert this graph to AIGER bytes
+    ///
+    /// This is a convenience method that delegates to `write_aiger`. The ASCII format is valid
+    /// UTF-8 whenever the names are.
+    fn to_aiger_bytes(&self, format: AIGERFormat) -> Result<Vec<u8>, AIGERWriteError> {
+        let mut buffer = Vec::new();
+        self.write_aiger(&mut buffer, format)?;
+        Ok(buffer)
+    }
+
+    /// Write this graph to an AIGER file
+    fn to_aiger_file<P: AsRef<Path>>(
+        &self,
+        path: P,
+        format: AIGERFormat,
+    ) -> Result<(), AIGERWriteError> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_aiger(&mut writer, format)?;
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+impl AIGERWriter for Aig {
+    fn write_aiger<W: Write>(
+        &self,
+        writer: &mut W,
+        format: AIGERFormat,
+    ) -> Result<(), AIGERWriteError> {
+        if let Some(name) = self
+            .inputs
+            .iter()
+            .chain(&self.outputs)
+            .find(|name| name.contains(['\n', '\r']))
+        {
+            return Err(AIGERWriteError::InvalidName {
+                name: Arc::from(name.as_ref()),
+            });
+        }
+        let num_inputs = self.inputs.len();
+        let magic = match format {
+            AIGERFormat::Ascii => "aag",
+            AIGERFormat::Binary => "aig",
+        };
+        writeln!(
+            writer,
+            "{} {} {} 0 {} {}",
+            magic,
+            num_inputs + self.ands.len(),
+            num_inputs,
+            self.outputs.len(),
+            self.ands.len()
+        )?;
+        // Binary inputs are implicit: input `k` is literal `2 * (k + 1)`.
+        if format == AIGERFormat::Ascii {
+            for k in 1..=num_inputs {
+                writeln!(writer, "{}", 2 * k)?;
+            }
+        }
+        for literal in &self.output_literals {
+            writeln!(writer, "{}", literal)?;
+        }
+        for (k, &(rhs0, rhs1)) in self.ands.iter().enumerate() {
+            let lhs = 2 * (num_inputs + 1 + k) as Literal;
+            match format {
+                AIGERFormat::Ascii => writeln!(writer, "{} {} {}", lhs, rhs0, rhs1)?,
+                AIGERFormat::Binary => {
+                    write_delta(writer, lhs - rhs0)?;
+                    write_delta(writer, rhs0 - rhs1)?;
+                }
+            }
+        }
+        for (k, name) in self.inputs.iter().enumerate() {
+            writeln!(writer, "i{} {}", k, name)?;
+        }
+        for (k, name) in self.outputs.iter().enumerate() {
+            writeln!(writer, "o{} {}", k, name)?;
+        }
+        Ok(())
+    }
+}
+
+impl<I: Label + AsRef<str>, O: Label + AsRef<str>> AIGERWriter for Cover<I, O> {
+    fn write_aiger<W: Write>(
+        &self,
+        writer: &mut W,
+        format: AIGERFormat,
+    ) -> Result<(), AIGERWriteError> {
+        Aig::from_cover(self)?.write_aiger(writer, format)
+    }
+}
+
+/// Write a binary gate delta as AIGER's little-endian base-128 varint.
+fn write_delta<W: Write>(writer: &mut W, mut delta: Literal) -> std::io::Result<()> {
+    while delta >= 0x80 {
+        writer.write_all(&[(delta & 0x7f) as u8 | 0x80])?;
+        delta >>= 7;
+    }
+    writer.write_all(&[delta as u8])
+}
+
+/// Accumulates a graph under construction, hashing gates so each distinct AND is built once.
+#[derive(Default)]
+struct GraphBuilder {
+    inputs: Vec<Symbol>,
+    ands: Vec<(Literal, Literal)>,
+    strash: HashMap<(Literal, Literal), Literal>,
+}
+
+impl GraphBuilder {
+    /// Declare the next input. All inputs are declared before the first gate.
+    fn input(&mut self, name: Symbol) -> Literal {
+        debug_assert!(self.ands.is_empty(), "inputs precede gates");
+        self.inputs.push(name);
+        2 * self.inputs.len() as Literal
+    }
+
+    fn and(&mut self, a: Literal, b: Literal) -> Literal {
+        let (hi, lo) = if a >= b { (a, b) } else { (b, a) };
+        if lo == 0 || hi == lo ^ 1 {
+            return 0;
+        }
+        if lo == 1 || hi == lo {
+            return hi;
+        }
+        *self.strash.entry((hi, lo)).or_insert_with(|| {
+            self.ands.push((hi, lo));
+            2 * (self.inputs.len() + self.ands.len()) as Literal
+        })
+    }
+
+    fn or(&mut self, a: Literal, b: Literal) -> Literal {
+        self.and(a ^ 1, b ^ 1) ^ 1
+    }
+
+    fn finish(self, outputs: Vec<Symbol>, output_literals: Vec<Literal>) -> Aig {
+        Aig {
+            inputs: self.inputs,
+            ands: self.ands,
+            outputs,
+            output_literals,
+        }
+    }
+}
+
+/// A forward-only view of AIGER bytes: text lines, with binary gate deltas between them.
+struct Bytes<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Bytes<'a> {
+    /// The next line, without its terminator, or `None` at the end.
+    fn line(&mut self) -> Option<Cow<'a, str>> {
+        if self.pos >= self.data.len() {
+            return None;
+        }
+        let rest = &self.data[self.pos..];
+        let end = rest.iter().position(|&b| b == b'\n').unwrap_or(rest.len());
+        self.pos += (end + 1).min(rest.len());
+        let line = rest[..end].strip_suffix(b"\r").unwrap_or(&rest[..end]);
+        Some(String::from_utf8_lossy(line))
+    }
+
+    fn required_line(&mut self) -> Result<Cow<'a, str>, AIGERError> {
+        self.line().ok_or(AIGERError::UnexpectedEnd)
+    }
+
+    /// The next binary delta.
+    fn delta(&mut self) -> Result<u64, AIGERError> {
+        let mut value = 0u64;
+        for shift in (0..).step_by(7) {
+            let &byte = self.data.get(self.pos).ok_or(AIGERError::UnexpectedEnd)?;
+            self.pos += 1;
+            if shift > 56 {
+                return Err(AIGERError::InvalidLiteral { literal: value });
+            }
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                break;
+            }
+        }
+        Ok(value)
+    }
+}
+
+/// How a variable of the file is defined.
+#[derive(Clone, Copy)]
+enum Definition {
+    Input(usize),
+    And(u64, u64),
+}
+
+/// Parse the numbers of one line, which must hold exactly `N` of them.
+fn literals<const N: usize>(line: &str) -> Result<[u64; N], AIGERError> {
+    let invalid = || AIGERError::InvalidLine {
+        line: Arc::from(line),
+    };
+    let mut fields = line.split_ascii_whitespace();
+    let mut values = [0; N];
+    for value in &mut values {
+        *value = fields
+            .next()
+            .and_then(|f| f.parse().ok())
+            .ok_or_else(invalid)?;
+    }
+    if fields.next().is_some() {
+        return Err(invalid());
+    }
+    Ok(values)
+}
+
+/// A file literal as a literal of the rebuilt graph, once its variable has been rebuilt.
+fn remap(mapped: &HashMap<u64, Literal>, literal: u64) -> Literal {
+    mapped
+        .get(&(literal >> 1))
+        .expect("variable rebuilt before use")
+        ^ (literal & 1) as Literal
+}
+
+/// Parse AIGER bytes into a graph, renumbered into reencoded order.
+fn parse_aiger(bytes: &[u8]) -> Result<Aig, AIGERError> {
+    let mut data = Bytes {
+        data: bytes,
+        pos: 0,
+    };
+
+    let header = data.required_line()?;
+    let invalid_header = || AIGERError::InvalidHeader {
+        line: Arc::from(header.as_ref()),
+    };
+    let mut fields = header.split_ascii_whitespace();
+    let binary = match fields.next() {
+        Some("aag") => false,
+        Some("aig") => true,
+        _ => return Err(invalid_header()),
+    };
+    let counts: Vec<u64> = fields
+        .map(|f| f.parse().map_err(|_| invalid_header()))
+        .collect::<Result<_, _>>()?;
+    if !(5..=9).contains(&counts.len()) {
+        return Err(invalid_header());
+    }
+    let (max_var, num_inputs, num_latches, num_outputs, num_ands) =
+        (counts[0], counts[1], counts[2], counts[3], counts[4]);
+    if num_latches != 0 {
+        return Err(AIGERError::Latches { count: num_latches });
+    }
+    if counts[5..].iter().any(|&c| c != 0) {
+        return Err(AIGERError::Properties);
+    }
+    // Every literal must fit a `Literal`, and the binary format numbers variables densely. Each
+    // input, output and gate takes at least a byte of the rest of the file, so a header promising
+    // more than that is not believed before anything is allocated for it.
+    let declared = num_inputs.saturating_add(num_ands);
+    let remaining = (data.data.len() - data.pos) as u64;
+    if max_var >= u64::from(Literal::MAX / 2)
+        || declared > max_var
+        || (binary && declared != max_var)
+        || declared.saturating_add(num_outputs) > remaining
+    {
+        return Err(invalid_header());
+    }
+    let max_literal = 2 * max_var + 1;
+    let check = |literal: u64| {
+        if literal > max_literal {
+            Err(AIGERError::InvalidLiteral { literal })
+        } else {
+            Ok(literal)
+        }
+    };
+
+    // Keyed by variable, as ASCII variables may be numbered sparsely up to `max_var`.
+    let mut definitions: HashMap<u64, Definition> = HashMap::new();
+    let mut define = |literal: u64, definition: Definition| {
+        if literal < 2 || literal & 1 == 1 {
+            return Err(AIGERError::InvalidLiteral { literal });
+        }
+        match definitions.entry(check(literal)? >> 1) {
+            Entry::Occupied(_) => Err(AIGERError::DuplicateDefinition { literal }),
+            Entry::Vacant(slot) => {
+                slot.insert(definition);
+                Ok(())
+            }
+        }
+    };
+
+    for k in 0..num_inputs as usize {
+        let literal = if binary {
+            2 * (k as u64 + 1)
+        } else {
+            let [literal] = literals(&data.required_line()?)?;
+            literal
+        };
+        define(literal, Definition::Input(k))?;
+    }
+    let mut output_literals = Vec::with_capacity(num_outputs as usize);
+    for _ in 0..num_outputs {
+        let [literal] = literals(&data.required_line()?)?;
+        output_literals.push(check(literal)?);
+    }
+    for k in 0..num_ands {
+        let (lhs, rhs0, rhs1) = if binary {
+            let lhs = 2 * (num_inputs + 1 + k);
+            let rhs0 = lhs
+                .checked_sub(data.delta()?)
+                .filter(|&rhs0| rhs0 < lhs)
+                .ok_or(AIGERError::InvalidLiteral { literal: lhs })?;
+            let rhs1 = rhs0
+                .checked_sub(data.delta()?)
+                .ok_or(AIGERError::InvalidLiteral { literal: lhs })?;
+            (lhs, rhs0, rhs1)
+        } else {
+            let [lhs, rhs0, rhs1] = literals(&data.required_line()?)?;
+            (lhs, check(rhs0)?, check(rhs1)?)
+        };
+        define(lhs, Definition::And(rhs0, rhs1))?;
+    }
+
+    let mut input_names: Vec<Option<Symbol>> = vec![None; num_inputs as usize];
+    let mut output_names: Vec<Option<Symbol>> = vec![None; num_outputs as usize];
+    while let Some(line) = data.line() {
+        if line == "c" {
+            break;
+        }
+        if line.is_empty() {
+            continue;
+        }
+        let invalid = || AIGERError::InvalidLine {
+            line: Arc::from(line.as_ref()),
+        };
+        let (position, name) = line.split_once(' ').ok_or_else(invalid)?;
+        let (kind, index) = position.split_at(1);
+        let names = match kind {
+            "i" => &mut input_names,
+            "o" => &mut output_names,
+            _ => return Err(invalid()),
+        };
+        let slot = index
+            .parse::<usize>()
+            .ok()
+            .and_then(|index| names.get_mut(index))
+            .ok_or_else(invalid)?;
+        *slot = Some(Symbol::from(name));
+    }
+
+    // Rebuild in reencoded order: the inputs in file order, then each gate after its fanins.
+    let mut graph = GraphBuilder::default();
+    let mut mapped: HashMap<u64, Literal> = HashMap::from([(0, 0)]);
+    let mut inputs_by_position: Vec<u64> = vec![0; num_inputs as usize];
+    for (&var, definition) in &definitions {
+        if let Definition::Input(k) = definition {
+            inputs_by_position[*k] = var;
+        }
+    }
+    for (k, (var, name)) in inputs_by_position.into_iter().zip(input_names).enumerate() {
+        let name = name.unwrap_or_else(|| Symbol::from(format!("i{}", k)));
+        mapped.insert(var, graph.input(name));
+    }
+
+    let mut in_progress: HashSet<u64> = HashSet::new();
+    let mut output_signals = Vec::with_capacity(output_literals.len());
+    for literal in output_literals {
+        // An iterative depth-first walk: a gate is rebuilt once both fanins are, and meeting a gate
+        // whose walk is still open means the gates loop.
+        let mut stack = vec![(literal >> 1, false)];
+        while let Some((var, fanins_done)) = stack.pop() {
+            if mapped.contains_key(&var) {
+                continue;
+            }
+            let Some(&Definition::And(rhs0, rhs1)) = definitions.get(&var) else {
+                return Err(AIGERError::UndefinedLiteral { literal: 2 * var });
+            };
+            if fanins_done {
+                let (a, b) = (remap(&mapped, rhs0), remap(&mapped, rhs1));
+                mapped.insert(var, graph.and(a, b));
+            } else {
+                if !in_progress.insert(var) {
+                    return Err(AIGERError::CombinationalCycle { literal: 2 * var });
+                }
+                stack.push((var, true));
+                stack.push((rhs0 >> 1, false));
+                stack.push((rhs1 >> 1, false));
+            }
+        }
+        output_signals.push(remap(&mapped, literal));
+    }
+
+    let outputs = output_names
+        .into_iter()
+        .enumerate()
+        .map(|(k, name)| name.unwrap_or_else(|| Symbol::from(format!("o{}", k))))
+        .collect();
+    Ok(graph.finish(outputs, output_signals))
+}
+
+#[cfg(test)]
+mod tests;
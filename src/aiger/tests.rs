@@ -0,0 +1,241 @@
+//! Tests for AIGER reading and writing.
+
+use super::*;
+use crate::{expr, CoverType, Minimizable};
+
+/// `f = a & b` and `g = !a | c`, with the cubes in a fixed order so the graph is predictable.
+fn sample_cover() -> Cover<Symbol, Symbol> {
+    Cover::from_blif_string(
+        ".model m\n.inputs a b c\n.outputs f g\n.names a b f\n11 1\n.names a c g\n0- 1\n-1 1\n.end\n",
+    )
+    .unwrap()
+}
+
+#[test]
+fn cover_is_written_as_ascii() {
+    let text =
+        String::from_utf8(sample_cover().to_aiger_bytes(AIGERFormat::Ascii).unwrap()).unwrap();
+    // g = !a | c = !(a & !c).
+    assert_eq!(
+        text,
+        "aag 5 3 0 2 2\n2\n4\n6\n8\n11\n8 4 2\n10 7 2\ni0 a\ni1 b\ni2 c\no0 f\no1 g\n"
+    );
+}
+
+#[test]
+fn binary_format_encodes_gate_deltas() {
+    let bytes = sample_cover().to_aiger_bytes(AIGERFormat::Binary).unwrap();
+    let mut expected = b"aig 5 3 0 2 2\n8\n11\n".to_vec();
+    // Gate 8 = 4 & 2 and gate 10 = 7 & 2, as (lhs - rhs0, rhs0 - rhs1).
+    expected.extend([4, 2, 3, 5]);
+    expected.extend(b"i0 a\ni1 b\ni2 c\no0 f\no1 g\n");
+    assert_eq!(bytes, expected);
+}
+
+#[test]
+fn both_formats_read_back_to_the_same_graph() {
+    let aig = Aig::from_cover(&sample_cover()).unwrap();
+    for format in [AIGERFormat::Ascii, AIGERFormat::Binary] {
+        let bytes = aig.to_aiger_bytes(format).unwrap();
+        assert_eq!(Aig::from_aiger_bytes(&bytes).unwrap(), aig);
+    }
+}
+
+#[test]
+fn long_binary_deltas_use_continuation_bytes() {
+    // 70 inputs ANDed together: the last gates reach back more than 127 literals.
+    let mut cover = Cover::new(CoverType::F);
+    let product = (0..70)
+        .map(|i| crate::BoolExpr::var(format!("x{i}")))
+        .reduce(|acc, x| acc.and(&x))
+        .unwrap();
+    cover.add_expr(&product, "f").unwrap();
+    let aig = Aig::from_cover(&cover).unwrap();
+    assert_eq!(aig.num_ands(), 69);
+    let bytes = aig.to_aiger_bytes(AIGERFormat::Binary).unwrap();
+    assert_eq!(Aig::from_aiger_bytes(&bytes).unwrap(), aig);
+}
+
+#[test]
+fn built_graph_matches_the_cover() {
+    let cover = sample_cover();
+    let aig = Aig::from_cover(&cover).unwrap();
+    let builder = crate::bdd_builder!();
+    let outputs = builder.build_aig(&aig);
+    assert_eq!(outputs.len(), 2);
+    assert_eq!(outputs[0].0, "f");
+    assert!(outputs[0].1.equivalent_to(&builder.parse("a & b").unwrap()));
+    assert_eq!(outputs[1].0, "g");
+    assert!(outputs[1]
+        .1
+        .equivalent_to(&builder.parse("!a | c").unwrap()));
+}
+
+#[test]
+fn complemented_outputs_are_negated_back() {
+    let mut cover = Cover::new(CoverType::F);
+    cover.add_expr(&expr!("a" + "b" + "c"), "f").unwrap();
+    let min = cover
+        .with_output_phase([false])
+        .unwrap()
+        .minimize()
+        .unwrap();
+    assert_eq!(min.complemented_outputs(), vec![0]);
+    let builder = crate::bdd_builder!();
+    let outputs = builder.build_aig(&Aig::from_cover(&min).unwrap());
+    assert!(outputs[0]
+        .1
+        .equivalent_to(&builder.parse("a | b | c").unwrap()));
+}
+
+#[test]
+fn constant_outputs_need_no_gates() {
+    let mut cover = Cover::new(CoverType::F);
+    cover.add_expr(&expr!("a" + !"a"), "one").unwrap();
+    cover.add_expr(&expr!("a" * !"a"), "zero").unwrap();
+    let aig = Aig::from_cover(&cover.minimize().unwrap()).unwrap();
+    assert_eq!(aig.num_ands(), 0);
+    let text = String::from_utf8(aig.to_aiger_bytes(AIGERFormat::Ascii).unwrap()).unwrap();
+    assert!(text.contains("\n1\n0\n"), "{text}");
+}
+
+#[test]
+fn bdds_fold_into_multiplexers() {
+    let builder = crate::bdd_builder!();
+    let xor = builder.parse("a ^ b").unwrap();
+    let maj = builder.parse("a & b | a & c | b & c").unwrap();
+    let aig = Aig::from_bdds([("x", &xor), ("m", &maj)]);
+    assert_eq!(aig.inputs(), ["a", "b", "c"]);
+    assert_eq!(aig.outputs(), ["x", "m"]);
+
+    let bytes = aig.to_aiger_bytes(AIGERFormat::Binary).unwrap();
+    let outputs = builder.build_aig(&Aig::from_aiger_bytes(&bytes).unwrap());
+    assert!(outputs[0].1.equivalent_to(&xor));
+    assert!(outputs[1].1.equivalent_to(&maj));
+
+    // A constant root is a constant output over no gates.
+    let aig = Aig::from_bdds([("t", &builder.constant(true))]);
+    assert!(aig.inputs().is_empty());
+    assert_eq!(aig.num_ands(), 0);
+}
+
+#[test]
+fn ascii_reader_renumbers_gates_and_names_defaults() {
+    // Sparse variables, a gate defined after its reader, no symbol table, and a comment.
+    let text = "aag 9 2 0 2 2\n4\n18\n12\n5\n12 17 5\n16 4 18\nc\nanything\n";
+    let aig = Aig::from_aiger_bytes(text.as_bytes()).unwrap();
+    assert_eq!(aig.inputs(), ["i0", "i1"]);
+    assert_eq!(aig.outputs(), ["o0", "o1"]);
+    assert_eq!(aig.num_ands(), 2);
+
+    let builder = crate::bdd_builder!();
+    let outputs = builder.build_aig(&aig);
+    // 12 = !(i0 & i1) & !i0 = !i0.
+    assert!(outputs[0].1.equivalent_to(&builder.parse("!i0").unwrap()));
+    assert!(outputs[1].1.equivalent_to(&builder.parse("!i0").unwrap()));
+}
+
+#[test]
+fn sparse_variables_cost_nothing_up_to_the_maximum() {
+    // A maximum variable index far past what the file uses allocates nothing for the gap.
+    let aig = Aig::from_aiger_bytes(b"aag 2000000000 1 0 1 0\n2\n3\n").unwrap();
+    assert_eq!(aig.inputs(), ["i0"]);
+    assert_eq!(aig.num_ands(), 0);
+}
+
+#[test]
+fn reader_accepts_aiger_1_9_headers_without_properties() {
+    let aig = Aig::from_aiger_bytes(b"aag 1 1 0 1 0 0 0 0 0\n2\n3\n").unwrap();
+    assert_eq!(aig.inputs(), ["i0"]);
+}
+
+#[test]
+fn reader_rejects_what_a_function_cannot_hold() {
+    let err = |s: &str| match Aig::from_aiger_bytes(s.as_bytes()) {
+        Err(AIGERReadError::AIGER(e)) => e,
+        other => panic!("expected an AIGER error, got {other:?}"),
+    };
+    assert!(matches!(err("aig 1\n"), AIGERError::InvalidHeader { .. }));
+    assert!(matches!(
+        err("aag x 0 0 0 0\n"),
+        AIGERError::InvalidHeader { .. }
+    ));
+    assert!(matches!(
+        err("aig 5 1 0 1 1\n"),
+        AIGERError::InvalidHeader { .. }
+    ));
+    // Counts the rest of the file cannot hold are rejected before anything is allocated for them.
+    for header in [
+        "aag 2000000000 2000000000 0 0 0\n",
+        "aig 2000000000 2000000000 0 0 0\n",
+        "aag 3 1 0 2 0\n2\n",
+    ] {
+        assert!(
+            matches!(err(header), AIGERError::InvalidHeader { .. }),
+            "{header:?}"
+        );
+    }
+    assert_eq!(
+        err("aag 2 1 1 0 0\n2\n4 2\n"),
+        AIGERError::Latches { count: 1 }
+    );
+    assert_eq!(err("aag 1 1 0 0 0 1\n2\n"), AIGERError::Properties);
+    assert_eq!(err("aag 1 1 0 1 0\n2\n"), AIGERError::UnexpectedEnd);
+    assert!(matches!(
+        err("aag 1 1 0 0 0\n2 3\n"),
+        AIGERError::InvalidLine { .. }
+    ));
+    assert_eq!(
+        err("aag 1 1 0 0 0\n3\n"),
+        AIGERError::InvalidLiteral { literal: 3 }
+    );
+    assert_eq!(
+        err("aag 1 1 0 1 0\n2\n4\n"),
+        AIGERError::InvalidLiteral { literal: 4 }
+    );
+    assert_eq!(
+        err("aag 2 2 0 0 0\n2\n2\n"),
+        AIGERError::DuplicateDefinition { literal: 2 }
+    );
+    assert_eq!(
+        err("aag 2 1 0 1 0\n2\n4\n"),
+        AIGERError::UndefinedLiteral { literal: 4 }
+    );
+    assert_eq!(
+        err("aag 3 1 0 1 2\n2\n4\n4 6 2\n6 4 2\n"),
+        AIGERError::CombinationalCycle { literal: 4 }
+    );
+    assert!(matches!(
+        err("aag 1 1 0 0 0\n2\nx0 a\n"),
+        AIGERError::InvalidLine { .. }
+    ));
+    assert!(matches!(
+        err("aag 1 1 0 0 0\n2\ni1 a\n"),
+        AIGERError::InvalidLine { .. }
+    ));
+    // A binary gate whose delta reaches past literal 0.
+    assert_eq!(
+        err("aig 2 1 0 0 1\n\x05\x00"),
+        AIGERError::InvalidLiteral { literal: 4 }
+    );
+    assert_eq!(
+        err("aig 3 1 0 0 2\n\x02\x01\x02"),
+        AIGERError::UnexpectedEnd
+    );
+}
+
+#[test]
+fn writer_rejects_what_aiger_cannot_hold() {
+    let paired = sample_cover().pair_inputs(&[(0, 1)]).unwrap();
+    assert!(matches!(
+        paired.to_aiger_bytes(AIGERFormat::Ascii),
+        Err(AIGERWriteError::MultipleValuedInputs)
+    ));
+
+    let builder = crate::bdd_builder!();
+    let aig = Aig::from_bdds([("two\nlines", &builder.var("a"))]);
+    assert!(matches!(
+        aig.to_aiger_bytes(AIGERFormat::Binary),
+        Err(AIGERWriteError::InvalidName { name }) if &*name == "two\nlines"
+    ));
+}
@@ -105,36 +105,52 @@ impl<B: Brand, C: ManagerCell> BddBuilder<B, C> {
     /// cube contributes its input product term whenever it is an ON-set cube, regardless of which output
     /// columns it asserts, so per-output structure is collapsed. For a per-output function, project the
     /// cover to one output before building. Only `F` (ON-set) cubes contribute; `D`/`R` cubes are ignored,
-    /// so the result is the ON-set of the cover.
+    /// so the result is the ON-set of the cover. An output whose cubes cover its complement (see
+    /// [`Cover::complemented_outputs`]) contributes the negation of its cubes instead.
+    ///
+    /// A multiple-valued field (see [`Cover::mv_layout`]) reads as its variable taking one of the
+    /// allowed values: each value it excludes contributes the negation of that value's one-hot
+    /// variable.
     #[must_use]
     pub fn build_cover<I: StringLabel, O>(&self, cover: &Cover<I, O>) -> Bdd<B, C> {
         use crate::cover::CubeType;
+        let complemented = cover.complemented_outputs();
+        let layout = cover.mv_layout();
         // Composed inside a `scope`: the OR-of-products fold runs on `Copy`, by-reference handles, so the
         // doubly-nested loop pays no per-operation refcount bump — only the returned root is materialised.
         self.scope(|s| {
+            // `acc` gathers the cubes of the outputs taken as given, and `negated[k]` those of the
+            // `k`th complemented output, OR-ed in negated at the end.
             let mut acc = s.constant(false);
+            let mut negated = vec![s.constant(false); complemented.len()];
             for cube in cover.cubes() {
                 if cube.cube_type() != CubeType::F {
                     continue;
                 }
                 // Product term: AND of this cube's fixed literals (don't-cares skipped). A fully
-                // don't-care cube is the constant `true`.
+                // don't-care cube is the constant `true`; one with an empty multiple-valued field
+                // covers nothing.
+                let Some(literals) = cube.inputs().literals(layout) else {
+                    continue;
+                };
                 let mut term = s.constant(true);
                 let labels = cube.inputs().vars();
-                for (label, value) in labels.iter().zip(cube.inputs().iter()) {
-                    match value {
-                        Some(true) => {
-                            term = term & s.var(label.as_ref());
-                        }
-                        Some(false) => {
-                            term = term & !s.var(label.as_ref());
-                        }
-                        None => {}
+                for (i, polarity) in literals {
+                    let var = s.var(labels[i].as_ref());
+                    term = term & if polarity { var } else { !var };
+                }
+                let mut positive = !cube.outputs().iter().any(|asserted| asserted);
+                for (j, _) in cube.outputs().iter().enumerate().filter(|&(_, a)| a) {
+                    match complemented.binary_search(&j) {
+                        Ok(k) => negated[k] = negated[k] | term,
+                        Err(_) => positive = true,
                     }
                 }
-                acc = acc | term;
+                if positive {
+                    acc = acc | term;
+                }
             }
-            acc
+            negated.into_iter().fold(acc, |acc, g| acc | !g)
         })
     }
 
@@ -406,6 +406,36 @@ impl<B: Brand, C: ManagerCell> Bdd<B, C> {
         self.root == FALSE_NODE
     }
 
+    /// Number of satisfying assignments over `num_vars` variables: this function's support plus
+    /// `num_vars - var_count()` variables it does not depend on.
+    ///
+    /// Counted in one [`fold`](Self::fold): a node's count over all `num_vars` variables is half the
+    /// sum of its children's, and both halves are exact because the tested variable is free below it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_vars` is less than [`var_count`](Self::var_count) or greater than 127 (the
+    /// count must fit a `u128`).
+    #[must_use]
+    pub fn count_minterms(&self, num_vars: usize) -> u128 {
+        assert!(
+            num_vars < 128 && num_vars >= self.var_count(),
+            "count_minterms over {} variables: must cover the support and be at most 127",
+            num_vars
+        );
+        let all = 1u128 << num_vars;
+        self.fold(|node| match node {
+            BddNode::Terminal(value) => {
+                if value {
+                    all
+                } else {
+                    0
+                }
+            }
+            BddNode::Decision { low, high, .. } => low / 2 + high / 2,
+        })
+    }
+
     // ---- Canonical equivalence ----------------------------------------------------------------
 
     /// Whether `self` and `other` denote the same Boolean function. O(1).
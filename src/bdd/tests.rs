@@ -869,6 +869,25 @@ fn tautology_and_contradiction() {
     assert!((a.clone() & !a).is_contradiction());
 }
 
+#[test]
+fn count_minterms_over_support_and_free_variables() {
+    let builder: BddBuilder<BrandA, LocalCell> = BddBuilder::new();
+    let maj = builder.parse("a & b | a & c | b & c").unwrap();
+    assert_eq!(maj.count_minterms(3), 4);
+    // Each variable the function ignores doubles the count.
+    assert_eq!(maj.count_minterms(5), 16);
+    assert_eq!(builder.parse("a ^ b ^ c ^ d").unwrap().count_minterms(4), 8);
+    assert_eq!(builder.constant(true).count_minterms(127), 1 << 127);
+    assert_eq!(builder.constant(false).count_minterms(0), 0);
+}
+
+#[test]
+#[should_panic(expected = "must cover the support")]
+fn count_minterms_rejects_fewer_variables_than_the_support() {
+    let builder: BddBuilder<BrandA, LocalCell> = BddBuilder::new();
+    let _ = builder.parse("a & b").unwrap().count_minterms(1);
+}
+
 // ---- Operators and canonicity ---------------------------------------------------------------------
 
 #[test]
@@ -1008,6 +1027,36 @@ fn build_cover_round_trip() {
     assert!(rebuilt.equivalent_to(&f));
 }
 
+#[test]
+fn build_cover_negates_complemented_outputs() {
+    use crate::cover::Minimizable;
+
+    let builder: BddBuilder<BrandA, LocalCell> = BddBuilder::new();
+    let mut cover = Cover::new(CoverType::F);
+    cover.add_expr(&crate::expr!("a" + "b"), "f").unwrap();
+    // Minimised in complement, f's single cube covers !a & !b.
+    let min = cover
+        .with_output_phase([false])
+        .unwrap()
+        .minimize()
+        .unwrap();
+    assert_eq!(min.complemented_outputs(), vec![0]);
+
+    let a = builder.var("a");
+    let b = builder.var("b");
+    assert!(builder
+        .build_cover(&min)
+        .equivalent_to(&(a.clone() | b.clone())));
+
+    // Alongside an output taken as given, the characteristic function is still the OR of both.
+    let mut two = min.clone();
+    two.add_expr(&crate::expr!("a" * "c"), "g").unwrap();
+    let c = builder.var("c");
+    assert!(builder
+        .build_cover(&two)
+        .equivalent_to(&(a.clone() | b | (a & c))));
+}
+
 #[test]
 fn contradiction_lowers_without_panicking() {
     use crate::BoolExpr;
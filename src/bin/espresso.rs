@@ -3,7 +3,12 @@
 //! A clean Rust implementation using the safe Cover API with process isolation
 
 use clap::{Parser, ValueEnum};
-use espresso_logic::{CoverType, EspressoConfig, Minimizable, PLAWriter, PlaCover, Symbol};
+use espresso_logic::cover::pla::PLAWriteError;
+use espresso_logic::{
+    CoverType, EspressoConfig, Minimizable, PLAWriter, PairingCost, PairingMinimizer,
+    PairingSearch, PhaseStrategy, PlaCover, SingleOutputStrategy, Symbol,
+};
+use std::io::{BufWriter, Write};
 use std::path::PathBuf;
 use std::process;
 
@@ -19,6 +24,19 @@ enum Command {
     Espresso,
     /// Exact minimisation (guarantees a minimal result; slower on large inputs)
     Exact,
+    /// Exact minimisation by the signature cube algorithm (faster on some multi-output tables)
+    Signature,
+    /// Output phase assignment: implement each output in whichever phase needs fewer terms
+    Opo,
+    /// Minimise each output on its own, sharing no terms between outputs (exactly with -e)
+    So,
+    /// As so, but implement each output in whichever phase needs fewer terms
+    #[value(name = "so_both")]
+    SoBoth,
+    /// Pair binary inputs into two-bit decoders, choosing the pairs by an algebraic estimate
+    Pair,
+    /// Pair binary inputs into two-bit decoders, trying every pairing
+    Pairall,
     /// Echo the PLA without modification
     Echo,
     /// Print statistics about the PLA
@@ -145,6 +163,9 @@ fn main() {
         ..Default::default()
     };
 
+    // A comment line some commands print ahead of the PLA.
+    let mut preamble: Option<String> = None;
+
     // Execute the command using the Cover trait
     match command {
         Command::Espresso => {
@@ -171,6 +192,72 @@ fn main() {
                 }
             };
         }
+        Command::Signature => {
+            if args.summary {
+                eprintln!("Running signature cube minimization (process-isolated)...");
+            }
+            cover = match cover.minimize_signature_with_config(&config) {
+                Ok(c) => c,
+                Err(e) => {
+                    eprintln!("Error during minimization: {}", e);
+                    process::exit(1);
+                }
+            };
+        }
+        Command::Opo => {
+            if args.summary {
+                eprintln!("Running output phase assignment (process-isolated)...");
+            }
+            cover = match cover
+                .try_assign_output_phase_with_config(PhaseStrategy::default(), &config)
+            {
+                Ok(c) => c,
+                Err(e) => {
+                    eprintln!("Error during minimization: {}", e);
+                    process::exit(1);
+                }
+            };
+        }
+        Command::So | Command::SoBoth => {
+            if args.summary {
+                eprintln!("Running single-output minimization (process-isolated)...");
+            }
+            let strategy = SingleOutputStrategy {
+                both_phases: command == Command::SoBoth,
+                exact: args.exact,
+            };
+            cover = match cover.try_minimize_single_output_with_config(strategy, &config) {
+                Ok(c) => c,
+                Err(e) => {
+                    eprintln!("Error during minimization: {}", e);
+                    process::exit(1);
+                }
+            };
+        }
+        Command::Pair | Command::Pairall => {
+            if args.summary {
+                eprintln!("Running input bit pairing (process-isolated)...");
+            }
+            let search = if command == Command::Pair {
+                PairingSearch::Estimated(PairingCost::default())
+            } else {
+                PairingSearch::Exhaustive(PairingMinimizer::default())
+            };
+            let pairs;
+            (cover, pairs) = match cover.try_find_input_pairing_with_config(search, &config) {
+                Ok(c) => c,
+                Err(e) => {
+                    eprintln!("Error during minimization: {}", e);
+                    process::exit(1);
+                }
+            };
+            // Like C, report the chosen pairs (1-based) ahead of the PLA.
+            let mut line = String::from("# pair is");
+            for (a, b) in pairs {
+                line.push_str(&format!(" ({} {})", a + 1, b + 1));
+            }
+            preamble = Some(line);
+        }
         Command::Echo => {
             if args.summary {
                 eprintln!("Echoing PLA without modification...");
@@ -203,8 +290,18 @@ fn main() {
         let output_type = CoverType::from(args.output_format);
 
         if let Some(ref output_path) = args.output_file {
-            match cover.to_pla_file(output_path, output_type) {
-                Ok(_) => {
+            let written = std::fs::File::create(output_path)
+                .map_err(PLAWriteError::from)
+                .and_then(|file| {
+                    write_output(
+                        &mut BufWriter::new(file),
+                        preamble.as_deref(),
+                        &cover,
+                        output_type,
+                    )
+                });
+            match written {
+                Ok(()) => {
                     if args.summary {
                         eprintln!("Wrote output to: {}", output_path.display());
                     }
@@ -218,7 +315,7 @@ fn main() {
             // Write to stdout
             let stdout = std::io::stdout();
             let mut handle = stdout.lock();
-            if let Err(e) = cover.write_pla(&mut handle, output_type) {
+            if let Err(e) = write_output(&mut handle, preamble.as_deref(), &cover, output_type) {
                 eprintln!("Error writing PLA output: {}", e);
                 process::exit(1);
             }
@@ -229,3 +326,18 @@ fn main() {
         eprintln!("Done.");
     }
 }
+
+/// Write `cover` as a PLA, after the `preamble` comment line if there is one.
+fn write_output<W: Write>(
+    writer: &mut W,
+    preamble: Option<&str>,
+    cover: &PlaCover<Symbol>,
+    output_type: CoverType,
+) -> Result<(), PLAWriteError> {
+    if let Some(line) = preamble {
+        writeln!(writer, "{line}")?;
+    }
+    cover.write_pla(writer, output_type)?;
+    writer.flush()?;
+    Ok(())
+}
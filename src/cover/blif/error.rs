@@ -0,0 +1,285 @@
+//! Error types for BLIF format parsing and writing
+
+use std::fmt;
+use std::io;
+use std::sync::Arc;
+
+/// Errors related to BLIF format parsing and validation
+///
+/// These errors occur when reading a BLIF file that is malformed or describes more than a two-level
+/// combinational model.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BLIFError {
+    /// A directive this reader does not support: latches (`.latch`, `.mlatch`), library gates
+    /// (`.gate`), hierarchy (`.subckt`, `.search`) and embedded state machines (`.start_kiss`).
+    /// Only a combinational model of `.names` blocks can become a cover.
+    UnsupportedDirective {
+        /// The directive as written
+        directive: Arc<str>,
+    },
+    /// The file declares a second `.model`. Hierarchical BLIF is not supported, so a file holds
+    /// exactly one model.
+    MultipleModels,
+    /// A `.names` directive lists no signals (it needs at least the signal it drives).
+    EmptyNamesDirective,
+    /// A `.names` block drives a signal that is not a primary output. Multi-level netlists with
+    /// internal signals are not collapsed: every block must give one output over the inputs.
+    InternalSignal {
+        /// The driven signal
+        name: Arc<str>,
+    },
+    /// A `.names` block reads a signal that is not a primary input.
+    UnknownSignal {
+        /// The signal read
+        name: Arc<str>,
+    },
+    /// A signal is declared twice in `.inputs`/`.outputs`, listed twice in one `.names`, or driven
+    /// by two `.names` blocks.
+    DuplicateSignal {
+        /// The repeated signal
+        name: Arc<str>,
+    },
+    /// A primary output has no `.names` block driving it.
+    UndrivenOutput {
+        /// The undriven output
+        name: Arc<str>,
+    },
+    /// A cube row appears before any `.names` directive.
+    CubeOutsideNames,
+    /// Invalid character in the input plane of a cube row (only `0`, `1` and `-` are allowed)
+    InvalidInputCharacter {
+        /// The invalid character
+        character: char,
+        /// Position in the input plane
+        position: usize,
+    },
+    /// Invalid output value in a cube row (only `0` and `1` are allowed)
+    InvalidOutputValue {
+        /// The invalid value string
+        value: Arc<str>,
+    },
+    /// A cube row's input plane does not have one character per input of its `.names` block.
+    CubeWidthMismatch {
+        /// Number of inputs the `.names` block lists
+        expected: usize,
+        /// Number of characters in the row
+        actual: usize,
+    },
+    /// A `.names` block mixes ON-set rows (output `1`) with OFF-set rows (output `0`), which BLIF
+    /// forbids; under `.exdc`, where rows give the don't-care set, OFF-set rows are rejected too.
+    MixedOutputValues {
+        /// The signal the block drives
+        name: Arc<str>,
+    },
+}
+
+impl fmt::Display for BLIFError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BLIFError::UnsupportedDirective { directive } => {
+                write!(f, "Unsupported BLIF directive: '{}'", directive)
+            }
+            BLIFError::MultipleModels => {
+                write!(f, "BLIF file declares more than one .model")
+            }
+            BLIFError::EmptyNamesDirective => {
+                write!(f, ".names directive lists no signals")
+            }
+            BLIFError::InternalSignal { name } => {
+                write!(f, ".names drives '{}', which is not a primary output", name)
+            }
+            BLIFError::UnknownSignal { name } => {
+                write!(f, ".names reads '{}', which is not a primary input", name)
+            }
+            BLIFError::DuplicateSignal { name } => {
+                write!(f, "signal '{}' is declared or driven more than once", name)
+            }
+            BLIFError::UndrivenOutput { name } => {
+                write!(f, "output '{}' is not driven by any .names block", name)
+            }
+            BLIFError::CubeOutsideNames => {
+                write!(f, "cube row outside a .names block")
+            }
+            BLIFError::InvalidInputCharacter {
+                character,
+                position,
+            } => {
+                write!(
+                    f,
+                    "Invalid input character '{}' at position {}",
+                    character, position
+                )
+            }
+            BLIFError::InvalidOutputValue { value } => {
+                write!(f, "Invalid output value: '{}'", value)
+            }
+            BLIFError::CubeWidthMismatch { expected, actual } => write!(
+                f,
+                "Cube row has {} inputs but its .names block lists {}",
+                actual, expected
+            ),
+            BLIFError::MixedOutputValues { name } => {
+                write!(
+                    f,
+                    ".names block for '{}' mixes ON-set and OFF-set rows",
+                    name
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for BLIFError {}
+
+impl From<BLIFError> for io::Error {
+    fn from(err: BLIFError) -> Self {
+        io::Error::new(io::ErrorKind::InvalidData, err)
+    }
+}
+
+/// Errors that can occur when reading BLIF format data
+///
+/// This error type is returned by the `Cover::from_blif_*` methods.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum BLIFReadError {
+    /// BLIF format error
+    BLIF(BLIFError),
+    /// IO error during reading
+    Io(io::Error),
+}
+
+impl fmt::Display for BLIFReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BLIFReadError::BLIF(e) => write!(f, "BLIF format error: {}", e),
+            BLIFReadError::Io(e) => write!(f, "IO error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for BLIFReadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BLIFReadError::BLIF(e) => Some(e),
+            BLIFReadError::Io(e) => Some(e),
+        }
+    }
+}
+
+impl From<BLIFError> for BLIFReadError {
+    fn from(err: BLIFError) -> Self {
+        BLIFReadError::BLIF(err)
+    }
+}
+
+impl From<io::Error> for BLIFReadError {
+    fn from(err: io::Error) -> Self {
+        BLIFReadError::Io(err)
+    }
+}
+
+impl From<BLIFReadError> for io::Error {
+    fn from(err: BLIFReadError) -> Self {
+        match err {
+            BLIFReadError::Io(e) => e,
+            BLIFReadError::BLIF(e) => io::Error::new(io::ErrorKind::InvalidData, e),
+        }
+    }
+}
+
+/// Errors that can occur when writing BLIF format data
+///
+/// This error type is returned by the [`BLIFWriter`](crate::BLIFWriter) methods
+/// (`write_blif`, `to_blif_string`, `to_blif_file`).
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum BLIFWriteError {
+    /// The cover has multiple-valued inputs (see [`Cover::mv_layout`](crate::Cover::mv_layout)),
+    /// which BLIF's binary `.names` blocks cannot express.
+    MultipleValuedInputs,
+    /// IO error during writing
+    Io(io::Error),
+}
+
+impl fmt::Display for BLIFWriteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BLIFWriteError::MultipleValuedInputs => {
+                write!(f, "BLIF cannot express a cover with multiple-valued inputs")
+            }
+            BLIFWriteError::Io(e) => write!(f, "IO error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for BLIFWriteError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BLIFWriteError::MultipleValuedInputs => None,
+            BLIFWriteError::Io(e) => Some(e),
+        }
+    }
+}
+
+impl From<io::Error> for BLIFWriteError {
+    fn from(err: io::Error) -> Self {
+        BLIFWriteError::Io(err)
+    }
+}
+
+impl From<BLIFWriteError> for io::Error {
+    fn from(err: BLIFWriteError) -> Self {
+        match err {
+            BLIFWriteError::Io(e) => e,
+            e @ BLIFWriteError::MultipleValuedInputs => {
+                io::Error::new(io::ErrorKind::InvalidInput, e)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blif_error_messages() {
+        let err = BLIFError::UnsupportedDirective {
+            directive: Arc::from(".latch"),
+        };
+        assert!(err.to_string().contains("'.latch'"));
+        let err = BLIFError::InternalSignal {
+            name: Arc::from("t"),
+        };
+        assert!(err.to_string().contains("'t'"));
+        assert!(err.to_string().contains("primary output"));
+        let err = BLIFError::CubeWidthMismatch {
+            expected: 3,
+            actual: 2,
+        };
+        assert!(err.to_string().contains("2 inputs"));
+        assert!(err.to_string().contains("lists 3"));
+    }
+
+    #[test]
+    fn test_blif_read_error_conversions() {
+        let read_err: BLIFReadError = BLIFError::MultipleModels.into();
+        assert!(matches!(read_err, BLIFReadError::BLIF(_)));
+        let io_err: io::Error = read_err.into();
+        assert_eq!(io_err.kind(), io::ErrorKind::InvalidData);
+
+        let read_err: BLIFReadError = io::Error::new(io::ErrorKind::NotFound, "missing").into();
+        let io_err: io::Error = read_err.into();
+        assert_eq!(io_err.kind(), io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn test_blif_write_error_to_io_error() {
+        let io_err: io::Error = BLIFWriteError::MultipleValuedInputs.into();
+        assert_eq!(io_err.kind(), io::ErrorKind::InvalidInput);
+        let write_err: BLIFWriteError = io::Error::other("disk full").into();
+        assert!(matches!(write_err, BLIFWriteError::Io(_)));
+    }
+}
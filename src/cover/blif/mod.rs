@@ -0,0 +1,526 @@
+//! BLIF (Berkeley Logic Interchange Format) support
+//!
+//! This module reads and writes covers in BLIF, the netlist format of the Berkeley synthesis tools
+//! (SIS, ABC, VPR, …). A BLIF model names its primary inputs and outputs (`.inputs`, `.outputs`)
+//! and describes each signal as a `.names` block: the signals it reads, then the one it drives,
+//! followed by the rows of a single-output truth table.
+//!
+//! A [`Cover`] is a two-level multi-output function, so it maps onto one `.names` block per
+//! output, each reading primary inputs only:
+//!
+//! - [`Cover::from_blif_reader`] (and the `_string`/`_file` forms) reads such a model into a
+//!   [`Cover<S, S>`](Cover) labelled by its `.inputs` and `.outputs`;
+//! - [`BLIFWriter`] writes any [`Cover`] whose labels render as names back out.
+//!
+//! # Quick Example
+//!
+//! ```
+//! use espresso_logic::{BLIFWriter, Cover, Minimizable, Symbol};
+//!
+//! # fn main() -> std::io::Result<()> {
+//! let blif = "\
+//! .model maj
+//! .inputs a b c
+//! .outputs f
+//! .names a b c f
+//! 11- 1
+//! 1-1 1
+//! -11 1
+//! 111 1
+//! .end
+//! ";
+//! let cover = Cover::<Symbol, Symbol>::from_blif_string(blif)?;
+//! let minimised = cover.minimize()?;
+//! assert_eq!(minimised.num_cubes(), 3);
+//!
+//! let text = minimised.to_blif_string("maj")?;
+//! assert!(text.starts_with(".model maj\n.inputs a b c\n.outputs f\n.names a b c f\n"));
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # What Is Read
+//!
+//! - `.model` names the model; the name is not kept. `.end` ends it.
+//! - `.inputs` and `.outputs` (which may each appear more than once) give the cover's input and
+//!   output labels, in order.
+//! - Each `.names` block drives one primary output from primary inputs. Its rows are `plane value`,
+//!   the plane holding one `0`, `1` or `-` per input of the block (a block with no inputs has a
+//!   bare `value`). An input the block does not read is a don't-care in every cube. Rows with value
+//!   `1` list the ON-set; rows with value `0` list the OFF-set, and the output is then read in
+//!   complement (see [`Cover::complemented_outputs`]). A block with no rows is constant `0`.
+//! - `.exdc` starts the external don't-care network: the `.names` blocks after it list each
+//!   output's don't-care set, and the cover is read as [`CoverType::FD`]. `.inputs`/`.outputs`
+//!   repeated there are ignored.
+//! - Lines ending in `\` continue on the next line, and `#` starts a comment.
+//!
+//! Sequential and hierarchical constructs (`.latch`, `.subckt`, `.gate`, …) are rejected, as are
+//! `.names` blocks that drive or read internal signals: this reader does not collapse multi-level
+//! logic. See [`BLIFError`] for the full list. Other directives (timing annotations and the like)
+//! are skipped.
+
+pub mod error;
+
+pub use error::{BLIFError, BLIFReadError, BLIFWriteError};
+
+use std::collections::HashMap;
+use std::fmt;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Cursor, Write};
+use std::path::Path;
+use std::sync::Arc;
+
+use super::conversions::{anonymous_cover_from_raw, RawCube};
+use super::label::{Label, StringLabel};
+use super::minterm::InputField;
+use super::symbols::Symbols;
+use super::{Cover, CoverType, CubeType, Phase};
+
+/// Trait for types that support BLIF serialisation (writing)
+///
+/// Implemented for every [`Cover<I, O>`](crate::Cover) whose input and output labels render as
+/// names ([`Display`](std::fmt::Display)); a positional ([`Anonymous`](crate::Anonymous)) side has
+/// no names to give BLIF's signals, so [`relabel`](crate::Cover::relabel) it first.
+pub trait BLIFWriter {
+    /// Write this cover as a BLIF model called `model`
+    ///
+    /// Each output becomes one `.names` block over the inputs its ON-set cubes depend on, with one
+    /// row per cube. An output whose cubes cover its complement (see
+    /// [`Cover::complemented_outputs`](crate::Cover::complemented_outputs)) is written with OFF-set
+    /// rows. Don't-care cubes, if the cover type includes them, follow as an `.exdc` network;
+    /// OFF-set cubes are not written.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BLIFWriteError::MultipleValuedInputs`] for a cover with multiple-valued inputs, and
+    /// [`BLIFWriteError::Io`] if writing fails.
+    fn write_blif<W: Write>(&self, writer: &mut W, model: &str) -> Result<(), BLIFWriteError>;
+
+    /// Convert this cover to a BLIF string
+    ///
+    /// This is a convenience method that delegates to `write_blif`.
+    fn to_blif_string(&self, model: &str) -> Result<String, BLIFWriteError> {
+        let mut buffer = Vec::new();
+        self.write_blif(&mut buffer, model)?;
+        // `write_blif` only writes formatted Rust strings, so the bytes are valid UTF-8.
+        Ok(String::from_utf8(buffer).expect("BLIF output is built from UTF-8 Rust strings"))
+    }
+
+    /// Write this cover to a BLIF file
+    ///
+    /// This method delegates to `write_blif`, which writes without building the entire string in
+    /// memory first.
+    fn to_blif_file<P: AsRef<Path>>(&self, path: P, model: &str) -> Result<(), BLIFWriteError> {
+        let file = File::create(path)?;
+        let mut writer = BufWriter::new(file);
+        self.write_blif(&mut writer, model)?;
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+impl<I: Label + fmt::Display, O: Label + fmt::Display> BLIFWriter for Cover<I, O> {
+    fn write_blif<W: Write>(&self, writer: &mut W, model: &str) -> Result<(), BLIFWriteError> {
+        if self.mv_layout().is_some_and(|l| !l.mv_sizes().is_empty()) {
+            return Err(BLIFWriteError::MultipleValuedInputs);
+        }
+        let inputs = self.input_labels();
+        let outputs = self.output_labels();
+
+        writeln!(writer, ".model {}", model)?;
+        write!(writer, ".inputs")?;
+        for label in inputs {
+            write!(writer, " {}", label)?;
+        }
+        writeln!(writer)?;
+        write!(writer, ".outputs")?;
+        for label in outputs {
+            write!(writer, " {}", label)?;
+        }
+        writeln!(writer)?;
+
+        let complemented = self.complemented_outputs();
+        for (j, output) in outputs.iter().enumerate() {
+            let value = if complemented.contains(&j) { '0' } else { '1' };
+            self.write_names_block(writer, j, output, CubeType::F, value)?;
+        }
+
+        // The don't-care set, as SIS writes it: a second network of `.names` blocks, one for each
+        // output that has any.
+        if self.cover_type.has_d() {
+            let has_dc = |j: usize| self.cubes().any(|c| c.set == CubeType::D && c.asserts(j));
+            let mut dc_outputs = (0..outputs.len()).filter(|&j| has_dc(j)).peekable();
+            if dc_outputs.peek().is_some() {
+                writeln!(writer, ".exdc")?;
+            }
+            for j in dc_outputs {
+                self.write_names_block(writer, j, &outputs[j], CubeType::D, '1')?;
+            }
+        }
+
+        writeln!(writer, ".end")?;
+        Ok(())
+    }
+}
+
+impl<I: Label + fmt::Display, O: Label + fmt::Display> Cover<I, O> {
+    /// Write the `.names` block for output `j` from its cubes of type `set`, each row ending in
+    /// `value`. The block reads only the inputs some row restricts.
+    fn write_names_block<W: Write>(
+        &self,
+        writer: &mut W,
+        j: usize,
+        output: &O,
+        set: CubeType,
+        value: char,
+    ) -> Result<(), BLIFWriteError> {
+        // A cube with an empty input field covers no point, so it adds no row.
+        let rows: Vec<Vec<InputField>> = self
+            .cubes()
+            .filter(|cube| cube.set == set && cube.asserts(j))
+            .map(|cube| cube.inputs.fields().collect::<Vec<_>>())
+            .filter(|fields| !fields.contains(&InputField::Empty))
+            .collect();
+        let support: Vec<usize> = (0..self.num_inputs())
+            .filter(|&i| rows.iter().any(|fields| fields[i] != InputField::DontCare))
+            .collect();
+
+        write!(writer, ".names")?;
+        for &i in &support {
+            write!(writer, " {}", self.input_labels()[i])?;
+        }
+        writeln!(writer, " {}", output)?;
+
+        // No rows means constant 0, so a complemented output with an empty OFF-set is written as
+        // the constant-1 row instead.
+        if rows.is_empty() && value == '0' {
+            writeln!(writer, "1")?;
+        }
+        for fields in &rows {
+            for &i in &support {
+                let c = match fields[i] {
+                    InputField::Zero => '0',
+                    InputField::One => '1',
+                    InputField::DontCare | InputField::Empty => '-',
+                };
+                write!(writer, "{}", c)?;
+            }
+            if support.is_empty() {
+                writeln!(writer, "{}", value)?;
+            } else {
+                writeln!(writer, " {}", value)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// One `.names` block as read: the signals it reads, the one it drives, its rows as
+/// `(plane, value)`, and whether it belongs to the `.exdc` network.
+struct NamesBlock {
+    inputs: Vec<String>,
+    output: String,
+    rows: Vec<(Vec<InputField>, bool)>,
+    exdc: bool,
+}
+
+/// Raw BLIF components from [`parse_blif`], before the labels are given a concrete type.
+struct ParsedBlif {
+    inputs: Vec<String>,
+    outputs: Vec<String>,
+    cubes: Vec<RawCube>,
+    /// One flag per output: `true` where its block lists the OFF-set.
+    complemented: Vec<bool>,
+    /// Whether the file has an `.exdc` network.
+    exdc: bool,
+}
+
+/// Directives naming sequential or hierarchical constructs, which a cover cannot represent.
+const UNSUPPORTED_DIRECTIVES: &[&str] = &[
+    ".latch",
+    ".mlatch",
+    ".clock",
+    ".gate",
+    ".subckt",
+    ".search",
+    ".start_kiss",
+];
+
+/// Read the logical lines of a BLIF stream: comments stripped, `\`-continued lines joined, and
+/// blank lines dropped.
+fn logical_lines<R: BufRead>(reader: R) -> impl Iterator<Item = std::io::Result<String>> {
+    let mut lines = reader.lines();
+    std::iter::from_fn(move || {
+        let mut logical = String::new();
+        loop {
+            let line = match lines.next() {
+                Some(Ok(line)) => line,
+                Some(Err(e)) => return Some(Err(e)),
+                None if logical.trim().is_empty() => return None,
+                None => return Some(Ok(logical)),
+            };
+            let line = line.split('#').next().unwrap_or("");
+            match line.trim_end().strip_suffix('\\') {
+                Some(head) => {
+                    logical.push_str(head);
+                    logical.push(' ');
+                }
+                None => {
+                    logical.push_str(line);
+                    if !logical.trim().is_empty() {
+                        return Some(Ok(logical));
+                    }
+                    logical.clear();
+                }
+            }
+        }
+    })
+}
+
+/// Parse one cube row of a `.names` block reading `width` signals.
+fn parse_row(words: &[&str], width: usize) -> Result<(Vec<InputField>, bool), BLIFError> {
+    let (plane, value) = match words {
+        [value] if width == 0 => ("", *value),
+        [plane, value] => (*plane, *value),
+        // A row missing its value is reported once its plane checks out.
+        [plane] => (*plane, ""),
+        _ => {
+            return Err(BLIFError::InvalidOutputValue {
+                value: Arc::from(words[1..].join(" ")),
+            })
+        }
+    };
+    let fields = plane
+        .chars()
+        .enumerate()
+        .map(|(position, character)| match character {
+            '0' => Ok(InputField::Zero),
+            '1' => Ok(InputField::One),
+            '-' => Ok(InputField::DontCare),
+            _ => Err(BLIFError::InvalidInputCharacter {
+                character,
+                position,
+            }),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if fields.len() != width {
+        return Err(BLIFError::CubeWidthMismatch {
+            expected: width,
+            actual: fields.len(),
+        });
+    }
+    let value = match value {
+        "1" => true,
+        "0" => false,
+        _ => {
+            return Err(BLIFError::InvalidOutputValue {
+                value: Arc::from(value),
+            })
+        }
+    };
+    Ok((fields, value))
+}
+
+/// The position of each name in `names`, rejecting a repeat.
+fn positions(names: &[String]) -> Result<HashMap<&str, usize>, BLIFError> {
+    let mut map = HashMap::with_capacity(names.len());
+    for (k, name) in names.iter().enumerate() {
+        if map.insert(name.as_str(), k).is_some() {
+            return Err(BLIFError::DuplicateSignal {
+                name: Arc::from(name.as_str()),
+            });
+        }
+    }
+    Ok(map)
+}
+
+/// Parse a BLIF stream into its raw components: the interface, and one cube per row of each
+/// `.names` block, laid out over the primary inputs.
+fn parse_blif<R: BufRead>(reader: R) -> Result<ParsedBlif, BLIFReadError> {
+    let mut inputs: Vec<String> = Vec::new();
+    let mut outputs: Vec<String> = Vec::new();
+    let mut blocks: Vec<NamesBlock> = Vec::new();
+    let mut model = false;
+    let mut exdc = false;
+
+    for line in logical_lines(reader) {
+        let line = line?;
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let Some(&first) = words.first() else {
+            continue;
+        };
+
+        if !first.starts_with('.') {
+            let block = blocks.last_mut().ok_or(BLIFError::CubeOutsideNames)?;
+            let row = parse_row(&words, block.inputs.len())?;
+            block.rows.push(row);
+            continue;
+        }
+
+        match first {
+            ".model" => {
+                if model {
+                    return Err(BLIFError::MultipleModels.into());
+                }
+                model = true;
+            }
+            // The `.exdc` network restates the interface; only its `.names` blocks matter.
+            ".inputs" if !exdc => inputs.extend(words[1..].iter().map(|s| s.to_string())),
+            ".outputs" if !exdc => outputs.extend(words[1..].iter().map(|s| s.to_string())),
+            ".names" => {
+                let (output, block_inputs) = words[1..]
+                    .split_last()
+                    .ok_or(BLIFError::EmptyNamesDirective)?;
+                blocks.push(NamesBlock {
+                    inputs: block_inputs.iter().map(|s| s.to_string()).collect(),
+                    output: output.to_string(),
+                    rows: Vec::new(),
+                    exdc,
+                });
+            }
+            ".exdc" => exdc = true,
+            ".end" => break,
+            _ if UNSUPPORTED_DIRECTIVES.contains(&first) => {
+                return Err(BLIFError::UnsupportedDirective {
+                    directive: Arc::from(first),
+                }
+                .into());
+            }
+            _ => {}
+        }
+    }
+
+    let input_positions = positions(&inputs)?;
+    let output_positions = positions(&outputs)?;
+    let mut driven = vec![false; outputs.len()];
+    let mut dc_driven = vec![false; outputs.len()];
+    let mut complemented = vec![false; outputs.len()];
+    let mut cubes: Vec<RawCube> = Vec::new();
+
+    for block in blocks {
+        let signal = |name: &str| Arc::from(name);
+        let j = *output_positions.get(block.output.as_str()).ok_or_else(|| {
+            BLIFError::InternalSignal {
+                name: signal(&block.output),
+            }
+        })?;
+        let columns = block
+            .inputs
+            .iter()
+            .map(|name| {
+                input_positions
+                    .get(name.as_str())
+                    .copied()
+                    .ok_or_else(|| BLIFError::UnknownSignal { name: signal(name) })
+            })
+            .collect::<Result<Vec<usize>, _>>()?;
+        let mut seen = vec![false; inputs.len()];
+        for (&i, name) in columns.iter().zip(&block.inputs) {
+            if std::mem::replace(&mut seen[i], true) {
+                return Err(BLIFError::DuplicateSignal { name: signal(name) }.into());
+            }
+        }
+        let drivers = if block.exdc {
+            &mut dc_driven
+        } else {
+            &mut driven
+        };
+        if std::mem::replace(&mut drivers[j], true) {
+            return Err(BLIFError::DuplicateSignal {
+                name: signal(&block.output),
+            }
+            .into());
+        }
+
+        // Every row of a block lists the same set; a don't-care block lists it directly.
+        let on_set = block.rows.first().is_none_or(|&(_, value)| value);
+        if block.rows.iter().any(|&(_, value)| value != on_set) || (block.exdc && !on_set) {
+            return Err(BLIFError::MixedOutputValues {
+                name: signal(&block.output),
+            }
+            .into());
+        }
+        if !block.exdc {
+            complemented[j] = !on_set;
+        }
+        let set = if block.exdc { CubeType::D } else { CubeType::F };
+        for (plane, _) in block.rows {
+            let mut fields = vec![InputField::DontCare; inputs.len()];
+            for (&i, field) in columns.iter().zip(plane) {
+                fields[i] = field;
+            }
+            let mut mask = vec![false; outputs.len()];
+            mask[j] = true;
+            cubes.push((fields, mask, set));
+        }
+    }
+
+    if let Some(j) = driven.iter().position(|&d| !d) {
+        return Err(BLIFError::UndrivenOutput {
+            name: Arc::from(outputs[j].as_str()),
+        }
+        .into());
+    }
+
+    Ok(ParsedBlif {
+        inputs,
+        outputs,
+        cubes,
+        complemented,
+        exdc: dc_driven.contains(&true),
+    })
+}
+
+impl<S: StringLabel> Cover<S, S> {
+    /// Parse a cover from a BLIF model in any `BufRead`, labelled by its `.inputs` and `.outputs`.
+    ///
+    /// Each `.names` block becomes the cubes of one output; see the [module
+    /// documentation](crate::cover::blif) for what is accepted. The cover is
+    /// [`CoverType::F`], or [`CoverType::FD`] if the model has an `.exdc` don't-care network. An
+    /// output listed by its OFF-set is read in complement: its cubes cover the complement, and it
+    /// appears in [`complemented_outputs`](Self::complemented_outputs).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BLIFReadError::BLIF`] for a malformed or unsupported model, and
+    /// [`BLIFReadError::Io`] if reading fails.
+    pub fn from_blif_reader<R: BufRead>(reader: R) -> Result<Self, BLIFReadError> {
+        let p = parse_blif(reader)?;
+        let cover_type = if p.exdc { CoverType::FD } else { CoverType::F };
+        let mut base =
+            anonymous_cover_from_raw(p.inputs.len(), p.outputs.len(), p.cubes, cover_type);
+        if p.complemented.contains(&true) {
+            let positive: Arc<[bool]> = p.complemented.iter().map(|&c| !c).collect();
+            base.phase = Some(Phase::Applied(positive));
+        }
+        let to_syms = |labels: Vec<String>| -> Arc<Symbols<S>> {
+            // `parse_blif` rejects a repeated `.inputs`/`.outputs` name (BLIFError::DuplicateSignal).
+            Symbols::new(labels.iter().map(|s| S::from(s.as_str())).collect())
+                .expect("BLIF signals are duplicate-checked during parsing")
+        };
+        Ok(base
+            .relabel_tables(to_syms(p.inputs), to_syms(p.outputs))
+            .expect("the raw cubes are laid out over the declared inputs and outputs"))
+    }
+
+    /// Parse a cover from a BLIF-format string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use espresso_logic::{Cover, Symbol};
+    ///
+    /// let blif = ".model and\n.inputs a b\n.outputs f\n.names a b f\n11 1\n.end\n";
+    /// let cover = Cover::<Symbol, Symbol>::from_blif_string(blif).unwrap();
+    /// assert_eq!(cover.num_inputs(), 2);
+    /// assert_eq!(cover.output_labels()[0].as_ref(), "f");
+    /// ```
+    pub fn from_blif_string<T: AsRef<str>>(s: T) -> Result<Self, BLIFReadError> {
+        Self::from_blif_reader(Cursor::new(s.as_ref().as_bytes()))
+    }
+
+    /// Load a cover from a BLIF file.
+    pub fn from_blif_file<P: AsRef<Path>>(path: P) -> Result<Self, BLIFReadError> {
+        Self::from_blif_reader(BufReader::new(File::open(path)?))
+    }
+}
@@ -47,6 +47,8 @@ pub(crate) fn anonymous_cover_from_raw(
         output_symbols,
         cubes,
         cover_type,
+        mv: None,
+        phase: None,
     }
 }
 
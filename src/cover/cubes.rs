@@ -22,6 +22,7 @@ use std::sync::Arc;
 
 /// Which of a cover's three sets a cube belongs to.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CubeType {
     /// ON-set (the function is 1).
     #[default]
@@ -0,0 +1,295 @@
+//! Error types for eqntott equation parsing and writing
+
+use std::fmt;
+use std::io;
+use std::sync::Arc;
+
+use crate::expression::ParseBoolExprError;
+
+/// Errors related to eqntott equation parsing and validation
+///
+/// These errors occur when reading an equation file that is malformed or uses more than a set of
+/// two-level equations over primary inputs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EqntottError {
+    /// Text after the last `;`: every statement must be terminated.
+    MissingSemicolon {
+        /// The unterminated statement
+        statement: Arc<str>,
+    },
+    /// A statement is not of the form `name = ...`.
+    MissingEquals {
+        /// The statement as written
+        statement: Arc<str>,
+    },
+    /// A signal name that is not an identifier (a letter or `_`, then letters, digits or `_`).
+    InvalidName {
+        /// The name as written
+        name: Arc<str>,
+    },
+    /// An equation's right-hand side is not a valid expression.
+    InvalidExpression {
+        /// The output the equation drives
+        output: Arc<str>,
+        /// The parser's error
+        error: ParseBoolExprError,
+    },
+    /// A signal is listed twice in `INORDER`/`OUTORDER`, driven by two equations, or declared
+    /// both as an input and as an output.
+    DuplicateSignal {
+        /// The repeated signal
+        name: Arc<str>,
+    },
+    /// `INORDER` or `OUTORDER` appears twice.
+    DuplicateOrder {
+        /// `INORDER` or `OUTORDER`
+        directive: Arc<str>,
+    },
+    /// An equation reads a signal that `INORDER` does not list.
+    UndeclaredInput {
+        /// The signal read
+        name: Arc<str>,
+    },
+    /// An equation drives a signal that `OUTORDER` does not list. Intermediate signals are not
+    /// collapsed: every equation must give one output over the inputs.
+    UndeclaredOutput {
+        /// The driven signal
+        name: Arc<str>,
+    },
+    /// An equation reads a signal that another equation drives.
+    InternalSignal {
+        /// The signal both read and driven
+        name: Arc<str>,
+    },
+    /// An `OUTORDER` output has no equation driving it.
+    UndrivenOutput {
+        /// The undriven output
+        name: Arc<str>,
+    },
+}
+
+impl fmt::Display for EqntottError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EqntottError::MissingSemicolon { statement } => {
+                write!(f, "statement '{}' is not terminated by ';'", statement)
+            }
+            EqntottError::MissingEquals { statement } => {
+                write!(
+                    f,
+                    "statement '{}' is not of the form 'name = ...'",
+                    statement
+                )
+            }
+            EqntottError::InvalidName { name } => {
+                write!(f, "'{}' is not a valid signal name", name)
+            }
+            EqntottError::InvalidExpression { output, error } => {
+                write!(f, "invalid equation for '{}': {}", output, error)
+            }
+            EqntottError::DuplicateSignal { name } => {
+                write!(f, "signal '{}' is declared or driven more than once", name)
+            }
+            EqntottError::DuplicateOrder { directive } => {
+                write!(f, "{} appears more than once", directive)
+            }
+            EqntottError::UndeclaredInput { name } => {
+                write!(f, "equation reads '{}', which INORDER does not list", name)
+            }
+            EqntottError::UndeclaredOutput { name } => {
+                write!(
+                    f,
+                    "equation drives '{}', which OUTORDER does not list",
+                    name
+                )
+            }
+            EqntottError::InternalSignal { name } => {
+                write!(
+                    f,
+                    "'{}' is both driven by an equation and read by one",
+                    name
+                )
+            }
+            EqntottError::UndrivenOutput { name } => {
+                write!(f, "output '{}' is not driven by any equation", name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for EqntottError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            EqntottError::InvalidExpression { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<EqntottError> for io::Error {
+    fn from(err: EqntottError) -> Self {
+        io::Error::new(io::ErrorKind::InvalidData, err)
+    }
+}
+
+/// Errors that can occur when reading eqntott equations
+///
+/// This error type is returned by the `Cover::from_eqntott_*` methods.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum EqntottReadError {
+    /// Equation format error
+    Eqntott(EqntottError),
+    /// IO error during reading
+    Io(io::Error),
+}
+
+impl fmt::Display for EqntottReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EqntottReadError::Eqntott(e) => write!(f, "eqntott format error: {}", e),
+            EqntottReadError::Io(e) => write!(f, "IO error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for EqntottReadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            EqntottReadError::Eqntott(e) => Some(e),
+            EqntottReadError::Io(e) => Some(e),
+        }
+    }
+}
+
+impl From<EqntottError> for EqntottReadError {
+    fn from(err: EqntottError) -> Self {
+        EqntottReadError::Eqntott(err)
+    }
+}
+
+impl From<io::Error> for EqntottReadError {
+    fn from(err: io::Error) -> Self {
+        EqntottReadError::Io(err)
+    }
+}
+
+impl From<EqntottReadError> for io::Error {
+    fn from(err: EqntottReadError) -> Self {
+        match err {
+            EqntottReadError::Io(e) => e,
+            EqntottReadError::Eqntott(e) => io::Error::new(io::ErrorKind::InvalidData, e),
+        }
+    }
+}
+
+/// Errors that can occur when writing eqntott equations
+///
+/// This error type is returned by the [`EqntottWriter`](crate::EqntottWriter) methods
+/// (`write_eqntott`, `to_eqntott_string`, `to_eqntott_file`).
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum EqntottWriteError {
+    /// The cover has multiple-valued inputs (see [`Cover::mv_layout`](crate::Cover::mv_layout)),
+    /// which equations over binary signals cannot express.
+    MultipleValuedInputs,
+    /// A label is not an identifier, so the equations could not be read back.
+    InvalidName {
+        /// The label as rendered
+        name: Arc<str>,
+    },
+    /// Two signals, most often an input and an output, are written under the same name, so the
+    /// equations could not be read back.
+    DuplicateSignal {
+        /// The shared name
+        name: Arc<str>,
+    },
+    /// IO error during writing
+    Io(io::Error),
+}
+
+impl fmt::Display for EqntottWriteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EqntottWriteError::MultipleValuedInputs => write!(
+                f,
+                "eqntott cannot express a cover with multiple-valued inputs"
+            ),
+            EqntottWriteError::InvalidName { name } => {
+                write!(f, "'{}' is not a valid eqntott signal name", name)
+            }
+            EqntottWriteError::DuplicateSignal { name } => {
+                write!(f, "more than one signal would be written as '{}'", name)
+            }
+            EqntottWriteError::Io(e) => write!(f, "IO error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for EqntottWriteError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            EqntottWriteError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for EqntottWriteError {
+    fn from(err: io::Error) -> Self {
+        EqntottWriteError::Io(err)
+    }
+}
+
+impl From<EqntottWriteError> for io::Error {
+    fn from(err: EqntottWriteError) -> Self {
+        match err {
+            EqntottWriteError::Io(e) => e,
+            e => io::Error::new(io::ErrorKind::InvalidInput, e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eqntott_error_messages() {
+        let err = EqntottError::UndeclaredOutput {
+            name: Arc::from("t"),
+        };
+        assert!(err.to_string().contains("'t'"));
+        assert!(err.to_string().contains("OUTORDER"));
+        let err = EqntottError::MissingSemicolon {
+            statement: Arc::from("f = a"),
+        };
+        assert!(err.to_string().contains("'f = a'"));
+        let err = EqntottError::InvalidExpression {
+            output: Arc::from("f"),
+            error: crate::BoolExpr::parse("a &").unwrap_err(),
+        };
+        assert!(err.to_string().starts_with("invalid equation for 'f'"));
+        assert!(std::error::Error::source(&err).is_some());
+    }
+
+    #[test]
+    fn test_eqntott_read_error_conversions() {
+        let read_err: EqntottReadError = EqntottError::DuplicateOrder {
+            directive: Arc::from("INORDER"),
+        }
+        .into();
+        assert!(matches!(read_err, EqntottReadError::Eqntott(_)));
+        let io_err: io::Error = read_err.into();
+        assert_eq!(io_err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_eqntott_write_error_to_io_error() {
+        let io_err: io::Error = EqntottWriteError::MultipleValuedInputs.into();
+        assert_eq!(io_err.kind(), io::ErrorKind::InvalidInput);
+        let write_err: EqntottWriteError = io::Error::other("disk full").into();
+        assert!(matches!(write_err, EqntottWriteError::Io(_)));
+    }
+}
@@ -0,0 +1,452 @@
+//! eqntott equation support
+//!
+//! This module reads and writes covers as eqntott equations, the input language of the `eqntott`
+//! truth-table generator and the `-o eqntott` output of the C Espresso. A file is a list of
+//! `;`-terminated statements, one equation `name = expression;` per output:
+//!
+//! ```text
+//! INORDER = a b c;
+//! OUTORDER = f g;
+//!
+//! f = (a&!b) | (c);
+//!
+//! g = (!a&b&c);
+//! ```
+//!
+//! - [`Cover::from_eqntott_reader`] (and the `_string`/`_file` forms) parses each right-hand side
+//!   with [`BoolExpr::parse`] and adds it with [`Cover::add_expr`], giving a
+//!   [`Cover<Symbol, Symbol>`](Cover) with one output per equation;
+//! - [`EqntottWriter`] writes any [`Cover`] whose labels render as names, one sum of products per
+//!   output, in the layout the C Espresso uses.
+//!
+//! # Quick Example
+//!
+//! ```
+//! use espresso_logic::{Cover, EqntottWriter, Minimizable, Symbol};
+//!
+//! # fn main() -> std::io::Result<()> {
+//! let equations = "f = a&b | a&!b&c;\ng = !(a | b);\n";
+//! let cover = Cover::<Symbol, Symbol>::from_eqntott_string(equations)?;
+//! assert_eq!(cover.num_inputs(), 3);
+//! assert_eq!(cover.num_outputs(), 2);
+//!
+//! let minimised = cover.minimize()?;
+//! let text = minimised.to_eqntott_string()?;
+//! assert!(text.starts_with("INORDER = a b c;\nOUTORDER = f g;\n"));
+//! assert!(text.contains("g = (!a&!b);"));
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # What Is Read
+//!
+//! - `INORDER = names;` gives the input labels, in order, including inputs no equation reads.
+//!   Without it the inputs are the signals the equations read, in order of first appearance.
+//! - `OUTORDER = names;` gives the output order; every listed output needs an equation, and every
+//!   equation must drive a listed output. Without it the outputs follow the equations.
+//! - Each other statement is an equation in the [`BoolExpr::parse`] syntax: `!`, `&`, `^`, `|`,
+//!   parentheses and the constants `0` and `1`. Names are identifiers: a letter or `_`, then
+//!   letters, digits or `_`.
+//! - `#` starts a comment that runs to the end of the line, and `/* ... */` comments may span
+//!   lines. Statements may span lines too.
+//!
+//! Intermediate signals (an equation reading what another drives) are rejected: this reader does
+//! not collapse multi-level logic. See [`EqntottError`] for the full list.
+
+pub mod error;
+
+pub use error::{EqntottError, EqntottReadError, EqntottWriteError};
+
+use std::collections::HashSet;
+use std::fmt;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Cursor, Write};
+use std::path::Path;
+use std::sync::Arc;
+
+use super::label::Label;
+use super::minterm::InputField;
+use super::{Cover, CoverType, CubeType};
+use crate::expression::BoolExpr;
+use crate::Symbol;
+
+/// The column past which the writer starts a new line, as the C `eqn_output` does.
+const LINE_WIDTH: usize = 72;
+
+/// Whether `name` can name a signal: an identifier that is neither a constant nor a directive.
+fn is_signal_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !matches!(name, "true" | "false" | "INORDER" | "OUTORDER")
+}
+
+/// The labels as written, each checked to be a signal name.
+fn signal_names<L: fmt::Display>(labels: &[L]) -> Result<Vec<String>, EqntottWriteError> {
+    labels
+        .iter()
+        .map(|label| {
+            let name = label.to_string();
+            if is_signal_name(&name) {
+                Ok(name)
+            } else {
+                Err(EqntottWriteError::InvalidName {
+                    name: Arc::from(name),
+                })
+            }
+        })
+        .collect()
+}
+
+/// Trait for types that support eqntott serialisation (writing)
+///
+/// Implemented for every [`Cover<I, O>`](crate::Cover) whose input and output labels render as
+/// names ([`Display`](std::fmt::Display)); a positional ([`Anonymous`](crate::Anonymous)) side has
+/// no names to give the signals, so [`relabel`](crate::Cover::relabel) it first.
+pub trait EqntottWriter {
+    /// Write this cover as eqntott equations
+    ///
+    /// `INORDER` and `OUTORDER` come first, so the labels keep their order when read back. Each
+    /// output then gets one equation: an OR of one parenthesised AND term per ON-set cube, `(1)`
+    /// for a cube with no literals and `0` for an output with no cubes, wrapped before column 72.
+    /// An output whose cubes cover its complement (see
+    /// [`Cover::complemented_outputs`](crate::Cover::complemented_outputs)) is written negated,
+    /// `f = !(...);`. Don't-care and OFF-set cubes are not written.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EqntottWriteError::MultipleValuedInputs`] for a cover with multiple-valued
+    /// inputs, [`EqntottWriteError::InvalidName`] for a label that is not an identifier,
+    /// [`EqntottWriteError::DuplicateSignal`] when two labels, such as an input and an output,
+    /// render as the same name, and
+    /// [`EqntottWriteError::Io`] if writing fails.
+    fn write_eqntott<W: Write>(&self, writer: &mut W) -> Result<(), EqntottWriteError>;
+
+    /// Convert this cover to an eqntott string
+    ///
+    /// This is a convenience method that delegates to `write_eqntott`.
+    fn to_eqntott_string(&self) -> Result<String, EqntottWriteError> {
+        let mut buffer = Vec::new();
+        self.write_eqntott(&mut buffer)?;
+        Ok(String::from_utf8(buffer).expect("eqntott output is built from UTF-8 Rust strings"))
+    }
+
+    /// Write this cover to an eqntott file
+    fn to_eqntott_file<P: AsRef<Path>>(&self, path: P) -> Result<(), EqntottWriteError> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_eqntott(&mut writer)?;
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+impl<I: Label + fmt::Display, O: Label + fmt::Display> EqntottWriter for Cover<I, O> {
+    fn write_eqntott<W: Write>(&self, writer: &mut W) -> Result<(), EqntottWriteError> {
+        if self.mv_layout().is_some_and(|l| !l.mv_sizes().is_empty()) {
+            return Err(EqntottWriteError::MultipleValuedInputs);
+        }
+        let inputs = signal_names(self.input_labels())?;
+        let outputs = signal_names(self.output_labels())?;
+        let mut seen = HashSet::new();
+        if let Some(name) = inputs
+            .iter()
+            .chain(&outputs)
+            .find(|name| !seen.insert(*name))
+        {
+            return Err(EqntottWriteError::DuplicateSignal {
+                name: Arc::from(name.as_str()),
+            });
+        }
+
+        writeln!(writer, "INORDER = {};", inputs.join(" "))?;
+        writeln!(writer, "OUTORDER = {};", outputs.join(" "))?;
+        let complemented = self.complemented_outputs();
+        for (j, output) in outputs.iter().enumerate() {
+            writeln!(writer)?;
+            write!(writer, "{} = ", output)?;
+            let mut col = output.len() + 3;
+            let negated = complemented.contains(&j);
+            if negated {
+                write!(writer, "!(")?;
+                col += 2;
+            }
+            let mut first_term = true;
+            // A cube with an empty input field covers no point, so it adds no term.
+            let terms = self
+                .cubes()
+                .filter(|cube| cube.set == CubeType::F && cube.asserts(j))
+                .map(|cube| cube.inputs.fields().collect::<Vec<_>>())
+                .filter(|fields| !fields.contains(&InputField::Empty));
+            for fields in terms {
+                if first_term {
+                    write!(writer, "(")?;
+                    col += 1;
+                } else {
+                    write!(writer, " | (")?;
+                    col += 4;
+                }
+                first_term = false;
+                let mut first_literal = true;
+                for (field, name) in fields.iter().zip(&inputs) {
+                    let complement = match field {
+                        InputField::Zero => true,
+                        InputField::One => false,
+                        InputField::DontCare | InputField::Empty => continue,
+                    };
+                    if col + name.len() > LINE_WIDTH {
+                        write!(writer, "\n    ")?;
+                        col = 4;
+                    }
+                    if !first_literal {
+                        write!(writer, "&")?;
+                        col += 1;
+                    }
+                    first_literal = false;
+                    if complement {
+                        write!(writer, "!")?;
+                        col += 1;
+                    }
+                    write!(writer, "{}", name)?;
+                    col += name.len();
+                }
+                if first_literal {
+                    write!(writer, "1")?;
+                    col += 1;
+                }
+                write!(writer, ")")?;
+                col += 1;
+            }
+            if first_term {
+                write!(writer, "0")?;
+            }
+            if negated {
+                write!(writer, ")")?;
+            }
+            writeln!(writer, ";")?;
+        }
+        Ok(())
+    }
+}
+
+/// Raw eqntott components from [`parse_eqntott`], in the order they were declared.
+struct ParsedEqntott {
+    inorder: Option<Vec<String>>,
+    outorder: Option<Vec<String>>,
+    equations: Vec<(String, BoolExpr)>,
+}
+
+/// Remove `/* ... */` comments; an unclosed one runs to the end of the text.
+fn strip_block_comments(text: &str) -> String {
+    let mut stripped = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("/*") {
+        stripped.push_str(&rest[..start]);
+        // A comment separates tokens, as whitespace does.
+        stripped.push(' ');
+        rest = match rest[start + 2..].find("*/") {
+            Some(end) => &rest[start + 2 + end + 2..],
+            None => "",
+        };
+    }
+    stripped.push_str(rest);
+    stripped
+}
+
+/// Split a file into its statements and parse each one.
+fn parse_eqntott<R: BufRead>(reader: R) -> Result<ParsedEqntott, EqntottReadError> {
+    let mut text = String::new();
+    for line in reader.lines() {
+        let line = line?;
+        let code = line.split('#').next().unwrap_or("");
+        text.push_str(code);
+        text.push('\n');
+    }
+    let text = strip_block_comments(&text);
+
+    let mut parsed = ParsedEqntott {
+        inorder: None,
+        outorder: None,
+        equations: Vec::new(),
+    };
+    let mut statements: Vec<&str> = text.split(';').map(str::trim).collect();
+    let trailing = statements.pop().unwrap_or("");
+    if !trailing.is_empty() {
+        return Err(EqntottError::MissingSemicolon {
+            statement: Arc::from(trailing),
+        }
+        .into());
+    }
+    for statement in statements.into_iter().filter(|s| !s.is_empty()) {
+        let (name, rhs) = statement
+            .split_once('=')
+            .ok_or_else(|| EqntottError::MissingEquals {
+                statement: Arc::from(statement),
+            })?;
+        let name = name.trim();
+        let order = match name {
+            "INORDER" => Some(&mut parsed.inorder),
+            "OUTORDER" => Some(&mut parsed.outorder),
+            _ => None,
+        };
+        if let Some(order) = order {
+            if order.is_some() {
+                return Err(EqntottError::DuplicateOrder {
+                    directive: Arc::from(name),
+                }
+                .into());
+            }
+            let names: Vec<String> = rhs.split_whitespace().map(str::to_string).collect();
+            if let Some(bad) = names.iter().find(|n| !is_signal_name(n)) {
+                return Err(EqntottError::InvalidName {
+                    name: Arc::from(bad.as_str()),
+                }
+                .into());
+            }
+            *order = Some(names);
+            continue;
+        }
+        if !is_signal_name(name) {
+            return Err(EqntottError::InvalidName {
+                name: Arc::from(name),
+            }
+            .into());
+        }
+        let expr = BoolExpr::parse(rhs).map_err(|error| EqntottError::InvalidExpression {
+            output: Arc::from(name),
+            error,
+        })?;
+        parsed.equations.push((name.to_string(), expr));
+    }
+    Ok(parsed)
+}
+
+/// The first name listed twice, if any.
+fn first_duplicate<'a>(names: impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+    let mut seen = HashSet::new();
+    names.into_iter().find(|name| !seen.insert(*name))
+}
+
+impl Cover<Symbol, Symbol> {
+    /// Parse a cover from eqntott equations in any `BufRead`, one output per equation.
+    ///
+    /// Each right-hand side is parsed with [`BoolExpr::parse`] and added with
+    /// [`add_expr`](Self::add_expr), so the cubes are its canonical sum of products rather than
+    /// the terms as written. See the [module documentation](crate::cover::eqntott) for what is
+    /// accepted. The cover is [`CoverType::F`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EqntottReadError::Eqntott`] for a malformed or unsupported file, and
+    /// [`EqntottReadError::Io`] if reading fails.
+    pub fn from_eqntott_reader<R: BufRead>(reader: R) -> Result<Self, EqntottReadError> {
+        let p = parse_eqntott(reader)?;
+        let driven: HashSet<&str> = p.equations.iter().map(|(name, _)| name.as_str()).collect();
+        if let Some(name) = first_duplicate(p.equations.iter().map(|(name, _)| name.as_str())) {
+            return Err(EqntottError::DuplicateSignal {
+                name: Arc::from(name),
+            }
+            .into());
+        }
+
+        let outputs: Vec<&str> = match &p.outorder {
+            Some(outorder) => {
+                let listed: HashSet<&str> = outorder.iter().map(String::as_str).collect();
+                if let Some(name) = first_duplicate(outorder.iter().map(String::as_str)) {
+                    return Err(EqntottError::DuplicateSignal {
+                        name: Arc::from(name),
+                    }
+                    .into());
+                }
+                if let Some((name, _)) = p
+                    .equations
+                    .iter()
+                    .find(|(n, _)| !listed.contains(n.as_str()))
+                {
+                    return Err(EqntottError::UndeclaredOutput {
+                        name: Arc::from(name.as_str()),
+                    }
+                    .into());
+                }
+                if let Some(name) = outorder.iter().find(|n| !driven.contains(n.as_str())) {
+                    return Err(EqntottError::UndrivenOutput {
+                        name: Arc::from(name.as_str()),
+                    }
+                    .into());
+                }
+                outorder.iter().map(String::as_str).collect()
+            }
+            None => p.equations.iter().map(|(name, _)| name.as_str()).collect(),
+        };
+
+        let mut inputs: Vec<Symbol> = Vec::new();
+        if let Some(inorder) = &p.inorder {
+            let inorder = inorder.iter().map(String::as_str);
+            if let Some(name) = first_duplicate(inorder.clone())
+                .or_else(|| inorder.clone().find(|n| driven.contains(n)))
+            {
+                return Err(EqntottError::DuplicateSignal {
+                    name: Arc::from(name),
+                }
+                .into());
+            }
+            inputs.extend(inorder.map(Symbol::from));
+        }
+        let declared: HashSet<Symbol> = inputs.iter().cloned().collect();
+        let mut seen = declared.clone();
+        for (_, expr) in &p.equations {
+            for var in expr.variables() {
+                if driven.contains(var.as_ref()) {
+                    return Err(EqntottError::InternalSignal {
+                        name: Arc::from(var.as_ref()),
+                    }
+                    .into());
+                }
+                if p.inorder.is_some() && !declared.contains(&var) {
+                    return Err(EqntottError::UndeclaredInput {
+                        name: Arc::from(var.as_ref()),
+                    }
+                    .into());
+                }
+                if seen.insert(var.clone()) {
+                    inputs.push(var);
+                }
+            }
+        }
+
+        let mut cover = Cover::with_labels(CoverType::F, &inputs, Vec::<&str>::new())
+            .expect("eqntott inputs are duplicate-checked during parsing");
+        for output in outputs {
+            let (_, expr) = p
+                .equations
+                .iter()
+                .find(|(name, _)| name == output)
+                .expect("every output has an equation");
+            cover
+                .add_expr(expr, output)
+                .expect("eqntott outputs are duplicate-checked during parsing");
+        }
+        Ok(cover)
+    }
+
+    /// Parse a cover from an eqntott-format string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use espresso_logic::{Cover, Symbol};
+    ///
+    /// let cover = Cover::<Symbol, Symbol>::from_eqntott_string("f = a & b;").unwrap();
+    /// assert_eq!(cover.num_inputs(), 2);
+    /// assert_eq!(cover.output_labels()[0].as_ref(), "f");
+    /// ```
+    pub fn from_eqntott_string<T: AsRef<str>>(s: T) -> Result<Self, EqntottReadError> {
+        Self::from_eqntott_reader(Cursor::new(s.as_ref().as_bytes()))
+    }
+
+    /// Load a cover from an eqntott file.
+    pub fn from_eqntott_file<P: AsRef<Path>>(path: P) -> Result<Self, EqntottReadError> {
+        Self::from_eqntott_reader(BufReader::new(File::open(path)?))
+    }
+}
@@ -1,5 +1,7 @@
 //! Error types for cover operations
 
+use super::label::Label;
+use crate::error::MinimizationError;
 use crate::Symbol;
 use std::fmt;
 use std::io;
@@ -28,6 +30,12 @@ pub enum CoverError {
         /// The maximum valid index (number of outputs - 1)
         max: usize,
     },
+    /// Two covers being merged both have cubes for an output, but one side's cubes cover its
+    /// complement (an applied output phase) and the other's cover the output itself.
+    PhaseConflict {
+        /// The output's position in the merged cover
+        output: usize,
+    },
 }
 
 impl fmt::Display for CoverError {
@@ -44,6 +52,11 @@ impl fmt::Display for CoverError {
                 "Output index {} out of bounds (valid range: 0..={})",
                 index, max
             ),
+            CoverError::PhaseConflict { output } => write!(
+                f,
+                "Output {} is complemented in one cover but not in the other",
+                output
+            ),
         }
     }
 }
@@ -339,6 +352,320 @@ impl From<LabelNotFound> for io::Error {
     }
 }
 
+/// [`MvLayout::new`](crate::MvLayout::new) was given a multiple-valued variable with no values.
+///
+/// Every multiple-valued variable needs at least one value: a zero-sized part would have no bits in
+/// the Espresso cube, so no cube could ever be non-empty in it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct EmptyMvVariable {
+    /// The index of the offending variable among the multiple-valued variables (`0` is the first
+    /// variable after the binary ones).
+    pub var: usize,
+}
+
+impl fmt::Display for EmptyMvVariable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "multiple-valued variable {} has no values", self.var)
+    }
+}
+
+impl std::error::Error for EmptyMvVariable {}
+
+impl From<EmptyMvVariable> for io::Error {
+    fn from(err: EmptyMvVariable) -> Self {
+        io::Error::new(io::ErrorKind::InvalidInput, err)
+    }
+}
+
+/// [`Cover::pair_inputs`](crate::Cover::pair_inputs) was given a pair naming an input that cannot
+/// be paired.
+///
+/// Only binary inputs pair into a two-bit decoder, and each at most once: `var` is out of range, a
+/// multiple-valued variable, or already in another pair (or paired with itself).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct InvalidPairing {
+    /// The offending input variable.
+    pub var: usize,
+}
+
+impl fmt::Display for InvalidPairing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "input {} cannot be paired: only binary inputs pair, each at most once",
+            self.var
+        )
+    }
+}
+
+impl std::error::Error for InvalidPairing {}
+
+impl From<InvalidPairing> for io::Error {
+    fn from(err: InvalidPairing) -> Self {
+        io::Error::new(io::ErrorKind::InvalidInput, err)
+    }
+}
+
+/// [`Cover::encode_symbolic_inputs`](crate::Cover::encode_symbolic_inputs) or
+/// [`Cover::encode_symbolic_outputs`](crate::Cover::encode_symbolic_outputs) was given a
+/// [`SymbolicVariable`](crate::SymbolicVariable) naming a variable that cannot be encoded.
+///
+/// A symbolic input is built from binary inputs and a symbolic output from outputs, each used by at
+/// most one symbolic variable: `var` is out of range, a multiple-valued input, or already in use. A
+/// variable of so many bits that its value count overflows is reported at its first bit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct InvalidSymbolic {
+    /// The offending input or output variable.
+    pub var: usize,
+}
+
+impl fmt::Display for InvalidSymbolic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "variable {} cannot join a symbolic variable: only binary inputs or outputs join, each \
+             at most once",
+            self.var
+        )
+    }
+}
+
+impl std::error::Error for InvalidSymbolic {}
+
+impl From<InvalidSymbolic> for io::Error {
+    fn from(err: InvalidSymbolic) -> Self {
+        io::Error::new(io::ErrorKind::InvalidInput, err)
+    }
+}
+
+/// Why [`Cover::encode_symbolic_outputs`](crate::Cover::encode_symbolic_outputs) failed.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum SymbolicOutputError {
+    /// A symbolic variable names an output that cannot be encoded.
+    Invalid(InvalidSymbolic),
+    /// The C core could not compute the cover's OFF-set or ON-set.
+    Minimization(MinimizationError),
+}
+
+impl fmt::Display for SymbolicOutputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SymbolicOutputError::Invalid(e) => write!(f, "{}", e),
+            SymbolicOutputError::Minimization(e) => {
+                write!(f, "symbolic output encoding failed to run: {}", e)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SymbolicOutputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SymbolicOutputError::Invalid(e) => Some(e),
+            SymbolicOutputError::Minimization(e) => Some(e),
+        }
+    }
+}
+
+impl From<InvalidSymbolic> for SymbolicOutputError {
+    fn from(err: InvalidSymbolic) -> Self {
+        SymbolicOutputError::Invalid(err)
+    }
+}
+
+impl From<MinimizationError> for SymbolicOutputError {
+    fn from(err: MinimizationError) -> Self {
+        SymbolicOutputError::Minimization(err)
+    }
+}
+
+impl From<SymbolicOutputError> for io::Error {
+    fn from(err: SymbolicOutputError) -> Self {
+        match err {
+            SymbolicOutputError::Invalid(e) => e.into(),
+            SymbolicOutputError::Minimization(e) => e.into(),
+        }
+    }
+}
+
+/// Why a cover failed [`Cover::verify`](crate::Cover::verify) against the function it was
+/// minimised from.
+///
+/// A failure carries a witness: an input minterm, fully specified, and the output at which the two
+/// covers disagree there.
+#[non_exhaustive]
+pub enum VerifyError<I> {
+    /// The two covers do not have the same inputs, outputs and multiple-valued layout.
+    HeaderMismatch,
+    /// The cover asserts `output` at `minterm`, where the original has it neither in its ON-set nor
+    /// in its don't-care set.
+    OutsideCareSet {
+        /// The witness input minterm
+        minterm: crate::Minterm<I>,
+        /// The offending output
+        output: usize,
+    },
+    /// The original asserts `output` at `minterm`, and the cover does not.
+    Uncovered {
+        /// The witness input minterm
+        minterm: crate::Minterm<I>,
+        /// The offending output
+        output: usize,
+    },
+    /// The check itself could not run.
+    Minimization(MinimizationError),
+}
+
+impl<I: Label + fmt::Debug> fmt::Debug for VerifyError<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VerifyError::HeaderMismatch => write!(f, "HeaderMismatch"),
+            VerifyError::OutsideCareSet { minterm, output } => f
+                .debug_struct("OutsideCareSet")
+                .field("minterm", minterm)
+                .field("output", output)
+                .finish(),
+            VerifyError::Uncovered { minterm, output } => f
+                .debug_struct("Uncovered")
+                .field("minterm", minterm)
+                .field("output", output)
+                .finish(),
+            VerifyError::Minimization(e) => f.debug_tuple("Minimization").field(e).finish(),
+        }
+    }
+}
+
+impl<I> fmt::Display for VerifyError<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VerifyError::HeaderMismatch => write!(
+                f,
+                "the covers do not have the same inputs, outputs and multiple-valued layout"
+            ),
+            VerifyError::OutsideCareSet { minterm, output } => write!(
+                f,
+                "output {} is asserted at {}, outside the original ON-set and don't-care set",
+                output, minterm
+            ),
+            VerifyError::Uncovered { minterm, output } => write!(
+                f,
+                "output {} of the original is not covered at {}",
+                output, minterm
+            ),
+            VerifyError::Minimization(e) => write!(f, "verification failed to run: {}", e),
+        }
+    }
+}
+
+impl<I: Label + fmt::Debug> std::error::Error for VerifyError<I> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            VerifyError::Minimization(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl<I> From<MinimizationError> for VerifyError<I> {
+    fn from(err: MinimizationError) -> Self {
+        VerifyError::Minimization(err)
+    }
+}
+
+impl<I: Label + fmt::Debug + Send + Sync + 'static> From<VerifyError<I>> for io::Error {
+    fn from(err: VerifyError<I>) -> Self {
+        match err {
+            VerifyError::Minimization(e) => e.into(),
+            e => io::Error::new(io::ErrorKind::InvalidData, e),
+        }
+    }
+}
+
+/// Why [`Cover::equivalent_to`](crate::Cover::equivalent_to) found two covers different, or could
+/// not compare them.
+///
+/// A difference carries a counterexample: an input minterm, fully specified over both covers'
+/// inputs, and every output on which the covers disagree there.
+#[non_exhaustive]
+pub enum EquivalenceError<I> {
+    /// The two covers do not have the same outputs, or do not share a multiple-valued layout.
+    HeaderMismatch,
+    /// The covers disagree at `minterm` on each of `outputs`, where neither has a don't-care.
+    NotEquivalent {
+        /// The distinguishing input minterm
+        minterm: crate::Minterm<I>,
+        /// The differing outputs, as positions in the receiving cover, ascending
+        outputs: Vec<usize>,
+    },
+    /// The check itself could not run.
+    Minimization(MinimizationError),
+}
+
+impl<I: Label + fmt::Debug> fmt::Debug for EquivalenceError<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EquivalenceError::HeaderMismatch => write!(f, "HeaderMismatch"),
+            EquivalenceError::NotEquivalent { minterm, outputs } => f
+                .debug_struct("NotEquivalent")
+                .field("minterm", minterm)
+                .field("outputs", outputs)
+                .finish(),
+            EquivalenceError::Minimization(e) => f.debug_tuple("Minimization").field(e).finish(),
+        }
+    }
+}
+
+impl<I> fmt::Display for EquivalenceError<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EquivalenceError::HeaderMismatch => write!(
+                f,
+                "the covers do not have the same outputs and multiple-valued layout"
+            ),
+            EquivalenceError::NotEquivalent { minterm, outputs } => {
+                let outputs: Vec<String> = outputs.iter().map(|o| o.to_string()).collect();
+                write!(
+                    f,
+                    "the covers differ at {} on output(s) {}",
+                    minterm,
+                    outputs.join(", ")
+                )
+            }
+            EquivalenceError::Minimization(e) => {
+                write!(f, "equivalence check failed to run: {}", e)
+            }
+        }
+    }
+}
+
+impl<I: Label + fmt::Debug> std::error::Error for EquivalenceError<I> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            EquivalenceError::Minimization(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl<I> From<MinimizationError> for EquivalenceError<I> {
+    fn from(err: MinimizationError) -> Self {
+        EquivalenceError::Minimization(err)
+    }
+}
+
+impl<I: Label + fmt::Debug + Send + Sync + 'static> From<EquivalenceError<I>> for io::Error {
+    fn from(err: EquivalenceError<I>) -> Self {
+        match err {
+            EquivalenceError::Minimization(e) => e.into(),
+            e => io::Error::new(io::ErrorKind::InvalidData, e),
+        }
+    }
+}
+
 /// Returned by `Symbols::new` when the label list repeats an identity.
 ///
 /// A symbol table's identities must be distinct — two labels with the same identity would collapse
@@ -7,6 +7,7 @@ use super::cubes::{Cube, CubeType};
 use super::error::{AddExprError, CoverError, ToExprError};
 use super::iterators::ToExprs;
 use super::minterm::Minterm;
+use super::mv::MvLayout;
 use super::output_set::OutputSet;
 use super::symbols::{identity_union, Symbols};
 use super::Cover;
@@ -60,11 +61,17 @@ impl Cover<Symbol, Symbol> {
                     cube.inputs = cube.inputs.project_onto(&new_syms);
                 }
                 self.input_symbols = new_syms;
+                self.mv = None;
             }
         }
 
-        // Append the new output to the output table; existing cubes gain an unasserted column.
+        // Append the new output to the output table; existing cubes gain an unasserted column. The
+        // new output is taken as given; the others keep their phase.
         let output_index = self.num_outputs();
+        self.phase = self
+            .phase
+            .as_ref()
+            .map(|p| p.remapped(&(0..output_index).collect::<Vec<_>>(), output_index + 1));
         let out_header: Arc<[Symbol]> = self
             .output_symbols
             .labels()
@@ -161,7 +168,11 @@ impl Cover<Symbol, Symbol> {
 impl<I: AsRef<str>, O> Cover<I, O> {
     /// Convert a specific output index to a boolean expression
     ///
-    /// Returns an error if the index is out of bounds.
+    /// An output whose cubes cover its complement (see
+    /// [`complemented_outputs`](Self::complemented_outputs)) is negated back, so the expression is
+    /// always the output's function. A multiple-valued field (see [`mv_layout`](Self::mv_layout))
+    /// reads as its variable taking one of the allowed values: the negation of each one-hot value it
+    /// excludes. Returns an error if the index is out of bounds.
     ///
     /// # Examples
     ///
@@ -190,7 +201,17 @@ impl<I: AsRef<str>, O> Cover<I, O> {
             .iter()
             .filter(|cube| cube.cube_type() == CubeType::F && cube.asserts(output_idx));
 
-        Ok(cubes_to_expr(relevant_cubes, self.input_symbols().labels()))
+        let expr = cubes_to_expr(
+            relevant_cubes,
+            self.input_symbols().labels(),
+            self.mv_layout(),
+        );
+        // An output minimised in complement has cubes covering its complement.
+        Ok(if self.complemented_outputs().contains(&output_idx) {
+            !expr
+        } else {
+            expr
+        })
     }
 
     /// Convert every output to a boolean expression.
@@ -259,10 +280,12 @@ impl<I: AsRef<str>, O: AsRef<str>> Cover<I, O> {
 /// Convert cubes back to a boolean expression.
 ///
 /// Reads each cube's input pattern against the input variable names (`variables`, one label per input
-/// — the caller always passes the cover's full input header).
+/// — the caller always passes the cover's full input header) and the cover's `layout`, under which a
+/// multiple-valued field excludes each value it does not allow (see [`Minterm::literals`]).
 pub(super) fn cubes_to_expr<'a, I: AsRef<str> + 'a, O: 'a>(
     cubes: impl IntoIterator<Item = &'a Cube<I, O>>,
     variables: &[I],
+    layout: Option<&MvLayout>,
 ) -> BoolExpr {
     use std::collections::BTreeMap;
 
@@ -271,15 +294,14 @@ pub(super) fn cubes_to_expr<'a, I: AsRef<str> + 'a, O: 'a>(
     // (the expression layer's name type) at this boundary.
     let product_terms: Vec<(BTreeMap<Symbol, bool>, bool)> = cubes
         .into_iter()
-        .map(|cube| {
-            let literals = (0..variables.len())
-                .filter_map(|i| {
-                    cube.inputs()
-                        .value_at(i)
-                        .map(|polarity| (Symbol::from(variables[i].as_ref()), polarity))
-                })
+        .filter_map(|cube| {
+            let literals = cube
+                .inputs()
+                .literals(layout)?
+                .into_iter()
+                .map(|(i, polarity)| (Symbol::from(variables[i].as_ref()), polarity))
                 .collect();
-            (literals, true)
+            Some((literals, true))
         })
         .collect();
 
@@ -0,0 +1,101 @@
+//! Error types for Verilog and VHDL export
+
+use std::fmt;
+use std::io;
+use std::sync::Arc;
+
+/// Errors that can occur when writing a cover as Verilog or VHDL
+///
+/// This error type is returned by the [`HDLWriter`](crate::HDLWriter) methods.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum HDLWriteError {
+    /// The cover has multiple-valued inputs (see [`Cover::mv_layout`](crate::Cover::mv_layout)),
+    /// which a single-bit port cannot carry.
+    MultipleValuedInputs,
+    /// A label (or the module name) cannot be written as an identifier even in escaped form: it is
+    /// empty, or holds whitespace (Verilog) or a control character (VHDL).
+    InvalidIdentifier {
+        /// The label as given
+        name: Arc<str>,
+    },
+    /// An input and an output share a label, so they would be the same port.
+    DuplicatePort {
+        /// The shared label
+        name: Arc<str>,
+    },
+    /// IO error during writing
+    Io(io::Error),
+}
+
+impl fmt::Display for HDLWriteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HDLWriteError::MultipleValuedInputs => {
+                write!(
+                    f,
+                    "HDL export cannot express a cover with multiple-valued inputs"
+                )
+            }
+            HDLWriteError::InvalidIdentifier { name } => {
+                write!(f, "'{}' cannot be written as an HDL identifier", name)
+            }
+            HDLWriteError::DuplicatePort { name } => {
+                write!(f, "'{}' names both an input and an output port", name)
+            }
+            HDLWriteError::Io(e) => write!(f, "IO error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for HDLWriteError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            HDLWriteError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for HDLWriteError {
+    fn from(err: io::Error) -> Self {
+        HDLWriteError::Io(err)
+    }
+}
+
+impl From<HDLWriteError> for io::Error {
+    fn from(err: HDLWriteError) -> Self {
+        match err {
+            HDLWriteError::Io(e) => e,
+            e => io::Error::new(io::ErrorKind::InvalidInput, e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hdl_write_error_messages() {
+        let err = HDLWriteError::InvalidIdentifier {
+            name: Arc::from("a b"),
+        };
+        assert!(err.to_string().contains("'a b'"));
+        let err = HDLWriteError::DuplicatePort {
+            name: Arc::from("x"),
+        };
+        assert!(err.to_string().contains("'x'"));
+        assert!(HDLWriteError::MultipleValuedInputs
+            .to_string()
+            .contains("multiple-valued"));
+    }
+
+    #[test]
+    fn test_hdl_write_error_to_io_error() {
+        let io_err: io::Error = HDLWriteError::MultipleValuedInputs.into();
+        assert_eq!(io_err.kind(), io::ErrorKind::InvalidInput);
+        let io_err: io::Error = HDLWriteError::Io(io::Error::other("disk full")).into();
+        assert_eq!(io_err.kind(), io::ErrorKind::Other);
+    }
+}
@@ -0,0 +1,708 @@
+//! Verilog and VHDL export
+//!
+//! This module writes a [`Cover`] as a synthesisable hardware description: a Verilog `module` or a
+//! VHDL `entity`/`architecture` pair, with one single-bit port per input and output label and one
+//! continuous assignment per output. It is the usual last step after
+//! [`Minimizable::minimize`](crate::Minimizable::minimize), replacing hand-written `assign`
+//! statements built from [`Cover::to_exprs`].
+//!
+//! Each output's logic is written in one of two [`HDLForm`]s:
+//!
+//! - [`HDLForm::SumOfProducts`] gives the flat two-level form, one product term per ON-set cube, so
+//!   the text mirrors the cover exactly;
+//! - [`HDLForm::Factored`] first factors the cubes algebraically (the same factoring
+//!   [`Cover::to_expr`] applies), which usually gives a shorter multi-level expression.
+//!
+//! # Quick Example
+//!
+//! ```
+//! use espresso_logic::{BoolExpr, Cover, CoverType, HDLForm, HDLWriter, Minimizable};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let mut cover = Cover::new(CoverType::F);
+//! cover.add_expr(&BoolExpr::parse("a * b + a * c")?, "f")?;
+//! let minimised = cover.minimize()?;
+//!
+//! let verilog = minimised.to_verilog_string("top", HDLForm::Factored)?;
+//! assert!(verilog.contains("assign f = a & (c | b);"));
+//!
+//! let vhdl = minimised.to_vhdl_string("top", HDLForm::SumOfProducts)?;
+//! assert!(vhdl.contains("f <= (a and c) or (a and b);"));
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # What Is Written
+//!
+//! - Ports are `wire`s (Verilog) or `std_logic` (VHDL), inputs first, in label order.
+//! - An output whose cubes cover its complement (see [`Cover::complemented_outputs`]) is written
+//!   negated, so the port still carries the true function.
+//! - Don't-care and OFF-set cubes are not written: the ON-set cubes alone give the implemented
+//!   function.
+//! - Operators of different kinds are always parenthesised, which VHDL requires and which keeps the
+//!   Verilog free of precedence surprises.
+//! - A label that is not a legal identifier (or is a reserved word) is written as an escaped
+//!   identifier: `\a[0] ` in Verilog, `\a[0]\` in VHDL. Since VHDL identifiers are
+//!   case-insensitive, labels differing only in case are escaped too.
+
+pub mod error;
+
+pub use error::HDLWriteError;
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::sync::Arc;
+
+use super::expressions::cubes_to_expr;
+use super::label::Label;
+use super::minterm::InputField;
+use super::{Cover, Cube, CubeType};
+use crate::expression::{BoolExpr, ExprNode};
+
+/// How an output's logic is written by [`HDLWriter`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum HDLForm {
+    /// Flat two-level logic: an OR of one AND term per ON-set cube
+    #[default]
+    SumOfProducts,
+    /// Multi-level logic from algebraic factoring of the ON-set cubes
+    Factored,
+}
+
+/// Trait for types that can be written as Verilog and VHDL
+///
+/// Implemented for every [`Cover<I, O>`](crate::Cover) whose input and output labels are names
+/// ([`AsRef<str>`]); a positional ([`Anonymous`](crate::Anonymous)) side has no names to give the
+/// ports, so [`relabel`](crate::Cover::relabel) it first.
+pub trait HDLWriter {
+    /// Write this cover as a Verilog module called `module`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HDLWriteError::MultipleValuedInputs`] for a cover with multiple-valued inputs,
+    /// [`HDLWriteError::InvalidIdentifier`] or [`HDLWriteError::DuplicatePort`] if the labels
+    /// cannot name distinct ports, and [`HDLWriteError::Io`] if writing fails.
+    fn write_verilog<W: Write>(
+        &self,
+        writer: &mut W,
+        module: &str,
+        form: HDLForm,
+    ) -> Result<(), HDLWriteError>;
+
+    /// Write this cover as a VHDL entity called `entity`, with an architecture named `rtl`
+    ///
+    /// # Errors
+    ///
+    /// As for [`write_verilog`](HDLWriter::write_verilog).
+    fn write_vhdl<W: Write>(
+        &self,
+        writer: &mut W,
+        entity: &str,
+        form: HDLForm,
+    ) -> Result<(), HDLWriteError>;
+
+    /// Convert this cover to a Verilog string
+    ///
+    /// This is a convenience method that delegates to `write_verilog`.
+    fn to_verilog_string(&self, module: &str, form: HDLForm) -> Result<String, HDLWriteError> {
+        let mut buffer = Vec::new();
+        self.write_verilog(&mut buffer, module, form)?;
+        Ok(String::from_utf8(buffer).expect("Verilog output is built from UTF-8 Rust strings"))
+    }
+
+    /// Convert this cover to a VHDL string
+    ///
+    /// This is a convenience method that delegates to `write_vhdl`.
+    fn to_vhdl_string(&self, entity: &str, form: HDLForm) -> Result<String, HDLWriteError> {
+        let mut buffer = Vec::new();
+        self.write_vhdl(&mut buffer, entity, form)?;
+        Ok(String::from_utf8(buffer).expect("VHDL output is built from UTF-8 Rust strings"))
+    }
+
+    /// Write this cover to a Verilog file
+    fn to_verilog_file<P: AsRef<Path>>(
+        &self,
+        path: P,
+        module: &str,
+        form: HDLForm,
+    ) -> Result<(), HDLWriteError> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_verilog(&mut writer, module, form)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Write this cover to a VHDL file
+    fn to_vhdl_file<P: AsRef<Path>>(
+        &self,
+        path: P,
+        entity: &str,
+        form: HDLForm,
+    ) -> Result<(), HDLWriteError> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_vhdl(&mut writer, entity, form)?;
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+impl<I: Label + AsRef<str>, O: Label + AsRef<str>> HDLWriter for Cover<I, O> {
+    fn write_verilog<W: Write>(
+        &self,
+        writer: &mut W,
+        module: &str,
+        form: HDLForm,
+    ) -> Result<(), HDLWriteError> {
+        let design = self.hdl_design(Dialect::Verilog, module, form)?;
+
+        writeln!(writer, "module {} (", design.name)?;
+        let ports = design
+            .inputs
+            .iter()
+            .map(|name| format!("    input  wire {}", name))
+            .chain(
+                design
+                    .outputs
+                    .iter()
+                    .map(|(name, _)| format!("    output wire {}", name)),
+            )
+            .collect::<Vec<_>>();
+        if !ports.is_empty() {
+            writeln!(writer, "{}", ports.join(",\n"))?;
+        }
+        writeln!(writer, ");")?;
+        writeln!(writer)?;
+        for (name, logic) in &design.outputs {
+            writeln!(writer, "    assign {} = {};", name, logic)?;
+        }
+        writeln!(writer)?;
+        writeln!(writer, "endmodule")?;
+        Ok(())
+    }
+
+    fn write_vhdl<W: Write>(
+        &self,
+        writer: &mut W,
+        entity: &str,
+        form: HDLForm,
+    ) -> Result<(), HDLWriteError> {
+        let design = self.hdl_design(Dialect::Vhdl, entity, form)?;
+
+        writeln!(writer, "library ieee;")?;
+        writeln!(writer, "use ieee.std_logic_1164.all;")?;
+        writeln!(writer)?;
+        writeln!(writer, "entity {} is", design.name)?;
+        let ports = design
+            .inputs
+            .iter()
+            .map(|name| format!("        {} : in  std_logic", name))
+            .chain(
+                design
+                    .outputs
+                    .iter()
+                    .map(|(name, _)| format!("        {} : out std_logic", name)),
+            )
+            .collect::<Vec<_>>();
+        // VHDL has no empty port list: a portless entity omits the clause.
+        if !ports.is_empty() {
+            writeln!(writer, "    port (")?;
+            writeln!(writer, "{}", ports.join(";\n"))?;
+            writeln!(writer, "    );")?;
+        }
+        writeln!(writer, "end entity {};", design.name)?;
+        writeln!(writer)?;
+        writeln!(writer, "architecture rtl of {} is", design.name)?;
+        writeln!(writer, "begin")?;
+        for (name, logic) in &design.outputs {
+            writeln!(writer, "    {} <= {};", name, logic)?;
+        }
+        writeln!(writer, "end architecture rtl;")?;
+        Ok(())
+    }
+}
+
+/// A cover rendered for one dialect: identifiers already escaped, and each output paired with the
+/// text of its logic.
+struct Design {
+    name: String,
+    inputs: Vec<String>,
+    outputs: Vec<(String, String)>,
+}
+
+impl<I: Label + AsRef<str>, O: Label + AsRef<str>> Cover<I, O> {
+    /// Check the cover can be written as HDL, then render its names and each output's logic.
+    fn hdl_design(
+        &self,
+        dialect: Dialect,
+        name: &str,
+        form: HDLForm,
+    ) -> Result<Design, HDLWriteError> {
+        if self.mv_layout().is_some_and(|l| !l.mv_sizes().is_empty()) {
+            return Err(HDLWriteError::MultipleValuedInputs);
+        }
+        let inputs: Vec<&str> = self.input_labels().iter().map(AsRef::as_ref).collect();
+        let outputs: Vec<&str> = self.output_labels().iter().map(AsRef::as_ref).collect();
+        if let Some(shared) = outputs.iter().find(|o| inputs.contains(o)) {
+            return Err(HDLWriteError::DuplicatePort {
+                name: Arc::from(*shared),
+            });
+        }
+
+        // Two ports differing only in case would be the same VHDL basic identifier, so they take
+        // the case-sensitive extended form instead.
+        let mut folded: HashMap<String, usize> = HashMap::new();
+        for label in inputs.iter().chain(&outputs) {
+            *folded.entry(label.to_ascii_lowercase()).or_default() += 1;
+        }
+        let identifier = |label: &str| {
+            let clashes = folded
+                .get(&label.to_ascii_lowercase())
+                .is_some_and(|&n| n > 1);
+            dialect.identifier(label, clashes)
+        };
+        let escaped: HashMap<&str, String> = inputs
+            .iter()
+            .map(|&label| Ok((label, identifier(label)?)))
+            .collect::<Result<_, HDLWriteError>>()?;
+
+        let complemented = self.complemented_outputs();
+        let mut rendered = Vec::with_capacity(outputs.len());
+        for (j, &output) in outputs.iter().enumerate() {
+            let on_set = self
+                .cubes()
+                .filter(|cube| cube.set == CubeType::F && cube.asserts(j));
+            let mut expr = match form {
+                HDLForm::SumOfProducts => self.sum_of_products(on_set),
+                HDLForm::Factored => cubes_to_expr(on_set, self.input_labels(), None),
+            };
+            if complemented.contains(&j) {
+                expr = expr.not();
+            }
+            let logic = dialect.render(&expr, &|label| escaped[label].as_str());
+            rendered.push((identifier(output)?, logic));
+        }
+
+        Ok(Design {
+            name: identifier(name)?,
+            inputs: inputs.iter().map(|label| escaped[label].clone()).collect(),
+            outputs: rendered,
+        })
+    }
+
+    /// The OR of one AND term per cube, left unfactored.
+    fn sum_of_products<'a>(&'a self, cubes: impl Iterator<Item = &'a Cube<I, O>>) -> BoolExpr
+    where
+        I: 'a,
+        O: 'a,
+    {
+        let labels = self.input_labels();
+        cubes
+            .filter_map(|cube| {
+                let mut term: Option<BoolExpr> = None;
+                for (i, field) in cube.inputs.fields().enumerate() {
+                    let literal = match field {
+                        InputField::Zero => BoolExpr::var(labels[i].as_ref()).not(),
+                        InputField::One => BoolExpr::var(labels[i].as_ref()),
+                        InputField::DontCare => continue,
+                        // An empty field makes the cube cover no point.
+                        InputField::Empty => return None,
+                    };
+                    term = Some(match term {
+                        Some(term) => term.and(&literal),
+                        None => literal,
+                    });
+                }
+                Some(term.unwrap_or_else(|| BoolExpr::constant(true)))
+            })
+            .reduce(|sum, term| sum.or(&term))
+            .unwrap_or_else(|| BoolExpr::constant(false))
+    }
+}
+
+/// The binary operator at the top of a rendered subexpression, for parenthesisation.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum TopOp {
+    And,
+    Or,
+    Xor,
+}
+
+#[derive(Clone, Copy)]
+enum Dialect {
+    Verilog,
+    Vhdl,
+}
+
+impl Dialect {
+    /// Render `expr`, mapping each variable name to its escaped identifier through `name`.
+    fn render<'n>(self, expr: &BoolExpr, name: &dyn Fn(&str) -> &'n str) -> String {
+        let (not, and, or, xor) = match self {
+            Dialect::Verilog => ("~", " & ", " | ", " ^ "),
+            Dialect::Vhdl => ("not ", " and ", " or ", " xor "),
+        };
+        let binary =
+            |op: TopOp, symbol: &str, l: (String, Option<TopOp>), r: (String, Option<TopOp>)| {
+                let operand = |(text, top): (String, Option<TopOp>)| match top {
+                    Some(top) if top != op => format!("({})", text),
+                    _ => text,
+                };
+                (format!("{}{}{}", operand(l), symbol, operand(r)), Some(op))
+            };
+        expr.fold(|node| match node {
+            ExprNode::Variable(v) => (name(v).to_string(), None),
+            ExprNode::Constant(value) => {
+                let text = match (self, value) {
+                    (Dialect::Verilog, false) => "1'b0",
+                    (Dialect::Verilog, true) => "1'b1",
+                    (Dialect::Vhdl, false) => "'0'",
+                    (Dialect::Vhdl, true) => "'1'",
+                };
+                (text.to_string(), None)
+            }
+            ExprNode::Not((text, None)) => (format!("{}{}", not, text), None),
+            ExprNode::Not((text, Some(_))) => (format!("{}({})", not, text), None),
+            ExprNode::And(l, r) => binary(TopOp::And, and, l, r),
+            ExprNode::Or(l, r) => binary(TopOp::Or, or, l, r),
+            ExprNode::Xor(l, r) => binary(TopOp::Xor, xor, l, r),
+        })
+        .0
+    }
+
+    /// Write `label` as an identifier, escaping it if it is not a plain one (or, for VHDL, if
+    /// `case_clash` says another name differs from it only in case).
+    fn identifier(self, label: &str, case_clash: bool) -> Result<String, HDLWriteError> {
+        let invalid = || HDLWriteError::InvalidIdentifier {
+            name: Arc::from(label),
+        };
+        match self {
+            Dialect::Verilog => {
+                if is_verilog_identifier(label) {
+                    Ok(label.to_string())
+                } else if label.is_empty() || label.chars().any(char::is_whitespace) {
+                    Err(invalid())
+                } else {
+                    // An escaped identifier runs to the next whitespace, so the space is part of it.
+                    Ok(format!("\\{} ", label))
+                }
+            }
+            Dialect::Vhdl => {
+                if !case_clash && is_vhdl_identifier(label) {
+                    Ok(label.to_string())
+                } else if label.is_empty() || label.chars().any(char::is_control) {
+                    Err(invalid())
+                } else {
+                    Ok(format!("\\{}\\", label.replace('\\', "\\\\")))
+                }
+            }
+        }
+    }
+}
+
+/// Whether `label` is a Verilog simple identifier: a letter or `_`, then letters, digits, `_` or
+/// `$`, and not a keyword.
+fn is_verilog_identifier(label: &str) -> bool {
+    let mut chars = label.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+        && !VERILOG_KEYWORDS.contains(&label)
+}
+
+/// Whether `label` is a VHDL basic identifier: a letter, then letters and digits with single
+/// underscores between them, and not a reserved word (in any case).
+fn is_vhdl_identifier(label: &str) -> bool {
+    label.starts_with(|c: char| c.is_ascii_alphabetic())
+        && !label.ends_with('_')
+        && !label.contains("__")
+        && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !VHDL_RESERVED_WORDS.contains(&label.to_ascii_lowercase().as_str())
+}
+
+/// IEEE 1364-2005 keywords.
+const VERILOG_KEYWORDS: &[&str] = &[
+    "always",
+    "and",
+    "assign",
+    "automatic",
+    "begin",
+    "buf",
+    "bufif0",
+    "bufif1",
+    "case",
+    "casex",
+    "casez",
+    "cell",
+    "cmos",
+    "config",
+    "deassign",
+    "default",
+    "defparam",
+    "design",
+    "disable",
+    "edge",
+    "else",
+    "end",
+    "endcase",
+    "endconfig",
+    "endfunction",
+    "endgenerate",
+    "endmodule",
+    "endprimitive",
+    "endspecify",
+    "endtable",
+    "endtask",
+    "event",
+    "for",
+    "force",
+    "forever",
+    "fork",
+    "function",
+    "generate",
+    "genvar",
+    "highz0",
+    "highz1",
+    "if",
+    "ifnone",
+    "incdir",
+    "include",
+    "initial",
+    "inout",
+    "input",
+    "instance",
+    "integer",
+    "join",
+    "large",
+    "liblist",
+    "library",
+    "localparam",
+    "macromodule",
+    "medium",
+    "module",
+    "nand",
+    "negedge",
+    "nmos",
+    "nor",
+    "noshowcancelled",
+    "not",
+    "notif0",
+    "notif1",
+    "or",
+    "output",
+    "parameter",
+    "pmos",
+    "posedge",
+    "primitive",
+    "pull0",
+    "pull1",
+    "pulldown",
+    "pullup",
+    "pulsestyle_ondetect",
+    "pulsestyle_onevent",
+    "rcmos",
+    "real",
+    "realtime",
+    "reg",
+    "release",
+    "repeat",
+    "rnmos",
+    "rpmos",
+    "rtran",
+    "rtranif0",
+    "rtranif1",
+    "scalared",
+    "showcancelled",
+    "signed",
+    "small",
+    "specify",
+    "specparam",
+    "strong0",
+    "strong1",
+    "supply0",
+    "supply1",
+    "table",
+    "task",
+    "time",
+    "tran",
+    "tranif0",
+    "tranif1",
+    "tri",
+    "tri0",
+    "tri1",
+    "triand",
+    "trior",
+    "trireg",
+    "unsigned",
+    "use",
+    "uwire",
+    "vectored",
+    "wait",
+    "wand",
+    "weak0",
+    "weak1",
+    "while",
+    "wire",
+    "wor",
+    "xnor",
+    "xor",
+];
+
+/// IEEE 1076-2008 reserved words.
+const VHDL_RESERVED_WORDS: &[&str] = &[
+    "abs",
+    "access",
+    "after",
+    "alias",
+    "all",
+    "and",
+    "architecture",
+    "array",
+    "assert",
+    "assume",
+    "assume_guarantee",
+    "attribute",
+    "begin",
+    "block",
+    "body",
+    "buffer",
+    "bus",
+    "case",
+    "component",
+    "configuration",
+    "constant",
+    "context",
+    "cover",
+    "default",
+    "disconnect",
+    "downto",
+    "else",
+    "elsif",
+    "end",
+    "entity",
+    "exit",
+    "fairness",
+    "file",
+    "for",
+    "force",
+    "function",
+    "generate",
+    "generic",
+    "group",
+    "guarded",
+    "if",
+    "impure",
+    "in",
+    "inertial",
+    "inout",
+    "is",
+    "label",
+    "library",
+    "linkage",
+    "literal",
+    "loop",
+    "map",
+    "mod",
+    "nand",
+    "new",
+    "next",
+    "nor",
+    "not",
+    "null",
+    "of",
+    "on",
+    "open",
+    "or",
+    "others",
+    "out",
+    "package",
+    "parameter",
+    "port",
+    "postponed",
+    "procedure",
+    "process",
+    "property",
+    "protected",
+    "pure",
+    "range",
+    "record",
+    "register",
+    "reject",
+    "release",
+    "rem",
+    "report",
+    "restrict",
+    "restrict_guarantee",
+    "return",
+    "rol",
+    "ror",
+    "select",
+    "sequence",
+    "severity",
+    "shared",
+    "signal",
+    "sla",
+    "sll",
+    "sra",
+    "srl",
+    "strong",
+    "subtype",
+    "then",
+    "to",
+    "transport",
+    "type",
+    "unaffected",
+    "units",
+    "until",
+    "use",
+    "variable",
+    "vmode",
+    "vprop",
+    "vunit",
+    "wait",
+    "when",
+    "while",
+    "with",
+    "xnor",
+    "xor",
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verilog_identifiers() {
+        assert_eq!(Dialect::Verilog.identifier("a_1$", false).unwrap(), "a_1$");
+        assert_eq!(
+            Dialect::Verilog.identifier("a[0]", false).unwrap(),
+            "\\a[0] "
+        );
+        assert_eq!(
+            Dialect::Verilog.identifier("wire", false).unwrap(),
+            "\\wire "
+        );
+        assert_eq!(Dialect::Verilog.identifier("1a", false).unwrap(), "\\1a ");
+        assert!(Dialect::Verilog.identifier("a b", false).is_err());
+        assert!(Dialect::Verilog.identifier("", false).is_err());
+    }
+
+    #[test]
+    fn test_vhdl_identifiers() {
+        assert_eq!(Dialect::Vhdl.identifier("a_1", false).unwrap(), "a_1");
+        assert_eq!(Dialect::Vhdl.identifier("a__1", false).unwrap(), "\\a__1\\");
+        assert_eq!(Dialect::Vhdl.identifier("_a", false).unwrap(), "\\_a\\");
+        assert_eq!(
+            Dialect::Vhdl.identifier("Signal", false).unwrap(),
+            "\\Signal\\"
+        );
+        assert_eq!(
+            Dialect::Vhdl.identifier("a\\b", false).unwrap(),
+            "\\a\\\\b\\"
+        );
+        assert_eq!(Dialect::Vhdl.identifier("A", true).unwrap(), "\\A\\");
+        assert_eq!(Dialect::Vhdl.identifier("a b", false).unwrap(), "\\a b\\");
+        assert!(Dialect::Vhdl.identifier("a\tb", false).is_err());
+    }
+}
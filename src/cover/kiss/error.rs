@@ -0,0 +1,271 @@
+//! Error types for KISS2 state-table parsing
+
+use std::fmt;
+use std::io;
+use std::sync::Arc;
+
+/// Errors related to KISS2 state-table parsing and validation
+///
+/// These errors occur when reading a state table that is malformed or does not describe a
+/// deterministic machine.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum KISSError {
+    /// A transition appears before the `.i` or `.o` directive that sizes it.
+    MissingDimension {
+        /// `.i` or `.o`
+        directive: Arc<str>,
+    },
+    /// A directive's value is missing or not what the directive expects.
+    InvalidDirectiveValue {
+        /// The directive
+        directive: Arc<str>,
+        /// The value as written
+        value: Arc<str>,
+    },
+    /// A directive that may appear once appears twice.
+    DuplicateDirective {
+        /// The repeated directive
+        directive: Arc<str>,
+    },
+    /// A transition line does not have the input, present-state, next-state and output fields.
+    InvalidTransition {
+        /// The line as written
+        line: Arc<str>,
+    },
+    /// Invalid character in a transition's input field.
+    InvalidInputCharacter {
+        /// The invalid character
+        character: char,
+        /// Position in the field
+        position: usize,
+    },
+    /// Invalid character in a transition's output field.
+    InvalidOutputCharacter {
+        /// The invalid character
+        character: char,
+        /// Position in the field
+        position: usize,
+    },
+    /// A transition's input field is not `.i` wide.
+    InputWidthMismatch {
+        /// The declared number of inputs
+        expected: usize,
+        /// The field's width
+        actual: usize,
+    },
+    /// A transition's output field is not `.o` wide.
+    OutputWidthMismatch {
+        /// The declared number of outputs
+        expected: usize,
+        /// The field's width
+        actual: usize,
+    },
+    /// `.ilb` or `.ob` names a different number of signals than `.i` or `.o` declares.
+    LabelCountMismatch {
+        /// `.ilb` or `.ob`
+        directive: Arc<str>,
+        /// The declared number of signals
+        expected: usize,
+        /// The number of names given
+        actual: usize,
+    },
+    /// `.ilb` or `.ob` repeats a name.
+    DuplicateSignal {
+        /// The repeated name
+        name: Arc<str>,
+    },
+    /// `.p` or `.s` disagrees with the table that follows.
+    CountMismatch {
+        /// `.p` or `.s`
+        directive: Arc<str>,
+        /// The declared count
+        declared: usize,
+        /// The count found in the table
+        found: usize,
+    },
+    /// `.r` names a state that no transition mentions.
+    UnknownResetState {
+        /// The reset state's name
+        name: Arc<str>,
+    },
+    /// Two transitions from the same state overlap on some input but disagree on the next state
+    /// or on an output.
+    ConflictingTransitions {
+        /// The present state, `*` if either transition is from any state
+        state: Arc<str>,
+    },
+}
+
+impl fmt::Display for KISSError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KISSError::MissingDimension { directive } => {
+                write!(f, "transition appears before the {} directive", directive)
+            }
+            KISSError::InvalidDirectiveValue { directive, value } => {
+                write!(f, "invalid value '{}' for {}", value, directive)
+            }
+            KISSError::DuplicateDirective { directive } => {
+                write!(f, "{} appears more than once", directive)
+            }
+            KISSError::InvalidTransition { line } => write!(
+                f,
+                "transition '{}' is not of the form 'input state next-state output'",
+                line
+            ),
+            KISSError::InvalidInputCharacter {
+                character,
+                position,
+            } => write!(
+                f,
+                "invalid character '{}' at position {} in input field",
+                character, position
+            ),
+            KISSError::InvalidOutputCharacter {
+                character,
+                position,
+            } => write!(
+                f,
+                "invalid character '{}' at position {} in output field",
+                character, position
+            ),
+            KISSError::InputWidthMismatch { expected, actual } => write!(
+                f,
+                "input field has {} characters, expected {}",
+                actual, expected
+            ),
+            KISSError::OutputWidthMismatch { expected, actual } => write!(
+                f,
+                "output field has {} characters, expected {}",
+                actual, expected
+            ),
+            KISSError::LabelCountMismatch {
+                directive,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "{} names {} signals, expected {}",
+                directive, actual, expected
+            ),
+            KISSError::DuplicateSignal { name } => {
+                write!(f, "signal '{}' is named more than once", name)
+            }
+            KISSError::CountMismatch {
+                directive,
+                declared,
+                found,
+            } => write!(
+                f,
+                "{} declares {}, but the table has {}",
+                directive, declared, found
+            ),
+            KISSError::UnknownResetState { name } => {
+                write!(f, "reset state '{}' has no transitions", name)
+            }
+            KISSError::ConflictingTransitions { state } => write!(
+                f,
+                "overlapping transitions from state '{}' disagree on the next state or outputs",
+                state
+            ),
+        }
+    }
+}
+
+impl std::error::Error for KISSError {}
+
+impl From<KISSError> for io::Error {
+    fn from(err: KISSError) -> Self {
+        io::Error::new(io::ErrorKind::InvalidData, err)
+    }
+}
+
+/// Errors that can occur when reading a KISS2 state table
+///
+/// This error type is returned by the `Fsm::from_kiss_*` methods.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum KISSReadError {
+    /// State-table format error
+    KISS(KISSError),
+    /// IO error during reading
+    Io(io::Error),
+}
+
+impl fmt::Display for KISSReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KISSReadError::KISS(e) => write!(f, "KISS2 format error: {}", e),
+            KISSReadError::Io(e) => write!(f, "IO error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for KISSReadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            KISSReadError::KISS(e) => Some(e),
+            KISSReadError::Io(e) => Some(e),
+        }
+    }
+}
+
+impl From<KISSError> for KISSReadError {
+    fn from(err: KISSError) -> Self {
+        KISSReadError::KISS(err)
+    }
+}
+
+impl From<io::Error> for KISSReadError {
+    fn from(err: io::Error) -> Self {
+        KISSReadError::Io(err)
+    }
+}
+
+impl From<KISSReadError> for io::Error {
+    fn from(err: KISSReadError) -> Self {
+        match err {
+            KISSReadError::Io(e) => e,
+            KISSReadError::KISS(e) => io::Error::new(io::ErrorKind::InvalidData, e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kiss_error_messages() {
+        let err = KISSError::CountMismatch {
+            directive: Arc::from(".s"),
+            declared: 4,
+            found: 3,
+        };
+        assert_eq!(err.to_string(), ".s declares 4, but the table has 3");
+        let err = KISSError::ConflictingTransitions {
+            state: Arc::from("idle"),
+        };
+        assert!(err.to_string().contains("'idle'"));
+        let err = KISSError::InputWidthMismatch {
+            expected: 2,
+            actual: 3,
+        };
+        assert!(err.to_string().contains("expected 2"));
+    }
+
+    #[test]
+    fn test_kiss_read_error_conversions() {
+        let read_err: KISSReadError = KISSError::UnknownResetState {
+            name: Arc::from("s9"),
+        }
+        .into();
+        assert!(matches!(read_err, KISSReadError::KISS(_)));
+        assert!(std::error::Error::source(&read_err).is_some());
+        let io_err: io::Error = read_err.into();
+        assert_eq!(io_err.kind(), io::ErrorKind::InvalidData);
+        let read_err: KISSReadError = io::Error::other("disk full").into();
+        assert!(matches!(read_err, KISSReadError::Io(_)));
+    }
+}
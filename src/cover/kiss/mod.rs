@@ -0,0 +1,712 @@
+//! KISS2 state-table support
+//!
+//! This module reads finite-state machines in KISS2, the state-table format of the Berkeley
+//! synthesis tools (SIS, NOVA, the MCNC benchmarks), and turns them into two-level logic. A table
+//! declares its dimensions, then lists one transition per line — an input cube, the present
+//! state, the next state and the output values:
+//!
+//! ```text
+//! .i 1
+//! .o 1
+//! .s 2
+//! .r idle
+//! 0 idle idle 0
+//! 1 idle seen 0
+//! 0 seen idle 0
+//! 1 seen seen 1
+//! .e
+//! ```
+//!
+//! - [`Fsm::from_kiss_reader`] (and the `_string`/`_file` forms) reads such a table into a
+//!   symbolic [`Fsm`], whose states are still names;
+//! - [`Fsm::encode`] assigns each state a binary code under a [`StateEncoding`] and minimises the
+//!   resulting next-state and output logic into a [`Cover<Symbol, Symbol>`](Cover).
+//!
+//! # Format
+//!
+//! Besides `.i` and `.o`, a table may declare `.p` (the number of transitions), `.s` (the number
+//! of states), `.r` (the reset state), and `.ilb`/`.ob` to name the inputs and outputs; `.p` and
+//! `.s` are checked against the table. `.e`/`.end` ends the table, `#` starts a comment, and other
+//! directives are skipped.
+//!
+//! A present state of `*` stands for every state, and a next state of `*` leaves it unspecified.
+//! Input and output fields use `0`, `1` and `-`. Whatever the table does not specify — a `-`
+//! output, a `*` next state, an input or state with no transition — is a don't-care of the
+//! encoded logic, as are the codes no state is given.
+//!
+//! # Quick Example
+//!
+//! ```
+//! use espresso_logic::cover::kiss::{Fsm, StateEncoding};
+//!
+//! # fn main() -> std::io::Result<()> {
+//! let kiss = "\
+//! .i 1
+//! .o 1
+//! .ilb x
+//! .ob z
+//! .r idle
+//! 0 idle idle 0
+//! 1 idle seen 0
+//! 0 seen idle 0
+//! 1 seen seen 1
+//! ";
+//! let fsm = Fsm::from_kiss_string(kiss)?;
+//! assert_eq!(fsm.states().len(), 2);
+//!
+//! let encoded = fsm.encode(StateEncoding::Binary)?;
+//! assert_eq!(encoded.state_code("seen"), Some(&[true][..]));
+//! // `y0_next = x`, `z = x & y0`
+//! assert_eq!(encoded.cover().num_cubes(), 2);
+//! assert_eq!(encoded.cover().input_labels()[1].as_ref(), "y0");
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod error;
+
+pub use error::{KISSError, KISSReadError};
+
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Cursor};
+use std::path::Path;
+use std::sync::Arc;
+
+use super::conversions::{anonymous_cover_from_raw, RawCube};
+use super::label::Anonymous;
+use super::minterm::InputField;
+use super::symbols::Symbols;
+use super::{Cover, CoverType, CubeType, Minimizable};
+use crate::error::MinimizationError;
+use crate::Symbol;
+
+/// How [`Fsm::encode`] assigns codes to states
+///
+/// The reset state, when the table names one, comes first: it is given the all-zero code by
+/// [`Binary`](Self::Binary), [`Gray`](Self::Gray) and [`FewestCubes`](Self::FewestCubes), and the
+/// first bit by [`OneHot`](Self::OneHot). Otherwise states are numbered in order of appearance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum StateEncoding {
+    /// The `k`th state is `k` in binary, on the fewest bits that number every state.
+    #[default]
+    Binary,
+    /// The `k`th state is the `k`th Gray code, on as many bits as [`Binary`](Self::Binary).
+    Gray,
+    /// One bit per state, set only in that state's code.
+    OneHot,
+    /// A code of [`Binary`](Self::Binary)'s width chosen to minimise the number of cubes.
+    ///
+    /// Starting from the better of the binary and Gray assignments, codes (including unused ones)
+    /// are swapped pairwise while a swap reduces the minimised cube count. Each pass minimises the
+    /// logic once per pair of codes, so this is meant for small controllers.
+    FewestCubes,
+}
+
+/// One row of a state table
+#[derive(Debug, Clone)]
+struct Transition {
+    inputs: Vec<InputField>,
+    /// `None` for `*`, any state.
+    present: Option<usize>,
+    /// `None` for `*`, unspecified.
+    next: Option<usize>,
+    outputs: Vec<Option<bool>>,
+}
+
+impl Transition {
+    /// Whether some input and state fire both transitions.
+    fn overlaps(&self, other: &Transition) -> bool {
+        let same_state = match (self.present, other.present) {
+            (Some(a), Some(b)) => a == b,
+            _ => true,
+        };
+        same_state
+            && self.inputs.iter().zip(&other.inputs).all(|pair| {
+                !matches!(
+                    pair,
+                    (InputField::Zero, InputField::One) | (InputField::One, InputField::Zero)
+                )
+            })
+    }
+
+    /// Whether the two transitions prescribe different next states or output values.
+    fn disagrees(&self, other: &Transition) -> bool {
+        let next = matches!((self.next, other.next), (Some(a), Some(b)) if a != b);
+        next || self
+            .outputs
+            .iter()
+            .zip(&other.outputs)
+            .any(|pair| matches!(pair, (Some(a), Some(b)) if a != b))
+    }
+}
+
+/// A symbolic finite-state machine read from a KISS2 state table
+///
+/// States are kept as names until [`encode`](Self::encode) gives them codes.
+#[derive(Debug, Clone)]
+pub struct Fsm {
+    inputs: Vec<Symbol>,
+    outputs: Vec<Symbol>,
+    /// The reset state first, if there is one, then in order of appearance.
+    states: Vec<Symbol>,
+    has_reset: bool,
+    transitions: Vec<Transition>,
+}
+
+/// Parse one transition line of a table with `num_inputs` inputs and `num_outputs` outputs,
+/// numbering new states in `states`.
+fn parse_transition(
+    words: &[&str],
+    num_inputs: usize,
+    num_outputs: usize,
+    states: &mut Vec<String>,
+) -> Result<Transition, KISSError> {
+    // A zero-width plane is written as no field at all.
+    let expected = 2 + usize::from(num_inputs > 0) + usize::from(num_outputs > 0);
+    if words.len() != expected {
+        return Err(KISSError::InvalidTransition {
+            line: Arc::from(words.join(" ")),
+        });
+    }
+    let (input_plane, rest) = if num_inputs > 0 {
+        (words[0], &words[1..])
+    } else {
+        ("", words)
+    };
+    let output_plane = if num_outputs > 0 { rest[2] } else { "" };
+
+    let inputs = input_plane
+        .chars()
+        .enumerate()
+        .map(|(position, character)| match character {
+            '0' => Ok(InputField::Zero),
+            '1' => Ok(InputField::One),
+            '-' => Ok(InputField::DontCare),
+            _ => Err(KISSError::InvalidInputCharacter {
+                character,
+                position,
+            }),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if inputs.len() != num_inputs {
+        return Err(KISSError::InputWidthMismatch {
+            expected: num_inputs,
+            actual: inputs.len(),
+        });
+    }
+    let outputs = output_plane
+        .chars()
+        .enumerate()
+        .map(|(position, character)| match character {
+            '0' => Ok(Some(false)),
+            '1' => Ok(Some(true)),
+            '-' => Ok(None),
+            _ => Err(KISSError::InvalidOutputCharacter {
+                character,
+                position,
+            }),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if outputs.len() != num_outputs {
+        return Err(KISSError::OutputWidthMismatch {
+            expected: num_outputs,
+            actual: outputs.len(),
+        });
+    }
+
+    let mut state = |name: &str| -> Option<usize> {
+        if name == "*" {
+            return None;
+        }
+        Some(match states.iter().position(|s| s == name) {
+            Some(k) => k,
+            None => {
+                states.push(name.to_string());
+                states.len() - 1
+            }
+        })
+    };
+    let present = state(rest[0]);
+    let next = state(rest[1]);
+    Ok(Transition {
+        inputs,
+        present,
+        next,
+        outputs,
+    })
+}
+
+/// Parse the value of a counting directive.
+fn parse_count(directive: &str, words: &[&str]) -> Result<usize, KISSError> {
+    match words {
+        [value] => value.parse().map_err(|_| KISSError::InvalidDirectiveValue {
+            directive: Arc::from(directive),
+            value: Arc::from(*value),
+        }),
+        _ => Err(KISSError::InvalidDirectiveValue {
+            directive: Arc::from(directive),
+            value: Arc::from(words.join(" ")),
+        }),
+    }
+}
+
+/// Set a directive that may appear once.
+fn set_once<T>(slot: &mut Option<T>, directive: &str, value: T) -> Result<(), KISSError> {
+    if slot.replace(value).is_some() {
+        return Err(KISSError::DuplicateDirective {
+            directive: Arc::from(directive),
+        });
+    }
+    Ok(())
+}
+
+/// Check `.ilb`/`.ob` names against the declared width, or number the signals `prefix0`, ….
+fn signal_labels(
+    directive: &str,
+    labels: Option<Vec<String>>,
+    count: usize,
+    prefix: &str,
+) -> Result<Vec<Symbol>, KISSError> {
+    let Some(labels) = labels else {
+        return Ok((0..count)
+            .map(|k| Symbol::from(format!("{prefix}{k}").as_str()))
+            .collect());
+    };
+    if labels.len() != count {
+        return Err(KISSError::LabelCountMismatch {
+            directive: Arc::from(directive),
+            expected: count,
+            actual: labels.len(),
+        });
+    }
+    let mut seen = HashSet::with_capacity(count);
+    for name in &labels {
+        if !seen.insert(name.as_str()) {
+            return Err(KISSError::DuplicateSignal {
+                name: Arc::from(name.as_str()),
+            });
+        }
+    }
+    Ok(labels.iter().map(|s| Symbol::from(s.as_str())).collect())
+}
+
+/// Parse a KISS2 stream into a symbolic machine.
+fn parse_kiss<R: BufRead>(reader: R) -> Result<Fsm, KISSReadError> {
+    let mut num_inputs: Option<usize> = None;
+    let mut num_outputs: Option<usize> = None;
+    let mut num_transitions: Option<usize> = None;
+    let mut num_states: Option<usize> = None;
+    let mut reset: Option<String> = None;
+    let mut input_labels: Option<Vec<String>> = None;
+    let mut output_labels: Option<Vec<String>> = None;
+    let mut states: Vec<String> = Vec::new();
+    let mut transitions: Vec<Transition> = Vec::new();
+
+    for line in reader.lines() {
+        let line = line?;
+        let line = line.split('#').next().unwrap_or("");
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let Some(&first) = words.first() else {
+            continue;
+        };
+
+        if !first.starts_with('.') {
+            let missing = |directive: &str| KISSError::MissingDimension {
+                directive: Arc::from(directive),
+            };
+            let ni = num_inputs.ok_or_else(|| missing(".i"))?;
+            let no = num_outputs.ok_or_else(|| missing(".o"))?;
+            transitions.push(parse_transition(&words, ni, no, &mut states)?);
+            continue;
+        }
+
+        let values = &words[1..];
+        match first {
+            ".i" => set_once(&mut num_inputs, first, parse_count(first, values)?)?,
+            ".o" => set_once(&mut num_outputs, first, parse_count(first, values)?)?,
+            ".p" => set_once(&mut num_transitions, first, parse_count(first, values)?)?,
+            ".s" => set_once(&mut num_states, first, parse_count(first, values)?)?,
+            ".r" => match values {
+                [name] if *name != "*" => set_once(&mut reset, first, name.to_string())?,
+                _ => {
+                    return Err(KISSError::InvalidDirectiveValue {
+                        directive: Arc::from(first),
+                        value: Arc::from(values.join(" ")),
+                    }
+                    .into())
+                }
+            },
+            ".ilb" => set_once(
+                &mut input_labels,
+                first,
+                values.iter().map(|s| s.to_string()).collect(),
+            )?,
+            ".ob" => set_once(
+                &mut output_labels,
+                first,
+                values.iter().map(|s| s.to_string()).collect(),
+            )?,
+            ".e" | ".end" => break,
+            _ => {}
+        }
+    }
+
+    let counts = [
+        (".p", num_transitions, transitions.len()),
+        (".s", num_states, states.len()),
+    ];
+    for (directive, declared, found) in counts {
+        if let Some(declared) = declared.filter(|&declared| declared != found) {
+            return Err(KISSError::CountMismatch {
+                directive: Arc::from(directive),
+                declared,
+                found,
+            }
+            .into());
+        }
+    }
+
+    let inputs = signal_labels(".ilb", input_labels, num_inputs.unwrap_or(0), "x")?;
+    let outputs = signal_labels(".ob", output_labels, num_outputs.unwrap_or(0), "z")?;
+
+    for (k, a) in transitions.iter().enumerate() {
+        if let Some(b) = transitions[k + 1..]
+            .iter()
+            .find(|b| a.overlaps(b) && a.disagrees(b))
+        {
+            let state = if a.present == b.present {
+                a.present
+            } else {
+                None
+            };
+            return Err(KISSError::ConflictingTransitions {
+                state: Arc::from(state.map_or("*", |s| states[s].as_str())),
+            }
+            .into());
+        }
+    }
+
+    // Move the reset state to the front, renumbering the transitions to match.
+    let has_reset = reset.is_some();
+    if let Some(name) = reset {
+        let r =
+            states
+                .iter()
+                .position(|s| *s == name)
+                .ok_or_else(|| KISSError::UnknownResetState {
+                    name: Arc::from(name.as_str()),
+                })?;
+        let renumber = |s: usize| match s.cmp(&r) {
+            std::cmp::Ordering::Less => s + 1,
+            std::cmp::Ordering::Equal => 0,
+            std::cmp::Ordering::Greater => s,
+        };
+        for t in &mut transitions {
+            t.present = t.present.map(renumber);
+            t.next = t.next.map(renumber);
+        }
+        let state = states.remove(r);
+        states.insert(0, state);
+    }
+
+    Ok(Fsm {
+        inputs,
+        outputs,
+        states: states.iter().map(|s| Symbol::from(s.as_str())).collect(),
+        has_reset,
+        transitions,
+    })
+}
+
+/// The fewest bits that give `n` states distinct codes.
+fn binary_width(n: usize) -> usize {
+    (usize::BITS - n.saturating_sub(1).leading_zeros()) as usize
+}
+
+/// `code` on `width` bits, most significant first; bits beyond `usize` read as zero.
+fn code_bits(code: usize, width: usize) -> Vec<bool> {
+    (0..width)
+        .rev()
+        .map(|b| {
+            u32::try_from(b)
+                .ok()
+                .and_then(|b| code.checked_shr(b))
+                .is_some_and(|c| c & 1 == 1)
+        })
+        .collect()
+}
+
+/// `base`, prefixed with `_` until no name in `taken` has it.
+fn fresh_name(base: String, taken: &[Symbol]) -> Symbol {
+    let mut name = base;
+    while taken.iter().any(|s| s.as_str() == name) {
+        name.insert(0, '_');
+    }
+    Symbol::from(name.as_str())
+}
+
+impl Fsm {
+    /// Parse a state table from a KISS2 stream in any `BufRead`.
+    ///
+    /// See the [module documentation](crate::cover::kiss) for what is accepted. Without `.ilb`
+    /// or `.ob`, the inputs are named `x0`, `x1`, … and the outputs `z0`, `z1`, ….
+    ///
+    /// # Errors
+    ///
+    /// Returns [`KISSReadError::KISS`] for a malformed table, or one in which overlapping
+    /// transitions disagree, and [`KISSReadError::Io`] if reading fails.
+    pub fn from_kiss_reader<R: BufRead>(reader: R) -> Result<Self, KISSReadError> {
+        parse_kiss(reader)
+    }
+
+    /// Parse a state table from a KISS2-format string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use espresso_logic::cover::kiss::Fsm;
+    ///
+    /// let fsm = Fsm::from_kiss_string(".i 1\n.o 1\n0 a b 1\n1 b a 0\n.e\n").unwrap();
+    /// assert_eq!(fsm.num_inputs(), 1);
+    /// assert_eq!(fsm.states()[1].as_str(), "b");
+    /// assert_eq!(fsm.reset_state(), None);
+    /// ```
+    pub fn from_kiss_string<T: AsRef<str>>(s: T) -> Result<Self, KISSReadError> {
+        Self::from_kiss_reader(Cursor::new(s.as_ref().as_bytes()))
+    }
+
+    /// Load a state table from a KISS2 file.
+    pub fn from_kiss_file<P: AsRef<Path>>(path: P) -> Result<Self, KISSReadError> {
+        Self::from_kiss_reader(BufReader::new(File::open(path)?))
+    }
+
+    /// Number of primary inputs
+    #[must_use]
+    pub fn num_inputs(&self) -> usize {
+        self.inputs.len()
+    }
+
+    /// Number of primary outputs
+    #[must_use]
+    pub fn num_outputs(&self) -> usize {
+        self.outputs.len()
+    }
+
+    /// The primary input names
+    #[must_use]
+    pub fn input_labels(&self) -> &[Symbol] {
+        &self.inputs
+    }
+
+    /// The primary output names
+    #[must_use]
+    pub fn output_labels(&self) -> &[Symbol] {
+        &self.outputs
+    }
+
+    /// The states, reset state first, in the order [`StateEncoding`] numbers them
+    #[must_use]
+    pub fn states(&self) -> &[Symbol] {
+        &self.states
+    }
+
+    /// The reset state named by `.r`, if any
+    #[must_use]
+    pub fn reset_state(&self) -> Option<&Symbol> {
+        self.states.first().filter(|_| self.has_reset)
+    }
+
+    /// Number of transitions in the table
+    #[must_use]
+    pub fn num_transitions(&self) -> usize {
+        self.transitions.len()
+    }
+
+    /// Encode the states and minimise the machine's combinational logic.
+    ///
+    /// The returned cover reads the primary inputs followed by the present-state bits `y0`,
+    /// `y1`, …, and drives the next-state bits `y0_next`, `y1_next`, … followed by the primary
+    /// outputs; `y0` is the most significant bit of a code. A state-bit name that an input or
+    /// output already uses is prefixed with `_` until it is unique.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MinimizationError`] if Espresso fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use espresso_logic::cover::kiss::{Fsm, StateEncoding};
+    ///
+    /// let fsm = Fsm::from_kiss_string(".i 1\n.o 0\n0 a a\n1 a b\n- b c\n- c a\n").unwrap();
+    /// let encoded = fsm.encode(StateEncoding::OneHot).unwrap();
+    /// assert_eq!(encoded.num_state_bits(), 3);
+    /// assert_eq!(encoded.state_code("c"), Some(&[false, false, true][..]));
+    /// assert_eq!(encoded.cover().num_outputs(), 3);
+    /// ```
+    pub fn encode(&self, encoding: StateEncoding) -> Result<EncodedFsm, MinimizationError> {
+        let n = self.states.len();
+        let width = binary_width(n);
+        let codes: Vec<Vec<bool>> = match encoding {
+            StateEncoding::Binary => (0..n).map(|k| code_bits(k, width)).collect(),
+            StateEncoding::Gray => (0..n).map(|k| code_bits(k ^ (k >> 1), width)).collect(),
+            StateEncoding::OneHot => (0..n).map(|k| (0..n).map(|j| j == k).collect()).collect(),
+            StateEncoding::FewestCubes => self
+                .fewest_cubes(width)?
+                .into_iter()
+                .map(|code| code_bits(code, width))
+                .collect(),
+        };
+        let width = codes.first().map_or(0, Vec::len);
+
+        let mut cover = self.logic(&codes).minimize()?;
+        cover.cubes.retain(|cube| cube.cube_type() == CubeType::F);
+        cover.cover_type = CoverType::F;
+
+        let present = (0..width).map(|b| fresh_name(format!("y{b}"), &self.inputs));
+        let next = (0..width).map(|b| fresh_name(format!("y{b}_next"), &self.outputs));
+        let input_symbols = Symbols::new(self.inputs.iter().cloned().chain(present).collect())
+            .expect("input names are duplicate-checked, and state bits are given fresh names");
+        let output_symbols = Symbols::new(next.chain(self.outputs.iter().cloned()).collect())
+            .expect("output names are duplicate-checked, and state bits are given fresh names");
+        let cover = cover
+            .relabel_tables(input_symbols, output_symbols)
+            .expect("the encoded logic is laid out over the inputs, outputs and state bits");
+
+        Ok(EncodedFsm {
+            cover,
+            states: self.states.clone(),
+            codes,
+        })
+    }
+
+    /// The encoded logic as an FR cover: each transition's ones in the ON-set and its zeros in the
+    /// OFF-set, leaving everything else a don't-care.
+    fn logic(&self, codes: &[Vec<bool>]) -> Cover<Anonymous, Anonymous> {
+        let width = codes.first().map_or(0, Vec::len);
+        let num_outputs = width + self.outputs.len();
+        let mut cubes: Vec<RawCube> = Vec::new();
+        for t in &self.transitions {
+            let mut fields = t.inputs.clone();
+            match t.present {
+                Some(s) => fields.extend(codes[s].iter().map(|&bit| {
+                    if bit {
+                        InputField::One
+                    } else {
+                        InputField::Zero
+                    }
+                })),
+                None => fields.extend(std::iter::repeat_n(InputField::DontCare, width)),
+            }
+            let values: Vec<Option<bool>> = match t.next {
+                Some(s) => codes[s].iter().map(|&bit| Some(bit)).collect(),
+                None => vec![None; width],
+            };
+            let values: Vec<Option<bool>> = values.into_iter().chain(t.outputs.clone()).collect();
+            for (value, set) in [(true, CubeType::F), (false, CubeType::R)] {
+                let mask: Vec<bool> = values.iter().map(|&v| v == Some(value)).collect();
+                if mask.contains(&true) {
+                    cubes.push((fields.clone(), mask, set));
+                }
+            }
+        }
+        anonymous_cover_from_raw(self.inputs.len() + width, num_outputs, cubes, CoverType::FR)
+    }
+
+    /// Number of ON-set cubes once the logic under `codes` (one per state, on `width` bits) is
+    /// minimised.
+    fn cube_count(&self, codes: &[usize], width: usize) -> Result<usize, MinimizationError> {
+        let codes: Vec<Vec<bool>> = codes.iter().map(|&c| code_bits(c, width)).collect();
+        Ok(self
+            .logic(&codes)
+            .minimize()?
+            .cubes
+            .iter()
+            .filter(|cube| cube.cube_type() == CubeType::F)
+            .count())
+    }
+
+    /// Search for the codes of [`StateEncoding::FewestCubes`].
+    fn fewest_cubes(&self, width: usize) -> Result<Vec<usize>, MinimizationError> {
+        let n = self.states.len();
+        let mut best: Vec<usize> = (0..n).collect();
+        let mut best_cost = self.cube_count(&best, width)?;
+        let gray: Vec<usize> = (0..n).map(|k| k ^ (k >> 1)).collect();
+        let gray_cost = self.cube_count(&gray, width)?;
+        if gray_cost < best_cost {
+            (best, best_cost) = (gray, gray_cost);
+        }
+
+        // The reset state keeps the all-zero code.
+        let first = usize::from(self.has_reset);
+        let slots = 1usize << width;
+        let mut improved = true;
+        while improved {
+            improved = false;
+            for a in first..slots {
+                for b in a + 1..slots {
+                    let mut trial = best.clone();
+                    let mut moved = false;
+                    for code in &mut trial {
+                        if *code == a || *code == b {
+                            *code ^= a ^ b;
+                            moved = true;
+                        }
+                    }
+                    if !moved {
+                        continue;
+                    }
+                    let cost = self.cube_count(&trial, width)?;
+                    if cost < best_cost {
+                        (best, best_cost) = (trial, cost);
+                        improved = true;
+                    }
+                }
+            }
+        }
+        Ok(best)
+    }
+}
+
+/// A state machine's minimised logic under a state encoding
+///
+/// Returned by [`Fsm::encode`].
+#[derive(Debug, Clone)]
+pub struct EncodedFsm {
+    cover: Cover<Symbol, Symbol>,
+    states: Vec<Symbol>,
+    codes: Vec<Vec<bool>>,
+}
+
+impl EncodedFsm {
+    /// The minimised next-state and output logic
+    #[must_use]
+    pub fn cover(&self) -> &Cover<Symbol, Symbol> {
+        &self.cover
+    }
+
+    /// Take the minimised next-state and output logic
+    #[must_use]
+    pub fn into_cover(self) -> Cover<Symbol, Symbol> {
+        self.cover
+    }
+
+    /// Number of bits in each state code
+    #[must_use]
+    pub fn num_state_bits(&self) -> usize {
+        self.codes.first().map_or(0, Vec::len)
+    }
+
+    /// The code of the named state, most significant bit (`y0`) first
+    #[must_use]
+    pub fn state_code(&self, state: &str) -> Option<&[bool]> {
+        let k = self.states.iter().position(|s| s.as_str() == state)?;
+        Some(&self.codes[k])
+    }
+
+    /// Each state with its code, in the order of [`Fsm::states`]
+    pub fn state_codes(&self) -> impl Iterator<Item = (&Symbol, &[bool])> {
+        self.states.iter().zip(self.codes.iter().map(Vec::as_slice))
+    }
+}
@@ -12,9 +12,9 @@
 use std::collections::HashSet;
 use std::hash::Hash;
 
-/// Seals the label trait family ([`Label`], [`ReconcilableLabel`], [`PlaLabel`], [`StringLabel`]) so
-/// they cannot be implemented for new types outside this crate. `Sealed` is implemented exactly where
-/// `Label` is — for every `Ord + Eq + Hash + Clone` type and for [`Anonymous`] — so a type that
+/// Seals the label trait family ([`Label`], [`ReconcilableLabel`], [`PairableLabel`],
+/// [`SymbolicLabel`], [`PlaLabel`], [`StringLabel`]) so they cannot be implemented for new types
+/// outside this crate. `Sealed` is implemented exactly where `Label` is — for every `Ord + Eq + Hash + Clone` type and for [`Anonymous`] — so a type that
 /// already qualifies as a label via the blanket impls is unaffected, but a foreign type that does not
 /// can no longer hand-roll a `Label` (and thus a custom [`identity`](Label::identity)) impl.
 pub(crate) mod sealed {
@@ -171,6 +171,69 @@ impl ReconcilableLabel for Anonymous {
     }
 }
 
+/// How a label type names the four values of a two-bit decoder built by
+/// [`Cover::pair_inputs`](crate::Cover::pair_inputs).
+///
+/// Pairing inputs `a` and `b` replaces their two positions with four, one per decoder line:
+/// - **string-like** labels take the reference tool's names, one per line of the decoder:
+///   `a.bar+b.bar`, `a.bar+b`, `a+b.bar` and `a+b`, reconciled against the rest of the header as
+///   [`ReconcilableLabel`] does;
+/// - [`Anonymous`] yields four fresh positions.
+///
+/// Like [`ReconcilableLabel`], only these two impls exist.
+pub trait PairableLabel: ReconcilableLabel {
+    /// The labels of the four decoder values for the pair (`first`, `second`), in value order.
+    fn pair_values(first: &Self, second: &Self) -> [Self; 4];
+}
+
+impl<T: StringLabel> PairableLabel for T {
+    fn pair_values(first: &Self, second: &Self) -> [Self; 4] {
+        let (a, b) = (first.as_ref(), second.as_ref());
+        [
+            format!("{a}.bar+{b}.bar"),
+            format!("{a}.bar+{b}"),
+            format!("{a}+{b}.bar"),
+            format!("{a}+{b}"),
+        ]
+        .map(|name| T::from(name.as_str()))
+    }
+}
+
+impl PairableLabel for Anonymous {
+    #[inline]
+    fn pair_values(_first: &Self, _second: &Self) -> [Self; 4] {
+        [Anonymous; 4]
+    }
+}
+
+/// How a label type names the values of a symbolic variable built by
+/// [`Cover::encode_symbolic_inputs`](crate::Cover::encode_symbolic_inputs) or
+/// [`Cover::encode_symbolic_outputs`](crate::Cover::encode_symbolic_outputs).
+///
+/// - **string-like** labels take the value's name (see
+///   [`SymbolicVariable::value_names`](crate::SymbolicVariable::value_names)), reconciled against the
+///   rest of the header as [`ReconcilableLabel`] does;
+/// - [`Anonymous`] yields a fresh position and drops the name.
+///
+/// Like [`ReconcilableLabel`], only these two impls exist.
+pub trait SymbolicLabel: ReconcilableLabel {
+    /// The label of the symbolic value called `name`.
+    fn symbolic_value(name: &str) -> Self;
+}
+
+impl<T: StringLabel> SymbolicLabel for T {
+    fn symbolic_value(name: &str) -> Self {
+        T::from(name)
+    }
+}
+
+impl SymbolicLabel for Anonymous {
+    #[inline]
+    fn symbolic_value(_name: &str) -> Self {
+        Anonymous
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -5,13 +5,15 @@
 //! for [`Cover`] and [`BoolExpr`].
 
 use super::cubes::{Cube, CubeType};
-use super::label::{Anonymous, Label};
-use super::minterm::Minterm;
+use super::label::{Anonymous, Label, PairableLabel};
+use super::minterm::{InputField, Minterm};
+use super::mv::MvLayout;
 use super::output_set::OutputSet;
 use super::symbols::Symbols;
-use super::Cover;
+use super::{Cover, Phase};
 use crate::espresso::error::MinimizationError;
-use crate::EspressoConfig;
+use crate::espresso::EspressoCover;
+use crate::{EspressoConfig, PairingMinimizer, PairingSearch, PhaseStrategy, SingleOutputStrategy};
 use std::sync::Arc;
 
 /// Public trait for types that can be minimised using Espresso
@@ -117,6 +119,10 @@ pub trait Minimizable {
 
     /// [`try_minimize`](Self::try_minimize) with a custom configuration.
     ///
+    /// A configuration with a [`deadline`](EspressoConfig::deadline) or a
+    /// [`cancellation`](EspressoConfig::cancellation) token stops early with
+    /// [`MinimizationError::Cancelled`] once either is reached.
+    ///
     /// This is one of the two primary methods an implementation must provide.
     fn try_minimize_with_config(&self, config: &EspressoConfig) -> Result<Self, MinimizationError>
     where
@@ -136,6 +142,9 @@ pub trait Minimizable {
 
     /// [`try_minimize_exact`](Self::try_minimize_exact) with a custom configuration.
     ///
+    /// Honours the configuration's deadline and cancellation token as
+    /// [`try_minimize_with_config`](Self::try_minimize_with_config) does.
+    ///
     /// This is one of the two primary methods an implementation must provide.
     fn try_minimize_exact_with_config(
         &self,
@@ -144,6 +153,39 @@ pub trait Minimizable {
     where
         Self: Sized;
 
+    /// Signature-cube counterpart of [`try_minimize_exact`](Self::try_minimize_exact): never panics
+    /// on an instance conflict, returning [`MinimizationError::Instance`] instead. A cover with
+    /// multiple-valued inputs, which the signature algorithm does not support, is rejected with
+    /// [`MinimizationError::EspressoFatal`].
+    ///
+    /// Defaults to [`try_minimize_signature_with_config`](Self::try_minimize_signature_with_config)
+    /// with the default configuration.
+    fn try_minimize_signature(&self) -> Result<Self, MinimizationError>
+    where
+        Self: Sized,
+    {
+        self.try_minimize_signature_with_config(&EspressoConfig::default())
+    }
+
+    /// [`try_minimize_signature`](Self::try_minimize_signature) with a custom configuration.
+    ///
+    /// Always runs the exact signature cube algorithm, so the heuristic options (`single_expand`,
+    /// `use_super_gasp`, `use_random_order`) have no effect. Honours the configuration's deadline
+    /// and cancellation token as [`try_minimize_with_config`](Self::try_minimize_with_config) does.
+    ///
+    /// Defaults to [`try_minimize_exact_with_config`](Self::try_minimize_exact_with_config), which
+    /// reaches a cover of the same, minimum size by the other exact algorithm, so existing
+    /// implementations need not provide it.
+    fn try_minimize_signature_with_config(
+        &self,
+        config: &EspressoConfig,
+    ) -> Result<Self, MinimizationError>
+    where
+        Self: Sized,
+    {
+        self.try_minimize_exact_with_config(config)
+    }
+
     /// Minimise using the heuristic Espresso algorithm.
     ///
     /// Returns a new minimised instance without modifying the original. Fast and near-optimal
@@ -164,6 +206,13 @@ pub trait Minimizable {
 
     /// [`minimize`](Self::minimize) with a custom configuration.
     ///
+    /// # Errors
+    ///
+    /// Returns [`MinimizationError::Cancelled`] if the configuration's
+    /// [`deadline`](EspressoConfig::deadline) passes or its
+    /// [`cancellation`](EspressoConfig::cancellation) token is cancelled before minimisation
+    /// finishes.
+    ///
     /// # Panics
     ///
     /// See [`minimize`](Self::minimize).
@@ -192,6 +241,15 @@ pub trait Minimizable {
 
     /// [`minimize_exact`](Self::minimize_exact) with a custom configuration.
     ///
+    /// A pathological cover can keep exact minimisation busy for hours; a
+    /// [`deadline`](EspressoConfig::deadline) or [`cancellation`](EspressoConfig::cancellation)
+    /// token bounds it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MinimizationError::Cancelled`] if the deadline passes or the token is cancelled
+    /// before minimisation finishes.
+    ///
     /// # Panics
     ///
     /// See [`minimize`](Self::minimize).
@@ -201,6 +259,62 @@ pub trait Minimizable {
     {
         panic_on_instance_conflict(self.try_minimize_exact_with_config(config))
     }
+
+    /// Minimise exactly with the signature cube algorithm (the reference tool's `-Dsignature`).
+    ///
+    /// Returns a cover of the same, minimum size as [`minimize_exact`](Self::minimize_exact), but
+    /// reaches it from the essential signature cubes rather than the complete prime set, so it
+    /// builds a smaller covering table. This is much faster on some multi-output functions with
+    /// many primes; see
+    /// [`Espresso::minimize_signature`](crate::espresso::Espresso::minimize_signature).
+    ///
+    /// # Panics
+    ///
+    /// See [`minimize`](Self::minimize); use [`try_minimize_signature`](Self::try_minimize_signature)
+    /// to handle an instance conflict as an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use espresso_logic::{Anonymous, Cover, CoverType, Cube, CubeType, Minimizable};
+    ///
+    /// # fn main() -> std::io::Result<()> {
+    /// let mut cover = Cover::<Anonymous, Anonymous>::anonymous(CoverType::F);
+    /// cover.push(Cube::anonymous(&[Some(true), Some(true)], &[true], CubeType::F));
+    /// cover.push(Cube::anonymous(&[Some(true), Some(false)], &[true], CubeType::F));
+    /// cover.push(Cube::anonymous(&[Some(false), Some(true)], &[true], CubeType::F));
+    ///
+    /// let exact = cover.minimize_exact()?;
+    /// let signature = cover.minimize_signature()?;
+    /// assert_eq!(signature.num_cubes(), exact.num_cubes());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn minimize_signature(&self) -> Result<Self, MinimizationError>
+    where
+        Self: Sized,
+    {
+        self.minimize_signature_with_config(&EspressoConfig::default())
+    }
+
+    /// [`minimize_signature`](Self::minimize_signature) with a custom configuration.
+    ///
+    /// Always runs the exact signature cube algorithm; see
+    /// [`try_minimize_signature_with_config`](Self::try_minimize_signature_with_config) for which
+    /// configuration fields it honours.
+    ///
+    /// # Panics
+    ///
+    /// See [`minimize`](Self::minimize).
+    fn minimize_signature_with_config(
+        &self,
+        config: &EspressoConfig,
+    ) -> Result<Self, MinimizationError>
+    where
+        Self: Sized,
+    {
+        panic_on_instance_conflict(self.try_minimize_signature_with_config(config))
+    }
 }
 
 /// Convert an instance-conflict error into a panic, passing every other result through unchanged.
@@ -221,12 +335,56 @@ fn panic_on_instance_conflict<T>(
     result
 }
 
-/// Private helper function to minimise a Cover using either heuristic or exact algorithm.
+/// The shape of the Espresso cube structure for `cover`: its [`MvLayout`] if it has one, else every
+/// input binary.
+pub(super) fn cube_layout<I, O>(cover: &Cover<I, O>) -> MvLayout {
+    cover
+        .mv_layout()
+        .cloned()
+        .unwrap_or_else(|| MvLayout::binary(cover.num_inputs()))
+}
+
+/// Whether some multiple-valued variable of `layout` allows no value in `m` — the multiple-valued
+/// counterpart of an empty binary field, making the cube vacuous.
+fn has_empty_mv_field<L>(m: &Minterm<L>, layout: &MvLayout) -> bool {
+    layout
+        .mv_parts()
+        .any(|(start, size)| m.mv_field(start..start + size).is_empty())
+}
+
+/// Whether two same-header minterms share no point under `layout`: some binary variable has no
+/// common value, or some multiple-valued variable has disjoint value sets. Vacuous cubes are dropped
+/// before this is asked, so no field is empty.
+fn mv_disjoint<L>(a: &Minterm<L>, b: &Minterm<L>, layout: &MvLayout) -> bool {
+    (0..layout.num_binary()).any(|i| {
+        matches!(
+            (a.field_at(i), b.field_at(i)),
+            (InputField::Zero, InputField::One) | (InputField::One, InputField::Zero)
+        )
+    }) || layout.mv_parts().any(|(start, size)| {
+        // A value is in both sets where the ANDed one-hot positions still allow `1`.
+        (start..start + size).all(|i| {
+            !matches!(
+                a.field_at(i) & b.field_at(i),
+                InputField::One | InputField::DontCare
+            )
+        })
+    })
+}
+
+/// The ON-set, don't-care set and OFF-set of a cover, marshalled into the thread's Espresso cube.
+type MarshalledSets = (EspressoCover, Option<EspressoCover>, Option<EspressoCover>);
+
+/// Private helper function to minimise a Cover using the heuristic or one of the exact algorithms.
 ///
 /// The caller constructs the [`Espresso`](crate::espresso::Espresso) instance (via `new` to panic on
 /// an instance conflict, or `try_new` to surface it as an error) and passes it in — keeping the
 /// panic-vs-error policy at the trait boundary, not buried here. `esp` must stay live for the whole
 /// call since [`EspressoCover::from_cubes`] reads the thread's current instance.
+///
+/// A requested output phase is applied first: each complemented output's ON-set and OFF-set are
+/// swapped before `minimize_fn` runs, and the result records the phase as applied. An error from
+/// `minimize_fn` is returned as is.
 fn minimize_cover_with<F, I, O>(
     cover: &Cover<I, O>,
     esp: &crate::espresso::Espresso,
@@ -235,29 +393,46 @@ fn minimize_cover_with<F, I, O>(
 where
     F: FnOnce(
         &crate::espresso::Espresso,
-        &crate::espresso::EspressoCover,
-        Option<&crate::espresso::EspressoCover>,
-        Option<&crate::espresso::EspressoCover>,
-    ) -> (
-        crate::espresso::EspressoCover,
-        crate::espresso::EspressoCover,
-        crate::espresso::EspressoCover,
-    ),
+        &EspressoCover,
+        Option<&EspressoCover>,
+        Option<&EspressoCover>,
+    ) -> Result<(EspressoCover, EspressoCover, EspressoCover), MinimizationError>,
 {
-    use crate::espresso::EspressoCover;
+    let (f_cover, d_cover, r_cover) = marshal_sets(cover)?;
+    let (f_cover, r_cover) = match &cover.phase {
+        Some(Phase::Requested(positive)) if positive.contains(&false) => {
+            let (f, r) =
+                esp.try_apply_output_phase(&f_cover, d_cover.as_ref(), r_cover.as_ref(), positive)?;
+            (f, Some(r))
+        }
+        _ => (f_cover, r_cover),
+    };
+
+    // Call the provided minimize function (heuristic or exact)
+    let (f_result, d_result, r_result) =
+        minimize_fn(esp, &f_cover, d_cover.as_ref(), r_cover.as_ref())?;
+    let phase = cover.phase.as_ref().map(Phase::applied);
+    Ok(rebuild_cover(cover, &f_result, &d_result, &r_result, phase))
+}
 
+/// Partition `cover` into its F, D and R sets, validate them, and marshal each into the thread's
+/// current Espresso instance (absent sets are `None`).
+pub(super) fn marshal_sets<I, O>(cover: &Cover<I, O>) -> Result<MarshalledSets, MinimizationError> {
     let no = cover.num_outputs();
-    let ni = cover.num_inputs();
+    let cube_shape = cube_layout(cover);
+    let layout = cover.mv_layout();
 
     // Pre-minimisation normalise + partition: split cubes into F, D, R sets, dropping any vacuous cube
-    // (one with an empty `00` input field). A vacuous cube covers no minterm, so removing it leaves
-    // the function unchanged — and keeps it away from Espresso's `expand`, which mishandles it (it is
-    // the very thing C's own verification flags).
+    // (one with an empty `00` input field, or a multiple-valued variable allowing no value). A vacuous
+    // cube covers no minterm, so removing it leaves the function unchanged — and keeps it away from
+    // Espresso's `expand`, which mishandles it (it is the very thing C's own verification flags).
     let mut f_cubes: Vec<&Cube<I, O>> = Vec::new();
     let mut d_cubes: Vec<&Cube<I, O>> = Vec::new();
     let mut r_cubes: Vec<&Cube<I, O>> = Vec::new();
     for cube in cover.cubes.iter() {
-        if cube.inputs().is_vacuous() {
+        if cube.inputs().is_vacuous()
+            || layout.is_some_and(|layout| has_empty_mv_field(cube.inputs(), layout))
+        {
             continue;
         }
         match cube.cube_type() {
@@ -273,7 +448,11 @@ where
     if !f_cubes.is_empty() && !r_cubes.is_empty() {
         for fc in &f_cubes {
             for rc in &r_cubes {
-                if !fc.inputs().is_disjoint_same_header(rc.inputs()) {
+                let disjoint = match layout {
+                    Some(layout) => mv_disjoint(fc.inputs(), rc.inputs(), layout),
+                    None => fc.inputs().is_disjoint_same_header(rc.inputs()),
+                };
+                if !disjoint {
                     if let Some(output) = (0..no).find(|&o| fc.asserts(o) && rc.asserts(o)) {
                         return Err(MinimizationError::NonOrthogonal { output });
                     }
@@ -282,22 +461,9 @@ where
         }
     }
 
-    // `esp` (the thread's Espresso instance) is supplied by the caller. Direct C calls below are
-    // thread-safe via thread-local storage. Marshal each set by copying the cubes' packed input words
-    // straight into the C cube (same 2-bit encoding) plus a per-output assertion bit.
-    let to_cover = |cubes: &[&Cube<I, O>]| -> Result<EspressoCover, MinimizationError> {
-        let data: Vec<(&[u64], Vec<bool>)> = cubes
-            .iter()
-            .map(|c| {
-                (
-                    c.inputs().raw_words(),
-                    (0..no).map(|i| c.asserts(i)).collect(),
-                )
-            })
-            .collect();
-        let refs: Vec<(&[u64], &[bool])> = data.iter().map(|(w, o)| (*w, o.as_slice())).collect();
-        EspressoCover::from_packed_cubes(&refs, ni, no)
-    };
+    // The thread's Espresso instance is live in the caller. Direct C calls below are thread-safe via
+    // thread-local storage.
+    let to_cover = |cubes: &[&Cube<I, O>]| pack_cubes(cubes, &cube_shape, no);
 
     let f_cover = to_cover(&f_cubes)?;
     let d_cover = if d_cubes.is_empty() {
@@ -310,30 +476,68 @@ where
     } else {
         Some(to_cover(&r_cubes)?)
     };
+    Ok((f_cover, d_cover, r_cover))
+}
 
-    // Call the provided minimize function (heuristic or exact)
-    let (f_result, d_result, r_result) =
-        minimize_fn(esp, &f_cover, d_cover.as_ref(), r_cover.as_ref());
+/// Marshal `cubes` into the thread's current Espresso instance, of shape `layout` and `no` outputs,
+/// by copying their packed input words straight into the C cube (same 2-bit encoding) plus a
+/// per-output assertion bit.
+pub(super) fn pack_cubes<I, O>(
+    cubes: &[&Cube<I, O>],
+    layout: &MvLayout,
+    no: usize,
+) -> Result<EspressoCover, MinimizationError> {
+    let data: Vec<(&[u64], Vec<bool>)> = cubes
+        .iter()
+        .map(|c| {
+            (
+                c.inputs().raw_words(),
+                (0..no).map(|i| c.asserts(i)).collect(),
+            )
+        })
+        .collect();
+    let refs: Vec<(&[u64], &[bool])> = data.iter().map(|(w, o)| (*w, o.as_slice())).collect();
+    EspressoCover::from_packed_cubes(&refs, layout, no)
+}
+
+/// Re-home one of Espresso's result cubes onto a cover's symbol tables.
+///
+/// Espresso returns anonymous positional cubes (`Cube<Anonymous, Anonymous>`) at exactly the cover's
+/// arity, in the same packed layout. Re-home each onto the cover's real `Symbols<L>` tables by cloning
+/// the packed-word `Arc`s (the packing is independent of the label type, and variable order is
+/// preserved across the boundary) — no per-variable re-packing. Unlike the identity-union re-home in
+/// `push`/`from_cubes`, this needs no padding/projection because the arities already match.
+pub(super) fn rehome_cube<I, O>(
+    input_symbols: &Arc<Symbols<I>>,
+    output_symbols: &Arc<Symbols<O>>,
+    cube: Cube<Anonymous, Anonymous>,
+) -> Cube<I, O> {
+    let im = Minterm::from_packed_words(
+        Arc::clone(input_symbols),
+        Arc::clone(cube.inputs().packed()),
+    );
+    let om = OutputSet::from_packed_bits(
+        Arc::clone(output_symbols),
+        Arc::clone(cube.outputs().packed()),
+    );
+    Cube::new(im, om, cube.cube_type())
+}
+
+/// Build the minimised cover from Espresso's three result sets, on `cover`'s headers and layout.
+fn rebuild_cover<I, O>(
+    cover: &Cover<I, O>,
+    f_result: &EspressoCover,
+    d_result: &EspressoCover,
+    r_result: &EspressoCover,
+    phase: Option<Phase>,
+) -> Cover<I, O> {
+    let ni = cover.num_inputs();
+    let no = cover.num_outputs();
 
-    // Extract minimised cubes back onto the cover's shared symbol tables (`ni`/`no` from above).
+    // Extract minimised cubes back onto the cover's shared symbol tables.
     let input_symbols = Arc::clone(cover.input_symbols());
     let output_symbols = Arc::clone(cover.output_symbols());
-    // Espresso returns anonymous positional cubes (`Cube<Anonymous, Anonymous>`) at exactly the cover's
-    // arity, in the same packed layout. Re-home each onto the cover's real `Symbols<L>` tables by
-    // cloning the packed-word `Arc`s (the packing is independent of the label type, and variable order
-    // is preserved across the boundary) — no per-variable re-packing. Unlike the identity-union
-    // re-home in `push`/`from_cubes`, this needs no padding/projection because the arities already match.
-    let rehome = |cube: Cube<Anonymous, Anonymous>| -> Cube<I, O> {
-        let im = Minterm::from_packed_words(
-            Arc::clone(&input_symbols),
-            Arc::clone(cube.inputs().packed()),
-        );
-        let om = OutputSet::from_packed_bits(
-            Arc::clone(&output_symbols),
-            Arc::clone(cube.outputs().packed()),
-        );
-        Cube::new(im, om, cube.cube_type())
-    };
+    let rehome = |cube| rehome_cube(&input_symbols, &output_symbols, cube);
 
     // Keep all three computed sets. They are NOT inert: the cover carries them so it can later be
     // written in a richer output format than its declared `.type` (e.g. a CLI `-o fdr` on an FD cover
@@ -344,12 +548,14 @@ where
     let r_cubes = r_result.to_cubes(ni, no, CubeType::R);
 
     // Build new cover with minimised cubes - reuse the cover's symbol tables (Arc, cheap)
-    Ok(Cover {
+    Cover {
         input_symbols: Arc::clone(cover.input_symbols()),
         output_symbols: Arc::clone(cover.output_symbols()),
         cubes: f_cubes.chain(d_cubes).chain(r_cubes).map(rehome).collect(),
         cover_type: cover.cover_type,
-    })
+        mv: cover.mv.clone(),
+        phase,
+    }
 }
 
 /// Generate the complete prime-implicant set of one cube-set, re-homed onto the caller's symbols.
@@ -370,8 +576,6 @@ pub(crate) fn primes_cubes<I, O>(
     d_cubes: &[&Cube<I, O>],
     tag: CubeType,
 ) -> Vec<Cube<I, O>> {
-    use crate::espresso::EspressoCover;
-
     let ni = input_symbols.arity();
     let no = output_symbols.arity();
 
@@ -420,8 +624,12 @@ pub(crate) fn primes_cubes<I, O>(
             })
             .collect();
         let refs: Vec<(&[u64], &[bool])> = data.iter().map(|(w, o)| (*w, o.as_slice())).collect();
-        panic_on_instance_conflict(EspressoCover::from_packed_cubes(&refs, ni, no))
-            .unwrap_or_else(|e| panic!("Espresso prime generation failed: {e}"))
+        panic_on_instance_conflict(EspressoCover::from_packed_cubes(
+            &refs,
+            &MvLayout::binary(ni),
+            no,
+        ))
+        .unwrap_or_else(|e| panic!("Espresso prime generation failed: {e}"))
     };
 
     let f_cover = to_cover(&f);
@@ -525,6 +733,361 @@ impl<I: Label, O: Clone> Cover<I, O> {
             self.cover_type,
         )
     }
+
+    /// A quick, cheap simplification of the ON-set, without full minimisation.
+    ///
+    /// Runs the C core's unate recursive `simplify`, the reference tool's `-Dsimplify` mode (see
+    /// [`Espresso::simplify`](crate::espresso::Espresso::simplify)). The result has the same ON-set
+    /// in no more cubes, but unlike [`minimize`](Minimizable::minimize) it computes no OFF-set and
+    /// makes no use of the don't-care set, so its cubes need not be prime or irredundant. That
+    /// makes it suited to large intermediate covers. Any don't-care (D) and OFF-set (R) cubes are
+    /// carried through unchanged, as are the [`CoverType`](crate::CoverType) and output phase.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`MinimizationError`] if the cover is contradictory, a low-level Espresso instance
+    /// of different dimensions is live on this thread, or the C core hits a fatal condition.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use espresso_logic::{Anonymous, Cover, CoverType, Cube, CubeType};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// // a·b + a·!b + !a·b is a + b.
+    /// let f = Cover::<Anonymous, Anonymous>::from_cubes(CoverType::F, [
+    ///     Cube::anonymous(&[Some(true), Some(true)], &[true], CubeType::F),
+    ///     Cube::anonymous(&[Some(true), Some(false)], &[true], CubeType::F),
+    ///     Cube::anonymous(&[Some(false), Some(true)], &[true], CubeType::F),
+    /// ]);
+    /// let simplified = f.simplify()?;
+    /// assert!(simplified.num_cubes() < f.num_cubes());
+    /// assert!(simplified.equivalent_to(&f).is_ok());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn simplify(&self) -> Result<Cover<I, O>, MinimizationError> {
+        let ni = self.num_inputs();
+        let no = self.num_outputs();
+        let esp = crate::espresso::Espresso::try_new_mv(&cube_layout(self), no, None)?;
+        let (f, _, _) = marshal_sets(self)?;
+        let simplified = esp.try_simplify(&f)?;
+
+        let mut cubes: Vec<Cube<I, O>> = simplified
+            .to_cubes(ni, no, CubeType::F)
+            .map(|cube| rehome_cube(self.input_symbols(), self.output_symbols(), cube))
+            .collect();
+        cubes.extend(
+            self.cubes
+                .iter()
+                .filter(|c| matches!(c.cube_type(), CubeType::D | CubeType::R))
+                .cloned(),
+        );
+        Ok(Cover {
+            input_symbols: Arc::clone(self.input_symbols()),
+            output_symbols: Arc::clone(self.output_symbols()),
+            cubes,
+            cover_type: self.cover_type,
+            mv: self.mv.clone(),
+            phase: self.phase.clone(),
+        })
+    }
+}
+
+impl<I, O> Cover<I, O> {
+    /// Choose the output phase assignment that minimises the number of product terms, and minimise
+    /// under it.
+    ///
+    /// Each output may be implemented either as given or in complement (a PLA with output inverters
+    /// can realise either). This runs Sasao's technique from the reference tool's `-Dopo` mode: it
+    /// minimises the double-phase function — every output alongside its complement — once, keeps
+    /// the smallest set of primes that realises one phase of every output, and minimises again under
+    /// that assignment. The result carries the chosen phase: its
+    /// [`complemented_outputs`](Self::complemented_outputs) are the outputs whose cubes now cover
+    /// the complement. Any phase the cover already carried is ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MinimizationError::NonOrthogonal`] for a contradictory `FR`/`FDR` cover, or
+    /// [`MinimizationError::EspressoFatal`] if the C core reports a fatal condition.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a low-level Espresso instance of different dimensions is live on this thread, as
+    /// [`minimize`](Minimizable::minimize) does. Use
+    /// [`try_assign_output_phase_with_config`](Self::try_assign_output_phase_with_config) to handle
+    /// that as an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use espresso_logic::{Anonymous, Cover, CoverType, Cube, CubeType, PhaseStrategy};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// // Both outputs need fewer terms complemented.
+    /// let cover = Cover::<Anonymous, Anonymous>::from_cubes(CoverType::F, [
+    ///     Cube::anonymous(&[Some(true), None, None], &[true, false], CubeType::F),
+    ///     Cube::anonymous(&[None, Some(true), None], &[true, true], CubeType::F),
+    ///     Cube::anonymous(&[None, None, Some(true)], &[false, true], CubeType::F),
+    ///     Cube::anonymous(&[Some(false), Some(false), Some(false)], &[true, false], CubeType::F),
+    /// ]);
+    /// let assigned = cover.assign_output_phase(PhaseStrategy::default())?;
+    /// assert_eq!(assigned.complemented_outputs(), vec![0, 1]);
+    /// assert_eq!(assigned.output_phase(), Some(&[false, false][..]));
+    /// assert_eq!(assigned.num_cubes(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn assign_output_phase(&self, strategy: PhaseStrategy) -> Result<Self, MinimizationError> {
+        panic_on_instance_conflict(
+            self.try_assign_output_phase_with_config(strategy, &EspressoConfig::default()),
+        )
+    }
+
+    /// [`assign_output_phase`](Self::assign_output_phase) with a custom configuration, surfacing an
+    /// instance conflict as [`MinimizationError::Instance`] instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// As [`assign_output_phase`](Self::assign_output_phase), plus
+    /// [`MinimizationError::Instance`] on an instance conflict.
+    pub fn try_assign_output_phase_with_config(
+        &self,
+        strategy: PhaseStrategy,
+        config: &EspressoConfig,
+    ) -> Result<Self, MinimizationError> {
+        let esp = crate::espresso::Espresso::try_new_mv(
+            &cube_layout(self),
+            self.num_outputs(),
+            Some(config),
+        )?;
+        let (f_cover, d_cover, r_cover) = marshal_sets(self)?;
+        let (f_result, d_result, r_result, positive) =
+            esp.try_phase_assignment(&f_cover, d_cover.as_ref(), r_cover.as_ref(), strategy)?;
+        let phase = Phase::Applied(positive.into());
+        Ok(rebuild_cover(
+            self,
+            &f_result,
+            &d_result,
+            &r_result,
+            Some(phase),
+        ))
+    }
+
+    /// Minimise every output on its own, so that no product term is shared between outputs.
+    ///
+    /// Some targets cannot feed one product term to several outputs. This runs the reference
+    /// tool's `-Dso` mode: each output's ON-set is minimised as a single-output function,
+    /// heuristically or exactly as `strategy` asks, and the results are gathered into one cover in
+    /// which every ON-set cube asserts exactly one output. A requested output phase is applied
+    /// first, as [`minimize`](Minimizable::minimize) does.
+    ///
+    /// With [`SingleOutputStrategy::both_phases`] it is `-Dso_both`: each output's complement is
+    /// minimised as well, and the output is implemented in whichever phase needs fewer cubes. The
+    /// result then carries the chosen phase, as
+    /// [`assign_output_phase`](Self::assign_output_phase) does, and any phase the cover already
+    /// carried is ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MinimizationError::NonOrthogonal`] for a contradictory `FR`/`FDR` cover, or
+    /// [`MinimizationError::EspressoFatal`] if the C core reports a fatal condition.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a low-level Espresso instance of different dimensions is live on this thread, as
+    /// [`minimize`](Minimizable::minimize) does. Use
+    /// [`try_minimize_single_output_with_config`](Self::try_minimize_single_output_with_config) to
+    /// handle that as an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use espresso_logic::{
+    ///     Anonymous, Cover, CoverType, Cube, CubeType, Minimizable, SingleOutputStrategy,
+    /// };
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// // a·b is shared by both outputs when they are minimised together.
+    /// let cover = Cover::<Anonymous, Anonymous>::from_cubes(CoverType::F, [
+    ///     Cube::anonymous(&[Some(true), Some(true)], &[true, true], CubeType::F),
+    ///     Cube::anonymous(&[Some(false), Some(false)], &[true, false], CubeType::F),
+    /// ]);
+    /// assert_eq!(cover.minimize()?.num_cubes(), 2);
+    ///
+    /// let separate = cover.minimize_single_output(SingleOutputStrategy::default())?;
+    /// assert_eq!(separate.num_cubes(), 3);
+    /// assert!(separate.cubes().all(|c| c.outputs().iter().filter(|&o| o).count() == 1));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn minimize_single_output(
+        &self,
+        strategy: SingleOutputStrategy,
+    ) -> Result<Self, MinimizationError> {
+        panic_on_instance_conflict(
+            self.try_minimize_single_output_with_config(strategy, &EspressoConfig::default()),
+        )
+    }
+
+    /// [`minimize_single_output`](Self::minimize_single_output) with a custom configuration,
+    /// surfacing an instance conflict as [`MinimizationError::Instance`] instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// As [`minimize_single_output`](Self::minimize_single_output), plus
+    /// [`MinimizationError::Instance`] on an instance conflict.
+    pub fn try_minimize_single_output_with_config(
+        &self,
+        strategy: SingleOutputStrategy,
+        config: &EspressoConfig,
+    ) -> Result<Self, MinimizationError> {
+        let esp = crate::espresso::Espresso::try_new_mv(
+            &cube_layout(self),
+            self.num_outputs(),
+            Some(config),
+        )?;
+        let (f_cover, d_cover, r_cover) = marshal_sets(self)?;
+        let (f_cover, r_cover) = match &self.phase {
+            Some(Phase::Requested(positive))
+                if !strategy.both_phases && positive.contains(&false) =>
+            {
+                let (f, r) = esp.try_apply_output_phase(
+                    &f_cover,
+                    d_cover.as_ref(),
+                    r_cover.as_ref(),
+                    positive,
+                )?;
+                (f, Some(r))
+            }
+            _ => (f_cover, r_cover),
+        };
+        let (f_result, d_result, r_result, positive) =
+            esp.try_minimize_single_output(&f_cover, d_cover.as_ref(), r_cover.as_ref(), strategy)?;
+        let phase = if strategy.both_phases {
+            Some(Phase::Applied(positive.into()))
+        } else {
+            self.phase.as_ref().map(Phase::applied)
+        };
+        Ok(rebuild_cover(self, &f_result, &d_result, &r_result, phase))
+    }
+}
+
+impl<I: PairableLabel, O: Clone> Cover<I, O> {
+    /// Search for a pairing of the binary inputs into two-bit decoders, and minimise under it.
+    ///
+    /// This is the reference tool's `-Dpair` (for [`PairingSearch::Estimated`]) and `-Dpairall`
+    /// (for [`PairingSearch::Exhaustive`]): Espresso chooses disjoint pairs of binary inputs, the
+    /// cover is paired as [`pair_inputs`](Self::pair_inputs) does, and the paired cover is
+    /// minimised — heuristically, or as the [`PairingMinimizer`](crate::PairingMinimizer) of an
+    /// exhaustive search asks. Returns the minimised paired cover and the chosen pairs (zero-based
+    /// input indices, in decoder order). A requested output phase is applied before the search,
+    /// and the OFF-set is computed before pairing, as the reference tool does.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MinimizationError::NonOrthogonal`] for a contradictory `FR`/`FDR` cover, or
+    /// [`MinimizationError::EspressoFatal`] if the C core reports a fatal condition.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a low-level Espresso instance of different dimensions is live on this thread, as
+    /// [`minimize`](Minimizable::minimize) does. Use
+    /// [`try_find_input_pairing_with_config`](Self::try_find_input_pairing_with_config) to handle
+    /// that as an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use espresso_logic::{Anonymous, Cover, CoverType, Cube, CubeType, PairingSearch};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// // (a ⊕ c) · (b ⊕ d): four terms over binary inputs, one over two decoders.
+    /// let cube = |v: [bool; 4]| Cube::anonymous(&v.map(Some), &[true], CubeType::F);
+    /// let cover = Cover::<Anonymous, Anonymous>::from_cubes(CoverType::F, [
+    ///     cube([false, false, true, true]),
+    ///     cube([false, true, true, false]),
+    ///     cube([true, false, false, true]),
+    ///     cube([true, true, false, false]),
+    /// ]);
+    /// let (paired, pairs) = cover.find_input_pairing(PairingSearch::default())?;
+    /// assert_eq!(pairs, vec![(0, 2), (1, 3)]);
+    /// assert_eq!(paired.mv_layout().unwrap().mv_sizes(), &[4, 4]);
+    /// assert_eq!(paired.num_cubes(), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn find_input_pairing(
+        &self,
+        search: PairingSearch,
+    ) -> Result<(Self, Vec<(usize, usize)>), MinimizationError> {
+        panic_on_instance_conflict(
+            self.try_find_input_pairing_with_config(search, &EspressoConfig::default()),
+        )
+    }
+
+    /// [`find_input_pairing`](Self::find_input_pairing) with a custom configuration, surfacing an
+    /// instance conflict as [`MinimizationError::Instance`] instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// As [`find_input_pairing`](Self::find_input_pairing), plus [`MinimizationError::Instance`]
+    /// on an instance conflict.
+    pub fn try_find_input_pairing_with_config(
+        &self,
+        search: PairingSearch,
+        config: &EspressoConfig,
+    ) -> Result<(Self, Vec<(usize, usize)>), MinimizationError> {
+        let no = self.num_outputs();
+
+        // Search in the original shape, on the sets the minimiser would see: with any requested phase
+        // applied and the OFF-set made explicit, so that it is carried through the pairing rather
+        // than recomputed over the decoders. The instance is dropped before the paired one is made.
+        let (start, pairs) = {
+            let layout = cube_layout(self);
+            let esp = crate::espresso::Espresso::try_new_mv(&layout, no, Some(config))?;
+            let (f_cover, d_cover, r_cover) = marshal_sets(self)?;
+            let (f_cover, r_cover) = match &self.phase {
+                Some(Phase::Requested(positive)) if positive.contains(&false) => esp
+                    .try_apply_output_phase(
+                        &f_cover,
+                        d_cover.as_ref(),
+                        r_cover.as_ref(),
+                        positive,
+                    )?,
+                _ => {
+                    let r_cover = match r_cover {
+                        Some(r) => r,
+                        None => esp.try_offset(&f_cover, d_cover.as_ref())?,
+                    };
+                    (f_cover, r_cover)
+                }
+            };
+            let pairs =
+                esp.try_input_pairing(&f_cover, d_cover.as_ref(), Some(&r_cover), search)?;
+            let d_cover = match d_cover {
+                Some(d) => d,
+                None => EspressoCover::from_packed_cubes(&[], &layout, no)?,
+            };
+            let phase = self.phase.as_ref().map(Phase::applied);
+            let start = rebuild_cover(self, &f_cover, &d_cover, &r_cover, phase);
+            (start, pairs)
+        };
+
+        let paired = start
+            .pair_inputs(&pairs)
+            .expect("the pairing search returns disjoint pairs of binary inputs");
+        let result = match search {
+            PairingSearch::Exhaustive(PairingMinimizer::Exact) => {
+                paired.try_minimize_exact_with_config(config)?
+            }
+            PairingSearch::Exhaustive(PairingMinimizer::OutputPhase) => {
+                paired.try_assign_output_phase_with_config(PhaseStrategy::default(), config)?
+            }
+            _ => paired.try_minimize_with_config(config)?,
+        };
+        Ok((result, pairs))
+    }
 }
 
 // Implement public Minimizable trait for Cover (any label type — minimisation is positional).
@@ -533,23 +1096,37 @@ impl<I: Label, O: Clone> Cover<I, O> {
 // → error); the panicking `minimize*` methods are the trait defaults wrapping these.
 impl<I, O> Minimizable for Cover<I, O> {
     fn try_minimize_with_config(&self, config: &EspressoConfig) -> Result<Self, MinimizationError> {
-        let esp = crate::espresso::Espresso::try_new(
-            self.num_inputs(),
+        let esp = crate::espresso::Espresso::try_new_mv(
+            &cube_layout(self),
             self.num_outputs(),
             Some(config),
         )?;
-        minimize_cover_with(self, &esp, |esp, f, d, r| esp.minimize(f, d, r))
+        minimize_cover_with(self, &esp, |esp, f, d, r| esp.try_minimize(f, d, r))
     }
 
     fn try_minimize_exact_with_config(
         &self,
         config: &EspressoConfig,
     ) -> Result<Self, MinimizationError> {
-        let esp = crate::espresso::Espresso::try_new(
-            self.num_inputs(),
+        let esp = crate::espresso::Espresso::try_new_mv(
+            &cube_layout(self),
+            self.num_outputs(),
+            Some(config),
+        )?;
+        minimize_cover_with(self, &esp, |esp, f, d, r| esp.try_minimize_exact(f, d, r))
+    }
+
+    fn try_minimize_signature_with_config(
+        &self,
+        config: &EspressoConfig,
+    ) -> Result<Self, MinimizationError> {
+        let esp = crate::espresso::Espresso::try_new_mv(
+            &cube_layout(self),
             self.num_outputs(),
             Some(config),
         )?;
-        minimize_cover_with(self, &esp, |esp, f, d, r| esp.minimize_exact(f, d, r))
+        minimize_cover_with(self, &esp, |esp, f, d, r| {
+            esp.try_minimize_signature(f, d, r)
+        })
     }
 }
@@ -44,12 +44,14 @@
 
 use super::error::{DuplicateLabel, IndexOutOfRange, LabelNotFound};
 use super::label::{Anonymous, Label, NamedLabel, StringLabel};
+use super::mv::{MvField, MvLayout};
 use super::symbols::{identity_union, Symbols};
 use crate::impl_binary_operator;
 use std::borrow::Borrow;
 use std::cmp::Ordering;
 use std::fmt;
 use std::hash::{Hash, Hasher};
+use std::ops::Range;
 use std::sync::Arc;
 
 /// Mask selecting the "allows-0" bit of every variable field in a word.
@@ -619,6 +621,79 @@ impl<L> Minterm<L> {
         words[word] |= (encode_field(field) as u64) << shift;
         Ok(())
     }
+
+    /// Read the positions in `positions` as the value set of one multiple-valued variable (see
+    /// [`MvLayout`](crate::MvLayout)): value `j` is in the set when position `positions.start + j`
+    /// allows `1` ([`One`](InputField::One) or [`DontCare`](InputField::DontCare)).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use espresso_logic::Minterm;
+    ///
+    /// // One binary input, then a 3-valued variable allowing values 0 and 2.
+    /// let m = Minterm::anonymous(&[Some(true), Some(true), Some(false), Some(true)]);
+    /// assert_eq!(m.mv_field(1..4).to_string(), "101");
+    /// ```
+    #[must_use]
+    pub fn mv_field(&self, positions: Range<usize>) -> MvField {
+        MvField::from_bits(
+            positions.map(|i| matches!(self.field_at(i), InputField::One | InputField::DontCare)),
+        )
+    }
+
+    /// This product's literals as `(position, polarity)` pairs under `layout`: one per fixed binary
+    /// input, and a negative one per value a multiple-valued field excludes. The values of a variable
+    /// are one-hot, so the latter reads the field as its variable taking one of the allowed values.
+    /// `None` if a field allows no value, leaving the product empty.
+    pub(crate) fn literals(&self, layout: Option<&MvLayout>) -> Option<Vec<(usize, bool)>> {
+        let num_binary = layout.map_or(self.num_vars(), MvLayout::num_binary);
+        let mut literals: Vec<(usize, bool)> = (0..num_binary)
+            .filter_map(|i| self.value_at(i).map(|polarity| (i, polarity)))
+            .collect();
+        for (start, size) in layout.into_iter().flat_map(MvLayout::mv_parts) {
+            let positions = start..start + size;
+            if self.mv_field(positions.clone()).is_empty() {
+                return None;
+            }
+            literals.extend(
+                positions
+                    .filter(|&i| self.field_at(i) == InputField::Zero)
+                    .map(|i| (i, false)),
+            );
+        }
+        Some(literals)
+    }
+
+    /// Write `field` as one multiple-valued variable's one-hot positions starting at `start`: each
+    /// position becomes [`One`](InputField::One) if its value is in the set and
+    /// [`Zero`](InputField::Zero) otherwise. The counterpart setter to [`mv_field`](Self::mv_field).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IndexOutOfRange`] if `start + field.size()` runs past `self.num_vars()`; the
+    /// minterm is left unchanged.
+    pub fn set_mv_field(&mut self, start: usize, field: &MvField) -> Result<(), IndexOutOfRange> {
+        let arity = self.num_vars();
+        let end = start + field.size();
+        if end > arity {
+            return Err(IndexOutOfRange {
+                index: end - 1,
+                arity,
+            });
+        }
+        for (i, bit) in (start..end).zip(field.bits()) {
+            self.set_field_at(
+                i,
+                if bit {
+                    InputField::One
+                } else {
+                    InputField::Zero
+                },
+            )?;
+        }
+        Ok(())
+    }
 }
 
 /// Iterator over a minterm's tri-state values in index order, created by `(&minterm).into_iter()`
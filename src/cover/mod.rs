@@ -100,31 +100,52 @@
 //! - [`Cube`] - Individual product terms in a cover
 //! - [`Minimizable`] - Trait for minimisation operations
 //! - [`pla`] - PLA file I/O for reading/writing covers in original Espresso format
+//! - [`blif`] - BLIF reading/writing for exchanging covers with synthesis tools
+//! - [`eqntott`] - eqntott equation reading/writing
+//! - [`hdl`] - Verilog and VHDL export of (minimised) covers
+//! - [`kiss`] - KISS2 state tables, encoded and minimised into covers
 
 // Module declarations
+pub mod blif;
 mod conversions;
 mod cubes;
+pub mod eqntott;
 pub mod error;
 mod expressions;
+pub mod hdl;
 mod iterators;
+pub mod kiss;
 mod label;
 mod minimisation;
 mod minterm;
+mod mv;
 mod output_set;
+mod pairing;
 pub mod pla;
+#[cfg(feature = "serde")]
+mod serialization;
+mod set_operations;
+mod symbolic;
 mod symbols;
+mod verification;
 
 // Public re-exports - core types
 pub use cubes::{Cube, CubeType};
 pub use error::{
-    AddExprError, ArityMismatch, CoverError, DuplicateLabel, IndexOutOfRange, LabelNotFound,
-    RelabelError, ToExprError,
+    AddExprError, ArityMismatch, CoverError, DuplicateLabel, EmptyMvVariable, EquivalenceError,
+    IndexOutOfRange, InvalidPairing, InvalidSymbolic, LabelNotFound, RelabelError,
+    SymbolicOutputError, ToExprError, VerifyError,
 };
 pub use iterators::{CubesIter, ToExprs};
-pub use label::{Anonymous, Label, NamedLabel, ReconcilableLabel, StringLabel};
+pub use label::{
+    Anonymous, Label, NamedLabel, PairableLabel, ReconcilableLabel, StringLabel, SymbolicLabel,
+};
 pub use minimisation::Minimizable;
 pub use minterm::{Disagreement, ExpandedMinterms, FieldsIter, InputField, Minterm, MintermIter};
+pub use mv::{MvField, MvLayout};
 pub use output_set::OutputSet;
+pub use symbolic::SymbolicVariable;
+pub use verification::{EssentialPrime, Verdict};
 // Crate-internal only: `Symbols` is not part of the public API, but other in-crate modules
 // (`espresso`, `bdd`) reach it through this path since the `symbols` module itself is private.
 pub(crate) use symbols::Symbols;
@@ -142,6 +163,7 @@ use std::sync::Arc;
 /// - FR: ON-set + OFF-set  
 /// - FDR: ON-set + Don't-care set + OFF-set
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CoverType {
     /// On-set only (F)
     #[default]
@@ -308,29 +330,137 @@ pub struct Cover<I, O> {
     pub(crate) cubes: Vec<Cube<I, O>>,
     /// Cover type (F, FD, FR, or FDR)
     pub(crate) cover_type: CoverType,
+    /// Grouping of the input positions into multiple-valued variables (`.mv` PLAs); `None` for an
+    /// all-binary cover. See [`mv_layout`](Cover::mv_layout).
+    pub(crate) mv: Option<Arc<MvLayout>>,
+    /// Output phase (`.phase`), requested or already applied; `None` when every output is taken as
+    /// given. See [`output_phase`](Cover::output_phase).
+    pub(crate) phase: Option<Phase>,
+}
+
+/// The output phase a cover carries, one flag per output (`false` = implemented in complement).
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum Phase {
+    /// Read from a `.phase` directive or set with [`Cover::with_output_phase`]: the cubes are the
+    /// function as given, and the next minimisation swaps ON-set and OFF-set for each `false` output.
+    Requested(Arc<[bool]>),
+    /// Already reflected in the cubes: a `false` output's cubes cover its complement.
+    Applied(Arc<[bool]>),
+}
+
+impl Phase {
+    /// The per-output flags, whichever the state.
+    pub(crate) fn positive(&self) -> &Arc<[bool]> {
+        match self {
+            Phase::Requested(p) | Phase::Applied(p) => p,
+        }
+    }
+
+    /// The phase once minimisation has run: a request becomes applied.
+    pub(crate) fn applied(&self) -> Phase {
+        Phase::Applied(Arc::clone(self.positive()))
+    }
+
+    /// The same phase over a header of `new_no` outputs, output `j` having moved to `map[j]`. An
+    /// output no flag lands on is new, and taken as given (`true`).
+    pub(crate) fn remapped(&self, map: &[usize], new_no: usize) -> Phase {
+        let mut positive = vec![true; new_no];
+        for (&p, &to) in self.positive().iter().zip(map) {
+            positive[to] = p;
+        }
+        match self {
+            Phase::Requested(_) => Phase::Requested(positive.into()),
+            Phase::Applied(_) => Phase::Applied(positive.into()),
+        }
+    }
+}
+
+/// The phase of a cover combining the cubes of `a` and `b`, whose outputs land at `a_map` and
+/// `b_map` in a header of `new_no` outputs.
+///
+/// If either side's phase is applied, so is the result's, with each output complemented where its
+/// cubes are; an output that one side's cubes cover in complement and the other's as given is a
+/// [`CoverError::PhaseConflict`]. Otherwise the requests combine, `a`'s taking precedence on a shared
+/// output. A request on the other side of an applied phase has not touched its cubes, so it is
+/// dropped.
+fn combine_phases<I, O>(
+    a: &Cover<I, O>,
+    a_map: &[usize],
+    b: &Cover<I, O>,
+    b_map: &[usize],
+    new_no: usize,
+) -> Result<Option<Phase>, CoverError> {
+    let applied = |cover: &Cover<I, O>| matches!(cover.phase, Some(Phase::Applied(_)));
+    if !applied(a) && !applied(b) {
+        return Ok(match (&a.phase, &b.phase) {
+            (None, None) => None,
+            _ => {
+                let mut positive = vec![true; new_no];
+                for (phase, map) in [(&b.phase, b_map), (&a.phase, a_map)] {
+                    for (&p, &to) in phase.iter().flat_map(|p| p.positive().iter()).zip(map) {
+                        positive[to] = p;
+                    }
+                }
+                Some(Phase::Requested(positive.into()))
+            }
+        });
+    }
+    // Each side's flag at every output it has, and whether it has cubes there to agree on.
+    let flags = |cover: &Cover<I, O>, map: &[usize]| {
+        let mut at = vec![None; new_no];
+        for (j, &to) in map.iter().enumerate() {
+            let positive = match &cover.phase {
+                Some(Phase::Applied(p)) => p[j],
+                _ => true,
+            };
+            at[to] = Some((positive, cover.cubes.iter().any(|c| c.asserts(j))));
+        }
+        at
+    };
+    let (a_at, b_at) = (flags(a, a_map), flags(b, b_map));
+    let positive = a_at
+        .iter()
+        .zip(&b_at)
+        .enumerate()
+        .map(|(output, (&x, &y))| match (x, y) {
+            (Some((x, true)), Some((y, true))) if x != y => {
+                Err(CoverError::PhaseConflict { output })
+            }
+            // The side with cubes decides; failing that, `a`.
+            (Some((_, false)), Some((y, true))) => Ok(y),
+            (Some((x, _)), _) | (None, Some((x, _))) => Ok(x),
+            (None, None) => Ok(true),
+        })
+        .collect::<Result<Arc<[bool]>, _>>()?;
+    Ok(Some(Phase::Applied(positive)))
 }
 
 /// Two covers are equal when they have the same cover type, the same input and output headers
-/// (position-for-position, compared by label [`identity`](Label::identity)), and the same cubes in the
-/// same order. Cube comparison is identity-based (see [`Cube`]'s `PartialEq`).
+/// (position-for-position, compared by label [`identity`](Label::identity)), the same
+/// [`MvLayout`] and output phase (if any), and the same cubes in the same order. Cube comparison is identity-based
+/// (see [`Cube`]'s `PartialEq`).
 impl<I: Label, O: Label> PartialEq for Cover<I, O> {
     fn eq(&self, other: &Self) -> bool {
         self.cover_type == other.cover_type
             && self.input_symbols == other.input_symbols
             && self.output_symbols == other.output_symbols
+            && self.mv == other.mv
+            && self.phase == other.phase
             && self.cubes == other.cubes
     }
 }
 
 impl<I: Label, O: Label> Eq for Cover<I, O> {}
 
-/// Hashes the same fields the [`PartialEq`] impl compares (cover type, both headers, and the cubes in
-/// order), keeping the `Hash`/`Eq` contract so a `Cover` can key a `HashMap`/`HashSet`.
+/// Hashes the same fields the [`PartialEq`] impl compares (cover type, both headers, the layout, the
+/// phase, and the cubes in order), keeping the `Hash`/`Eq` contract so a `Cover` can key a `HashMap`/`HashSet`.
 impl<I: Label, O: Label> std::hash::Hash for Cover<I, O> {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         self.cover_type.hash(state);
         self.input_symbols.hash(state);
         self.output_symbols.hash(state);
+        self.mv.hash(state);
+        self.phase.hash(state);
         self.cubes.hash(state);
     }
 }
@@ -389,6 +519,8 @@ where
                 .map_err(|e| DuplicateLabel::Output { index: e.index })?,
             cubes: Vec::new(),
             cover_type,
+            mv: None,
+            phase: None,
         })
     }
 }
@@ -430,6 +562,8 @@ impl<I: Label, O: Label> Cover<I, O> {
                 .map_err(|e| DuplicateLabel::Output { index: e.index })?,
             cubes: Vec::new(),
             cover_type,
+            mv: None,
+            phase: None,
         })
     }
 }
@@ -469,6 +603,8 @@ impl<I: Label, O: Label> Cover<I, O> {
             output_symbols: Arc::clone(&self.output_symbols),
             cubes,
             cover_type: self.cover_type,
+            mv: self.mv.clone(),
+            phase: self.phase.clone(),
         }
     }
 }
@@ -672,6 +808,8 @@ impl<I, O> Cover<I, O> {
             output_symbols: Symbols::empty(),
             cubes: Vec::new(),
             cover_type,
+            mv: None,
+            phase: None,
         }
     }
 
@@ -694,6 +832,8 @@ impl<I, O> Cover<I, O> {
             output_symbols,
             cubes,
             cover_type,
+            mv: None,
+            phase: None,
         }
     }
 
@@ -749,6 +889,8 @@ impl<I, O> Cover<I, O> {
             output_symbols,
             cubes,
             cover_type: self.cover_type,
+            mv: self.mv,
+            phase: self.phase,
         })
     }
 
@@ -785,6 +927,8 @@ impl<I, O> Cover<I, O> {
             output_symbols: self.output_symbols,
             cubes,
             cover_type: self.cover_type,
+            mv: self.mv,
+            phase: self.phase,
         })
     }
 
@@ -821,6 +965,8 @@ impl<I, O> Cover<I, O> {
             output_symbols,
             cubes,
             cover_type: self.cover_type,
+            mv: self.mv,
+            phase: self.phase,
         })
     }
 
@@ -1058,6 +1204,123 @@ impl<I, O> Cover<I, O> {
         self.cover_type
     }
 
+    /// How the input positions group into multiple-valued variables, if this cover has any.
+    ///
+    /// `Some` for a cover read from a `.mv` PLA (or given a layout with
+    /// [`with_mv_layout`](Self::with_mv_layout)); `None` for an all-binary cover. The grouping is
+    /// honoured by minimisation and the PLA writer. Operations that add input positions (e.g.
+    /// [`push`](Self::push) of a wider cube, [`merge`](Self::merge) with a different header) drop it,
+    /// and operations that rebuild the header from scratch return an all-binary cover.
+    #[must_use]
+    pub fn mv_layout(&self) -> Option<&MvLayout> {
+        self.mv.as_deref()
+    }
+
+    /// Group this cover's input positions into multiple-valued variables per `layout`.
+    ///
+    /// Each multiple-valued variable's positions hold its value set one-hot (see
+    /// [`Minterm::mv_field`]); the cubes are taken as they are.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArityMismatch::Inputs`] if `layout` does not span exactly this cover's inputs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use espresso_logic::{Anonymous, Cover, CoverType, Cube, CubeType, MvLayout};
+    ///
+    /// // One binary input, then a 3-valued variable restricted to value 1.
+    /// let cover = Cover::<Anonymous, Anonymous>::from_cubes(CoverType::F, [Cube::anonymous(
+    ///     &[Some(true), Some(false), Some(true), Some(false)],
+    ///     &[true],
+    ///     CubeType::F,
+    /// )]);
+    /// let cover = cover.with_mv_layout(MvLayout::new(1, [3]).unwrap()).unwrap();
+    /// assert_eq!(cover.mv_layout().unwrap().mv_sizes(), &[3]);
+    /// ```
+    pub fn with_mv_layout(mut self, layout: MvLayout) -> Result<Self, ArityMismatch> {
+        if layout.num_positions() != self.num_inputs() {
+            return Err(ArityMismatch::Inputs {
+                expected: self.num_inputs(),
+                actual: layout.num_positions(),
+            });
+        }
+        self.mv = Some(Arc::new(layout));
+        Ok(self)
+    }
+
+    /// The output phase, one flag per output: `false` where the output is implemented in complement.
+    ///
+    /// `Some` for a cover read from a PLA with a `.phase` directive, given a phase with
+    /// [`with_output_phase`](Self::with_output_phase), or returned by
+    /// [`assign_output_phase`](Self::assign_output_phase). Until the cover is minimised the phase is
+    /// only a request and the cubes are the function as given; minimisation swaps the ON-set and
+    /// OFF-set of each `false` output, after which
+    /// [`complemented_outputs`](Self::complemented_outputs) lists them. Operations that add outputs
+    /// drop it.
+    #[must_use]
+    pub fn output_phase(&self) -> Option<&[bool]> {
+        self.phase.as_ref().map(|p| &**p.positive())
+    }
+
+    /// Request that minimisation implement each output in the given phase (`false` = complement).
+    ///
+    /// Replaces any phase the cover carries, including one already applied; the cubes are taken as
+    /// the function as given.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArityMismatch::Outputs`] if `phase` does not have one flag per output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use espresso_logic::{Anonymous, Cover, CoverType, Cube, CubeType, Minimizable};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// // a + b, implemented in complement: the OFF-set !a·!b is a single cube.
+    /// let cover = Cover::<Anonymous, Anonymous>::from_cubes(CoverType::F, [
+    ///     Cube::anonymous(&[Some(true), None], &[true], CubeType::F),
+    ///     Cube::anonymous(&[None, Some(true)], &[true], CubeType::F),
+    /// ]);
+    /// let min = cover.with_output_phase([false])?.minimize()?;
+    /// assert_eq!(min.complemented_outputs(), vec![0]);
+    /// assert_eq!(min.num_cubes(), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_output_phase(
+        mut self,
+        phase: impl IntoIterator<Item = bool>,
+    ) -> Result<Self, ArityMismatch> {
+        let phase: Arc<[bool]> = phase.into_iter().collect();
+        if phase.len() != self.num_outputs() {
+            return Err(ArityMismatch::Outputs {
+                expected: self.num_outputs(),
+                actual: phase.len(),
+            });
+        }
+        self.phase = Some(Phase::Requested(phase));
+        Ok(self)
+    }
+
+    /// The outputs whose cubes cover the complement of the function, ascending.
+    ///
+    /// Empty unless the cover's [`output_phase`](Self::output_phase) has been applied by
+    /// minimisation or chosen by [`assign_output_phase`](Self::assign_output_phase).
+    #[must_use]
+    pub fn complemented_outputs(&self) -> Vec<usize> {
+        match &self.phase {
+            Some(Phase::Applied(positive)) => positive
+                .iter()
+                .enumerate()
+                .filter_map(|(j, &p)| (!p).then_some(j))
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
     /// The shared input symbol table.
     pub(crate) fn input_symbols(&self) -> &Arc<Symbols<I>> {
         &self.input_symbols
@@ -1190,6 +1453,8 @@ impl<I: Label, O: Label> Cover<I, O> {
             output_symbols,
             cubes,
             cover_type,
+            mv: None,
+            phase: None,
         }
     }
 
@@ -1253,6 +1518,17 @@ impl<I: Label, O: Label> Cover<I, O> {
                 c.inputs = inputs;
                 c.outputs = outputs;
             }
+            // A new input position no longer fits the multiple-valued grouping.
+            if grows_inputs {
+                self.mv = None;
+            }
+            // A new output is taken as given; the others keep their phase.
+            if grows_outputs {
+                self.phase = self
+                    .phase
+                    .as_ref()
+                    .map(|p| p.remapped(&self_out_map, new_no));
+            }
             self.input_symbols = new_input;
             self.output_symbols = new_output;
         }
@@ -1346,13 +1622,15 @@ fn append_outputs<O: ReconcilableLabel>(
 
 /// Re-point both covers' cubes onto the given combined headers. Inputs union by identity (via
 /// [`project_onto`](Minterm::project_onto)); outputs follow the supplied per-side maps. Each cube keeps
-/// its [`CubeType`]. Shared by `extend` and `merge` — only the output header/maps differ.
+/// its [`CubeType`]. Shared by `extend` and `merge` — only the output header/maps differ. The output
+/// phase is the caller's, from [`combine_phases`].
 fn assemble<I: Label, O: Label>(
     a: &Cover<I, O>,
     b: &Cover<I, O>,
     new_output: Arc<Symbols<O>>,
     a_out_map: Vec<usize>,
     b_out_map: Vec<usize>,
+    phase: Option<Phase>,
 ) -> Cover<I, O> {
     let new_input = union_inputs(&a.input_symbols, &b.input_symbols);
     let new_no = new_output.arity();
@@ -1369,11 +1647,19 @@ fn assemble<I: Label, O: Label>(
         .map(|c| rebuild(c, &a_out_map))
         .chain(b.cubes.iter().map(|c| rebuild(c, &b_out_map)))
         .collect();
+    // The multiple-valued grouping survives only when both sides agree on it and no input was added.
+    let mv = if a.mv == b.mv && new_input.arity() == a.num_inputs() {
+        a.mv.clone()
+    } else {
+        None
+    };
     Cover {
         input_symbols: new_input,
         output_symbols: new_output,
         cubes,
         cover_type: a.cover_type,
+        mv,
+        phase,
     }
 }
 
@@ -1385,7 +1671,26 @@ impl<I: Label, O: Label> Cover<I, O> {
     /// `self` lands on the same column; new identities extend the header. For an anonymous output that
     /// means output `i` of `other` overlays output `i` of `self`; the result has
     /// `max(self.num_outputs(), other.num_outputs())` outputs. Consistent across every label type.
+    ///
+    /// An output phase carries over: an output whose cubes cover its complement on either side stays
+    /// complemented, and new outputs are taken as given.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an output's cubes cover its complement on one side but not on the other; see
+    /// [`try_merge`](Self::try_merge).
     pub fn merge(&mut self, other: &Cover<I, O>) {
+        self.try_merge(other).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// [`merge`](Self::merge), returning an error instead of panicking on an output phase conflict.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CoverError::PhaseConflict`] if one cover has minimised an output in complement (see
+    /// [`complemented_outputs`](Self::complemented_outputs)) and the other has cubes for it as
+    /// given. `self` is left unchanged.
+    pub fn try_merge(&mut self, other: &Cover<I, O>) -> Result<(), CoverError> {
         // Probe (no allocation) whether `other` carries an identity `self` lacks. Only then must `self`'s
         // cubes be re-homed onto wider headers; otherwise `self` keeps its headers and cubes, and
         // `other`'s cubes are appended in place — no union `Symbols`, no rebuild of `self`.
@@ -1413,11 +1718,16 @@ impl<I: Label, O: Label> Cover<I, O> {
         if grows_inputs || grows_outputs {
             let (new_output, a_map, b_map) =
                 overlay_outputs(&self.output_symbols, &other.output_symbols);
-            *self = assemble(self, other, new_output, a_map, b_map);
-            return;
+            let phase = combine_phases(self, &a_map, other, &b_map, new_output.arity())?;
+            *self = assemble(self, other, new_output, a_map, b_map, phase);
+            return Ok(());
         }
 
         let no = self.num_outputs();
+        let b_map: Vec<usize> = (0..other.num_outputs())
+            .map(|j| identity_position(&self.output_symbols, &other.output_symbols, j))
+            .collect();
+        self.phase = combine_phases(self, &(0..no).collect::<Vec<_>>(), other, &b_map, no)?;
         self.cubes.reserve(other.cubes.len());
         for c in &other.cubes {
             let inputs = c.inputs().project_onto(&self.input_symbols);
@@ -1426,6 +1736,7 @@ impl<I: Label, O: Label> Cover<I, O> {
             });
             self.cubes.push(Cube::new(inputs, outputs, c.set));
         }
+        Ok(())
     }
 }
 
@@ -1437,11 +1748,14 @@ impl<I: Label, O: ReconcilableLabel> Cover<I, O> {
     /// is appended (the result always has `self.num_outputs() + other.num_outputs()` outputs), so use
     /// this to stack two functions into one multi-output cover. A clashing output **name** is reconciled
     /// by [`ReconcilableLabel`] (string `f` → `f0`); an anonymous output appends a fresh position.
-    /// Consistent across every label type — unlike `merge`, never overlays.
+    /// Consistent across every label type — unlike `merge`, never overlays. Each output keeps its
+    /// phase.
     pub fn extend(&mut self, other: &Cover<I, O>) {
         let (new_output, a_map, b_map) =
             append_outputs(&self.output_symbols, &other.output_symbols);
-        *self = assemble(self, other, new_output, a_map, b_map);
+        let phase = combine_phases(self, &a_map, other, &b_map, new_output.arity())
+            .expect("extend appends outputs, so no output has cubes from both sides");
+        *self = assemble(self, other, new_output, a_map, b_map, phase);
     }
 }
 
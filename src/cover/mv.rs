@@ -0,0 +1,288 @@
+//! Multiple-valued input variables: the [`MvLayout`] of a `.mv` PLA and the [`MvField`] value set.
+//!
+//! Espresso minimises over *multiple-valued* variables as well as binary ones. A variable of size
+//! `k` takes one of `k` values, and a cube restricts it to a *set* of those values — one bit per
+//! value in the C cube, so a cube may allow any subset (the full set is the variable's don't-care,
+//! the empty set makes the cube vacuous). PLA files declare them with the `.mv` directive.
+//!
+//! The Rust side keeps [`Minterm`](super::Minterm) binary and stores a multiple-valued variable as
+//! `k` consecutive **one-hot positions**: position `j` is [`One`](super::InputField::One) when value
+//! `j` is in the cube's set and [`Zero`](super::InputField::Zero) when it is not. A cover that carries
+//! an [`MvLayout`] (see [`Cover::mv_layout`](super::Cover::mv_layout)) tells the minimiser and the
+//! PLA writer which positions belong together; every position past the binary prefix belongs to
+//! exactly one multiple-valued variable.
+//!
+//! Pairing two binary inputs into a *two-bit decoder* (see
+//! [`Cover::pair_inputs`](super::Cover::pair_inputs)) produces a 4-valued variable of this kind;
+//! the layout marks such variables as [paired](MvLayout::num_paired). Encoding a group of binary
+//! inputs as one symbolic variable (see
+//! [`Cover::encode_symbolic_inputs`](super::Cover::encode_symbolic_inputs)) produces one too.
+
+use super::error::EmptyMvVariable;
+use std::fmt;
+use std::ops::Range;
+use std::sync::Arc;
+
+/// How a cover's input positions group into variables: `num_binary` binary variables first, then
+/// one multiple-valued variable per entry of [`mv_sizes`](Self::mv_sizes), each spanning that many
+/// consecutive one-hot positions.
+///
+/// The layout only describes the *inputs*; the output part of a `.mv` PLA is the cover's outputs.
+///
+/// The first [`num_paired`](Self::num_paired) multiple-valued variables are two-bit decoders built
+/// by input pairing. Espresso treats them as dense rather than sparse variables — it does not try
+/// to lower them to fewer values once a cover is minimised — so they minimise differently from a
+/// 4-valued variable declared with `.mv`.
+///
+/// # Examples
+///
+/// ```
+/// use espresso_logic::MvLayout;
+///
+/// // Two binary inputs followed by a 3-valued and a 4-valued variable.
+/// let layout = MvLayout::new(2, [3, 4]).unwrap();
+/// assert_eq!(layout.num_vars(), 4);
+/// assert_eq!(layout.num_positions(), 9);
+/// assert_eq!(layout.positions(1), 1..2);
+/// assert_eq!(layout.positions(2), 2..5);
+/// assert_eq!(layout.positions(3), 5..9);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MvLayout {
+    num_binary: usize,
+    sizes: Arc<[usize]>,
+    num_paired: usize,
+}
+
+impl MvLayout {
+    /// A layout of `num_binary` binary variables followed by multiple-valued variables of the given
+    /// sizes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmptyMvVariable`] if any size is zero.
+    pub fn new(
+        num_binary: usize,
+        mv_sizes: impl IntoIterator<Item = usize>,
+    ) -> Result<Self, EmptyMvVariable> {
+        let sizes: Arc<[usize]> = mv_sizes.into_iter().collect();
+        if let Some(var) = sizes.iter().position(|&size| size == 0) {
+            return Err(EmptyMvVariable { var });
+        }
+        Ok(MvLayout {
+            num_binary,
+            sizes,
+            num_paired: 0,
+        })
+    }
+
+    /// An all-binary layout of `num_binary` variables.
+    pub(crate) fn binary(num_binary: usize) -> Self {
+        MvLayout {
+            num_binary,
+            sizes: Arc::from([]),
+            num_paired: 0,
+        }
+    }
+
+    /// The layout after pairing: the `unpaired` binary variables, then one 4-valued decoder per
+    /// pair, then this layout's multiple-valued variables.
+    pub(crate) fn paired(&self, unpaired: usize, pairs: usize) -> Self {
+        MvLayout {
+            num_binary: unpaired,
+            sizes: std::iter::repeat_n(4, pairs)
+                .chain(self.sizes.iter().copied())
+                .collect(),
+            num_paired: pairs + self.num_paired,
+        }
+    }
+
+    /// This layout with its first `num_paired` variables marked as pair decoders. The caller checks
+    /// that they are four-valued.
+    #[cfg(feature = "serde")]
+    pub(crate) fn with_num_paired(self, num_paired: usize) -> Self {
+        MvLayout { num_paired, ..self }
+    }
+
+    /// The layout after symbolic encoding: `kept` binary variables, then this layout's
+    /// multiple-valued variables, then one variable per entry of `added`.
+    pub(crate) fn symbolic(&self, kept: usize, added: impl IntoIterator<Item = usize>) -> Self {
+        MvLayout {
+            num_binary: kept,
+            sizes: self.sizes.iter().copied().chain(added).collect(),
+            num_paired: self.num_paired,
+        }
+    }
+
+    /// The number of leading binary variables.
+    #[must_use]
+    pub fn num_binary(&self) -> usize {
+        self.num_binary
+    }
+
+    /// The sizes of the multiple-valued variables, in order.
+    #[must_use]
+    pub fn mv_sizes(&self) -> &[usize] {
+        &self.sizes
+    }
+
+    /// The number of leading multiple-valued variables that are two-bit decoders built by input
+    /// pairing; `0` for a layout made with [`new`](Self::new).
+    #[must_use]
+    pub fn num_paired(&self) -> usize {
+        self.num_paired
+    }
+
+    /// The total number of variables, binary and multiple-valued.
+    #[must_use]
+    pub fn num_vars(&self) -> usize {
+        self.num_binary + self.sizes.len()
+    }
+
+    /// The number of input positions the layout spans — the input arity of a cover carrying it.
+    #[must_use]
+    pub fn num_positions(&self) -> usize {
+        self.num_binary + self.sizes.iter().sum::<usize>()
+    }
+
+    /// The input positions occupied by variable `var` (binary variables first): a single position
+    /// for a binary variable, `size` consecutive positions for a multiple-valued one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `var >= self.num_vars()`.
+    #[must_use]
+    pub fn positions(&self, var: usize) -> Range<usize> {
+        if var < self.num_binary {
+            return var..var + 1;
+        }
+        let mv = var - self.num_binary;
+        assert!(
+            mv < self.sizes.len(),
+            "variable {var} is out of range for a layout of {} variables",
+            self.num_vars()
+        );
+        let start = self.num_binary + self.sizes[..mv].iter().sum::<usize>();
+        start..start + self.sizes[mv]
+    }
+
+    /// `(first position, size)` of each multiple-valued variable, in order.
+    pub(crate) fn mv_parts(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.sizes.iter().scan(self.num_binary, |start, &size| {
+            let part = (*start, size);
+            *start += size;
+            Some(part)
+        })
+    }
+}
+
+/// The set of values a cube allows for one multiple-valued variable.
+///
+/// Displays as the PLA bit string, one `0`/`1` per value (`1` = value in the set).
+///
+/// # Examples
+///
+/// ```
+/// use espresso_logic::MvField;
+///
+/// let mut field = MvField::empty(4);
+/// field.insert(1);
+/// field.insert(2);
+/// assert!(field.contains(2));
+/// assert_eq!(field.values().collect::<Vec<_>>(), vec![1, 2]);
+/// assert_eq!(field.to_string(), "0110");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MvField {
+    bits: Vec<bool>,
+}
+
+impl MvField {
+    /// The empty set over a variable of `size` values.
+    #[must_use]
+    pub fn empty(size: usize) -> Self {
+        MvField {
+            bits: vec![false; size],
+        }
+    }
+
+    /// The full set (the variable's don't-care) over a variable of `size` values.
+    #[must_use]
+    pub fn full(size: usize) -> Self {
+        MvField {
+            bits: vec![true; size],
+        }
+    }
+
+    /// Build a set from one membership flag per value.
+    #[must_use]
+    pub fn from_bits(bits: impl IntoIterator<Item = bool>) -> Self {
+        MvField {
+            bits: bits.into_iter().collect(),
+        }
+    }
+
+    /// The number of values the variable takes.
+    #[must_use]
+    pub fn size(&self) -> usize {
+        self.bits.len()
+    }
+
+    /// Whether `value` is in the set. Values past the variable's size are never in it.
+    #[must_use]
+    pub fn contains(&self, value: usize) -> bool {
+        self.bits.get(value).copied().unwrap_or(false)
+    }
+
+    /// Add `value` to the set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value >= self.size()`.
+    pub fn insert(&mut self, value: usize) {
+        self.bits[value] = true;
+    }
+
+    /// Remove `value` from the set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value >= self.size()`.
+    pub fn remove(&mut self, value: usize) {
+        self.bits[value] = false;
+    }
+
+    /// Whether no value is in the set (a cube with such a field is vacuous).
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        !self.bits.iter().any(|&b| b)
+    }
+
+    /// Whether every value is in the set (the variable's don't-care).
+    #[must_use]
+    pub fn is_full(&self) -> bool {
+        self.bits.iter().all(|&b| b)
+    }
+
+    /// The values in the set, ascending.
+    pub fn values(&self) -> impl Iterator<Item = usize> + '_ {
+        self.bits
+            .iter()
+            .enumerate()
+            .filter_map(|(value, &b)| b.then_some(value))
+    }
+
+    /// The membership flags, one per value.
+    pub fn bits(&self) -> impl Iterator<Item = bool> + '_ {
+        self.bits.iter().copied()
+    }
+}
+
+impl fmt::Display for MvField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for &b in &self.bits {
+            f.write_str(if b { "1" } else { "0" })?;
+        }
+        Ok(())
+    }
+}
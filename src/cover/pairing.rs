@@ -0,0 +1,144 @@
+//! Input bit pairing: replacing pairs of binary inputs with two-bit decoders.
+//!
+//! A PLA built with two-bit decoders in front of its AND plane sees each decoded pair of inputs
+//! `(a, b)` as one 4-valued variable whose values are the decoder's four lines. A product term may
+//! then select any subset of those lines, so functions such as `a ⊕ b` take a single literal. This
+//! module rewrites a cover into that shape; the search for a good pairing lives with the minimiser
+//! ([`Cover::find_input_pairing`]).
+
+use super::error::InvalidPairing;
+use super::label::PairableLabel;
+use super::minterm::{InputField, Minterm};
+use super::mv::MvLayout;
+use super::symbols::Symbols;
+use super::{Cover, Cube};
+use std::sync::Arc;
+
+/// The value combination `(a, b)` selecting each decoder value, in value order — as `pair.c` lays
+/// out a paired variable.
+const DECODER_VALUES: [(bool, bool); 4] =
+    [(true, true), (true, false), (false, true), (false, false)];
+
+/// Whether an input field allows `value`.
+pub(super) fn allows(field: InputField, value: bool) -> bool {
+    matches!(
+        (field, value),
+        (InputField::DontCare, _) | (InputField::One, true) | (InputField::Zero, false)
+    )
+}
+
+impl<I: PairableLabel, O: Clone> Cover<I, O> {
+    /// Pair binary inputs into two-bit decoders, as a `.pair` directive does.
+    ///
+    /// Each pair `(a, b)` of binary input variables becomes one 4-valued variable, whose values
+    /// stand for the combinations `a·b`, `a·!b`, `!a·b` and `!a·!b` in that order. The result is laid
+    /// out as the reference tool lays out a paired PLA: the inputs left unpaired first, then one
+    /// decoder per pair, then any multiple-valued variables the cover already had (see
+    /// [`mv_layout`](Self::mv_layout)). Every cube is rewritten to select the decoder values its two
+    /// fields allowed, so the function is unchanged; only how minimisation may group it differs.
+    ///
+    /// With named labels the decoder values take the reference tool's names, `a.bar+b.bar` for
+    /// `a·b` through `a+b` for `!a·!b`; see [`PairableLabel`].
+    ///
+    /// An empty `pairs` returns the cover unchanged. Use
+    /// [`find_input_pairing`](Self::find_input_pairing) to have Espresso choose the pairs.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidPairing`] if a pair names an input that is not a binary variable of this
+    /// cover, or names an input already paired.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use espresso_logic::{Cover, CoverType, Cube, CubeType, Minimizable, Symbol};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// // a ⊕ b needs two product terms, but a single decoder literal.
+    /// let cube = |a, b| {
+    ///     let inputs = [("a", Some(a)), ("b", Some(b))];
+    ///     Cube::<Symbol, Symbol>::with_labels(&inputs, &[("f", true)], CubeType::F)
+    /// };
+    /// let cover = Cover::from_cubes(CoverType::F, [cube(true, false)?, cube(false, true)?]);
+    ///
+    /// let paired = cover.pair_inputs(&[(0, 1)])?;
+    /// assert_eq!(paired.mv_layout().unwrap().mv_sizes(), &[4]);
+    /// assert_eq!(paired.input_labels()[1].as_ref(), "a.bar+b");
+    /// assert_eq!(paired.minimize()?.num_cubes(), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn pair_inputs(&self, pairs: &[(usize, usize)]) -> Result<Self, InvalidPairing> {
+        if pairs.is_empty() {
+            return Ok(self.clone());
+        }
+        let layout = self
+            .mv_layout()
+            .cloned()
+            .unwrap_or_else(|| MvLayout::binary(self.num_inputs()));
+        let num_binary = layout.num_binary();
+        let mut paired = vec![false; num_binary];
+        for &var in pairs.iter().flat_map(|(a, b)| [a, b]) {
+            match paired.get_mut(var) {
+                Some(taken @ false) => *taken = true,
+                _ => return Err(InvalidPairing { var }),
+            }
+        }
+        let unpaired: Vec<usize> = (0..num_binary).filter(|&var| !paired[var]).collect();
+
+        // The header: kept binary labels, the decoder values (renamed clear of every kept label), then
+        // the multiple-valued positions.
+        let labels = self.input_symbols().labels();
+        let kept: Vec<I> = unpaired
+            .iter()
+            .map(|&var| labels[var].clone())
+            .chain(labels[num_binary..].iter().cloned())
+            .collect();
+        let values: Vec<I> = pairs
+            .iter()
+            .flat_map(|&(a, b)| I::pair_values(&labels[a], &labels[b]))
+            .collect();
+        let values = I::reconcile(&kept, &values);
+        let header: Arc<[I]> = kept[..unpaired.len()]
+            .iter()
+            .chain(&values)
+            .chain(&kept[unpaired.len()..])
+            .cloned()
+            .collect();
+        let symbols = Symbols::new(header).expect("paired labels are reconciled to be distinct");
+
+        let cubes = self
+            .cubes
+            .iter()
+            .map(|cube| {
+                let m = cube.inputs();
+                let decoders = pairs.iter().flat_map(|&(a, b)| {
+                    let (fa, fb) = (m.field_at(a), m.field_at(b));
+                    DECODER_VALUES.map(|(va, vb)| {
+                        if allows(fa, va) && allows(fb, vb) {
+                            InputField::One
+                        } else {
+                            InputField::Zero
+                        }
+                    })
+                });
+                let fields = unpaired
+                    .iter()
+                    .map(|&var| m.field_at(var))
+                    .chain(decoders)
+                    .chain((num_binary..m.num_vars()).map(|i| m.field_at(i)));
+                let inputs = Minterm::from_symbols_input_fields(Arc::clone(&symbols), fields);
+                Cube::new(inputs, cube.outputs().clone(), cube.cube_type())
+            })
+            .collect();
+
+        Ok(Cover {
+            input_symbols: symbols,
+            output_symbols: Arc::clone(self.output_symbols()),
+            cubes,
+            cover_type: self.cover_type,
+            mv: Some(Arc::new(layout.paired(unpaired.len(), pairs.len()))),
+            phase: self.phase.clone(),
+        })
+    }
+}
@@ -92,7 +92,8 @@ pub enum PLAError {
         /// The name repeated within the label section
         name: Arc<str>,
     },
-    /// The `.ilb` label section appears more than once in a PLA file.
+    /// The `.ilb` label section appears more than once in a PLA file, or a `.label` section names
+    /// the same multiple-valued variable twice.
     ///
     /// C's reference reader (`cvrin.c`) silently overwrites the input label slots on a repeated
     /// `.ilb` section — no warning at all, unlike the "extra .i ignored" it prints for a repeated
@@ -105,6 +106,69 @@ pub enum PLAError {
     /// of [`DuplicateInputLabelDirective`](Self::DuplicateInputLabelDirective); see its
     /// documentation for the rationale.
     DuplicateOutputLabelDirective,
+    /// Malformed `.mv` directive: a missing or non-numeric field, fewer part sizes than the
+    /// declared variable count, no output part, or a zero-sized part.
+    InvalidMvDirective {
+        /// The directive's arguments as written
+        value: Arc<str>,
+    },
+    /// `.mv` is combined with `.i`/`.o`, or appears more than once. C rejects mixing the two
+    /// (`cannot mix .i and .mv`); a repeated `.mv` is rejected for the same reason as
+    /// [`DuplicateInputDirective`](Self::DuplicateInputDirective).
+    ConflictingMvDirective,
+    /// A `.mv` part size is negative, which C reads as a *symbolic* variable (values named by
+    /// tokens in the cube data) — not supported by this reader.
+    SymbolicMvVariable {
+        /// The variable index (counting binary variables) as written in `.mv`
+        var: usize,
+    },
+    /// Malformed `.label var=N` directive, or `N` does not name a multiple-valued input variable.
+    InvalidLabelDirective {
+        /// The directive's arguments as written
+        value: Arc<str>,
+    },
+    /// Malformed `.phase` directive: it must be a single run of `0`/`1`, one per output (C: "only 0
+    /// or 1 allowed in phase description").
+    InvalidPhaseDirective {
+        /// The directive's arguments as written
+        value: Arc<str>,
+    },
+    /// The `.phase` directive appears more than once. C prints "extra .phase ignored" and keeps the
+    /// first; this crate rejects it, as it does a repeated [`.i`](Self::DuplicateInputDirective).
+    DuplicatePhaseDirective,
+    /// Malformed `.pair` directive: `N` pairs of binary inputs, named by their `.ilb` labels (or by
+    /// zero-based number when there are none), each input paired at most once (C: "syntax error in
+    /// .pair", "can only pair binary-valued variables").
+    InvalidPairDirective {
+        /// The directive's arguments as written
+        value: Arc<str>,
+    },
+    /// The `.pair` directive appears more than once. C prints "extra .pair ignored" and keeps the
+    /// first; this crate rejects it, as it does a repeated [`.phase`](Self::DuplicatePhaseDirective).
+    DuplicatePairDirective,
+    /// Malformed `.symbolic` directive: binary inputs, named by their `.ilb` labels (or by zero-based
+    /// number when there are none), then `;`, the value names and `;`, all on one line. Each input
+    /// may join one symbolic variable, and none may also be paired (C: "error reading .symbolic",
+    /// ".symbolic requires binary variables").
+    InvalidSymbolicDirective {
+        /// The directive's arguments as written
+        value: Arc<str>,
+    },
+    /// Malformed `.symbolic-output` directive: as [`.symbolic`](Self::InvalidSymbolicDirective), but
+    /// over the outputs and their `.ob` labels. It cannot be combined with `.phase`, whose outputs
+    /// it would replace (C: "error reading .symbolic-output", "symbolic-output index out of range").
+    InvalidSymbolicOutputDirective {
+        /// The directive's arguments as written
+        value: Arc<str>,
+    },
+    /// A directive the streaming [`PlaCubeReader`](super::PlaCubeReader) cannot honour: `.pair`,
+    /// `.symbolic` and `.symbolic-output` rewrite every cube once the whole file is read, and any
+    /// directive other than `.p`/`.e`/`.end` after the first cube would change a header already
+    /// handed out. Read such a file with [`PlaCover`](super::PlaCover) instead.
+    StreamingDirective {
+        /// The directive as written (e.g. `.pair`)
+        directive: Arc<str>,
+    },
 }
 
 impl fmt::Display for PLAError {
@@ -164,6 +228,39 @@ impl fmt::Display for PLAError {
             PLAError::DuplicateOutputLabelDirective => {
                 write!(f, "PLA file declares .ob more than once")
             }
+            PLAError::InvalidMvDirective { value } => {
+                write!(f, "Invalid .mv directive value: '{}'", value)
+            }
+            PLAError::ConflictingMvDirective => {
+                write!(f, "PLA file mixes .mv with .i/.o or declares .mv more than once")
+            }
+            PLAError::SymbolicMvVariable { var } => {
+                write!(f, "symbolic .mv variable {} is not supported", var)
+            }
+            PLAError::InvalidLabelDirective { value } => {
+                write!(f, "Invalid .label directive value: '{}'", value)
+            }
+            PLAError::InvalidPhaseDirective { value } => {
+                write!(f, "Invalid .phase directive value: '{}'", value)
+            }
+            PLAError::DuplicatePhaseDirective => {
+                write!(f, "PLA file declares .phase more than once")
+            }
+            PLAError::InvalidPairDirective { value } => {
+                write!(f, "Invalid .pair directive value: '{}'", value)
+            }
+            PLAError::DuplicatePairDirective => {
+                write!(f, "PLA file declares .pair more than once")
+            }
+            PLAError::InvalidSymbolicDirective { value } => {
+                write!(f, "Invalid .symbolic directive value: '{}'", value)
+            }
+            PLAError::InvalidSymbolicOutputDirective { value } => {
+                write!(f, "Invalid .symbolic-output directive value: '{}'", value)
+            }
+            PLAError::StreamingDirective { directive } => {
+                write!(f, "{} directive is not supported when streaming cubes", directive)
+            }
         }
     }
 }
@@ -176,22 +273,121 @@ impl From<PLAError> for io::Error {
     }
 }
 
+/// Where in a PLA source a [`PLAError`] was found.
+///
+/// Errors in cube data point at the offending character, and carry the index of the cube being
+/// read — cubes may span or share lines, so the line alone does not identify it. Errors in a
+/// directive point at the directive; errors only detectable once the whole file is read (e.g. a
+/// label count) point at the directive they concern, or past the last line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct PlaSpan {
+    /// One-based line number.
+    pub line: usize,
+    /// One-based column, counted in characters.
+    pub column: usize,
+    /// Zero-based index of the cube being read, for errors in cube data.
+    pub cube: Option<usize>,
+    /// The line as read (empty past the last line).
+    pub text: Arc<str>,
+}
+
+impl fmt::Display for PlaSpan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)?;
+        match self.cube {
+            Some(cube) => write!(f, " (cube {})", cube),
+            None => Ok(()),
+        }
+    }
+}
+
 /// Errors that can occur when reading PLA format data
 ///
-/// This error type is returned by the `PlaCover::from_pla_*` methods.
+/// This error type is returned by the `PlaCover::from_pla_*` methods and [`PlaCubeReader`](super::PlaCubeReader).
+/// The readers report every format error as [`Located`](Self::Located); [`pla_error`](Self::pla_error)
+/// and [`span`](Self::span) read either format variant.
 #[derive(Debug)]
 #[non_exhaustive]
 pub enum PLAReadError {
-    /// PLA format error
+    /// PLA format error without a source location
     PLA(PLAError),
+    /// PLA format error at a known place in the source
+    Located {
+        /// What is wrong
+        error: PLAError,
+        /// Where it was found
+        span: PlaSpan,
+    },
     /// IO error during reading
     Io(io::Error),
 }
 
+impl PLAReadError {
+    /// The format error, or `None` for an I/O error.
+    #[must_use]
+    pub fn pla_error(&self) -> Option<&PLAError> {
+        match self {
+            PLAReadError::PLA(e) | PLAReadError::Located { error: e, .. } => Some(e),
+            PLAReadError::Io(_) => None,
+        }
+    }
+
+    /// Where the format error was found, if known.
+    #[must_use]
+    pub fn span(&self) -> Option<&PlaSpan> {
+        match self {
+            PLAReadError::Located { span, .. } => Some(span),
+            _ => None,
+        }
+    }
+
+    /// The error as a multi-line diagnostic: the message, then — when the location is known — the
+    /// offending line with a caret under the column.
+    ///
+    /// ```
+    /// use espresso_logic::{PlaCover, Symbol};
+    ///
+    /// let err = PlaCover::<Symbol>::from_pla_string(".i 2\n.o 1\n01 1\n0x 1\n").unwrap_err();
+    /// assert_eq!(
+    ///     err.render(),
+    ///     "error: Invalid input character 'x' at position 1\n \
+    ///      --> line 4, column 2 (cube 1)\n  \
+    ///        |\n\
+    ///      4 | 0x 1\n  \
+    ///        |  ^\n"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn render(&self) -> String {
+        let (error, span) = match self {
+            PLAReadError::Located { error, span } => (error, span),
+            PLAReadError::PLA(e) => return format!("error: {}\n", e),
+            PLAReadError::Io(e) => return format!("error: {}\n", e),
+        };
+        let number = span.line.to_string();
+        let gutter = " ".repeat(number.len());
+        // Tabs keep their width, so the caret lines up however the terminal expands them.
+        let pad: String = span
+            .text
+            .chars()
+            .take(span.column.saturating_sub(1))
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+        format!(
+            "error: {error}\n{gutter}--> {span}\n{gutter} |\n{number} | {text}\n{gutter} | {pad}^\n",
+            text = span.text,
+        )
+    }
+}
+
 impl fmt::Display for PLAReadError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             PLAReadError::PLA(e) => write!(f, "PLA format error: {}", e),
+            PLAReadError::Located { error, span } => {
+                write!(f, "PLA format error at {}: {}", span, error)
+            }
             PLAReadError::Io(e) => write!(f, "IO error: {}", e),
         }
     }
@@ -201,6 +397,7 @@ impl std::error::Error for PLAReadError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
             PLAReadError::PLA(e) => Some(e),
+            PLAReadError::Located { error, .. } => Some(error),
             PLAReadError::Io(e) => Some(e),
         }
     }
@@ -225,6 +422,9 @@ impl From<PLAReadError> for io::Error {
             PLAReadError::Io(e) => e,
             // Otherwise, wrap it as InvalidData
             PLAReadError::PLA(e) => io::Error::new(io::ErrorKind::InvalidData, e),
+            located @ PLAReadError::Located { .. } => {
+                io::Error::new(io::ErrorKind::InvalidData, located)
+            }
         }
     }
 }
@@ -320,6 +520,35 @@ mod tests {
         assert!(matches!(read_err, PLAReadError::Io(_)));
     }
 
+    #[test]
+    fn test_pla_read_error_located() {
+        let err = PLAReadError::Located {
+            error: PLAError::MissingDimensions,
+            span: PlaSpan {
+                line: 3,
+                column: 2,
+                cube: None,
+                text: Arc::from(" 01 1"),
+            },
+        };
+        assert_eq!(
+            err.to_string(),
+            "PLA format error at line 3, column 2: PLA file has no dimension information"
+        );
+        assert_eq!(err.pla_error(), Some(&PLAError::MissingDimensions));
+        assert_eq!(err.span().map(|span| span.line), Some(3));
+        let io_err: io::Error = err.into();
+        assert_eq!(io_err.kind(), io::ErrorKind::InvalidData);
+
+        // Without a location there is nothing to point at.
+        let err = PLAReadError::PLA(PLAError::MissingDimensions);
+        assert_eq!(err.span(), None);
+        assert_eq!(
+            err.render(),
+            "error: PLA file has no dimension information\n"
+        );
+    }
+
     #[test]
     fn test_pla_write_error_from_io_error() {
         let io_err = io::Error::new(io::ErrorKind::PermissionDenied, "permission denied");
@@ -370,6 +599,54 @@ mod tests {
         assert!(msg.contains("more than once"));
     }
 
+    #[test]
+    fn test_pla_error_mv_directives() {
+        let err = PLAError::InvalidMvDirective {
+            value: Arc::from("3 1 0 2"),
+        };
+        assert!(err.to_string().contains("'3 1 0 2'"));
+        assert!(PLAError::ConflictingMvDirective.to_string().contains(".mv"));
+        assert!(PLAError::SymbolicMvVariable { var: 2 }
+            .to_string()
+            .contains("symbolic"));
+    }
+
+    #[test]
+    fn test_pla_error_phase_directives() {
+        let err = PLAError::InvalidPhaseDirective {
+            value: Arc::from("01x"),
+        };
+        assert!(err.to_string().contains("'01x'"));
+        let msg = PLAError::DuplicatePhaseDirective.to_string();
+        assert!(msg.contains(".phase"));
+        assert!(msg.contains("more than once"));
+    }
+
+    #[test]
+    fn test_pla_error_pair_directives() {
+        let err = PLAError::InvalidPairDirective {
+            value: Arc::from("1 (a z)"),
+        };
+        assert!(err.to_string().contains("'1 (a z)'"));
+        let msg = PLAError::DuplicatePairDirective.to_string();
+        assert!(msg.contains(".pair"));
+        assert!(msg.contains("more than once"));
+    }
+
+    #[test]
+    fn test_pla_error_symbolic_directives() {
+        let err = PLAError::InvalidSymbolicDirective {
+            value: Arc::from("a b ; X Y"),
+        };
+        assert!(err.to_string().contains(".symbolic directive"));
+        assert!(err.to_string().contains("'a b ; X Y'"));
+        let err = PLAError::InvalidSymbolicOutputDirective {
+            value: Arc::from("z ; ;"),
+        };
+        assert!(err.to_string().contains(".symbolic-output"));
+        assert!(err.to_string().contains("'z ; ;'"));
+    }
+
     #[test]
     fn test_pla_error_to_io_error() {
         let err = PLAError::MissingInputDirective;
@@ -15,6 +15,7 @@
 //!
 //! - [`PlaCover`] - the reader output: a cover typed by which label sections (`.ilb`/`.ob`) the file
 //!   carried (named sides use the label type `S`, absent sides are [`Anonymous`])
+//! - [`PlaCubeReader`] - the streaming reader: a [`PlaHeader`] up front, then one cube at a time
 //! - [`PLAWriter`] - serialising any [`Cover`] whose labels can render (see [`PlaLabel`])
 //!
 //! Reading is provided by [`PlaCover`]; writing by [`PLAWriter`], making PLA file I/O straightforward.
@@ -47,8 +48,10 @@
 #![doc = include_str!("../../../docs/PLA_FORMAT.md")]
 
 pub mod error;
+mod stream;
 
-pub use error::{PLAError, PLAReadError, PLAWriteError};
+pub use error::{PLAError, PLAReadError, PLAWriteError, PlaSpan};
+pub use stream::{PlaCubeReader, PlaHeader};
 
 use std::fmt;
 use std::fs::File;
@@ -57,13 +60,16 @@ use std::path::Path;
 use std::sync::Arc;
 
 use super::conversions::{anonymous_cover_from_raw, RawCube};
-use super::label::{Anonymous, Label, StringLabel};
+use super::error::{InvalidPairing, InvalidSymbolic, SymbolicOutputError};
+use super::label::{Anonymous, Label, ReconcilableLabel, StringLabel};
 use super::minimisation::Minimizable;
 use super::minterm::InputField;
+use super::mv::MvLayout;
+use super::symbolic::SymbolicVariable;
 use super::symbols::Symbols;
-use super::{Cover, CoverType, CubeType};
+use super::{Cover, CoverType, CubeType, Phase};
 use crate::espresso::error::MinimizationError;
-use crate::EspressoConfig;
+use crate::{EspressoConfig, PairingSearch, PhaseStrategy, SingleOutputStrategy};
 
 /// How a label type renders into a PLA `.ilb`/`.ob` section — the type-level "is this a name?" test.
 ///
@@ -157,14 +163,40 @@ impl<I: PlaLabel, O: PlaLabel> PLAWriter for Cover<I, O> {
             CoverType::F => {} // F is default, no .type needed
         }
 
-        // Write PLA header (matching C output order: .i, .o, .ilb, .ob)
-        writeln!(writer, ".i {}", self.num_inputs())?;
-        writeln!(writer, ".o {}", self.num_outputs())?;
+        // A cover with multiple-valued inputs is written as C's `fpr_header` does: `.mv` replaces
+        // `.i`/`.o`, `.ilb` names only the binary variables, and each multiple-valued variable gets a
+        // `.label var=N` line after `.ob`. A section holding only the reader's placeholder names
+        // (`v{var}` / `v{var}.{value}`, see `mv_input_labels`) was absent from the file and is
+        // skipped, as C skips an unlabelled variable.
+        let layout = self.mv_layout().filter(|l| !l.mv_sizes().is_empty());
+        let num_binary = layout.map_or(self.num_inputs(), MvLayout::num_binary);
+
+        // Write PLA header (matching C output order: .i/.mv, .o, .ilb, .ob, .label)
+        match layout {
+            Some(layout) => {
+                write!(writer, ".mv {} {}", layout.num_vars() + 1, num_binary)?;
+                for size in layout.mv_sizes() {
+                    write!(writer, " {}", size)?;
+                }
+                writeln!(writer, " {}", self.num_outputs())?;
+            }
+            None => {
+                writeln!(writer, ".i {}", self.num_inputs())?;
+                writeln!(writer, ".o {}", self.num_outputs())?;
+            }
+        }
 
         // Write input labels iff the input label type is a name (Display); Anonymous omits.
-        if let Some(labels) = I::pla_labels(self.input_symbols().labels()) {
+        let input_labels = I::pla_labels(self.input_symbols().labels());
+        let placeholder = |labels: &[String], name: &dyn Fn(usize) -> String| {
+            layout.is_some() && labels.iter().enumerate().all(|(j, l)| *l == name(j))
+        };
+        if let Some(labels) = input_labels
+            .as_ref()
+            .filter(|l| num_binary > 0 && !placeholder(&l[..num_binary], &|var| format!("v{var}")))
+        {
             write!(writer, ".ilb")?;
-            for label in labels {
+            for label in &labels[..num_binary] {
                 write!(writer, " {}", label)?;
             }
             writeln!(writer)?;
@@ -179,6 +211,38 @@ impl<I: PlaLabel, O: PlaLabel> PLAWriter for Cover<I, O> {
             writeln!(writer)?;
         }
 
+        // Multiple-valued variable labels, one `.label` line per variable (C numbers variables
+        // from 0 across the binary ones).
+        if let (Some(layout), Some(labels)) = (layout, &input_labels) {
+            for (k, (start, size)) in layout.mv_parts().enumerate() {
+                let var = num_binary + k;
+                let names = &labels[start..start + size];
+                if placeholder(names, &|value| format!("v{var}.{value}")) {
+                    continue;
+                }
+                write!(writer, ".label var={}", var)?;
+                for label in names {
+                    write!(writer, " {}", label)?;
+                }
+                writeln!(writer)?;
+            }
+        }
+
+        // A requested phase is re-emitted as the directive it was read from. Once minimisation has
+        // applied it, C's `fpr_header` records it as a `#.phase` comment instead: the cubes already
+        // reflect it, so reading the file back must not apply it a second time.
+        if let Some(phase) = &self.phase {
+            let prefix = match phase {
+                Phase::Requested(_) => "",
+                Phase::Applied(_) => "#",
+            };
+            write!(writer, "{prefix}.phase ")?;
+            for &positive in phase.positive().iter() {
+                write!(writer, "{}", if positive { '1' } else { '0' })?;
+            }
+            writeln!(writer)?;
+        }
+
         // Filter cubes based on output type using the cube's set tag
         let mut filtered_cubes: Vec<_> = self
             .cubes
@@ -208,7 +272,7 @@ impl<I: PlaLabel, O: PlaLabel> PLAWriter for Cover<I, O> {
         for cube in filtered_cubes {
             // Write inputs from the faithful fields so the empty literal `?` round-trips (the
             // `Option<bool>` view would fold it to `-`). Matches C's `print_cube` map `"?01-"[field]`.
-            for field in cube.inputs.fields() {
+            for field in cube.inputs.fields().take(num_binary) {
                 write!(
                     writer,
                     "{}",
@@ -221,6 +285,13 @@ impl<I: PlaLabel, O: PlaLabel> PLAWriter for Cover<I, O> {
                 )?;
             }
 
+            // Each multiple-valued variable follows as its own space-separated `0`/`1` value set.
+            if let Some(layout) = layout {
+                for (start, size) in layout.mv_parts() {
+                    write!(writer, " {}", cube.inputs.mv_field(start..start + size))?;
+                }
+            }
+
             write!(writer, " ")?;
 
             // Encode outputs. `cube.outputs` is a membership bitmap: a set bit = asserted.
@@ -360,10 +431,24 @@ impl<S> PlaCover<S> {
 /// Raw PLA components from [`parse_pla`]: label sections kept as the strings read from the file (an
 /// absent section is `None`), to be turned into a concrete label type by [`PlaCover`].
 struct ParsedPla {
+    /// Input positions: the binary variables plus one per value of each multiple-valued variable.
     num_inputs: usize,
     num_outputs: usize,
+    /// The `.mv` grouping, when the file declared at least one multiple-valued input variable.
+    mv: Option<MvLayout>,
+    /// One label per input position: `.ilb` for the binary variables and `.label var=N` for the
+    /// multiple-valued ones. With `.symbolic`, the positions are those after it is applied.
     input_labels: Option<Vec<String>>,
+    /// One label per output; with `.symbolic-output`, the outputs after it is applied.
     output_labels: Option<Vec<String>>,
+    /// The `.phase` request, one flag per output (`false` = implement in complement).
+    phase: Option<Vec<bool>>,
+    /// The `.pair` request, as pairs of binary input indices once `symbolic` has been applied.
+    pair: Option<Vec<(usize, usize)>>,
+    /// The `.symbolic` groups, over the binary inputs.
+    symbolic: Vec<SymbolicVariable>,
+    /// The `.symbolic-output` groups, over the outputs.
+    symbolic_output: Vec<SymbolicVariable>,
     cubes: Vec<RawCube>,
     cover_type: CoverType,
 }
@@ -379,211 +464,814 @@ fn first_duplicate(labels: &[String]) -> Option<&str> {
         .map(String::as_str)
 }
 
-/// Parse a PLA stream into its raw components (dimensions, optional `.ilb`/`.ob` strings, cubes). The
-/// label type is decided later by [`PlaCover`], so this stays label-type-agnostic.
-fn parse_pla<R: std::io::BufRead>(reader: R) -> Result<ParsedPla, PLAReadError> {
-    let mut num_inputs: Option<usize> = None;
-    let mut num_outputs: Option<usize> = None;
-    let mut cubes: Vec<RawCube> = Vec::new();
-    // Default to FD_type to match C espresso behaviour (main.c line 21)
-    // This causes '-' in outputs to be parsed as D cubes, not just don't-care bits
-    let mut cover_type = CoverType::FD;
-    let mut input_labels: Option<Vec<String>> = None;
-    let mut output_labels: Option<Vec<String>> = None;
-
-    // C's `parse_pla` (cvrin.c) reads cube data as a single character stream: space, tab, `|` and
-    // *newlines* are all insignificant, and one cube is exactly `ni + no` significant characters —
-    // there are no cube separators. We mirror that by accumulating significant cube characters and
-    // draining complete `ni + no` chunks as they form, instead of treating each line as a cube.
-    let is_pla_delimiter = |c: char| c.is_whitespace() || c == '|';
-    let mut cube_stream: Vec<char> = Vec::new();
+/// Parse the arguments of a `.mv num_vars num_binary s_nb .. s_last` directive into the binary count
+/// and the part sizes, the last of which is the output part (cvrin.c).
+fn parse_mv_directive(args: &[&str]) -> Result<(usize, Vec<usize>), PLAError> {
+    let invalid = || PLAError::InvalidMvDirective {
+        value: Arc::from(args.join(" ")),
+    };
+    let nums: Vec<i64> = args
+        .iter()
+        .map(|s| s.parse())
+        .collect::<Result<_, _>>()
+        .map_err(|_| invalid())?;
+    let (num_vars, num_binary) = match nums.as_slice() {
+        [num_vars, num_binary, ..] if *num_binary >= 0 && num_vars > num_binary => {
+            (*num_vars as usize, *num_binary as usize)
+        }
+        _ => return Err(invalid()),
+    };
+    let sizes = &nums[2..];
+    if sizes.len() != num_vars - num_binary {
+        return Err(invalid());
+    }
+    sizes
+        .iter()
+        .enumerate()
+        .map(|(k, &size)| match size {
+            s if s < 0 => Err(PLAError::SymbolicMvVariable {
+                var: num_binary + k,
+            }),
+            0 => Err(invalid()),
+            s => Ok(s as usize),
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map(|sizes| (num_binary, sizes))
+}
 
-    // Stream lines one at a time rather than buffering the whole file: each line's `io::Error`
-    // (if any) surfaces at the point it occurs via `?`.
-    for raw_line in reader.lines() {
-        let raw_line = raw_line?;
-        let line = raw_line.trim();
+/// The directives of a PLA as read so far, before they are checked against each other. Shared by
+/// [`parse_pla`] and the streaming [`PlaCubeReader`], so both read a header the same way.
+struct Directives {
+    num_inputs: Option<usize>,
+    num_outputs: Option<usize>,
+    cover_type: CoverType,
+    input_labels: Option<Vec<String>>,
+    output_labels: Option<Vec<String>>,
+    /// `.mv`: the binary count and the multiple-valued input sizes (the output part is `num_outputs`).
+    mv: Option<(usize, Vec<usize>)>,
+    /// `.label var=N` sections, validated against the layout once the file is read.
+    mv_labels: Vec<(usize, Vec<String>)>,
+    /// `.phase`, as written; checked against the output count once the file is read.
+    phase: Option<String>,
+    /// `.pair`, as written; resolved against the `.ilb` names once the file is read.
+    pair: Option<String>,
+    /// `.symbolic` and `.symbolic-output`, as written; resolved once the file is read.
+    symbolic: Vec<String>,
+    symbolic_output: Vec<String>,
+    /// Where each directive checked only once the file is read (`.ilb`, `.phase`, …) was found, so
+    /// an error from [`finish`](Self::finish) can point back at it.
+    deferred: Vec<PlaSpan>,
+}
 
-        // Skip empty lines and comments
-        if line.is_empty() || line.starts_with('#') {
-            continue;
+impl Directives {
+    fn new() -> Self {
+        Directives {
+            num_inputs: None,
+            num_outputs: None,
+            // Default to FD_type to match C espresso behaviour (main.c line 21)
+            // This causes '-' in outputs to be parsed as D cubes, not just don't-care bits
+            cover_type: CoverType::FD,
+            input_labels: None,
+            output_labels: None,
+            mv: None,
+            mv_labels: Vec::new(),
+            phase: None,
+            pair: None,
+            symbolic: Vec::new(),
+            symbolic_output: Vec::new(),
+            deferred: Vec::new(),
         }
+    }
 
-        // Parse directives
-        if line.starts_with('.') {
-            let parts: Vec<&str> = line.split_whitespace().collect();
-
-            match parts.first().copied() {
-                Some(".i") => {
-                    // A second `.i` is rejected rather than silently re-assigned (unlike C's "extra
-                    // .i ignored") — see `PLAError::DuplicateInputDirective` for the rationale. This
-                    // also covers a redeclaration after cube data has started: by then `num_inputs` is
-                    // necessarily already `Some`, since a cube line requires it (below).
-                    if num_inputs.is_some() {
-                        return Err(PLAError::DuplicateInputDirective.into());
-                    }
-                    let val: usize =
-                        parts.get(1).and_then(|s| s.parse().ok()).ok_or_else(|| {
-                            PLAError::InvalidInputDirective {
-                                value: Arc::from(*parts.get(1).unwrap_or(&"")),
-                            }
-                        })?;
-                    num_inputs = Some(val);
+    /// Read directive line `number` (one-based), locating any error at the directive. Returns
+    /// `false` at `.e`/`.end`, as [`apply`](Self::apply).
+    fn read(&mut self, number: usize, line: &str) -> Result<bool, PLAReadError> {
+        let parts: Vec<&str> = line.split_whitespace().collect();
+        let more = self.apply(&parts).map_err(|error| PLAReadError::Located {
+            error,
+            span: line_start(number, line, None),
+        })?;
+        if matches!(
+            parts[0],
+            ".ilb" | ".ob" | ".label" | ".phase" | ".pair" | ".symbolic" | ".symbolic-output"
+        ) {
+            self.deferred.push(line_start(number, line, None));
+        }
+        Ok(more)
+    }
+
+    /// Apply one directive line, split into words. Returns `false` at `.e`/`.end`, which ends the
+    /// data; unknown directives are ignored, as C does.
+    fn apply(&mut self, parts: &[&str]) -> Result<bool, PLAError> {
+        match parts.first().copied() {
+            Some(".i") => {
+                // A second `.i` is rejected rather than silently re-assigned (unlike C's "extra
+                // .i ignored") — see `PLAError::DuplicateInputDirective` for the rationale. This
+                // also covers a redeclaration after cube data has started: by then `num_inputs` is
+                // necessarily already `Some`, since a cube line requires it (below).
+                if self.mv.is_some() {
+                    return Err(PLAError::ConflictingMvDirective);
                 }
-                Some(".o") => {
-                    // See the `.i` arm above — same rejection, same rationale.
-                    if num_outputs.is_some() {
-                        return Err(PLAError::DuplicateOutputDirective.into());
+                if self.num_inputs.is_some() {
+                    return Err(PLAError::DuplicateInputDirective);
+                }
+                let val: usize = parts.get(1).and_then(|s| s.parse().ok()).ok_or_else(|| {
+                    PLAError::InvalidInputDirective {
+                        value: Arc::from(*parts.get(1).unwrap_or(&"")),
                     }
-                    let val: usize =
-                        parts.get(1).and_then(|s| s.parse().ok()).ok_or_else(|| {
-                            PLAError::InvalidOutputDirective {
-                                value: Arc::from(*parts.get(1).unwrap_or(&"")),
-                            }
-                        })?;
-                    num_outputs = Some(val);
+                })?;
+                self.num_inputs = Some(val);
+            }
+            Some(".o") => {
+                // See the `.i` arm above — same rejection, same rationale.
+                if self.mv.is_some() {
+                    return Err(PLAError::ConflictingMvDirective);
                 }
-                Some(".type") => {
-                    // An unrecognised or missing `.type` value is rejected, mirroring how bad
-                    // `.i`/`.o` values error (rather than silently falling back to a default).
-                    cover_type = match parts.get(1).copied() {
-                        Some("f") => CoverType::F,
-                        Some("fd") => CoverType::FD,
-                        Some("fr") => CoverType::FR,
-                        Some("fdr") => CoverType::FDR,
-                        other => {
-                            return Err(PLAError::InvalidTypeDirective {
-                                value: Arc::from(other.unwrap_or("")),
-                            }
-                            .into())
-                        }
-                    };
+                if self.num_outputs.is_some() {
+                    return Err(PLAError::DuplicateOutputDirective);
                 }
-                Some(".ilb") => {
-                    // Parse input labels: .ilb label1 label2 label3 ...
-                    let labels: Vec<String> = parts.iter().skip(1).map(|s| s.to_string()).collect();
-                    if !labels.is_empty() {
-                        if let Some(name) = first_duplicate(&labels) {
-                            return Err(PLAError::DuplicateLabel {
-                                label_type: Arc::from("input"),
-                                name: Arc::from(name),
-                            }
-                            .into());
-                        }
-                        // A second `.ilb` section is rejected rather than silently replacing the
-                        // first (unlike C's silent overwrite, which leaks the previously
-                        // `strdup`'d strings) — see `PLAError::DuplicateInputLabelDirective` for
-                        // the rationale.
-                        if input_labels.is_some() {
-                            return Err(PLAError::DuplicateInputLabelDirective.into());
-                        }
-                        input_labels = Some(labels);
+                let val: usize = parts.get(1).and_then(|s| s.parse().ok()).ok_or_else(|| {
+                    PLAError::InvalidOutputDirective {
+                        value: Arc::from(*parts.get(1).unwrap_or(&"")),
+                    }
+                })?;
+                self.num_outputs = Some(val);
+            }
+            Some(".type") => {
+                // An unrecognised or missing `.type` value is rejected, mirroring how bad
+                // `.i`/`.o` values error (rather than silently falling back to a default).
+                self.cover_type = match parts.get(1).copied() {
+                    Some("f") => CoverType::F,
+                    Some("fd") => CoverType::FD,
+                    Some("fr") => CoverType::FR,
+                    Some("fdr") => CoverType::FDR,
+                    other => {
+                        return Err(PLAError::InvalidTypeDirective {
+                            value: Arc::from(other.unwrap_or("")),
+                        })
+                    }
+                };
+            }
+            Some(".ilb") => {
+                // Parse input labels: .ilb label1 label2 label3 ...
+                let labels: Vec<String> = parts.iter().skip(1).map(|s| s.to_string()).collect();
+                if !labels.is_empty() {
+                    if let Some(name) = first_duplicate(&labels) {
+                        return Err(PLAError::DuplicateLabel {
+                            label_type: Arc::from("input"),
+                            name: Arc::from(name),
+                        });
+                    }
+                    // A second `.ilb` section is rejected rather than silently replacing the
+                    // first (unlike C's silent overwrite, which leaks the previously
+                    // `strdup`'d strings) — see `PLAError::DuplicateInputLabelDirective` for
+                    // the rationale.
+                    if self.input_labels.is_some() {
+                        return Err(PLAError::DuplicateInputLabelDirective);
                     }
+                    self.input_labels = Some(labels);
                 }
-                Some(".ob") => {
-                    // Parse output labels: .ob label1 label2 label3 ...
-                    let labels: Vec<String> = parts.iter().skip(1).map(|s| s.to_string()).collect();
-                    if !labels.is_empty() {
-                        if let Some(name) = first_duplicate(&labels) {
-                            return Err(PLAError::DuplicateLabel {
-                                label_type: Arc::from("output"),
-                                name: Arc::from(name),
-                            }
-                            .into());
-                        }
-                        // See the `.ilb` arm above — same rejection, same rationale.
-                        if output_labels.is_some() {
-                            return Err(PLAError::DuplicateOutputLabelDirective.into());
-                        }
-                        output_labels = Some(labels);
+            }
+            Some(".ob") => {
+                // Parse output labels: .ob label1 label2 label3 ...
+                let labels: Vec<String> = parts.iter().skip(1).map(|s| s.to_string()).collect();
+                if !labels.is_empty() {
+                    if let Some(name) = first_duplicate(&labels) {
+                        return Err(PLAError::DuplicateLabel {
+                            label_type: Arc::from("output"),
+                            name: Arc::from(name),
+                        });
+                    }
+                    // See the `.ilb` arm above — same rejection, same rationale.
+                    if self.output_labels.is_some() {
+                        return Err(PLAError::DuplicateOutputLabelDirective);
                     }
+                    self.output_labels = Some(labels);
                 }
-                Some(".e") | Some(".end") => break,
-                Some(".p") => {}
-                _ => {}
             }
-            continue;
+            Some(".mv") => {
+                // `.mv` declares both dimensions at once, so it cannot follow `.i`/`.o` (C:
+                // "cannot mix .i and .mv") or itself.
+                if self.mv.is_some() || self.num_inputs.is_some() || self.num_outputs.is_some() {
+                    return Err(PLAError::ConflictingMvDirective);
+                }
+                let (num_binary, mut sizes) = parse_mv_directive(&parts[1..])?;
+                let output_size = sizes.pop().expect("`.mv` has at least the output part");
+                let positions = sizes
+                    .iter()
+                    .try_fold(num_binary, |acc, &size| acc.checked_add(size))
+                    .ok_or_else(|| PLAError::InvalidMvDirective {
+                        value: Arc::from(parts[1..].join(" ")),
+                    })?;
+                self.num_inputs = Some(positions);
+                self.num_outputs = Some(output_size);
+                self.mv = Some((num_binary, sizes));
+            }
+            Some(".label") => {
+                // `.label var=N name ...` names the values of multiple-valued variable `N`.
+                let var = parts
+                    .get(1)
+                    .and_then(|s| s.strip_prefix("var="))
+                    .and_then(|s| s.parse::<usize>().ok())
+                    .ok_or_else(|| PLAError::InvalidLabelDirective {
+                        value: Arc::from(parts[1..].join(" ")),
+                    })?;
+                let labels: Vec<String> = parts.iter().skip(2).map(|s| s.to_string()).collect();
+                if self.mv_labels.iter().any(|(v, _)| *v == var) {
+                    return Err(PLAError::DuplicateInputLabelDirective);
+                }
+                self.mv_labels.push((var, labels));
+            }
+            Some(".phase") => {
+                if self.phase.is_some() {
+                    return Err(PLAError::DuplicatePhaseDirective);
+                }
+                self.phase = Some(parts[1..].join(" "));
+            }
+            Some(".pair") => {
+                if self.pair.is_some() {
+                    return Err(PLAError::DuplicatePairDirective);
+                }
+                self.pair = Some(parts[1..].join(" "));
+            }
+            Some(".symbolic") => self.symbolic.push(parts[1..].join(" ")),
+            Some(".symbolic-output") => self.symbolic_output.push(parts[1..].join(" ")),
+            Some(".e") | Some(".end") => return Ok(false),
+            Some(".p") => {}
+            _ => {}
+        }
+        Ok(true)
+    }
+
+    /// The cube width, split as `(ni, no)`. Dimensions must already be declared: there is no
+    /// inference, because space/tab/`|`/newlines are all insignificant, so cube data alone cannot
+    /// locate the input/output split (C requires `.i`/`.o` before any cube — cvrin.c).
+    fn dimensions(&self) -> Result<(usize, usize), PLAError> {
+        match (self.num_inputs, self.num_outputs) {
+            (Some(ni), Some(no)) => Ok((ni, no)),
+            (None, None) => Err(PLAError::MissingDimensions),
+            (None, Some(_)) => Err(PLAError::MissingInputDirective),
+            (Some(_), None) => Err(PLAError::MissingOutputDirective),
         }
+    }
 
-        // Cube-data line. Dimensions must already be declared: there is no inference, because
-        // space/tab/`|`/newlines are all insignificant, so cube data alone cannot locate the
-        // input/output split (C requires `.i`/`.o` before any cube — cvrin.c).
-        let (ni, no) = match (num_inputs, num_outputs) {
-            (Some(ni), Some(no)) => (ni, no),
-            _ => {
-                return Err(match (num_inputs.is_none(), num_outputs.is_none()) {
-                    (true, true) => PLAError::MissingDimensions,
-                    (true, false) => PLAError::MissingInputDirective,
-                    (false, true) => PLAError::MissingOutputDirective,
-                    (false, false) => unreachable!("both-declared is the Some/Some arm above"),
+    /// How many leading input positions are binary variables, given `ni` positions in all.
+    fn num_binary(&self, ni: usize) -> usize {
+        self.mv.as_ref().map_or(ni, |(nb, _)| *nb)
+    }
+
+    /// Check the directives against each other and the dimensions, resolving them — with the cubes
+    /// read — into a [`ParsedPla`]. An error points at the directive it concerns, or at line `end`
+    /// (just past the last line read) when it concerns none.
+    fn finish(mut self, cubes: Vec<RawCube>, end: usize) -> Result<ParsedPla, PLAReadError> {
+        let deferred = std::mem::take(&mut self.deferred);
+        self.resolve(cubes).map_err(|error| {
+            let span = locate_directive(&deferred, &error).unwrap_or_else(|| PlaSpan {
+                line: end,
+                column: 1,
+                cube: None,
+                text: Arc::from(""),
+            });
+            PLAReadError::Located { error, span }
+        })
+    }
+
+    fn resolve(self, cubes: Vec<RawCube>) -> Result<ParsedPla, PLAError> {
+        let Directives {
+            num_inputs,
+            num_outputs,
+            cover_type,
+            input_labels,
+            output_labels,
+            mv,
+            mv_labels,
+            phase,
+            pair,
+            symbolic,
+            symbolic_output,
+            deferred: _,
+        } = self;
+
+        // Verify we got dimensions
+        let num_inputs = num_inputs.ok_or(PLAError::MissingInputDirective)?;
+        let num_outputs = num_outputs.ok_or(PLAError::MissingOutputDirective)?;
+
+        // Only a file with at least one multiple-valued *input* variable needs a layout; `.mv` with just
+        // the output part is an ordinary binary PLA.
+        let mv = match mv {
+            Some((num_binary, sizes)) if !sizes.is_empty() => Some(
+                MvLayout::new(num_binary, sizes).expect("`.mv` part sizes are checked non-zero"),
+            ),
+            _ => None,
+        };
+        let num_binary = mv.as_ref().map_or(num_inputs, MvLayout::num_binary);
+
+        // Validate label counts if present (`.ilb` names only the binary variables)
+        if let Some(ref labels) = input_labels {
+            if labels.len() != num_binary {
+                return Err(PLAError::LabelCountMismatch {
+                    label_type: Arc::from("input"),
+                    expected: num_binary,
+                    actual: labels.len(),
+                });
+            }
+        }
+        let symbolic = parse_symbolic_directives(&symbolic, input_labels.as_deref(), num_binary)
+            .map_err(|value| PLAError::InvalidSymbolicDirective { value })?;
+        let pair = pair
+            .map(|value| {
+                let pairs = parse_pair_directive(&value, input_labels.as_deref(), num_binary)?;
+                symbolic_pairs(pairs, &symbolic, num_binary).ok_or(PLAError::InvalidPairDirective {
+                    value: Arc::from(value),
+                })
+            })
+            .transpose()?;
+        let input_labels = match &mv {
+            _ if !symbolic.is_empty() => {
+                let layout = mv.clone().unwrap_or_else(|| MvLayout::binary(num_binary));
+                Some(mv_input_labels(
+                    &layout,
+                    input_labels,
+                    mv_labels,
+                    &symbolic,
+                )?)
+            }
+            Some(layout) if input_labels.is_some() || !mv_labels.is_empty() => {
+                Some(mv_input_labels(layout, input_labels, mv_labels, &[])?)
+            }
+            _ => match mv_labels.first() {
+                Some((var, _)) => {
+                    return Err(PLAError::InvalidLabelDirective {
+                        value: Arc::from(format!("var={var}")),
+                    })
                 }
-                .into());
+                None => input_labels,
+            },
+        };
+        if let Some(ref labels) = output_labels {
+            if labels.len() != num_outputs {
+                return Err(PLAError::LabelCountMismatch {
+                    label_type: Arc::from("output"),
+                    expected: num_outputs,
+                    actual: labels.len(),
+                });
             }
+        }
+
+        // `.phase` is a single run of one `0`/`1` per output, as C reads it character by character.
+        let phase = phase
+            .map(|value| {
+                let flags: Option<Vec<bool>> = value
+                    .chars()
+                    .map(|c| match c {
+                        '0' => Some(false),
+                        '1' => Some(true),
+                        _ => None,
+                    })
+                    .collect();
+                flags
+                    .filter(|flags| flags.len() == num_outputs)
+                    .ok_or_else(|| PLAError::InvalidPhaseDirective {
+                        value: Arc::from(value),
+                    })
+            })
+            .transpose()?;
+
+        // `.symbolic-output` replaces the outputs a `.phase` would name, so the two do not combine.
+        let invalid_output = |value: Arc<str>| PLAError::InvalidSymbolicOutputDirective { value };
+        if let (Some(value), Some(_)) = (symbolic_output.first(), &phase) {
+            return Err(invalid_output(Arc::from(value.as_str())));
+        }
+        let symbolic_output =
+            parse_symbolic_directives(&symbolic_output, output_labels.as_deref(), num_outputs)
+                .map_err(invalid_output)?;
+        let output_labels = if symbolic_output.is_empty() {
+            output_labels
+        } else {
+            // C's `makeup_labels` names an output `v{var}.{i}`, `var` being the output part's index
+            // once every input variable is in place.
+            let num_symbolic: usize = symbolic.iter().map(|s| s.vars().len()).sum();
+            let num_pairs = pair.as_ref().map_or(0, Vec::len);
+            let num_mv = mv.as_ref().map_or(0, |l| l.mv_sizes().len());
+            let output_var = num_binary - num_symbolic - num_pairs + num_mv + symbolic.len();
+            Some(symbolic_output_labels(
+                output_labels,
+                num_outputs,
+                output_var,
+                &symbolic_output,
+            )?)
         };
 
-        // Append this line's significant characters to the stream, then drain every complete
-        // `ni + no` cube now available. A cube may span several lines, and several cubes may share a
-        // line — exactly as C reads it.
-        cube_stream.extend(line.chars().filter(|c| !is_pla_delimiter(*c)));
+        // Label sections stay `Option`: their presence/absence is what selects the `PlaCover` variant
+        // (and thus whether the writer re-emits them).
+        Ok(ParsedPla {
+            num_inputs,
+            num_outputs,
+            mv,
+            input_labels,
+            output_labels,
+            phase,
+            pair,
+            symbolic,
+            symbolic_output,
+            cubes,
+            cover_type,
+        })
+    }
+}
+
+/// A span at the first non-blank character of line `number` (one-based): where a directive, or a
+/// line of cube data, starts.
+fn line_start(number: usize, line: &str, cube: Option<usize>) -> PlaSpan {
+    PlaSpan {
+        line: number,
+        column: line.chars().take_while(|c| c.is_whitespace()).count() + 1,
+        cube,
+        text: Arc::from(line),
+    }
+}
+
+/// The deferred directive an error from [`Directives::finish`] concerns: the last one with the
+/// keyword the error names, preferring one whose arguments start with the value the error quotes.
+fn locate_directive(deferred: &[PlaSpan], error: &PLAError) -> Option<PlaSpan> {
+    let (keywords, value): (&[&str], Option<&str>) = match error {
+        PLAError::LabelCountMismatch { label_type, .. }
+        | PLAError::DuplicateLabel { label_type, .. } => match &**label_type {
+            "input" => (&[".ilb", ".label"], None),
+            "output" => (&[".ob", ".symbolic-output"], None),
+            var => (&[".label"], Some(var)),
+        },
+        PLAError::InvalidLabelDirective { value } => (&[".label"], Some(value)),
+        PLAError::InvalidPhaseDirective { .. } => (&[".phase"], None),
+        PLAError::InvalidPairDirective { .. } => (&[".pair"], None),
+        PLAError::InvalidSymbolicDirective { value } => (&[".symbolic"], Some(value)),
+        PLAError::InvalidSymbolicOutputDirective { value } => (&[".symbolic-output"], Some(value)),
+        _ => return None,
+    };
+    let mut candidates = deferred.iter().rev().filter(|span| {
+        let keyword = span.text.split_whitespace().next().unwrap_or("");
+        keywords.contains(&keyword)
+    });
+    let quotes_value = |span: &&PlaSpan| {
+        value.is_some_and(|value| {
+            let args: Vec<&str> = span.text.split_whitespace().skip(1).collect();
+            args.join(" ").starts_with(value)
+        })
+    };
+    candidates
+        .clone()
+        .find(quotes_value)
+        .or_else(|| candidates.next())
+        .cloned()
+}
+
+/// Cube data, read as C's `parse_pla` (cvrin.c) reads it: a single character stream in which space,
+/// tab, `|` and *newlines* are all insignificant, and one cube is exactly `ni + no` significant
+/// characters — there are no cube separators. We mirror that by accumulating significant cube
+/// characters and draining complete chunks as they form, instead of treating each line as a cube: a
+/// cube may span several lines, and several cubes may share a line — exactly as C reads it.
+///
+/// Each pending character keeps its line and column, so a bad one is reported where it was written.
+struct CubeStream {
+    /// Significant characters not yet forming a whole cube, each with its (one-based) line and
+    /// column.
+    chars: Vec<(char, usize, usize)>,
+    /// The lines those characters came from, kept for the error spans.
+    lines: Vec<(usize, String)>,
+    /// Cubes read so far, counted before each splits into its F, D and R parts.
+    count: usize,
+    /// One cube's characters, reused from cube to cube.
+    chunk: Vec<char>,
+}
+
+impl CubeStream {
+    fn new() -> Self {
+        CubeStream {
+            chars: Vec::new(),
+            lines: Vec::new(),
+            count: 0,
+            chunk: Vec::new(),
+        }
+    }
+
+    /// Append cube-data line `number`'s significant characters, then drain every complete
+    /// `ni + no` cube now available into `cubes`.
+    fn push_line(
+        &mut self,
+        number: usize,
+        line: String,
+        (ni, no): (usize, usize),
+        num_binary: usize,
+        cover_type: CoverType,
+        cubes: &mut Vec<RawCube>,
+    ) -> Result<(), PLAReadError> {
+        self.chars.extend(
+            line.chars()
+                .enumerate()
+                .filter(|&(_, c)| !(c.is_whitespace() || c == '|'))
+                .map(|(i, c)| (c, number, i + 1)),
+        );
+        self.lines.push((number, line));
         let width = ni + no;
         // `checked_div` yields `None` only for a degenerate zero-width cover (`.i 0 .o 0`), where no
         // cube can ever form; skip draining in that case rather than dividing by zero.
-        if let Some(complete) = cube_stream.len().checked_div(width) {
+        if let Some(complete) = self.chars.len().checked_div(width) {
             for k in 0..complete {
-                let chunk = &cube_stream[k * width..(k + 1) * width];
-                push_cube(&chunk[..ni], &chunk[ni..], cover_type, &mut cubes)?;
+                let chunk = &self.chars[k * width..(k + 1) * width];
+                self.chunk.clear();
+                self.chunk.extend(chunk.iter().map(|&(c, _, _)| c));
+                let (inputs, outputs) = self.chunk.split_at(ni);
+                if let Err(error) = push_cube(inputs, outputs, num_binary, cover_type, cubes) {
+                    let offset = match error {
+                        PLAError::InvalidInputCharacter { position, .. } => position,
+                        PLAError::InvalidOutputCharacter { position, .. } => ni + position,
+                        _ => 0,
+                    };
+                    let (_, line, column) = chunk[offset];
+                    let text = self
+                        .lines
+                        .iter()
+                        .find(|(number, _)| *number == line)
+                        .map_or("", |(_, text)| text.as_str());
+                    let span = PlaSpan {
+                        line,
+                        column,
+                        cube: Some(self.count),
+                        text: Arc::from(text),
+                    };
+                    return Err(PLAReadError::Located { error, span });
+                }
+                self.count += 1;
             }
-            cube_stream.drain(..complete * width);
+            self.chars.drain(..complete * width);
         }
+        // Keep only the lines still holding pending characters.
+        let first = self.chars.first().map_or(usize::MAX, |&(_, line, _)| line);
+        self.lines.retain(|&(number, _)| number >= first);
+        Ok(())
     }
+}
 
-    // Any trailing characters that do not complete a final `ni + no` cube are ignored, matching C
-    // (which warns about and skips an incomplete final product term).
+/// Parse a PLA stream into its raw components (dimensions, optional `.ilb`/`.ob` strings, cubes). The
+/// label type is decided later by [`PlaCover`], so this stays label-type-agnostic.
+fn parse_pla<R: std::io::BufRead>(reader: R) -> Result<ParsedPla, PLAReadError> {
+    let mut directives = Directives::new();
+    let mut cubes: Vec<RawCube> = Vec::new();
+    let mut cube_stream = CubeStream::new();
+    let mut end = 1;
 
-    // Verify we got dimensions
-    let num_inputs = num_inputs.ok_or(PLAError::MissingInputDirective)?;
-    let num_outputs = num_outputs.ok_or(PLAError::MissingOutputDirective)?;
+    // Stream lines one at a time rather than buffering the whole file: each line's `io::Error`
+    // (if any) surfaces at the point it occurs via `?`.
+    for (index, raw_line) in reader.lines().enumerate() {
+        let raw_line = raw_line?;
+        let number = index + 1;
+        end = number + 1;
+        let line = raw_line.trim();
 
-    // Validate label counts if present
-    if let Some(ref labels) = input_labels {
-        if labels.len() != num_inputs {
-            return Err(PLAError::LabelCountMismatch {
-                label_type: Arc::from("input"),
-                expected: num_inputs,
-                actual: labels.len(),
+        // Skip empty lines and comments
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        // Parse directives
+        if line.starts_with('.') {
+            if !directives.read(number, &raw_line)? {
+                break;
             }
-            .into());
+            continue;
         }
+
+        // Cube-data line.
+        let dims = directives
+            .dimensions()
+            .map_err(|error| PLAReadError::Located {
+                error,
+                span: line_start(number, &raw_line, Some(cube_stream.count)),
+            })?;
+        cube_stream.push_line(
+            number,
+            raw_line,
+            dims,
+            directives.num_binary(dims.0),
+            directives.cover_type,
+            &mut cubes,
+        )?;
+    }
+
+    // Any trailing characters that do not complete a final `ni + no` cube are ignored, matching C
+    // (which warns about and skips an incomplete final product term).
+    directives.finish(cubes, end)
+}
+
+/// Resolve every `.symbolic` (or `.symbolic-output`) directive, `vars ; values ;`, into a symbolic
+/// variable over `num_vars` variables. Variables are named by `labels` when the file has them, else
+/// by zero-based number, as C's `label_index` reads them. Each variable may join one symbolic
+/// variable. On error, returns the offending directive's arguments.
+fn parse_symbolic_directives(
+    values: &[String],
+    labels: Option<&[String]>,
+    num_vars: usize,
+) -> Result<Vec<SymbolicVariable>, Arc<str>> {
+    let mut used = vec![false; num_vars];
+    values
+        .iter()
+        .map(|value| {
+            let words: Vec<&str> = value.split_whitespace().collect();
+            let mut sections = words.split(|&word| word == ";");
+            let (Some(vars), Some(names), Some([]), None) = (
+                sections.next(),
+                sections.next(),
+                sections.next(),
+                sections.next(),
+            ) else {
+                return Err(Arc::from(value.as_str()));
+            };
+            let vars: Vec<usize> = vars
+                .iter()
+                .map(|&word| match labels {
+                    Some(labels) => labels.iter().position(|label| label == word),
+                    None => word.parse().ok().filter(|&var| var < num_vars),
+                })
+                .collect::<Option<_>>()
+                .filter(|vars: &Vec<usize>| {
+                    vars.len() < usize::BITS as usize
+                        && vars
+                            .iter()
+                            .all(|&var| !std::mem::replace(&mut used[var], true))
+                })
+                .ok_or_else(|| Arc::from(value.as_str()))?;
+            Ok(SymbolicVariable::new(vars, names.iter().copied()))
+        })
+        .collect()
+}
+
+/// Renumber `.pair` indices past the inputs `.symbolic` removes, or `None` if a pair names one.
+/// The pairing is applied after the symbolic inputs are, which lays the variables out as C does.
+fn symbolic_pairs(
+    pairs: Vec<(usize, usize)>,
+    symbolic: &[SymbolicVariable],
+    num_binary: usize,
+) -> Option<Vec<(usize, usize)>> {
+    let mut renumbered: Vec<Option<usize>> = (0..num_binary).map(Some).collect();
+    for &var in symbolic.iter().flat_map(SymbolicVariable::vars) {
+        renumbered[var] = None;
+    }
+    for (next, slot) in renumbered.iter_mut().flatten().enumerate() {
+        *slot = next;
+    }
+    pairs
+        .into_iter()
+        .map(|(a, b)| Some((renumbered[a]?, renumbered[b]?)))
+        .collect()
+}
+
+/// The output header once `.symbolic-output` is applied: each symbolic output's value names, then
+/// the outputs left over — named by `.ob`, or as C's `makeup_labels` would name them when the file
+/// has no `.ob`.
+fn symbolic_output_labels(
+    labels: Option<Vec<String>>,
+    num_outputs: usize,
+    output_var: usize,
+    symbolic: &[SymbolicVariable],
+) -> Result<Vec<String>, PLAError> {
+    let mut used = vec![false; num_outputs];
+    for &o in symbolic.iter().flat_map(SymbolicVariable::vars) {
+        used[o] = true;
+    }
+    let added: usize = symbolic.iter().map(SymbolicVariable::num_values).sum();
+    let kept: Vec<String> = (0..num_outputs)
+        .filter(|&o| !used[o])
+        .enumerate()
+        .map(|(k, o)| match &labels {
+            Some(labels) => labels[o].clone(),
+            None => format!("v{output_var}.{}", added + k),
+        })
+        .collect();
+    let values: Vec<String> = symbolic
+        .iter()
+        .flat_map(SymbolicVariable::value_names)
+        .collect();
+    let mut header = String::reconcile(&kept, &values);
+    header.extend(kept);
+    if let Some(name) = first_duplicate(&header) {
+        return Err(PLAError::DuplicateLabel {
+            label_type: Arc::from("output"),
+            name: Arc::from(name),
+        });
+    }
+    Ok(header)
+}
+
+/// Resolve the arguments of a `.pair N (a b) ...` directive into `N` pairs of binary input indices.
+/// Inputs are named by their `.ilb` labels when the file has them, else by zero-based number, as C's
+/// `label_index` reads them; the parentheses are optional. Each input may be paired once.
+fn parse_pair_directive(
+    value: &str,
+    labels: Option<&[String]>,
+    num_binary: usize,
+) -> Result<Vec<(usize, usize)>, PLAError> {
+    let invalid = || PLAError::InvalidPairDirective {
+        value: Arc::from(value),
+    };
+    let mut words = value
+        .split(|c: char| c.is_whitespace() || c == '(' || c == ')')
+        .filter(|word| !word.is_empty());
+    let count: usize = words
+        .next()
+        .and_then(|word| word.parse().ok())
+        .ok_or_else(invalid)?;
+    let vars: Vec<usize> = words
+        .map(|word| match labels {
+            Some(labels) => labels.iter().position(|label| label == word),
+            None => word.parse().ok().filter(|&var| var < num_binary),
+        })
+        .collect::<Option<_>>()
+        .ok_or_else(invalid)?;
+    let mut seen = vec![false; num_binary];
+    if vars.len() != 2 * count
+        || vars
+            .iter()
+            .any(|&var| std::mem::replace(&mut seen[var], true))
+    {
+        return Err(invalid());
+    }
+    Ok(vars.chunks(2).map(|pair| (pair[0], pair[1])).collect())
+}
+
+/// The full per-position input header of a `.mv` PLA: the `.ilb` names for the binary variables and the
+/// `.label var=N` names for each multiple-valued one. A side the file left unnamed gets C's
+/// `makeup_labels` placeholder (`v{var}` for a binary variable, `v{var}.{value}` for a value).
+///
+/// With `.symbolic` groups, the header is the one they leave: the binary variables they do not
+/// encode, the multiple-valued ones (renumbered, for the placeholders), then each group's values.
+fn mv_input_labels(
+    layout: &MvLayout,
+    binary_labels: Option<Vec<String>>,
+    mv_labels: Vec<(usize, Vec<String>)>,
+    symbolic: &[SymbolicVariable],
+) -> Result<Vec<String>, PLAError> {
+    let num_binary = layout.num_binary();
+    let mut used = vec![false; num_binary];
+    for &var in symbolic.iter().flat_map(SymbolicVariable::vars) {
+        used[var] = true;
     }
-    if let Some(ref labels) = output_labels {
-        if labels.len() != num_outputs {
+    let mut labels: Vec<String> = (0..num_binary)
+        .filter(|&var| !used[var])
+        .enumerate()
+        .map(|(k, var)| match &binary_labels {
+            Some(names) => names[var].clone(),
+            None => format!("v{k}"),
+        })
+        .collect();
+    let num_kept = labels.len();
+    let mut named: Vec<Option<Vec<String>>> = vec![None; layout.mv_sizes().len()];
+    for (var, names) in mv_labels {
+        let Some(slot) = var.checked_sub(num_binary).and_then(|k| named.get_mut(k)) else {
+            return Err(PLAError::InvalidLabelDirective {
+                value: Arc::from(format!("var={var}")),
+            });
+        };
+        let expected = layout.mv_sizes()[var - num_binary];
+        if names.len() != expected {
             return Err(PLAError::LabelCountMismatch {
-                label_type: Arc::from("output"),
-                expected: num_outputs,
-                actual: labels.len(),
-            }
-            .into());
+                label_type: Arc::from(format!("var={var}")),
+                expected,
+                actual: names.len(),
+            });
         }
+        *slot = Some(names);
     }
-
-    // Label sections stay `Option`: their presence/absence is what selects the `PlaCover` variant
-    // (and thus whether the writer re-emits them).
-    Ok(ParsedPla {
-        num_inputs,
-        num_outputs,
-        input_labels,
-        output_labels,
-        cubes,
-        cover_type,
-    })
+    for (k, names) in named.into_iter().enumerate() {
+        let var = num_kept + k;
+        labels.extend(names.unwrap_or_else(|| {
+            (0..layout.mv_sizes()[k])
+                .map(|value| format!("v{var}.{value}"))
+                .collect()
+        }));
+    }
+    let values: Vec<String> = symbolic
+        .iter()
+        .flat_map(SymbolicVariable::value_names)
+        .collect();
+    let values = String::reconcile(&labels, &values);
+    labels.extend(values);
+    if let Some(name) = first_duplicate(&labels) {
+        return Err(PLAError::DuplicateLabel {
+            label_type: Arc::from("input"),
+            name: Arc::from(name),
+        });
+    }
+    Ok(labels)
 }
 
 /// Parse one cube's worth of significant characters — already split at the input/output boundary, so
 /// `input_chars` is exactly `ni` long and `output_chars` exactly `no` — and append the resulting
-/// F/D/R raw cubes. Mirrors C's `read_cube` output convention (cvrin.c).
+/// F/D/R raw cubes. Mirrors C's `read_cube` output convention (cvrin.c). Positions from `num_binary`
+/// on belong to multiple-valued variables and take only `0`/`1` (value not in / in the set).
 fn push_cube(
     input_chars: &[char],
     output_chars: &[char],
+    num_binary: usize,
     cover_type: CoverType,
     cubes: &mut Vec<RawCube>,
 ) -> Result<(), PLAError> {
@@ -595,8 +1283,8 @@ fn push_cube(
         inputs.push(match ch {
             '0' => InputField::Zero,
             '1' => InputField::One,
-            '-' | '2' => InputField::DontCare,
-            '?' => InputField::Empty,
+            '-' | '2' if pos < num_binary => InputField::DontCare,
+            '?' if pos < num_binary => InputField::Empty,
             _ => {
                 return Err(PLAError::InvalidInputCharacter {
                     character: ch,
@@ -704,7 +1392,47 @@ impl<S: StringLabel> PlaCover<S> {
     /// section relabels that side, selecting the variant.
     pub fn from_pla_reader<R: std::io::BufRead>(reader: R) -> Result<Self, PLAReadError> {
         let p = parse_pla(reader)?;
-        let base = anonymous_cover_from_raw(p.num_inputs, p.num_outputs, p.cubes, p.cover_type);
+        let mut base = anonymous_cover_from_raw(p.num_inputs, p.num_outputs, p.cubes, p.cover_type);
+        base.mv = p.mv.map(Arc::new);
+        base.phase = p.phase.map(|phase| Phase::Requested(phase.into()));
+        // `.symbolic` and `.symbolic-output` are applied as read, as C's `read_pla` does, before the
+        // label sections: `parse_pla` has already named the positions they leave.
+        if !p.symbolic.is_empty() {
+            base = base
+                .encode_symbolic_inputs(&p.symbolic)
+                .expect("`.symbolic` is validated against the binary inputs during parsing");
+        }
+        if !p.symbolic_output.is_empty() {
+            base = match base.encode_symbolic_outputs(&p.symbolic_output) {
+                Ok(cover) => cover,
+                Err(SymbolicOutputError::Minimization(e)) => {
+                    return Err(PLAReadError::Io(e.into()))
+                }
+                Err(e) => {
+                    unreachable!(
+                        "`.symbolic-output` is validated against the outputs during parsing: {e}"
+                    )
+                }
+            };
+        }
+        let cover = Self::from_positional(base, p.input_labels, p.output_labels);
+        // A `.pair` request is applied as read, as C's `read_pla` does; `parse_pla` has resolved it
+        // to disjoint binary inputs.
+        Ok(match p.pair {
+            Some(pairs) => cover
+                .pair_inputs(&pairs)
+                .expect("`.pair` is validated against the binary inputs during parsing"),
+            None => cover,
+        })
+    }
+
+    /// Relabel a positional cover by whichever label sections were read, selecting the variant. Each
+    /// present section must already be duplicate-free and match its side's arity.
+    fn from_positional(
+        base: Cover<Anonymous, Anonymous>,
+        input_labels: Option<Vec<String>>,
+        output_labels: Option<Vec<String>>,
+    ) -> Self {
         let to_syms = |labels: Vec<String>| -> Arc<Symbols<S>> {
             // `parse_pla` rejects a `.ilb`/`.ob` section that repeats a label
             // (PLAError::DuplicateLabel), so the header is distinct here.
@@ -714,7 +1442,7 @@ impl<S: StringLabel> PlaCover<S> {
         // `parse_pla` has already checked each present label section against the cube width
         // (PLAError::LabelCountMismatch), so these relabels match by construction.
         let arity = "label sections were validated against the cube width during parsing";
-        Ok(match (p.input_labels, p.output_labels) {
+        match (input_labels, output_labels) {
             (Some(i), Some(o)) => {
                 Self::InputsOutputsNamed(base.relabel_tables(to_syms(i), to_syms(o)).expect(arity))
             }
@@ -725,7 +1453,7 @@ impl<S: StringLabel> PlaCover<S> {
                 Self::OutputsNamed(base.relabel_outputs_tables(to_syms(o)).expect(arity))
             }
             (None, None) => Self::Positional(base),
-        })
+        }
     }
 
     /// Parse a `PlaCover` from a PLA-format string.
@@ -748,6 +1476,85 @@ impl<S: StringLabel> PlaCover<S> {
     pub fn from_pla_file<P: AsRef<Path>>(path: P) -> Result<Self, PLAReadError> {
         Self::from_pla_reader(BufReader::new(File::open(path)?))
     }
+
+    /// Pair binary inputs into two-bit decoders, preserving which sides are named. See
+    /// [`Cover::pair_inputs`].
+    ///
+    /// # Errors
+    ///
+    /// As [`Cover::pair_inputs`].
+    pub fn pair_inputs(&self, pairs: &[(usize, usize)]) -> Result<Self, InvalidPairing> {
+        Ok(map_inner_cover!(self, c => c.pair_inputs(pairs)?))
+    }
+
+    /// Recode groups of binary inputs as multiple-valued variables, preserving which sides are
+    /// named. See [`Cover::encode_symbolic_inputs`].
+    ///
+    /// # Errors
+    ///
+    /// As [`Cover::encode_symbolic_inputs`].
+    pub fn encode_symbolic_inputs(
+        &self,
+        symbolic: &[SymbolicVariable],
+    ) -> Result<Self, InvalidSymbolic> {
+        Ok(map_inner_cover!(self, c => c.encode_symbolic_inputs(symbolic)?))
+    }
+
+    /// Recode groups of outputs as one-hot symbolic outputs, preserving which sides are named. See
+    /// [`Cover::encode_symbolic_outputs`].
+    ///
+    /// # Errors
+    ///
+    /// As [`Cover::encode_symbolic_outputs`].
+    pub fn encode_symbolic_outputs(
+        &self,
+        symbolic: &[SymbolicVariable],
+    ) -> Result<Self, SymbolicOutputError> {
+        Ok(map_inner_cover!(self, c => c.encode_symbolic_outputs(symbolic)?))
+    }
+
+    /// Search for an input pairing and minimise under it, preserving which sides are named. See
+    /// [`Cover::find_input_pairing`].
+    ///
+    /// # Errors
+    ///
+    /// As [`Cover::find_input_pairing`].
+    ///
+    /// # Panics
+    ///
+    /// As [`Cover::find_input_pairing`].
+    pub fn find_input_pairing(
+        &self,
+        search: PairingSearch,
+    ) -> Result<(Self, Vec<(usize, usize)>), MinimizationError> {
+        let pairs;
+        let cover = map_inner_cover!(self, c => {
+            let (cover, found) = c.find_input_pairing(search)?;
+            pairs = found;
+            cover
+        });
+        Ok((cover, pairs))
+    }
+
+    /// [`find_input_pairing`](Self::find_input_pairing) with a custom configuration. See
+    /// [`Cover::try_find_input_pairing_with_config`].
+    ///
+    /// # Errors
+    ///
+    /// As [`Cover::try_find_input_pairing_with_config`].
+    pub fn try_find_input_pairing_with_config(
+        &self,
+        search: PairingSearch,
+        config: &EspressoConfig,
+    ) -> Result<(Self, Vec<(usize, usize)>), MinimizationError> {
+        let pairs;
+        let cover = map_inner_cover!(self, c => {
+            let (cover, found) = c.try_find_input_pairing_with_config(search, config)?;
+            pairs = found;
+            cover
+        });
+        Ok((cover, pairs))
+    }
 }
 
 impl<S> PlaCover<S> {
@@ -774,6 +1581,85 @@ impl<S> PlaCover<S> {
     pub fn cover_type(&self) -> CoverType {
         on_inner_cover!(self, c => c.cover_type())
     }
+
+    /// The multiple-valued input grouping declared by `.mv`, or `None` for a binary PLA. See
+    /// [`Cover::mv_layout`].
+    #[must_use]
+    pub fn mv_layout(&self) -> Option<&MvLayout> {
+        on_inner_cover!(self, c => c.mv_layout())
+    }
+
+    /// The output phase from `.phase` (or chosen by
+    /// [`assign_output_phase`](Self::assign_output_phase)). See [`Cover::output_phase`].
+    #[must_use]
+    pub fn output_phase(&self) -> Option<&[bool]> {
+        on_inner_cover!(self, c => c.output_phase())
+    }
+
+    /// The outputs whose cubes cover the complement. See [`Cover::complemented_outputs`].
+    #[must_use]
+    pub fn complemented_outputs(&self) -> Vec<usize> {
+        on_inner_cover!(self, c => c.complemented_outputs())
+    }
+
+    /// Choose an output phase assignment and minimise under it, preserving which sides are named.
+    /// See [`Cover::assign_output_phase`].
+    ///
+    /// # Errors
+    ///
+    /// As [`Cover::assign_output_phase`].
+    ///
+    /// # Panics
+    ///
+    /// As [`Cover::assign_output_phase`].
+    pub fn assign_output_phase(&self, strategy: PhaseStrategy) -> Result<Self, MinimizationError> {
+        Ok(map_inner_cover!(self, c => c.assign_output_phase(strategy)?))
+    }
+
+    /// [`assign_output_phase`](Self::assign_output_phase) with a custom configuration. See
+    /// [`Cover::try_assign_output_phase_with_config`].
+    ///
+    /// # Errors
+    ///
+    /// As [`Cover::try_assign_output_phase_with_config`].
+    pub fn try_assign_output_phase_with_config(
+        &self,
+        strategy: PhaseStrategy,
+        config: &EspressoConfig,
+    ) -> Result<Self, MinimizationError> {
+        Ok(map_inner_cover!(self, c => c.try_assign_output_phase_with_config(strategy, config)?))
+    }
+
+    /// Minimise every output on its own, preserving which sides are named. See
+    /// [`Cover::minimize_single_output`].
+    ///
+    /// # Errors
+    ///
+    /// As [`Cover::minimize_single_output`].
+    ///
+    /// # Panics
+    ///
+    /// As [`Cover::minimize_single_output`].
+    pub fn minimize_single_output(
+        &self,
+        strategy: SingleOutputStrategy,
+    ) -> Result<Self, MinimizationError> {
+        Ok(map_inner_cover!(self, c => c.minimize_single_output(strategy)?))
+    }
+
+    /// [`minimize_single_output`](Self::minimize_single_output) with a custom configuration. See
+    /// [`Cover::try_minimize_single_output_with_config`].
+    ///
+    /// # Errors
+    ///
+    /// As [`Cover::try_minimize_single_output_with_config`].
+    pub fn try_minimize_single_output_with_config(
+        &self,
+        strategy: SingleOutputStrategy,
+        config: &EspressoConfig,
+    ) -> Result<Self, MinimizationError> {
+        Ok(map_inner_cover!(self, c => c.try_minimize_single_output_with_config(strategy, config)?))
+    }
 }
 
 impl<S: AsRef<str>> PlaCover<S> {
@@ -823,4 +1709,11 @@ impl<S> Minimizable for PlaCover<S> {
     ) -> Result<Self, MinimizationError> {
         Ok(map_inner_cover!(self, c => c.try_minimize_exact_with_config(config)?))
     }
+
+    fn try_minimize_signature_with_config(
+        &self,
+        config: &EspressoConfig,
+    ) -> Result<Self, MinimizationError> {
+        Ok(map_inner_cover!(self, c => c.try_minimize_signature_with_config(config)?))
+    }
 }
@@ -0,0 +1,348 @@
+//! Streaming PLA reading: the header first, then one [`Cube`] at a time.
+//!
+//! [`PlaCover::from_pla_reader`] holds every cube before it returns. [`PlaCubeReader`] reads the same
+//! format through the same directive handling and character-stream chunking, but keeps only the
+//! current line's cubes in memory — enough to filter, transform or shard a PLA far larger than RAM.
+
+use std::fs::File;
+use std::io::{BufRead, BufReader, Lines};
+use std::path::Path;
+use std::sync::Arc;
+
+use super::{line_start, CubeStream, Directives, PLAError, PLAReadError, PlaCover};
+use crate::cover::conversions::RawCube;
+use crate::cover::{
+    Anonymous, Cover, CoverType, Cube, Minterm, MvLayout, OutputSet, Phase, StringLabel, Symbols,
+};
+
+/// The directives of a PLA read by [`PlaCubeReader`]: everything the file declared before its first
+/// cube, checked exactly as [`PlaCover`] checks it.
+///
+/// Cubes from the reader are positional ([`Anonymous`] on both sides); the label sections are kept
+/// as read, so [`to_cover`](Self::to_cover) can name a gathered set of cubes the way [`PlaCover`]
+/// would have.
+#[derive(Debug, Clone)]
+pub struct PlaHeader {
+    /// Shared by every cube the reader yields.
+    input_symbols: Arc<Symbols<Anonymous>>,
+    output_symbols: Arc<Symbols<Anonymous>>,
+    cover_type: CoverType,
+    mv: Option<Arc<MvLayout>>,
+    input_labels: Option<Vec<String>>,
+    output_labels: Option<Vec<String>>,
+    phase: Option<Arc<[bool]>>,
+}
+
+impl PlaHeader {
+    /// Number of input positions (binary variables plus one per value of each multiple-valued one).
+    #[must_use]
+    pub fn num_inputs(&self) -> usize {
+        self.input_symbols.arity()
+    }
+
+    /// Number of outputs.
+    #[must_use]
+    pub fn num_outputs(&self) -> usize {
+        self.output_symbols.arity()
+    }
+
+    /// The `.type` directive, [`CoverType::FD`] when the file has none (as in C).
+    #[must_use]
+    pub fn cover_type(&self) -> CoverType {
+        self.cover_type
+    }
+
+    /// The `.mv` grouping, when the file declared at least one multiple-valued input variable.
+    #[must_use]
+    pub fn mv_layout(&self) -> Option<&MvLayout> {
+        self.mv.as_deref()
+    }
+
+    /// One name per input position (`.ilb` plus any `.label var=N`), or `None` without `.ilb`.
+    #[must_use]
+    pub fn input_labels(&self) -> Option<&[String]> {
+        self.input_labels.as_deref()
+    }
+
+    /// One name per output (`.ob`), or `None` without it.
+    #[must_use]
+    pub fn output_labels(&self) -> Option<&[String]> {
+        self.output_labels.as_deref()
+    }
+
+    /// The `.phase` request, one flag per output (`false` = implement in complement).
+    #[must_use]
+    pub fn output_phase(&self) -> Option<&[bool]> {
+        self.phase.as_deref()
+    }
+
+    /// Gather cubes into the [`PlaCover`] that [`PlaCover::from_pla_reader`] would build from a file
+    /// with this header and those cubes: same variant, labels, `.mv` layout and `.phase` request.
+    ///
+    /// # Panics
+    ///
+    /// If a cube's input or output count differs from this header's. Every cube from the
+    /// [`PlaCubeReader`] that produced the header fits.
+    #[must_use]
+    pub fn to_cover<S: StringLabel>(
+        &self,
+        cubes: impl IntoIterator<Item = Cube<Anonymous, Anonymous>>,
+    ) -> PlaCover<S> {
+        let cubes: Vec<_> = cubes
+            .into_iter()
+            .inspect(|cube| {
+                assert!(
+                    cube.inputs().num_vars() == self.num_inputs()
+                        && cube.outputs().num_vars() == self.num_outputs(),
+                    "cube arity ({}, {}) does not match the PLA header ({}, {})",
+                    cube.inputs().num_vars(),
+                    cube.outputs().num_vars(),
+                    self.num_inputs(),
+                    self.num_outputs()
+                );
+            })
+            .collect();
+        let mut base = Cover::from_parts(
+            Arc::clone(&self.input_symbols),
+            Arc::clone(&self.output_symbols),
+            cubes,
+            self.cover_type,
+        );
+        base.mv = self.mv.clone();
+        base.phase = self.phase.clone().map(Phase::Requested);
+        PlaCover::from_positional(base, self.input_labels.clone(), self.output_labels.clone())
+    }
+
+    /// A raw cube from the [`CubeStream`], homed on this header's symbol tables.
+    fn cube(&self, (inputs, mask, set): RawCube) -> Cube<Anonymous, Anonymous> {
+        Cube::new(
+            Minterm::from_symbols_input_fields(Arc::clone(&self.input_symbols), inputs),
+            OutputSet::from_symbols(Arc::clone(&self.output_symbols), mask),
+            set,
+        )
+    }
+}
+
+/// Reads a PLA one cube at a time: the [`PlaHeader`] is resolved up front, then the reader is an
+/// [`Iterator`] over the cubes.
+///
+/// Cube data is read exactly as [`PlaCover`] reads it — whitespace, `|` and newlines are
+/// insignificant, a cube is `ni + no` characters, and one output field may yield separate F, D and R
+/// cubes — but only the current line's cubes are held. Directives that need the whole file are
+/// rejected with [`PLAError::StreamingDirective`]: `.pair`, `.symbolic` and `.symbolic-output`
+/// anywhere, and anything but `.p`, `.e` and `.end` after the first cube.
+///
+/// The first error ends the iteration.
+///
+/// # Examples
+///
+/// ```
+/// use espresso_logic::{CubeType, PlaCover, PlaCubeReader, Symbol};
+///
+/// let pla = ".i 2\n.o 1\n.ilb a b\n.ob f\n.type fr\n01 1\n10 1\n11 0\n.e\n";
+/// let reader = PlaCubeReader::from_pla_reader(pla.as_bytes()).unwrap();
+/// assert_eq!(reader.header().num_inputs(), 2);
+///
+/// // Keep only the ON-set cubes, then name them as `PlaCover` would.
+/// let header = reader.header().clone();
+/// let mut on_set = Vec::new();
+/// for cube in reader {
+///     let cube = cube.unwrap();
+///     if cube.cube_type() == CubeType::F {
+///         on_set.push(cube);
+///     }
+/// }
+/// assert_eq!(on_set.len(), 2);
+/// let cover = header.to_cover::<Symbol>(on_set);
+/// assert!(matches!(cover, PlaCover::InputsOutputsNamed(_)));
+/// ```
+pub struct PlaCubeReader<R> {
+    lines: Lines<R>,
+    header: PlaHeader,
+    num_binary: usize,
+    /// Lines read so far; the number of the last one.
+    number: usize,
+    /// The first cube line, read while looking for the end of the header.
+    first_line: Option<String>,
+    stream: CubeStream,
+    /// The cubes of the last line read, not yet yielded.
+    pending: std::vec::IntoIter<RawCube>,
+    /// Set at `.e`/`.end`, end of input or the first error.
+    done: bool,
+}
+
+/// Opaque over the underlying reader; shows the header.
+impl<R> std::fmt::Debug for PlaCubeReader<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PlaCubeReader")
+            .field("header", &self.header)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<R: BufRead> PlaCubeReader<R> {
+    /// Read the directives up to the first cube and resolve the header.
+    ///
+    /// # Errors
+    ///
+    /// [`PLAReadError::Io`] if reading fails; [`PLAReadError::Located`] for any directive error
+    /// [`PlaCover::from_pla_reader`] would report, or a [`PLAError::StreamingDirective`].
+    pub fn from_pla_reader(reader: R) -> Result<Self, PLAReadError> {
+        let mut directives = Directives::new();
+        let mut lines = reader.lines();
+        let mut number = 0;
+        let mut first_line = None;
+        let mut done = true;
+        for raw_line in lines.by_ref() {
+            let raw_line = raw_line?;
+            number += 1;
+            let line = raw_line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if !line.starts_with('.') {
+                first_line = Some(raw_line);
+                done = false;
+                break;
+            }
+            let keyword = line.split_whitespace().next().unwrap_or(line);
+            if matches!(keyword, ".pair" | ".symbolic" | ".symbolic-output") {
+                return Err(PLAReadError::Located {
+                    error: PLAError::StreamingDirective {
+                        directive: Arc::from(keyword),
+                    },
+                    span: line_start(number, &raw_line, None),
+                });
+            }
+            if !directives.read(number, &raw_line)? {
+                break;
+            }
+        }
+
+        // As `parse_pla`, a cube line needs both dimensions declared before it.
+        let num_inputs = match &first_line {
+            Some(line) => {
+                let dims = directives
+                    .dimensions()
+                    .map_err(|error| PLAReadError::Located {
+                        error,
+                        span: line_start(number, line, Some(0)),
+                    })?;
+                dims.0
+            }
+            None => directives.num_inputs.unwrap_or(0),
+        };
+        let num_binary = directives.num_binary(num_inputs);
+        let parsed = directives.finish(Vec::new(), number + 1)?;
+        let header = PlaHeader {
+            input_symbols: Symbols::<Anonymous>::anonymous(parsed.num_inputs),
+            output_symbols: Symbols::<Anonymous>::anonymous(parsed.num_outputs),
+            cover_type: parsed.cover_type,
+            mv: parsed.mv.map(Arc::new),
+            input_labels: parsed.input_labels,
+            output_labels: parsed.output_labels,
+            phase: parsed.phase.map(Arc::from),
+        };
+        Ok(PlaCubeReader {
+            lines,
+            header,
+            num_binary,
+            number,
+            first_line,
+            stream: CubeStream::new(),
+            pending: Vec::new().into_iter(),
+            done,
+        })
+    }
+
+    /// The directives read before the first cube.
+    #[must_use]
+    pub fn header(&self) -> &PlaHeader {
+        &self.header
+    }
+
+    /// Read line `self.number` after the header, queueing its cubes.
+    fn read_line(&mut self, raw_line: String) -> Result<(), PLAReadError> {
+        let line = raw_line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            return Ok(());
+        }
+        if line.starts_with('.') {
+            let directive = line.split_whitespace().next().unwrap_or(line);
+            return match directive {
+                ".e" | ".end" => {
+                    self.done = true;
+                    Ok(())
+                }
+                ".p" => Ok(()),
+                _ => Err(PLAReadError::Located {
+                    error: PLAError::StreamingDirective {
+                        directive: Arc::from(directive),
+                    },
+                    span: line_start(self.number, &raw_line, None),
+                }),
+            };
+        }
+        let mut cubes = Vec::new();
+        self.stream.push_line(
+            self.number,
+            raw_line,
+            (self.header.num_inputs(), self.header.num_outputs()),
+            self.num_binary,
+            self.header.cover_type,
+            &mut cubes,
+        )?;
+        self.pending = cubes.into_iter();
+        Ok(())
+    }
+}
+
+impl PlaCubeReader<BufReader<File>> {
+    /// Open a PLA file and read its header.
+    ///
+    /// # Errors
+    ///
+    /// As [`from_pla_reader`](Self::from_pla_reader), plus [`PLAReadError::Io`] if the file cannot
+    /// be opened.
+    pub fn from_pla_file<P: AsRef<Path>>(path: P) -> Result<Self, PLAReadError> {
+        Self::from_pla_reader(BufReader::new(File::open(path)?))
+    }
+}
+
+impl<R: BufRead> Iterator for PlaCubeReader<R> {
+    type Item = Result<Cube<Anonymous, Anonymous>, PLAReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(raw) = self.pending.next() {
+                return Some(Ok(self.header.cube(raw)));
+            }
+            let line = match self.first_line.take() {
+                Some(line) => line,
+                None if self.done => return None,
+                None => match self.lines.next() {
+                    Some(Ok(line)) => {
+                        self.number += 1;
+                        line
+                    }
+                    Some(Err(e)) => {
+                        self.done = true;
+                        return Some(Err(e.into()));
+                    }
+                    // Trailing characters short of a whole cube are ignored, as `parse_pla` does.
+                    None => {
+                        self.done = true;
+                        return None;
+                    }
+                },
+            };
+            if let Err(e) = self.read_line(line) {
+                self.done = true;
+                return Some(Err(e));
+            }
+        }
+    }
+}
+
+// `done` stays set and `pending` stays empty once the reader has finished or failed.
+impl<R: BufRead> std::iter::FusedIterator for PlaCubeReader<R> {}
@@ -0,0 +1,514 @@
+//! `serde` support for covers and their parts (behind the `serde` feature).
+//!
+//! A label header is written once per value: as its list of labels for a named label type, or as its
+//! bare arity for [`Anonymous`], whose labels carry nothing. Rows are the PLA-style strings the
+//! `Display` impls produce — `1`/`0`/`-`/`?` per input and `1`/`0` per output — so a cover of `Symbol`
+//! labels reads in JSON as
+//!
+//! ```json
+//! {"type":"F","inputs":["a","b"],"outputs":["f"],"cubes":[{"inputs":"1-","outputs":"1"}]}
+//! ```
+//!
+//! A cube's set is written only when it is not `F`; a cover's [`MvLayout`] and output phase only when
+//! it has one. Deserialising checks what the constructors guarantee: labels are distinct, every row
+//! has the header's arity, a multiple-valued layout spans the inputs, and a phase has one flag per
+//! output. An anonymous arity may be at most 65536.
+
+use super::cubes::{Cube, CubeType};
+use super::label::{Anonymous, Label};
+use super::minterm::{InputField, Minterm};
+use super::mv::MvLayout;
+use super::output_set::OutputSet;
+use super::symbols::Symbols;
+use super::{Cover, CoverType, Phase};
+use serde::de::{self, value::UnitDeserializer, Deserializer};
+use serde::ser::Serializer;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::sync::Arc;
+
+/// The zero-sized placeholder serialises as a unit, so anonymous headers can stay generic over `L`.
+impl Serialize for Anonymous {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_unit_struct("Anonymous")
+    }
+}
+
+impl<'de> Deserialize<'de> for Anonymous {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Anonymous, D::Error> {
+        struct AnonymousVisitor;
+
+        impl de::Visitor<'_> for AnonymousVisitor {
+            type Value = Anonymous;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a unit")
+            }
+
+            fn visit_unit<E: de::Error>(self) -> Result<Anonymous, E> {
+                Ok(Anonymous)
+            }
+        }
+
+        deserializer.deserialize_unit_struct("Anonymous", AnonymousVisitor)
+    }
+}
+
+/// The largest arity an anonymous header may declare. Far beyond any cover espresso can minimise, it
+/// keeps a hostile arity from making deserialisation build placeholders without end.
+const MAX_ANONYMOUS_ARITY: usize = 1 << 16;
+
+/// A shared label header on the wire: the labels of a named table, the arity of an anonymous one.
+struct Header<L>(Arc<Symbols<L>>);
+
+impl<L: Label + Serialize> Serialize for Header<L> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if L::NAMED {
+            serializer.collect_seq(self.0.labels())
+        } else {
+            serializer.serialize_u64(self.0.arity() as u64)
+        }
+    }
+}
+
+impl<'de, L: Label + Deserialize<'de>> Deserialize<'de> for Header<L> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Header<L>, D::Error> {
+        let labels: Vec<L> = if L::NAMED {
+            Vec::deserialize(deserializer)?
+        } else {
+            // An unnamed label is a unit on the wire (see `Anonymous` above), so each of the `arity`
+            // placeholders is produced by deserialising one from a unit.
+            let arity = usize::deserialize(deserializer)?;
+            if arity > MAX_ANONYMOUS_ARITY {
+                return Err(de::Error::custom(format_args!(
+                    "anonymous arity {arity} exceeds the maximum of {MAX_ANONYMOUS_ARITY}"
+                )));
+            }
+            (0..arity)
+                .map(|_| L::deserialize(UnitDeserializer::<D::Error>::new()))
+                .collect::<Result<_, _>>()?
+        };
+        Symbols::new(labels.into())
+            .map(Header)
+            .map_err(de::Error::custom)
+    }
+}
+
+/// Parse a row of input fields, checking it against the header's arity.
+fn parse_fields<E: de::Error>(row: &str, arity: usize) -> Result<Vec<InputField>, E> {
+    let fields = row
+        .chars()
+        .map(|c| match c {
+            '0' => Ok(InputField::Zero),
+            '1' => Ok(InputField::One),
+            '-' => Ok(InputField::DontCare),
+            '?' => Ok(InputField::Empty),
+            _ => Err(E::custom(format_args!(
+                "invalid input field {c:?} in {row:?}"
+            ))),
+        })
+        .collect::<Result<Vec<_>, E>>()?;
+    if fields.len() != arity {
+        return Err(E::custom(format_args!(
+            "input row {row:?} has {} fields, expected {arity}",
+            fields.len()
+        )));
+    }
+    Ok(fields)
+}
+
+/// Parse a row of output flags, checking it against the header's arity.
+fn parse_bits<E: de::Error>(row: &str, arity: usize) -> Result<Vec<bool>, E> {
+    let bits = row
+        .chars()
+        .map(|c| match c {
+            '0' => Ok(false),
+            '1' => Ok(true),
+            _ => Err(E::custom(format_args!(
+                "invalid output flag {c:?} in {row:?}"
+            ))),
+        })
+        .collect::<Result<Vec<_>, E>>()?;
+    if bits.len() != arity {
+        return Err(E::custom(format_args!(
+            "output row {row:?} has {} flags, expected {arity}",
+            bits.len()
+        )));
+    }
+    Ok(bits)
+}
+
+fn is_on_set(set: &CubeType) -> bool {
+    *set == CubeType::F
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "L: Label + Serialize",
+    deserialize = "L: Label + Deserialize<'de>"
+))]
+struct MintermWire<L> {
+    vars: Header<L>,
+    fields: String,
+}
+
+impl<L: Label + Serialize> Serialize for Minterm<L> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        MintermWire {
+            vars: Header(Arc::clone(self.symbols())),
+            fields: self.to_string(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, L: Label + Deserialize<'de>> Deserialize<'de> for Minterm<L> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Minterm<L>, D::Error> {
+        let MintermWire { vars, fields } = MintermWire::deserialize(deserializer)?;
+        let fields = parse_fields(&fields, vars.0.arity())?;
+        Ok(Minterm::from_symbols_input_fields(vars.0, fields))
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "O: Label + Serialize",
+    deserialize = "O: Label + Deserialize<'de>"
+))]
+struct OutputSetWire<O> {
+    vars: Header<O>,
+    bits: String,
+}
+
+impl<O: Label + Serialize> Serialize for OutputSet<O> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        OutputSetWire {
+            vars: Header(Arc::clone(self.symbols())),
+            bits: self.to_string(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, O: Label + Deserialize<'de>> Deserialize<'de> for OutputSet<O> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<OutputSet<O>, D::Error> {
+        let OutputSetWire { vars, bits } = OutputSetWire::deserialize(deserializer)?;
+        let bits = parse_bits(&bits, vars.0.arity())?;
+        Ok(OutputSet::from_symbols(vars.0, bits))
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "I: Label + Serialize, O: Label + Serialize",
+    deserialize = "I: Label + Deserialize<'de>, O: Label + Deserialize<'de>"
+))]
+struct CubeWire<I, O> {
+    inputs: Minterm<I>,
+    outputs: OutputSet<O>,
+    #[serde(default, skip_serializing_if = "is_on_set")]
+    set: CubeType,
+}
+
+impl<I: Label + Serialize, O: Label + Serialize> Serialize for Cube<I, O> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        CubeWire {
+            inputs: self.inputs.clone(),
+            outputs: self.outputs.clone(),
+            set: self.set,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, I: Label + Deserialize<'de>, O: Label + Deserialize<'de>> Deserialize<'de>
+    for Cube<I, O>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Cube<I, O>, D::Error> {
+        let CubeWire {
+            inputs,
+            outputs,
+            set,
+        } = CubeWire::deserialize(deserializer)?;
+        Ok(Cube::new(inputs, outputs, set))
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct MvLayoutWire {
+    num_binary: usize,
+    mv_sizes: Vec<usize>,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    num_paired: usize,
+}
+
+fn is_zero(n: &usize) -> bool {
+    *n == 0
+}
+
+impl Serialize for MvLayout {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        MvLayoutWire {
+            num_binary: self.num_binary(),
+            mv_sizes: self.mv_sizes().to_vec(),
+            num_paired: self.num_paired(),
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Rejects an empty variable, and paired variables that are not the leading four-valued decoders.
+impl<'de> Deserialize<'de> for MvLayout {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<MvLayout, D::Error> {
+        let wire = MvLayoutWire::deserialize(deserializer)?;
+        let layout = MvLayout::new(wire.num_binary, wire.mv_sizes).map_err(de::Error::custom)?;
+        let sizes = layout.mv_sizes();
+        if wire.num_paired > sizes.len() || sizes[..wire.num_paired].iter().any(|&s| s != 4) {
+            return Err(de::Error::custom(format_args!(
+                "{} paired variables do not lead the layout as four-valued decoders",
+                wire.num_paired
+            )));
+        }
+        Ok(layout.with_num_paired(wire.num_paired))
+    }
+}
+
+/// A cover's output phase, tagged by whether minimisation has applied it yet.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum PhaseWire {
+    Requested(Vec<bool>),
+    Applied(Vec<bool>),
+}
+
+/// One cube of a cover, against the cover's headers.
+#[derive(Serialize, Deserialize)]
+struct Row {
+    inputs: String,
+    outputs: String,
+    #[serde(default, skip_serializing_if = "is_on_set")]
+    set: CubeType,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "I: Label + Serialize, O: Label + Serialize",
+    deserialize = "I: Label + Deserialize<'de>, O: Label + Deserialize<'de>"
+))]
+struct CoverWire<I, O> {
+    #[serde(rename = "type")]
+    cover_type: CoverType,
+    inputs: Header<I>,
+    outputs: Header<O>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    mv: Option<MvLayout>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    phase: Option<PhaseWire>,
+    cubes: Vec<Row>,
+}
+
+impl<I: Label + Serialize, O: Label + Serialize> Serialize for Cover<I, O> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        CoverWire {
+            cover_type: self.cover_type,
+            inputs: Header(Arc::clone(self.input_symbols())),
+            outputs: Header(Arc::clone(self.output_symbols())),
+            mv: self.mv.as_deref().cloned(),
+            phase: self.phase.as_ref().map(|phase| match phase {
+                Phase::Requested(p) => PhaseWire::Requested(p.to_vec()),
+                Phase::Applied(p) => PhaseWire::Applied(p.to_vec()),
+            }),
+            // Every cube the cover holds, not only those its type exposes through `cubes()`.
+            cubes: self
+                .cubes
+                .iter()
+                .map(|cube| Row {
+                    inputs: cube.inputs.to_string(),
+                    outputs: cube.outputs.to_string(),
+                    set: cube.set,
+                })
+                .collect(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, I: Label + Deserialize<'de>, O: Label + Deserialize<'de>> Deserialize<'de>
+    for Cover<I, O>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Cover<I, O>, D::Error> {
+        let wire = CoverWire::<I, O>::deserialize(deserializer)?;
+        let (inputs, outputs) = (wire.inputs.0, wire.outputs.0);
+        let cubes = wire
+            .cubes
+            .iter()
+            .map(|row| {
+                Ok(Cube::new(
+                    Minterm::from_symbols_input_fields(
+                        Arc::clone(&inputs),
+                        parse_fields(&row.inputs, inputs.arity())?,
+                    ),
+                    OutputSet::from_symbols(
+                        Arc::clone(&outputs),
+                        parse_bits(&row.outputs, outputs.arity())?,
+                    ),
+                    row.set,
+                ))
+            })
+            .collect::<Result<Vec<_>, D::Error>>()?;
+
+        let mut cover = Cover::from_parts(inputs, outputs, cubes, wire.cover_type);
+        if let Some(layout) = wire.mv {
+            cover = cover.with_mv_layout(layout).map_err(de::Error::custom)?;
+        }
+        if let Some(phase) = wire.phase {
+            let (flags, applied) = match phase {
+                PhaseWire::Requested(p) => (p, false),
+                PhaseWire::Applied(p) => (p, true),
+            };
+            cover = cover.with_output_phase(flags).map_err(de::Error::custom)?;
+            if applied {
+                cover.phase = cover.phase.as_ref().map(Phase::applied);
+            }
+        }
+        Ok(cover)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Minimizable, Symbol};
+
+    fn sample() -> Cover<Symbol, Symbol> {
+        Cover::from_blif_string(
+            ".model m\n.inputs a b c\n.outputs f g\n.names a b f\n11 1\n.names a c g\n0- 1\n-1 1\n.end\n",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn named_cover_round_trips_with_one_header() {
+        let cover = sample();
+        let json = serde_json::to_string(&cover).unwrap();
+        assert_eq!(
+            json,
+            r#"{"type":"F","inputs":["a","b","c"],"outputs":["f","g"],"cubes":[{"inputs":"11-","outputs":"10"},{"inputs":"0--","outputs":"01"},{"inputs":"--1","outputs":"01"}]}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<Cover<Symbol, Symbol>>(&json).unwrap(),
+            cover
+        );
+    }
+
+    #[test]
+    fn anonymous_headers_are_written_as_arities() {
+        let cover = Cover::<Anonymous, Anonymous>::from_cubes(
+            CoverType::FD,
+            [
+                Cube::anonymous(&[Some(true), None], &[true], CubeType::F),
+                Cube::anonymous(&[Some(false), Some(false)], &[true], CubeType::D),
+            ],
+        );
+        let json = serde_json::to_string(&cover).unwrap();
+        assert_eq!(
+            json,
+            r#"{"type":"FD","inputs":2,"outputs":1,"cubes":[{"inputs":"1-","outputs":"1"},{"inputs":"00","outputs":"1","set":"D"}]}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<Cover<Anonymous, Anonymous>>(&json).unwrap(),
+            cover
+        );
+    }
+
+    #[test]
+    fn anonymous_arity_is_bounded() {
+        let json = format!(
+            r#"{{"type":"F","inputs":{},"outputs":1,"cubes":[]}}"#,
+            u64::MAX
+        );
+        let err = serde_json::from_str::<Cover<Anonymous, Anonymous>>(&json).unwrap_err();
+        assert!(err.to_string().contains("exceeds the maximum"), "{err}");
+
+        let json = r#"{"type":"F","inputs":65536,"outputs":1,"cubes":[]}"#;
+        let cover = serde_json::from_str::<Cover<Anonymous, Anonymous>>(json).unwrap();
+        assert_eq!(cover.num_inputs(), 1 << 16);
+    }
+
+    #[test]
+    fn layout_and_phase_round_trip() {
+        let paired = sample().pair_inputs(&[(0, 1)]).unwrap();
+        let json = serde_json::to_string(&paired).unwrap();
+        assert!(json.contains(r#""mv":{"num_binary":1,"mv_sizes":[4],"num_paired":1}"#));
+        assert_eq!(
+            serde_json::from_str::<Cover<Symbol, Symbol>>(&json).unwrap(),
+            paired
+        );
+
+        let requested = sample().with_output_phase([false, true]).unwrap();
+        let applied = requested.minimize().unwrap();
+        for cover in [requested, applied] {
+            let json = serde_json::to_string(&cover).unwrap();
+            let back = serde_json::from_str::<Cover<Symbol, Symbol>>(&json).unwrap();
+            assert_eq!(back, cover);
+            assert_eq!(back.complemented_outputs(), cover.complemented_outputs());
+        }
+    }
+
+    #[test]
+    fn parts_round_trip_on_their_own() {
+        let cover = sample();
+        let cube = cover.cubes().next().unwrap().clone();
+        let json = serde_json::to_string(&cube).unwrap();
+        assert_eq!(
+            json,
+            r#"{"inputs":{"vars":["a","b","c"],"fields":"11-"},"outputs":{"vars":["f","g"],"bits":"10"}}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<Cube<Symbol, Symbol>>(&json).unwrap(),
+            cube
+        );
+
+        let minterm: Minterm<Symbol> =
+            serde_json::from_str(r#"{"vars":["x","y"],"fields":"?1"}"#).unwrap();
+        assert!(minterm.is_vacuous());
+        assert_eq!(serde_json::to_string(&CoverType::FDR).unwrap(), r#""FDR""#);
+    }
+
+    #[test]
+    fn invariants_are_checked_on_the_way_in() {
+        let err = |json: &str| {
+            serde_json::from_str::<Cover<Symbol, Symbol>>(json)
+                .unwrap_err()
+                .to_string()
+        };
+        assert!(
+            err(r#"{"type":"F","inputs":["a","a"],"outputs":["f"],"cubes":[]}"#)
+                .contains("duplicate label at position 1")
+        );
+        assert!(err(
+            r#"{"type":"F","inputs":["a"],"outputs":["f"],"cubes":[{"inputs":"10","outputs":"1"}]}"#
+        )
+        .contains("has 2 fields, expected 1"));
+        assert!(err(
+            r#"{"type":"F","inputs":["a"],"outputs":["f"],"cubes":[{"inputs":"x","outputs":"1"}]}"#
+        )
+        .contains("invalid input field 'x'"));
+        assert!(err(
+            r#"{"type":"F","inputs":["a"],"outputs":["f"],"cubes":[{"inputs":"1","outputs":"-"}]}"#
+        )
+        .contains("invalid output flag '-'"));
+        assert!(err(
+            r#"{"type":"F","inputs":["a"],"outputs":["f"],"mv":{"num_binary":0,"mv_sizes":[3]},"cubes":[]}"#
+        )
+        .contains("input arity mismatch"));
+        assert!(err(
+            r#"{"type":"F","inputs":["a","b","c"],"outputs":["f"],"mv":{"num_binary":0,"mv_sizes":[3],"num_paired":1},"cubes":[]}"#
+        )
+        .contains("paired"));
+        assert!(err(
+            r#"{"type":"F","inputs":["a"],"outputs":["f"],"phase":{"applied":[true,false]},"cubes":[]}"#
+        )
+        .contains("output arity mismatch"));
+    }
+}
@@ -0,0 +1,325 @@
+//! Set operations on covers, run by the C core.
+//!
+//! Each operation marshals the cover into a thread-local Espresso instance of the cover's
+//! dimensions, runs the reference tool's routine under the `fatal()` guard, and re-homes the result
+//! onto the cover's own symbol tables — so callers never touch the low-level
+//! [`EspressoCover`](crate::espresso::EspressoCover) API.
+
+use super::label::Label;
+use super::minimisation::{cube_layout, rehome_cube};
+use super::verification::{aligned, function_sets, on_set};
+use super::{overlay_outputs, union_inputs, Cover, CoverType, Cube, CubeType};
+use crate::error::MinimizationError;
+use crate::espresso::{Espresso, EspressoCover, SetOperation};
+use std::sync::Arc;
+
+impl<I: Label, O: Label> Cover<I, O> {
+    /// The complement of this cover's function, per output, as an `FR` cover.
+    ///
+    /// The result's ON-set (its `F` cubes) is this cover's OFF-set, computed by the C `complement`
+    /// of the ON-set and don't-care set; its OFF-set (its `R` cubes) is this cover's ON-set. The
+    /// don't-care set is left unspecified, so it stays a don't-care of the complement. For an `FR`
+    /// cover the ON-set and OFF-set simply trade places. An output this cover implements in
+    /// complement (see [`complemented_outputs`](Self::complemented_outputs)) is complemented as the
+    /// function it stands for; the result carries no output phase.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`MinimizationError`] if the cover is contradictory, a low-level Espresso instance
+    /// of different dimensions is live on this thread, or the C core hits a fatal condition.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use espresso_logic::{Anonymous, Cover, CoverType, Cube, CubeType};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// // The complement of a·b is !a + !b.
+    /// let and = Cover::<Anonymous, Anonymous>::from_cubes(CoverType::F, [
+    ///     Cube::anonymous(&[Some(true), Some(true)], &[true], CubeType::F),
+    /// ]);
+    /// let nand = and.complement()?;
+    /// assert_eq!(nand.cover_type(), CoverType::FR);
+    ///
+    /// let expected = Cover::<Anonymous, Anonymous>::from_cubes(CoverType::F, [
+    ///     Cube::anonymous(&[Some(false), None], &[true], CubeType::F),
+    ///     Cube::anonymous(&[None, Some(false)], &[true], CubeType::F),
+    /// ]);
+    /// assert!(nand.equivalent_to(&expected).is_ok());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn complement(&self) -> Result<Cover<I, O>, MinimizationError> {
+        let ni = self.num_inputs();
+        let no = self.num_outputs();
+        let esp = Espresso::try_new_mv(&cube_layout(self), no, None)?;
+        let (f, d) = function_sets(&esp, self, &self.complemented_outputs())?;
+        let off = esp.try_offset(&f, d.as_ref())?;
+
+        let input_symbols = Arc::clone(self.input_symbols());
+        let output_symbols = Arc::clone(self.output_symbols());
+        let cubes = off
+            .to_cubes(ni, no, CubeType::F)
+            .chain(f.to_cubes(ni, no, CubeType::R))
+            .map(|cube| rehome_cube(&input_symbols, &output_symbols, cube))
+            .collect();
+        Ok(Cover {
+            input_symbols,
+            output_symbols,
+            cubes,
+            cover_type: CoverType::FR,
+            mv: self.mv.clone(),
+            phase: None,
+        })
+    }
+
+    /// The intersection of this cover's ON-set with `other`'s, per output (the C `-Dintersect`).
+    ///
+    /// Columns are aligned by label as in [`merge`](Self::merge): inputs union by identity, and the
+    /// result has every output of either cover, an output only one of them has being empty. Each
+    /// cover's ON-set is its `F` cubes, read as the function they stand for when an output is
+    /// complemented (see [`complemented_outputs`](Self::complemented_outputs)); `D` and `R` cubes
+    /// take no part. The result is an `F` cover with no output phase.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`MinimizationError`] if either cover is contradictory, a low-level Espresso
+    /// instance of different dimensions is live on this thread, or the C core hits a fatal
+    /// condition.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use espresso_logic::{Anonymous, Cover, CoverType, Cube, CubeType};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let a = Cover::<Anonymous, Anonymous>::from_cubes(CoverType::F, [
+    ///     Cube::anonymous(&[Some(true), None], &[true], CubeType::F),
+    /// ]);
+    /// let b = Cover::<Anonymous, Anonymous>::from_cubes(CoverType::F, [
+    ///     Cube::anonymous(&[None, Some(true)], &[true], CubeType::F),
+    /// ]);
+    /// let both = a.intersect(&b)?;
+    /// assert_eq!(both.num_cubes(), 1);
+    /// assert_eq!(both.cubes().next().unwrap().inputs().to_string(), "11");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn intersect(&self, other: &Cover<I, O>) -> Result<Cover<I, O>, MinimizationError> {
+        self.combine(other, SetOperation::Intersect)
+    }
+
+    /// The union of this cover's ON-set with `other`'s, per output, without cubes that another
+    /// cube of the union contains (the C `-Dunion`).
+    ///
+    /// Columns are aligned and ON-sets read as for [`intersect`](Self::intersect). Unlike
+    /// [`merge`](Self::merge), the result is an `F` cover of the two functions' union rather than
+    /// the two covers' cubes side by side.
+    ///
+    /// # Errors
+    ///
+    /// As for [`intersect`](Self::intersect).
+    pub fn union(&self, other: &Cover<I, O>) -> Result<Cover<I, O>, MinimizationError> {
+        self.combine(other, SetOperation::Union)
+    }
+
+    /// The sharp product `self # other`: the minterms of this cover's ON-set outside `other`'s, per
+    /// output (the C `-Dsharp`).
+    ///
+    /// Columns are aligned and ON-sets read as for [`intersect`](Self::intersect). The result's
+    /// cubes may overlap; [`disjoint_sharp`](Self::disjoint_sharp) makes them disjoint.
+    ///
+    /// # Errors
+    ///
+    /// As for [`intersect`](Self::intersect).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use espresso_logic::{Cover, CoverType, Cube, CubeType, Symbol};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut any = Cover::<Symbol, Symbol>::new(CoverType::F);
+    /// any.push(Cube::with_labels(&[("a", None), ("b", None)], &[("f", true)], CubeType::F)?);
+    /// let mut ab = Cover::<Symbol, Symbol>::new(CoverType::F);
+    /// ab.push(Cube::with_labels(&[("b", Some(true)), ("a", Some(true))], &[("f", true)], CubeType::F)?);
+    ///
+    /// // Everything but a·b is !a + !b.
+    /// let rest = any.sharp(&ab)?;
+    /// assert_eq!(rest.num_cubes(), 2);
+    /// assert!(rest.equivalent_to(&ab.complement()?).is_ok());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn sharp(&self, other: &Cover<I, O>) -> Result<Cover<I, O>, MinimizationError> {
+        self.combine(other, SetOperation::Sharp)
+    }
+
+    /// The sharp product `self # other` as pairwise disjoint cubes (the C `-Ddsharp`).
+    ///
+    /// The same function as [`sharp`](Self::sharp), but no minterm of an output is covered by two
+    /// cubes, at the price of usually more cubes.
+    ///
+    /// # Errors
+    ///
+    /// As for [`intersect`](Self::intersect).
+    pub fn disjoint_sharp(&self, other: &Cover<I, O>) -> Result<Cover<I, O>, MinimizationError> {
+        self.combine(other, SetOperation::DisjointSharp)
+    }
+
+    /// The cofactor of this cover's ON-set against `cube`'s inputs, for every output (the C
+    /// `cofactor`).
+    ///
+    /// The cubes that meet `cube` are kept, and every input `cube` fixes becomes a don't-care in
+    /// them: the result is the function restricted to `cube`, no longer depending on those inputs.
+    /// The cube's inputs are aligned by label; one this cover does not have is ignored, since the
+    /// cover does not depend on it. The cube's outputs are ignored. ON-sets are read as for
+    /// [`intersect`](Self::intersect), and the result is an `F` cover on this cover's headers.
+    ///
+    /// # Errors
+    ///
+    /// As for [`intersect`](Self::intersect).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use espresso_logic::{Cover, CoverType, Cube, CubeType, Symbol};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// // f = a·b + !a·c
+    /// let mut f = Cover::<Symbol, Symbol>::new(CoverType::F);
+    /// f.push(Cube::with_labels(&[("a", Some(true)), ("b", Some(true)), ("c", None)], &[("f", true)], CubeType::F)?);
+    /// f.push(Cube::with_labels(&[("a", Some(false)), ("b", None), ("c", Some(true))], &[("f", true)], CubeType::F)?);
+    ///
+    /// // With a = 1, f is b.
+    /// let a = Cube::with_labels(&[("a", Some(true))], &[("f", true)], CubeType::F)?;
+    /// let fa = f.cofactor(&a)?;
+    /// assert_eq!(fa.num_cubes(), 1);
+    /// assert_eq!(fa.cubes().next().unwrap().inputs().to_string(), "-1-");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn cofactor(&self, cube: &Cube<I, O>) -> Result<Cover<I, O>, MinimizationError> {
+        let ni = self.num_inputs();
+        let no = self.num_outputs();
+        let layout = cube_layout(self);
+        let esp = Espresso::try_new_mv(&layout, no, None)?;
+        let f = on_set(&esp, self, &self.complemented_outputs())?;
+        let point = cube.inputs().project_onto(self.input_symbols());
+        let all_outputs = vec![true; no];
+        let against =
+            EspressoCover::from_packed_cubes(&[(point.raw_words(), &all_outputs)], &layout, no)?;
+        let result = esp.try_set_operation(SetOperation::Cofactor, &f, &against)?;
+
+        let input_symbols = Arc::clone(self.input_symbols());
+        let output_symbols = Arc::clone(self.output_symbols());
+        let cubes = result
+            .to_cubes(ni, no, CubeType::F)
+            .map(|cube| rehome_cube(&input_symbols, &output_symbols, cube))
+            .collect();
+        let mut cofactor = Cover::from_parts(input_symbols, output_symbols, cubes, CoverType::F);
+        cofactor.mv = self.mv.clone();
+        Ok(cofactor)
+    }
+
+    /// Whether a single cube of this cover's ON-set contains `cube`, inputs and outputs alike
+    /// (single-cube containment, as `contain.c` uses it).
+    ///
+    /// This is the cheap, sufficient test: a cube split across several cubes of the cover is not
+    /// reported. Labels are aligned as for [`cofactor`](Self::cofactor), except that an output
+    /// `cube` asserts and this cover lacks is never contained. ON-sets are read as for
+    /// [`intersect`](Self::intersect).
+    ///
+    /// # Errors
+    ///
+    /// As for [`intersect`](Self::intersect).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use espresso_logic::{Anonymous, Cover, CoverType, Cube, CubeType};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let cover = Cover::<Anonymous, Anonymous>::from_cubes(CoverType::F, [
+    ///     Cube::anonymous(&[Some(true), None], &[true], CubeType::F),
+    ///     Cube::anonymous(&[Some(false), None], &[true], CubeType::F),
+    /// ]);
+    /// assert!(cover.contains_cube(&Cube::anonymous(&[Some(true), Some(false)], &[true], CubeType::F))?);
+    /// // Covered, but by two cubes together.
+    /// assert!(!cover.contains_cube(&Cube::anonymous(&[None, Some(false)], &[true], CubeType::F))?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn contains_cube(&self, cube: &Cube<I, O>) -> Result<bool, MinimizationError> {
+        let ni = self.num_inputs();
+        let no = self.num_outputs();
+        let mut outputs = vec![false; no];
+        for (j, label) in cube.outputs().vars().iter().enumerate() {
+            if !cube.asserts(j) {
+                continue;
+            }
+            match self
+                .output_symbols()
+                .position_of_identity(&label.identity(j))
+            {
+                Some(pos) => outputs[pos as usize] = true,
+                None => return Ok(false),
+            }
+        }
+        let point = cube.inputs().project_onto(self.input_symbols());
+
+        let esp = Espresso::try_new_mv(&cube_layout(self), no, None)?;
+        let f = on_set(&esp, self, &self.complemented_outputs())?;
+        let input_symbols = Arc::clone(self.input_symbols());
+        let output_symbols = Arc::clone(self.output_symbols());
+        Ok(f.to_cubes(ni, no, CubeType::F).any(|c| {
+            let c = rehome_cube(&input_symbols, &output_symbols, c);
+            point.is_subset_of(c.inputs()) && (0..no).all(|j| !outputs[j] || c.asserts(j))
+        }))
+    }
+
+    /// Run a two-cover operation on the ON-sets of this cover and `other`, aligned as `merge`
+    /// aligns them.
+    fn combine(
+        &self,
+        other: &Cover<I, O>,
+        op: SetOperation,
+    ) -> Result<Cover<I, O>, MinimizationError> {
+        let (outputs, ours_map, theirs_map) =
+            overlay_outputs(self.output_symbols(), other.output_symbols());
+        let inputs = union_inputs(self.input_symbols(), other.input_symbols());
+        let ni = inputs.arity();
+        let no = outputs.arity();
+        // As in `merge`, the multiple-valued grouping survives only when both sides agree on it and
+        // no input was added.
+        let mv = if self.mv == other.mv && ni == self.num_inputs() {
+            self.mv.clone()
+        } else {
+            None
+        };
+        let mut ours = aligned(self, &inputs, &outputs, &ours_map);
+        let mut theirs = aligned(other, &inputs, &outputs, &theirs_map);
+        ours.mv = mv.clone();
+        theirs.mv = mv.clone();
+        let remap = |complemented: Vec<usize>, map: &[usize]| -> Vec<usize> {
+            complemented.into_iter().map(|j| map[j]).collect()
+        };
+
+        let esp = Espresso::try_new_mv(&cube_layout(&ours), no, None)?;
+        let a = on_set(&esp, &ours, &remap(self.complemented_outputs(), &ours_map))?;
+        let b = on_set(
+            &esp,
+            &theirs,
+            &remap(other.complemented_outputs(), &theirs_map),
+        )?;
+        let result = esp.try_set_operation(op, &a, &b)?;
+
+        let cubes = result
+            .to_cubes(ni, no, CubeType::F)
+            .map(|cube| rehome_cube(&inputs, &outputs, cube))
+            .collect();
+        let mut combined = Cover::from_parts(inputs, outputs, cubes, CoverType::F);
+        combined.mv = mv;
+        Ok(combined)
+    }
+}
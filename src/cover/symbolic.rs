@@ -0,0 +1,550 @@
+//! Symbolic variables: groups of binary inputs or outputs recoded as one multiple-valued variable.
+//!
+//! A PLA often carries a symbolic quantity — a machine state, an opcode — as `n` binary bits, each
+//! of the `2ⁿ` bit combinations standing for one value. Minimised bit by bit, a product term can
+//! only select values that form a subcube of the encoding; recoded as one `2ⁿ`-valued variable it
+//! may select any set of them. This module rewrites a cover into that shape, as the reference tool
+//! does for the `.symbolic` and `.symbolic-output` directives.
+
+use super::error::{InvalidSymbolic, SymbolicOutputError};
+use super::label::SymbolicLabel;
+use super::minimisation::{cube_layout, pack_cubes};
+use super::minterm::{InputField, Minterm};
+use super::mv::MvLayout;
+use super::output_set::OutputSet;
+use super::pairing::allows;
+use super::symbols::Symbols;
+use super::{Cover, CoverType, Cube, CubeType};
+use crate::espresso::{Espresso, EspressoCover};
+use std::ops::Range;
+use std::sync::Arc;
+
+/// A symbolic variable: the binary variables that encode it, most significant first, and the
+/// names of its values.
+///
+/// A variable of `n` bits takes `2ⁿ` values. Value `v` is the combination whose bits spell `v` in
+/// binary, the first variable being the most significant bit, so the first value is the
+/// combination of all zeros. Values past the names given are called `X{v}`, as the reference tool
+/// calls them; names past the last value are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use espresso_logic::SymbolicVariable;
+///
+/// let state = SymbolicVariable::new([2, 3], ["IDLE", "RUN", "STOP"]);
+/// assert_eq!(state.num_values(), 4);
+/// assert_eq!(
+///     state.value_names().collect::<Vec<_>>(),
+///     ["IDLE", "RUN", "STOP", "X3"]
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SymbolicVariable {
+    vars: Vec<usize>,
+    values: Vec<String>,
+}
+
+impl SymbolicVariable {
+    /// A symbolic variable encoded by `vars` (zero-based indices, most significant first), with
+    /// its values named by `values` in order.
+    pub fn new<S: Into<String>>(
+        vars: impl IntoIterator<Item = usize>,
+        values: impl IntoIterator<Item = S>,
+    ) -> Self {
+        SymbolicVariable {
+            vars: vars.into_iter().collect(),
+            values: values.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// The encoding variables, most significant first.
+    #[must_use]
+    pub fn vars(&self) -> &[usize] {
+        &self.vars
+    }
+
+    /// The value names as given, which may be fewer or more than [`num_values`](Self::num_values).
+    #[must_use]
+    pub fn values(&self) -> &[String] {
+        &self.values
+    }
+
+    /// The number of values, `2ⁿ` for `n` encoding variables.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are `usize::BITS` encoding variables or more.
+    #[must_use]
+    pub fn num_values(&self) -> usize {
+        1usize
+            .checked_shl(self.vars.len() as u32)
+            .expect("a symbolic variable has fewer encoding bits than `usize::BITS`")
+    }
+
+    /// The name of each value in order: the names given, then `X{v}` for the rest.
+    pub fn value_names(&self) -> impl Iterator<Item = String> + '_ {
+        (0..self.num_values()).map(|v| match self.values.get(v) {
+            Some(name) => name.clone(),
+            None => format!("X{v}"),
+        })
+    }
+}
+
+/// Mark the variables `symbolic` encodes among `num_vars`, checking that each is in range, used
+/// once, and that every value count fits.
+fn claim_vars(
+    symbolic: &[SymbolicVariable],
+    num_vars: usize,
+) -> Result<Vec<bool>, InvalidSymbolic> {
+    let mut used = vec![false; num_vars];
+    for s in symbolic {
+        if let Some(&var) = s.vars.get(usize::BITS as usize - 1) {
+            return Err(InvalidSymbolic { var });
+        }
+        for &var in &s.vars {
+            match used.get_mut(var) {
+                Some(taken @ false) => *taken = true,
+                _ => return Err(InvalidSymbolic { var }),
+            }
+        }
+    }
+    Ok(used)
+}
+
+/// The values of `symbolic` a cube allows, given the cube's field for each encoding variable: the
+/// values whose every bit is allowed by its variable's field.
+fn allowed_values(symbolic: &SymbolicVariable, field: impl Fn(usize) -> InputField) -> Vec<bool> {
+    symbolic.vars.iter().fold(vec![true], |allowed, &var| {
+        let field = field(var);
+        (0..2 * allowed.len())
+            .map(|v| allowed[v / 2] && allows(field, v % 2 == 1))
+            .collect()
+    })
+}
+
+impl<I: SymbolicLabel, O: Clone> Cover<I, O> {
+    /// Recode groups of binary inputs as multiple-valued variables, as a `.symbolic` directive does.
+    ///
+    /// The encoding inputs of each [`SymbolicVariable`] are replaced by one variable with a value
+    /// per bit combination, and every cube is rewritten to allow the values its fields allowed, so
+    /// the function is unchanged; only how minimisation may group it differs. The result is laid
+    /// out as the reference tool lays it out: the binary inputs left over first, then the cover's
+    /// multiple-valued variables (see [`mv_layout`](Self::mv_layout)), then one variable per entry
+    /// of `symbolic`.
+    ///
+    /// With named labels the new values take their [names](SymbolicVariable::value_names),
+    /// reconciled against the rest of the header; see [`SymbolicLabel`].
+    ///
+    /// An empty `symbolic` returns the cover unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidSymbolic`] if a symbolic variable names an input that is not a binary
+    /// variable of this cover, or one already named.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use espresso_logic::{Cover, CoverType, Cube, CubeType, Minimizable, Symbol, SymbolicVariable};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// // `go` in states RUN (01) and STOP (10): two product terms over the state bits.
+    /// let cube = |s1, s0| {
+    ///     let inputs = [("s1", Some(s1)), ("s0", Some(s0))];
+    ///     Cube::<Symbol, Symbol>::with_labels(&inputs, &[("go", true)], CubeType::F)
+    /// };
+    /// let cover = Cover::from_cubes(CoverType::F, [cube(false, true)?, cube(true, false)?]);
+    ///
+    /// let state = SymbolicVariable::new([0, 1], ["IDLE", "RUN", "STOP"]);
+    /// let encoded = cover.encode_symbolic_inputs(&[state])?;
+    /// assert_eq!(encoded.mv_layout().unwrap().mv_sizes(), &[4]);
+    /// assert_eq!(encoded.input_labels()[3].as_ref(), "X3");
+    /// assert_eq!(encoded.minimize()?.num_cubes(), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn encode_symbolic_inputs(
+        &self,
+        symbolic: &[SymbolicVariable],
+    ) -> Result<Self, InvalidSymbolic> {
+        if symbolic.is_empty() {
+            return Ok(self.clone());
+        }
+        let layout = cube_layout(self);
+        let num_binary = layout.num_binary();
+        let used = claim_vars(symbolic, num_binary)?;
+        let kept: Vec<usize> = (0..num_binary).filter(|&var| !used[var]).collect();
+
+        // The header: kept binary labels and the multiple-valued positions, then the symbolic
+        // values renamed clear of them.
+        let labels = self.input_symbols().labels();
+        let kept_labels: Vec<I> = kept
+            .iter()
+            .map(|&var| labels[var].clone())
+            .chain(labels[num_binary..].iter().cloned())
+            .collect();
+        let values: Vec<I> = symbolic
+            .iter()
+            .flat_map(SymbolicVariable::value_names)
+            .map(|name| I::symbolic_value(&name))
+            .collect();
+        let values = I::reconcile(&kept_labels, &values);
+        let header: Arc<[I]> = kept_labels.into_iter().chain(values).collect();
+        let symbols = Symbols::new(header).expect("symbolic values are reconciled to be distinct");
+
+        let cubes = self
+            .cubes
+            .iter()
+            .map(|cube| {
+                let m = cube.inputs();
+                let encoded = symbolic
+                    .iter()
+                    .flat_map(|s| allowed_values(s, |var| m.field_at(var)))
+                    .map(|allowed| {
+                        if allowed {
+                            InputField::One
+                        } else {
+                            InputField::Zero
+                        }
+                    });
+                let fields = kept
+                    .iter()
+                    .map(|&var| m.field_at(var))
+                    .chain((num_binary..m.num_vars()).map(|i| m.field_at(i)))
+                    .chain(encoded);
+                let inputs = Minterm::from_symbols_input_fields(Arc::clone(&symbols), fields);
+                Cube::new(inputs, cube.outputs().clone(), cube.cube_type())
+            })
+            .collect();
+
+        let sizes = symbolic.iter().map(SymbolicVariable::num_values);
+        Ok(Cover {
+            input_symbols: symbols,
+            output_symbols: Arc::clone(self.output_symbols()),
+            cubes,
+            cover_type: self.cover_type,
+            mv: Some(Arc::new(layout.symbolic(kept.len(), sizes))),
+            phase: self.phase.clone(),
+        })
+    }
+}
+
+impl<I: Clone, O: SymbolicLabel> Cover<I, O> {
+    /// Recode groups of outputs as one-hot symbolic outputs, as a `.symbolic-output` directive does.
+    ///
+    /// The outputs of each [`SymbolicVariable`] together spell one of its values at every input
+    /// point; they are replaced by one output per value, asserted where the point's outputs spell
+    /// it. A point with a don't-care among the encoding outputs spells no value, and a point the
+    /// ON-set does not reach asserts none — it is left to the OFF-set, as the reference tool leaves
+    /// it. The result has the new outputs first, one group per entry of `symbolic`, then the outputs
+    /// left over.
+    ///
+    /// The reference tool's reading is followed throughout: a cover with don't-cares first has its
+    /// ON-set recomputed as the points neither don't-care nor OFF, an `FR`/`FDR` cover keeps the
+    /// OFF-set it was given while any other has it computed, and the OFF-set of the result is left
+    /// to be computed again, so the result is an `F` cover (or `FD` if the cover had a don't-care
+    /// set) without OFF-set cubes. Any output phase the cover carried is dropped, as the outputs it
+    /// named are gone.
+    ///
+    /// With named labels the new outputs take their [names](SymbolicVariable::value_names),
+    /// reconciled against the outputs left over; see [`SymbolicLabel`].
+    ///
+    /// An empty `symbolic` returns the cover unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SymbolicOutputError::Invalid`] if a symbolic variable names an output out of
+    /// range, or one already named, and [`SymbolicOutputError::Minimization`] if a low-level
+    /// Espresso instance of different dimensions is live on this thread or the C core hits a fatal
+    /// condition while computing the OFF-set or ON-set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use espresso_logic::{Cover, CoverType, Cube, CubeType, Symbol, SymbolicVariable};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// // The next state is encoded on outputs `n1 n0`: input `a` selects state 10, `!a` state 01.
+    /// let cube = |a, outputs: &[(&str, bool)]| {
+    ///     Cube::<Symbol, Symbol>::with_labels(&[("a", Some(a))], outputs, CubeType::F)
+    /// };
+    /// let cover = Cover::from_cubes(CoverType::F, [
+    ///     cube(true, &[("n1", true), ("n0", false)])?,
+    ///     cube(false, &[("n1", false), ("n0", true)])?,
+    /// ]);
+    ///
+    /// let next = SymbolicVariable::new([0, 1], ["S0", "S1", "S2", "S3"]);
+    /// let encoded = cover.encode_symbolic_outputs(&[next])?;
+    /// let names: Vec<&str> = encoded.output_labels().iter().map(|l| l.as_ref()).collect();
+    /// assert_eq!(names, ["S0", "S1", "S2", "S3"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn encode_symbolic_outputs(
+        &self,
+        symbolic: &[SymbolicVariable],
+    ) -> Result<Self, SymbolicOutputError> {
+        if symbolic.is_empty() {
+            return Ok(self.clone());
+        }
+        let no = self.num_outputs();
+        let used = claim_vars(symbolic, no)?;
+        let added: usize = symbolic.iter().map(SymbolicVariable::num_values).sum();
+        let layout = cube_layout(self);
+
+        // The three sets as `map_output_symbolic` (hack.c) starts from, complemented by the C core
+        // where they are computed.
+        let esp = Espresso::try_new_mv(&layout, no, None)?;
+        let space = Space::new(&layout, no);
+        let set = |set: CubeType| -> Vec<&Cube<I, O>> {
+            self.cubes
+                .iter()
+                .filter(|cube| cube.cube_type() == set && space.bits(cube).is_some())
+                .collect()
+        };
+        let d_cubes = set(CubeType::D);
+        let f = pack_cubes(&set(CubeType::F), &layout, no)?;
+        let d = pack_cubes(&d_cubes, &layout, no)?;
+        let r = if self.cover_type.has_r() {
+            pack_cubes(&set(CubeType::R), &layout, no)?
+        } else {
+            esp.try_offset(&f, Some(&d))?
+        };
+        let f = if d_cubes.is_empty() {
+            f
+        } else {
+            esp.try_offset(&d, Some(&r))?
+        };
+        let ni = self.num_inputs();
+        let bits = |cover: &EspressoCover| -> Vec<Bits> {
+            cover
+                .to_cubes(ni, no, CubeType::F)
+                .filter_map(|cube| space.bits(&cube))
+                .collect()
+        };
+        let (f, d, r) = (bits(&f), bits(&d), bits(&r));
+
+        // Open room for the one-hot outputs at the front of the output part, then find, for each
+        // value in turn, the ON-set points whose encoding outputs spell it.
+        let wide = Space::new(&layout, added + no);
+        let out = wide.outputs();
+        let widen = |cubes: Vec<Bits>| -> Vec<Bits> {
+            cubes
+                .into_iter()
+                .map(|mut bits| {
+                    bits.splice(out.start..out.start, std::iter::repeat_n(false, added));
+                    bits
+                })
+                .collect()
+        };
+        let (mut f, d, r) = (widen(f), widen(d), widen(r));
+        let mut base = out.start;
+        for s in symbolic {
+            let encoding: Vec<usize> = s.vars.iter().map(|&o| out.start + added + o).collect();
+            let mut next = Vec::new();
+            wide.find_inputs(None, &f, &r, &encoding, base, 0, &mut next);
+            f = next;
+            base += s.num_values();
+        }
+
+        // The header: the symbolic values renamed clear of the outputs left over, then those.
+        let kept: Vec<usize> = (0..no).filter(|&o| !used[o]).collect();
+        let labels = self.output_symbols().labels();
+        let kept_labels: Vec<O> = kept.iter().map(|&o| labels[o].clone()).collect();
+        let values: Vec<O> = symbolic
+            .iter()
+            .flat_map(SymbolicVariable::value_names)
+            .map(|name| O::symbolic_value(&name))
+            .collect();
+        let values = O::reconcile(&kept_labels, &values);
+        let header: Arc<[O]> = values.into_iter().chain(kept_labels).collect();
+        let symbols = Symbols::new(header).expect("symbolic values are reconciled to be distinct");
+
+        let rebuild = |bits: Bits, set: CubeType| -> Option<Cube<I, O>> {
+            let outputs: Vec<bool> = (out.start..out.start + added)
+                .chain(kept.iter().map(|&o| out.start + added + o))
+                .map(|i| bits[i])
+                .collect();
+            if !outputs.contains(&true) {
+                return None;
+            }
+            let fields = (0..layout.num_binary())
+                .map(|var| match (bits[2 * var], bits[2 * var + 1]) {
+                    (true, true) => InputField::DontCare,
+                    (false, true) => InputField::One,
+                    (true, false) => InputField::Zero,
+                    (false, false) => InputField::Empty,
+                })
+                .chain((2 * layout.num_binary()..out.start).map(|i| {
+                    if bits[i] {
+                        InputField::One
+                    } else {
+                        InputField::Zero
+                    }
+                }));
+            let inputs =
+                Minterm::from_symbols_input_fields(Arc::clone(self.input_symbols()), fields);
+            let outputs = OutputSet::from_symbols(Arc::clone(&symbols), outputs);
+            Some(Cube::new(inputs, outputs, set))
+        };
+        let cubes = without_contained(f)
+            .into_iter()
+            .filter_map(|bits| rebuild(bits, CubeType::F))
+            .chain(
+                without_contained(d)
+                    .into_iter()
+                    .filter_map(|bits| rebuild(bits, CubeType::D)),
+            )
+            .collect();
+
+        Ok(Cover {
+            input_symbols: Arc::clone(self.input_symbols()),
+            output_symbols: symbols,
+            cubes,
+            cover_type: if self.cover_type.has_d() {
+                CoverType::FD
+            } else {
+                CoverType::F
+            },
+            mv: self.mv.clone(),
+            phase: None,
+        })
+    }
+}
+
+/// A cube in the C core's positional form: two bits per binary variable (value `0` allowed, value
+/// `1` allowed), one per value of each multiple-valued variable, then one per output.
+type Bits = Vec<bool>;
+
+/// The bit ranges of the variables of a [`Bits`] cube, the output part last.
+struct Space {
+    num_binary: usize,
+    parts: Vec<Range<usize>>,
+}
+
+impl Space {
+    fn new(layout: &MvLayout, num_outputs: usize) -> Self {
+        let sizes = std::iter::repeat_n(2, layout.num_binary())
+            .chain(layout.mv_sizes().iter().copied())
+            .chain([num_outputs]);
+        let parts = sizes
+            .scan(0, |start, size| {
+                let part = *start..*start + size;
+                *start += size;
+                Some(part)
+            })
+            .collect();
+        Space {
+            num_binary: layout.num_binary(),
+            parts,
+        }
+    }
+
+    /// The bits of the output part.
+    fn outputs(&self) -> Range<usize> {
+        self.parts
+            .last()
+            .expect("a space has an output part")
+            .clone()
+    }
+
+    /// `cube` in positional form, or `None` if it is vacuous.
+    fn bits<I, O>(&self, cube: &Cube<I, O>) -> Option<Bits> {
+        let m = cube.inputs();
+        let mut bits = Vec::with_capacity(self.outputs().end);
+        for i in 0..m.num_vars() {
+            let field = m.field_at(i);
+            if i < self.num_binary {
+                bits.extend([allows(field, false), allows(field, true)]);
+            } else {
+                bits.push(matches!(field, InputField::One | InputField::DontCare));
+            }
+        }
+        bits.extend((0..self.outputs().len()).map(|o| cube.asserts(o)));
+        self.is_cube(&bits).then_some(bits)
+    }
+
+    /// Whether every variable allows some value.
+    fn is_cube(&self, bits: &[bool]) -> bool {
+        self.parts
+            .iter()
+            .all(|part| bits[part.clone()].contains(&true))
+    }
+
+    /// The intersection of two cubes, or `None` if they share no point.
+    fn intersect(&self, a: &[bool], b: &[bool]) -> Option<Bits> {
+        let bits: Bits = a.iter().zip(b).map(|(&x, &y)| x && y).collect();
+        self.is_cube(&bits).then_some(bits)
+    }
+
+    /// The pairwise intersections of two covers.
+    fn intersect_covers(&self, a: &[Bits], b: &[Bits]) -> Vec<Bits> {
+        a.iter()
+            .flat_map(|x| b.iter().filter_map(|y| self.intersect(x, y)))
+            .collect()
+    }
+
+    /// The cubes of `set` that assert output bit `bit`, with every output raised — C's
+    /// `cof_output`.
+    fn cofactor_output(&self, set: &[Bits], bit: usize) -> Vec<Bits> {
+        let out = self.outputs();
+        set.iter()
+            .filter(|bits| bits[bit])
+            .map(|bits| {
+                let mut bits = bits.clone();
+                bits[out.clone()].fill(true);
+                bits
+            })
+            .collect()
+    }
+
+    /// C's `find_inputs` (hack.c): split the input points `a` (all of them when `None`) by each
+    /// encoding output in turn — OFF-set first, then ON-set — and at the end of `encoding` raise the
+    /// value's bit, `base + value`, on the ON-set cubes over those points.
+    #[allow(clippy::too_many_arguments)]
+    fn find_inputs(
+        &self,
+        a: Option<&[Bits]>,
+        f: &[Bits],
+        r: &[Bits],
+        encoding: &[usize],
+        base: usize,
+        value: usize,
+        out: &mut Vec<Bits>,
+    ) {
+        let restrict = |cover: Vec<Bits>| match a {
+            Some(a) => self.intersect_covers(a, &cover),
+            None => cover,
+        };
+        match encoding.split_first() {
+            None => out.extend(restrict(f.to_vec()).into_iter().map(|mut bits| {
+                bits[base + value] = true;
+                bits
+            })),
+            Some((&bit, rest)) => {
+                for (set, digit) in [(r, 0), (f, 1)] {
+                    let points = restrict(self.cofactor_output(set, bit));
+                    self.find_inputs(Some(&points), f, r, rest, base, 2 * value + digit, out);
+                }
+            }
+        }
+    }
+}
+
+/// Whether cube `a` contains cube `b`.
+fn contains(a: &[bool], b: &[bool]) -> bool {
+    a.iter().zip(b).all(|(&x, &y)| x || !y)
+}
+
+/// `cubes` without any cube another contains (the first of equal cubes is kept).
+fn without_contained(cubes: Vec<Bits>) -> Vec<Bits> {
+    (0..cubes.len())
+        .filter(|&i| {
+            !cubes.iter().enumerate().any(|(j, other)| {
+                j != i && contains(other, &cubes[i]) && (j < i || !contains(&cubes[i], other))
+            })
+        })
+        .map(|i| cubes[i].clone())
+        .collect()
+}
@@ -3,7 +3,7 @@
 use super::pla::{PLAWriter, PlaCover};
 use super::*;
 use crate::expr;
-use crate::Symbol;
+use crate::{PhaseStrategy, SingleOutputStrategy, Symbol};
 use std::sync::Arc;
 
 #[test]
@@ -827,6 +827,56 @@ fn test_to_exprs_after_minimization() {
     assert!(vars.len() >= 2); // At least a and b
 }
 
+#[test]
+fn to_expr_negates_complemented_outputs() {
+    let mut cover = Cover::new(CoverType::F);
+    cover.add_expr(&expr!("a" + "b"), "f").unwrap();
+    cover.add_expr(&expr!("a" * "c"), "g").unwrap();
+    // f minimised in complement: its cube covers !a & !b.
+    let min = cover
+        .with_output_phase([false, true])
+        .unwrap()
+        .minimize()
+        .unwrap();
+    assert_eq!(min.complemented_outputs(), vec![0]);
+
+    let builder = crate::bdd_builder!();
+    let equivalent = |e: &crate::BoolExpr, f: &crate::BoolExpr| {
+        builder.build(e).equivalent_to(&builder.build(f))
+    };
+    assert!(equivalent(&min.to_expr("f").unwrap(), &expr!("a" + "b")));
+    assert!(equivalent(
+        &min.to_expr_by_index(1).unwrap(),
+        &expr!("a" * "c")
+    ));
+    let exprs: Vec<_> = min.to_exprs().map(|(_, e)| e).collect();
+    assert!(equivalent(&exprs[0], &expr!("a" + "b")));
+    assert!(equivalent(&exprs[1], &expr!("a" * "c")));
+}
+
+#[test]
+fn to_expr_reads_multiple_valued_fields_as_value_sets() {
+    // A binary input and a 3-valued colour. The first cube allows red or blue, the second green
+    // alone, and the third no colour at all, so it covers nothing.
+    let PlaCover::InputsNamed(cover) = PlaCover::<Symbol>::from_pla_string(
+        ".mv 3 1 3 1\n.ilb a\n.label var=1 r g b\n.p 3\n- 101 1\n1 010 1\n1 000 1\n.e\n",
+    )
+    .unwrap() else {
+        panic!("the table names its inputs only");
+    };
+
+    let builder = crate::bdd_builder!();
+    let expected = builder.build(&expr!(!"g" + "a" * !"r" * !"b"));
+    assert!(builder
+        .build(&cover.to_expr_by_index(0).unwrap())
+        .equivalent_to(&expected));
+    let (_, expr) = cover.to_exprs().next().unwrap();
+    assert!(builder.build(&expr).equivalent_to(&expected));
+    assert!(builder.build_cover(&cover).equivalent_to(&expected));
+    // Red or blue is every assignment without green, plus green alone under `a`.
+    assert_eq!(builder.build_cover(&cover).count_minterms(4), 9);
+}
+
 // ===== Cover Type Tests =====
 
 #[test]
@@ -1081,6 +1131,82 @@ fn pla_cube_stream_chunks_by_char_count_like_c() {
     assert!(PlaCover::<Symbol>::from_pla_string(".i 2\n.o 1\n01 1\n.end\n").is_ok());
 }
 
+#[test]
+fn pla_cube_reader_streams_what_pla_cover_reads() {
+    use super::pla::PlaCubeReader;
+    // Gathering the streamed cubes under the header rebuilds exactly the cover `PlaCover` reads —
+    // labels, `.mv`, `.phase`, multi-set output fields and cubes spanning or sharing lines included.
+    let phased = ".i 2\n.o 2\n.ob f g\n.phase 01\n.type fr\n01 1-\n10\n 01\n11 00 00 11\n.e\n";
+    let positional = ".i 3\n.o 1\n.p 2\n0-1 1\n# comment\n1-0 1\n11";
+    for src in [MV_PLA, phased, positional] {
+        let reader = PlaCubeReader::from_pla_reader(src.as_bytes()).unwrap();
+        let header = reader.header().clone();
+        let cubes: Vec<_> = reader.collect::<Result<_, _>>().unwrap();
+        let expected = PlaCover::<Symbol>::from_pla_string(src).unwrap();
+        assert_eq!(cubes.len(), expected.num_cubes(), "{src}");
+        assert_eq!(header.to_cover::<Symbol>(cubes), expected, "{src}");
+    }
+
+    let reader = PlaCubeReader::from_pla_reader(MV_PLA.as_bytes()).unwrap();
+    let header = reader.header();
+    assert_eq!(header.num_inputs(), 9);
+    assert_eq!(header.num_outputs(), 2);
+    assert_eq!(header.cover_type(), CoverType::FD);
+    assert_eq!(header.mv_layout().unwrap().mv_sizes(), &[3, 4]);
+    assert_eq!(header.input_labels().unwrap()[2], "red");
+    assert_eq!(header.output_labels(), None);
+
+    // A file with no cubes still has a header; the reader is simply empty.
+    let mut empty =
+        PlaCubeReader::from_pla_reader(".i 2\n.o 1\n.phase 0\n.e\n".as_bytes()).unwrap();
+    assert_eq!(empty.header().output_phase(), Some(&[false][..]));
+    assert!(empty.next().is_none());
+}
+
+#[test]
+fn pla_cube_reader_rejects_whole_file_directives() {
+    use super::pla::{PLAError, PLAReadError, PlaCubeReader};
+    let header_err = |s: &str| match PlaCubeReader::from_pla_reader(s.as_bytes()) {
+        Err(PLAReadError::Located { error: e, .. }) => e,
+        other => panic!("{s:?} should fail, got {other:?}"),
+    };
+    for directive in [".pair", ".symbolic", ".symbolic-output"] {
+        assert_eq!(
+            header_err(&format!(".i 2\n.o 1\n{directive} 0 1\n01 1\n.e\n")),
+            PLAError::StreamingDirective {
+                directive: Arc::from(directive)
+            }
+        );
+    }
+    // Header errors are those `PlaCover` reports.
+    assert_eq!(header_err(".o 1\n01 1\n"), PLAError::MissingInputDirective);
+    assert!(matches!(
+        header_err(".i 2\n.o 1\n.ilb a\n.e\n"),
+        PLAError::LabelCountMismatch { .. }
+    ));
+
+    // After the first cube only `.p` and `.e` may follow; the first error ends the stream.
+    let src = ".i 2\n.o 1\n01 1\n.p 2\n10 1\n.ilb a b\n11 1\n.e\n";
+    let mut reader = PlaCubeReader::from_pla_reader(src.as_bytes()).unwrap();
+    assert!(reader.next().unwrap().is_ok());
+    assert!(reader.next().unwrap().is_ok());
+    assert!(matches!(
+        reader.next(),
+        Some(Err(PLAReadError::Located { error: PLAError::StreamingDirective { directive }, .. })) if &*directive == ".ilb"
+    ));
+    assert!(reader.next().is_none());
+
+    let mut reader = PlaCubeReader::from_pla_reader(".i 2\n.o 1\n0x 1\n".as_bytes()).unwrap();
+    assert!(matches!(
+        reader.next(),
+        Some(Err(PLAReadError::Located {
+            error: PLAError::InvalidInputCharacter { character: 'x', .. },
+            ..
+        }))
+    ));
+    assert!(reader.next().is_none());
+}
+
 #[test]
 fn relabel_arity_mismatch_errors() {
     use super::{ArityMismatch, RelabelError};
@@ -1509,6 +1635,78 @@ fn pla_cover_minimize_exact_preserves_variant() {
     }
 }
 
+#[test]
+fn signature_minimisation_matches_exact_size() {
+    // A multi-output function with don't-cares and shared terms, where the two exact algorithms may
+    // pick different covers but must agree on the minimum size.
+    let src = ".i 4\n.o 2\n.ilb a b c d\n.ob f g\n.type fd\n\
+               1-0- 10\n-11- 11\n0-01 01\n0000 1-\n1111 -1\n10-1 01\n.e\n";
+    let cover = named_cover(src);
+    let exact = cover.minimize_exact().unwrap();
+    let signature = cover.minimize_signature().unwrap();
+    assert_eq!(signature.num_cubes(), exact.num_cubes());
+    assert!(signature.verify(&cover).is_ok());
+
+    // PlaCover forwards to the signature path rather than falling back to `minimize_exact`.
+    let pla = PlaCover::<Symbol>::from_pla_string(src).unwrap();
+    match pla.minimize_signature().unwrap() {
+        PlaCover::InputsOutputsNamed(c) => {
+            assert_eq!(
+                c.to_pla_string(CoverType::F).unwrap(),
+                signature.to_pla_string(CoverType::F).unwrap()
+            )
+        }
+        other => panic!("variant not preserved by signature minimisation: {other:?}"),
+    }
+
+    // Multiple-valued inputs are refused rather than minimised into a wrong cover.
+    let mv = PlaCover::<Symbol>::from_pla_string(MV_PLA).unwrap();
+    assert!(matches!(
+        mv.try_minimize_signature(),
+        Err(crate::error::MinimizationError::EspressoFatal { .. })
+    ));
+}
+
+#[test]
+fn cancelled_minimisation_returns_cancelled() {
+    use crate::error::MinimizationError;
+    use crate::{CancellationToken, EspressoConfig};
+    use std::time::Instant;
+
+    let cover = named_cover(".i 3\n.o 1\n.ilb a b c\n.ob f\n010 1\n011 1\n110 1\n.e\n");
+    let token = CancellationToken::new();
+    let config = EspressoConfig {
+        cancellation: Some(token.clone()),
+        ..EspressoConfig::default()
+    };
+    assert_eq!(
+        cover
+            .minimize_exact_with_config(&config)
+            .unwrap()
+            .num_cubes(),
+        2
+    );
+
+    token.cancel();
+    assert!(matches!(
+        cover.minimize_exact_with_config(&config),
+        Err(MinimizationError::Cancelled { timed_out: false })
+    ));
+    assert!(matches!(
+        cover.minimize_signature_with_config(&config),
+        Err(MinimizationError::Cancelled { timed_out: false })
+    ));
+    let expired = EspressoConfig {
+        deadline: Some(Instant::now()),
+        ..EspressoConfig::default()
+    };
+    assert!(matches!(
+        cover.minimize_with_config(&expired),
+        Err(MinimizationError::Cancelled { timed_out: true })
+    ));
+    assert_eq!(cover.minimize().unwrap().num_cubes(), 2);
+}
+
 #[test]
 fn try_minimize_surfaces_instance_conflict() {
     use crate::error::MinimizationError;
@@ -1530,6 +1728,8 @@ fn try_minimize_surfaces_instance_conflict() {
     assert!(matches!(err, MinimizationError::Instance(_)));
     let err = cover.try_minimize_exact().unwrap_err();
     assert!(matches!(err, MinimizationError::Instance(_)));
+    let err = cover.try_minimize_signature().unwrap_err();
+    assert!(matches!(err, MinimizationError::Instance(_)));
 }
 
 #[test]
@@ -1590,80 +1790,77 @@ fn symbol_from_str() {
 
 #[test]
 fn malformed_pla_other_errors() {
-    use super::pla::{PLAError, PLAReadError};
+    use super::pla::PLAError;
 
-    let err = |s: &str| PlaCover::<Symbol>::from_pla_string(s).expect_err("should error");
+    let err = |s: &str| {
+        let e = PlaCover::<Symbol>::from_pla_string(s).expect_err("should error");
+        e.pla_error().cloned().expect("a format error")
+    };
     // .ilb declares fewer labels than .i inputs.
     assert!(matches!(
         err(".i 2\n.o 1\n.ilb a\n01 1\n.e\n"),
-        PLAReadError::PLA(PLAError::LabelCountMismatch { .. })
+        PLAError::LabelCountMismatch { .. }
     ));
     // A cube before any `.i`/`.o` is rejected: dimensions are required up front (as in C), never
     // inferred from the cube. A single-token line...
-    assert!(matches!(
-        err("0101\n.e\n"),
-        PLAReadError::PLA(PLAError::MissingDimensions)
-    ));
+    assert!(matches!(err("0101\n.e\n"), PLAError::MissingDimensions));
     // ...and a two-token line (which an earlier whitespace-inference path wrongly *accepted*) are
     // both rejected now.
-    assert!(matches!(
-        err("01 1\n.e\n"),
-        PLAReadError::PLA(PLAError::MissingDimensions)
-    ));
+    assert!(matches!(err("01 1\n.e\n"), PLAError::MissingDimensions));
     // Invalid character in the input field.
     assert!(matches!(
         err(".i 2\n.o 1\n0z 1\n.e\n"),
-        PLAReadError::PLA(PLAError::InvalidInputCharacter { .. })
+        PLAError::InvalidInputCharacter { .. }
     ));
     // Invalid character in the output field.
     assert!(matches!(
         err(".i 1\n.o 1\n0 9\n.e\n"),
-        PLAReadError::PLA(PLAError::InvalidOutputCharacter { .. })
+        PLAError::InvalidOutputCharacter { .. }
     ));
     // Non-numeric .i directive value.
     assert!(matches!(
         err(".i two\n.o 1\n01 1\n.e\n"),
-        PLAReadError::PLA(PLAError::InvalidInputDirective { .. })
+        PLAError::InvalidInputDirective { .. }
     ));
     // Non-numeric .o directive value (symmetric to the .i case above).
     assert!(matches!(
         err(".i 2\n.o two\n01 1\n.e\n"),
-        PLAReadError::PLA(PLAError::InvalidOutputDirective { .. })
+        PLAError::InvalidOutputDirective { .. }
     ));
     // .i present but no .o.
     assert!(matches!(
         err(".i 2\n.e\n"),
-        PLAReadError::PLA(PLAError::MissingOutputDirective)
+        PLAError::MissingOutputDirective
     ));
     // .o present but no .i. (Order between .i and .o does not matter; only that both are present.)
-    assert!(matches!(
-        err(".o 1\n.e\n"),
-        PLAReadError::PLA(PLAError::MissingInputDirective)
-    ));
+    assert!(matches!(err(".o 1\n.e\n"), PLAError::MissingInputDirective));
     // Unrecognised .type value is rejected (consistent with bad .i/.o), not silently defaulted.
     assert!(matches!(
         err(".i 2\n.o 1\n.type bogus\n01 1\n.e\n"),
-        PLAReadError::PLA(PLAError::InvalidTypeDirective { .. })
+        PLAError::InvalidTypeDirective { .. }
     ));
 }
 
 #[test]
 fn pla_duplicate_labels_are_rejected() {
-    use super::pla::{PLAError, PLAReadError};
+    use super::pla::PLAError;
 
-    let err = |s: &str| PlaCover::<Symbol>::from_pla_string(s).expect_err("should error");
+    let err = |s: &str| {
+        let e = PlaCover::<Symbol>::from_pla_string(s).expect_err("should error");
+        e.pla_error().cloned().expect("a format error")
+    };
 
     // .ilb repeats a name: would otherwise build a Symbols whose identity order is no longer
     // unique, silently misaligning later lookups.
     assert!(matches!(
         err(".i 3\n.o 1\n.ilb a a b\n001 1\n.e\n"),
-        PLAReadError::PLA(PLAError::DuplicateLabel { label_type, name })
+        PLAError::DuplicateLabel { label_type, name }
             if &*label_type == "input" && &*name == "a"
     ));
     // Same check on the output side (.ob).
     assert!(matches!(
         err(".i 1\n.o 2\n.ob f f\n0 11\n.e\n"),
-        PLAReadError::PLA(PLAError::DuplicateLabel { label_type, name })
+        PLAError::DuplicateLabel { label_type, name }
             if &*label_type == "output" && &*name == "f"
     ));
 
@@ -1675,20 +1872,23 @@ fn pla_duplicate_labels_are_rejected() {
 
 #[test]
 fn pla_dimension_redeclaration_is_rejected() {
-    use super::pla::{PLAError, PLAReadError};
+    use super::pla::PLAError;
 
-    let err = |s: &str| PlaCover::<Symbol>::from_pla_string(s).expect_err("should error");
+    let err = |s: &str| {
+        let e = PlaCover::<Symbol>::from_pla_string(s).expect_err("should error");
+        e.pla_error().cloned().expect("a format error")
+    };
 
     // .i re-declared after a cube line has already been read: the already-consumed cube stream was
     // split at the old width, so silently accepting the new width would mis-split later cubes.
     assert!(matches!(
         err(".i 2\n.o 1\n01 1\n.i 2\n01 1\n.e\n"),
-        PLAReadError::PLA(PLAError::DuplicateInputDirective)
+        PLAError::DuplicateInputDirective
     ));
     // Same check for .o after a cube line.
     assert!(matches!(
         err(".i 2\n.o 1\n01 1\n.o 1\n01 1\n.e\n"),
-        PLAReadError::PLA(PLAError::DuplicateOutputDirective)
+        PLAError::DuplicateOutputDirective
     ));
 
     // .i re-declared before any cube data is read is rejected too: C's reference reader silently
@@ -1698,11 +1898,11 @@ fn pla_dimension_redeclaration_is_rejected() {
     // regardless of whether cube data has started.
     assert!(matches!(
         err(".i 2\n.i 2\n.o 1\n01 1\n.e\n"),
-        PLAReadError::PLA(PLAError::DuplicateInputDirective)
+        PLAError::DuplicateInputDirective
     ));
     assert!(matches!(
         err(".i 2\n.o 1\n.o 1\n01 1\n.e\n"),
-        PLAReadError::PLA(PLAError::DuplicateOutputDirective)
+        PLAError::DuplicateOutputDirective
     ));
 
     // A well-formed file that declares each dimension exactly once still parses (no regression).
@@ -1712,40 +1912,43 @@ fn pla_dimension_redeclaration_is_rejected() {
 
 #[test]
 fn pla_label_section_redeclaration_is_rejected() {
-    use super::pla::{PLAError, PLAReadError};
+    use super::pla::PLAError;
 
-    let err = |s: &str| PlaCover::<Symbol>::from_pla_string(s).expect_err("should error");
+    let err = |s: &str| {
+        let e = PlaCover::<Symbol>::from_pla_string(s).expect_err("should error");
+        e.pla_error().cloned().expect("a format error")
+    };
 
     // .ilb re-declared with a different second section: unlike C's silent overwrite (which leaks
     // the previously `strdup`'d strings), this is a hard error.
     assert!(matches!(
         err(".i 2\n.o 1\n.ilb a b\n.ilb c d\n01 1\n.e\n"),
-        PLAReadError::PLA(PLAError::DuplicateInputLabelDirective)
+        PLAError::DuplicateInputLabelDirective
     ));
     // Same check on the output side (.ob).
     assert!(matches!(
         err(".i 1\n.o 2\n.ob f g\n.ob h i\n0 11\n.e\n"),
-        PLAReadError::PLA(PLAError::DuplicateOutputLabelDirective)
+        PLAError::DuplicateOutputLabelDirective
     ));
 
     // The second section is rejected even when it is identical to the first.
     assert!(matches!(
         err(".i 2\n.o 1\n.ilb a b\n.ilb a b\n01 1\n.e\n"),
-        PLAReadError::PLA(PLAError::DuplicateInputLabelDirective)
+        PLAError::DuplicateInputLabelDirective
     ));
     assert!(matches!(
         err(".i 1\n.o 2\n.ob f g\n.ob f g\n0 11\n.e\n"),
-        PLAReadError::PLA(PLAError::DuplicateOutputLabelDirective)
+        PLAError::DuplicateOutputLabelDirective
     ));
 
     // The repeat is rejected even when it comes after cube data has already been read.
     assert!(matches!(
         err(".i 2\n.o 1\n.ilb a b\n01 1\n.ilb c d\n01 1\n.e\n"),
-        PLAReadError::PLA(PLAError::DuplicateInputLabelDirective)
+        PLAError::DuplicateInputLabelDirective
     ));
     assert!(matches!(
         err(".i 1\n.o 2\n.ob f g\n0 11\n.ob h i\n0 11\n.e\n"),
-        PLAReadError::PLA(PLAError::DuplicateOutputLabelDirective)
+        PLAError::DuplicateOutputLabelDirective
     ));
 
     // An empty second `.ilb`/`.ob` line remains a no-op today (as an empty line is for the first
@@ -1876,6 +2079,55 @@ fn minimise_empty_cover_is_ok_with_no_cubes() {
     assert_eq!(minimised.num_outputs(), 1);
 }
 
+#[test]
+fn pla_errors_point_at_their_source() {
+    use super::pla::PlaSpan;
+    let span = |s: &str| {
+        let e = PlaCover::<Symbol>::from_pla_string(s).expect_err("should error");
+        let span = e.span().expect("reader errors are located");
+        (span.line, span.column, span.cube, span.text.to_string())
+    };
+    // A cube spanning lines: the bad character is found on the line it was written on, in cube 1.
+    assert_eq!(
+        span(".i 4\n.o 1\n0101 1\n01\n0z 1\n.e\n"),
+        (5, 2, Some(1), "0z 1".to_string())
+    );
+    // Output characters count from the start of the line, past the input field.
+    assert_eq!(
+        span(".i 1\n.o 1\n0\t9\n"),
+        (3, 3, Some(0), "0\t9".to_string())
+    );
+    // A directive error points at the directive.
+    assert_eq!(span(".i 2\n  .o x\n"), (2, 3, None, "  .o x".to_string()));
+    // Errors found once the whole file is read point at the directive they concern...
+    assert_eq!(
+        span(".i 2\n.o 1\n.ilb a\n01 1\n.e\n"),
+        (3, 1, None, ".ilb a".to_string())
+    );
+    assert_eq!(
+        span(".i 4\n.o 1\n.symbolic 0 1 ; A B C D ;\n.symbolic 2 9 ; E F G H ;\n.e\n"),
+        (4, 1, None, ".symbolic 2 9 ; E F G H ;".to_string())
+    );
+    // ...or past the last line read when they concern none.
+    assert_eq!(span(".o 1\n.e\n"), (3, 1, None, String::new()));
+
+    // The renderer keeps a tab before the caret, so the caret lines up under the bad character.
+    let err = PlaCover::<Symbol>::from_pla_string(".i 1\n.o 1\n0\t9\n").unwrap_err();
+    assert_eq!(
+        err.render(),
+        "error: Invalid output character '9' at position 0\n --> line 3, column 3 (cube 0)\n  |\n3 | 0\t9\n  |  \t^\n"
+    );
+    assert_eq!(
+        err.span(),
+        Some(&PlaSpan {
+            line: 3,
+            column: 3,
+            cube: Some(0),
+            text: Arc::from("0\t9"),
+        })
+    );
+}
+
 #[test]
 fn pla_input_field_chars_match_c() {
     use super::pla::{PLAError, PLAReadError};
@@ -1886,7 +2138,10 @@ fn pla_input_field_chars_match_c() {
         assert!(
             matches!(
                 PlaCover::<Symbol>::from_pla_string(&src),
-                Err(PLAReadError::PLA(PLAError::InvalidInputCharacter { .. }))
+                Err(PLAReadError::Located {
+                    error: PLAError::InvalidInputCharacter { .. },
+                    ..
+                })
             ),
             "input {bad:?} should be rejected"
         );
@@ -2870,3 +3125,1699 @@ fn pla_writer_output_unchanged_for_dont_care_only_cover() {
         ".i 2\n.o 1\n.p 1\n-- 1\n.e\n"
     );
 }
+
+const MV_PLA: &str = "\
+.mv 5 2 3 4 2
+.ilb a b
+.label var=2 red green blue
+.p 6
+01 100 1000 10
+11 100 1000 10
+01 010 1000 10
+10 001 0100 01
+10 001 0010 01
+00 110 0001 11
+.e
+";
+
+#[test]
+fn mv_pla_minimises_like_the_reference() {
+    let cover = PlaCover::<Symbol>::from_pla_string(MV_PLA).unwrap();
+    let layout = cover.mv_layout().unwrap();
+    assert_eq!(layout.num_binary(), 2);
+    assert_eq!(layout.mv_sizes(), &[3, 4]);
+    // One position per binary input plus one per value of each multiple-valued variable.
+    assert_eq!(cover.num_inputs(), 9);
+    assert_eq!(&*cover.input_labels()[2], "red");
+    assert_eq!(&*cover.input_labels()[5], "v3.0");
+
+    let min = cover.minimize().unwrap();
+    // Byte-for-byte what the C `espresso` binary prints for the same file.
+    assert_eq!(
+        min.to_pla_string(CoverType::F).unwrap(),
+        "\
+.mv 5 2 3 4 2
+.ilb a b
+.label var=2 red green blue
+.p 4
+10 001 0110 01
+01 010 1000 10
+-1 100 1000 10
+00 110 0001 11
+.e
+"
+    );
+
+    // The value sets read back through `Minterm::mv_field`.
+    let anon = min.into_anonymous();
+    let layout = anon.mv_layout().unwrap().clone();
+    let first = anon.cubes().next().unwrap();
+    assert_eq!(
+        first.inputs().mv_field(layout.positions(3)).to_string(),
+        "0110"
+    );
+    assert_eq!(
+        first
+            .inputs()
+            .mv_field(layout.positions(2))
+            .values()
+            .collect::<Vec<_>>(),
+        vec![2]
+    );
+}
+
+#[test]
+fn mv_layout_must_span_the_inputs() {
+    let cover = Cover::<Anonymous, Anonymous>::from_cubes(
+        CoverType::F,
+        [Cube::anonymous(
+            &[Some(true), Some(true), Some(false)],
+            &[true],
+            CubeType::F,
+        )],
+    );
+    assert!(matches!(
+        cover.clone().with_mv_layout(MvLayout::new(1, [3]).unwrap()),
+        Err(ArityMismatch::Inputs {
+            expected: 3,
+            actual: 4
+        })
+    ));
+    assert_eq!(MvLayout::new(1, [2, 0]), Err(EmptyMvVariable { var: 1 }));
+
+    // Value 0 or 1 of the 2-valued variable covers the whole variable.
+    let mut cover = cover
+        .with_mv_layout(MvLayout::new(1, [2]).unwrap())
+        .unwrap();
+    cover.push(Cube::anonymous(
+        &[Some(true), Some(false), Some(true)],
+        &[true],
+        CubeType::F,
+    ));
+    let min = cover.minimize().unwrap();
+    assert_eq!(min.mv_layout(), Some(&MvLayout::new(1, [2]).unwrap()));
+    assert_eq!(min.num_cubes(), 1);
+    let cube = min.cubes().next().unwrap();
+    assert!(cube.inputs().mv_field(1..3).is_full());
+}
+
+#[test]
+fn malformed_mv_pla_errors() {
+    use super::pla::PLAError;
+
+    let err = |s: &str| {
+        let e = PlaCover::<Symbol>::from_pla_string(s).expect_err("should error");
+        e.pla_error().cloned().expect("a format error")
+    };
+    // `.mv` fixes the dimensions itself, so it cannot be mixed with `.i`/`.o`.
+    assert!(matches!(
+        err(".i 2\n.mv 3 1 2 1\n.e\n"),
+        PLAError::ConflictingMvDirective
+    ));
+    assert!(matches!(
+        err(".mv 3 1 2 1\n.o 1\n.e\n"),
+        PLAError::ConflictingMvDirective
+    ));
+    // A negative size declares a symbolic variable, which `.mv` alone cannot describe.
+    assert!(matches!(
+        err(".mv 3 1 -2 1\n.e\n"),
+        PLAError::SymbolicMvVariable { var: 1 }
+    ));
+    // Zero-valued variables and a size list that disagrees with the variable count.
+    assert!(matches!(
+        err(".mv 3 1 0 1\n.e\n"),
+        PLAError::InvalidMvDirective { .. }
+    ));
+    assert!(matches!(
+        err(".mv 4 1 2 1\n.e\n"),
+        PLAError::InvalidMvDirective { .. }
+    ));
+    // Multiple-valued parts hold one `0`/`1` per value; `-` is binary-only.
+    assert!(matches!(
+        err(".mv 3 1 2 1\n1 -1 1\n.e\n"),
+        PLAError::InvalidInputCharacter { .. }
+    ));
+    // `.label` names a multiple-valued variable, so needs a `.mv` header and a matching count.
+    assert!(matches!(
+        err(".i 2\n.o 1\n.label var=0 x\n01 1\n.e\n"),
+        PLAError::InvalidLabelDirective { .. }
+    ));
+    assert!(matches!(
+        err(".mv 3 1 2 1\n.label var=1 x\n1 01 1\n.e\n"),
+        PLAError::LabelCountMismatch { .. }
+    ));
+}
+
+const PHASE_PLA: &str = "\
+.i 3
+.o 2
+.ilb a b c
+.ob f g
+.phase 01
+.p 4
+1-- 10
+-1- 11
+--1 01
+000 10
+.e
+";
+
+#[test]
+fn pla_phase_is_read_rewritten_and_applied() {
+    let cover = PlaCover::<Symbol>::from_pla_string(PHASE_PLA).unwrap();
+    assert_eq!(cover.output_phase(), Some(&[false, true][..]));
+    // Unminimised, the request is written back as the directive it was read from.
+    let echoed = cover.to_pla_string(CoverType::F).unwrap();
+    assert!(echoed.contains("\n.ob f g\n.phase 01\n.p 4\n"));
+    assert!(cover.complemented_outputs().is_empty());
+
+    // Minimising implements `f` in complement. Byte-for-byte what the C `espresso` binary prints,
+    // including the `#.phase` comment recording the applied phase.
+    let min = cover.minimize().unwrap();
+    assert_eq!(min.complemented_outputs(), vec![0]);
+    assert_eq!(
+        min.to_pla_string(CoverType::F).unwrap(),
+        "\
+.i 3
+.o 2
+.ilb a b c
+.ob f g
+#.phase 01
+.p 3
+001 10
+-1- 01
+--1 01
+.e
+"
+    );
+    // The comment is not re-read as a request.
+    let reread = PlaCover::<Symbol>::from_pla_string(min.to_pla_string(CoverType::F).unwrap());
+    assert_eq!(reread.unwrap().output_phase(), None);
+}
+
+#[test]
+fn applied_phase_survives_new_outputs() {
+    // f = a + b, minimised in complement: its single cube `00` covers !f.
+    let mut f = Cover::new(CoverType::F);
+    f.add_expr(&expr!("a" + "b"), "f").unwrap();
+    let min = f
+        .clone()
+        .with_output_phase([false])
+        .unwrap()
+        .minimize()
+        .unwrap();
+    assert_eq!(min.complemented_outputs(), vec![0]);
+
+    let g = |cover: &mut Cover<Symbol, Symbol>| cover.add_expr(&expr!("a" * "c"), "g").unwrap();
+    let mut reference = f.clone();
+    g(&mut reference);
+
+    let mut added = min.clone();
+    g(&mut added);
+    assert_eq!(added.output_phase(), Some(&[false, true][..]));
+    assert_eq!(added.complemented_outputs(), vec![0]);
+    assert!(added.equivalent_to(&reference).is_ok());
+
+    let mut pushed = min.clone();
+    pushed.push(
+        Cube::with_labels(
+            &[("a", Some(true)), ("c", Some(true))],
+            &[("g", true)],
+            CubeType::F,
+        )
+        .unwrap(),
+    );
+    assert_eq!(pushed.complemented_outputs(), vec![0]);
+    assert!(pushed.equivalent_to(&reference).is_ok());
+
+    let mut merged = min.clone();
+    let mut other = Cover::new(CoverType::F);
+    g(&mut other);
+    merged.merge(&other);
+    assert_eq!(merged.complemented_outputs(), vec![0]);
+    assert!(merged.equivalent_to(&reference).is_ok());
+    // Merging in a phase-minimised cover keeps its complemented output too.
+    let mut merged = other.clone();
+    merged.merge(&min);
+    assert_eq!(merged.complemented_outputs(), vec![1]);
+    assert!(merged.equivalent_to(&reference).is_ok());
+
+    // Cubes for `f` as given cannot join cubes for its complement.
+    let mut conflicting = min.clone();
+    assert!(matches!(
+        conflicting.try_merge(&f),
+        Err(CoverError::PhaseConflict { output: 0 })
+    ));
+    assert_eq!(conflicting, min);
+}
+
+#[test]
+fn assign_output_phase_matches_the_reference() {
+    let src = PHASE_PLA.replace(".phase 01\n", "");
+    let cover = PlaCover::<Symbol>::from_pla_string(src).unwrap();
+    let assigned = cover.assign_output_phase(PhaseStrategy::default()).unwrap();
+    assert_eq!(assigned.complemented_outputs(), vec![0, 1]);
+    // `espresso -Dopo` on the same file.
+    assert_eq!(
+        assigned.to_pla_string(CoverType::F).unwrap(),
+        "\
+.i 3
+.o 2
+.ilb a b c
+.ob f g
+#.phase 00
+.p 2
+001 10
+-00 01
+.e
+"
+    );
+}
+
+#[test]
+fn output_phase_must_match_the_outputs() {
+    let cover = Cover::<Anonymous, Anonymous>::from_cubes(
+        CoverType::F,
+        [Cube::anonymous(&[Some(true)], &[true, true], CubeType::F)],
+    );
+    assert!(matches!(
+        cover.with_output_phase([false]),
+        Err(ArityMismatch::Outputs {
+            expected: 2,
+            actual: 1
+        })
+    ));
+}
+
+#[test]
+fn single_output_minimisation_matches_the_reference() {
+    let src = PHASE_PLA.replace(".phase 01\n", "");
+    let cover = PlaCover::<Symbol>::from_pla_string(&src).unwrap();
+    let header = ".i 3\n.o 2\n.ilb a b c\n.ob f g\n";
+
+    // `espresso -Dso`: -1- is kept once per output rather than shared.
+    let so = cover
+        .minimize_single_output(SingleOutputStrategy::default())
+        .unwrap();
+    assert!(so.complemented_outputs().is_empty());
+    assert_eq!(
+        so.to_pla_string(CoverType::F).unwrap(),
+        format!("{header}.p 5\n--0 10\n-1- 10\n1-- 10\n--1 01\n-1- 01\n.e\n")
+    );
+
+    // `espresso -Dso_both`, heuristic and exact: both outputs are cheaper complemented.
+    for exact in [false, true] {
+        let both = cover
+            .minimize_single_output(SingleOutputStrategy {
+                both_phases: true,
+                exact,
+            })
+            .unwrap();
+        assert_eq!(both.complemented_outputs(), vec![0, 1]);
+        assert_eq!(
+            both.to_pla_string(CoverType::F).unwrap(),
+            format!("{header}#.phase 00\n.p 2\n001 10\n-00 01\n.e\n")
+        );
+    }
+
+    // A requested phase is applied first under `-Dso`, as `minimize` does.
+    let phased = PlaCover::<Symbol>::from_pla_string(PHASE_PLA).unwrap();
+    let so = phased
+        .minimize_single_output(SingleOutputStrategy::default())
+        .unwrap();
+    assert_eq!(
+        so.to_pla_string(CoverType::F).unwrap(),
+        format!("{header}#.phase 01\n.p 3\n001 10\n--1 01\n-1- 01\n.e\n")
+    );
+}
+
+#[test]
+fn assign_output_phase_recovers_from_a_fatal() {
+    use crate::error::MinimizationError;
+    use crate::EspressoConfig;
+
+    // The exact strategy trips C's orthogonality check on the double-phase function for this cover
+    // (as `espresso -Dopo -S4` does). The error is returned with the output part restored, so the
+    // thread can go on minimising.
+    let cover = PlaCover::<Symbol>::from_pla_string(
+        ".type fd\n.i 6\n.o 2\n-01-00 ~~\n00011- ~~\n001--1 ~~\n0--110 1-\n000000 ~-\n\
+         11-10- 11\n1-00-0 11\n--110- -~\n--10-1 -~\n001-0- 1-\n1100-0 -1\n.e\n",
+    )
+    .unwrap();
+    let exact = PhaseStrategy {
+        exact: true,
+        ..PhaseStrategy::default()
+    };
+    assert!(matches!(
+        cover.try_assign_output_phase_with_config(exact, &EspressoConfig::default()),
+        Err(MinimizationError::EspressoFatal { .. })
+    ));
+    let heuristic = cover.assign_output_phase(PhaseStrategy::default()).unwrap();
+    assert_eq!(heuristic.num_outputs(), 2);
+    assert!(cover.minimize().unwrap().num_cubes() > 0);
+}
+
+fn named_cover(text: &str) -> Cover<Symbol, Symbol> {
+    match PlaCover::<Symbol>::from_pla_string(text).unwrap() {
+        PlaCover::InputsOutputsNamed(cover) => cover,
+        other => panic!("expected a named cover, got {other:?}"),
+    }
+}
+
+#[test]
+fn verify_accepts_what_the_minimiser_produces() {
+    let fd = named_cover(
+        ".i 3\n.o 2\n.ilb a b c\n.ob f g\n.type fd\n11- 10\n0-1 01\n111 01\n10- -0\n000 -1\n.e\n",
+    );
+    let min = fd.minimize().unwrap();
+    assert!(min.verify(&fd).is_ok());
+    assert!(fd.minimize_exact().unwrap().verify(&fd).is_ok());
+
+    // Everything outside the ON-set and OFF-set of an FR cover is a don't-care.
+    let fr = named_cover(".i 2\n.o 1\n.ilb a b\n.ob f\n.type fr\n11 1\n00 0\n.e\n");
+    let min = fr.minimize().unwrap();
+    assert!(min.verify(&fr).is_ok());
+
+    // Outputs the minimiser implements in complement are checked against the OFF-set.
+    let phased = named_cover(PHASE_PLA);
+    let min = phased.minimize().unwrap();
+    assert_eq!(min.complemented_outputs(), vec![0]);
+    assert!(min.verify(&phased).is_ok());
+    let unphased = named_cover(&PHASE_PLA.replace(".phase 01\n", ""));
+    let assigned = unphased
+        .assign_output_phase(PhaseStrategy::default())
+        .unwrap();
+    assert!(assigned.verify(&unphased).is_ok());
+}
+
+#[test]
+fn verify_reports_a_witness_minterm_and_output() {
+    use crate::error::VerifyError;
+
+    let original = named_cover(".i 3\n.o 2\n.ilb a b c\n.ob f g\n1-- 10\n-11 01\n.e\n");
+    let grown = named_cover(".i 3\n.o 2\n.ilb a b c\n.ob f g\n1-- 10\n-11 01\n001 01\n.e\n");
+    match grown.verify(&original) {
+        Err(VerifyError::OutsideCareSet { minterm, output }) => {
+            assert_eq!(minterm.to_string(), "001");
+            assert_eq!(output, 1);
+        }
+        other => panic!("expected OutsideCareSet, got {other:?}"),
+    }
+    let err = original.verify(&grown).unwrap_err();
+    assert!(matches!(err, VerifyError::Uncovered { output: 1, .. }));
+    assert!(err.to_string().starts_with("output 1 of the original"));
+
+    let relabelled = named_cover(".i 3\n.o 2\n.ilb a b d\n.ob f g\n1-- 10\n.e\n");
+    assert!(matches!(
+        relabelled.verify(&original),
+        Err(VerifyError::HeaderMismatch)
+    ));
+}
+
+#[test]
+fn equivalence_aligns_columns_by_label() {
+    use crate::error::EquivalenceError;
+
+    let ours = named_cover(".i 3\n.o 2\n.ilb a b c\n.ob f g\n1-- 10\n-11 01\n.e\n");
+    // The same function with inputs and outputs in another order, and a fourth, unused input.
+    let theirs = named_cover(".i 4\n.o 2\n.ilb c b a d\n.ob g f\n--1- 01\n11-- 10\n.e\n");
+    assert!(ours.equivalent_to(&theirs).is_ok());
+    assert!(theirs.equivalent_to(&ours).is_ok());
+    assert!(ours.equivalent_to(&ours.minimize().unwrap()).is_ok());
+
+    let differs = named_cover(".i 3\n.o 2\n.ilb c b a\n.ob g f\n--1 01\n11- 10\n000 11\n.e\n");
+    match ours.equivalent_to(&differs) {
+        Err(EquivalenceError::NotEquivalent { minterm, outputs }) => {
+            assert_eq!(minterm.to_string(), "000");
+            assert_eq!(outputs, vec![0, 1]);
+        }
+        other => panic!("expected NotEquivalent, got {other:?}"),
+    }
+
+    let renamed = named_cover(".i 3\n.o 2\n.ilb a b c\n.ob f h\n1-- 10\n.e\n");
+    assert!(matches!(
+        ours.equivalent_to(&renamed),
+        Err(EquivalenceError::HeaderMismatch)
+    ));
+}
+
+#[test]
+fn equivalence_respects_each_sides_dont_cares() {
+    use crate::error::EquivalenceError;
+
+    // g is a don't-care at 000 on one side only.
+    let with_dc =
+        named_cover(".i 3\n.o 2\n.ilb a b c\n.ob f g\n.type fd\n1-- 10\n-11 01\n000 0-\n.e\n");
+    let asserted = named_cover(".i 3\n.o 2\n.ilb a b c\n.ob f g\n1-- 10\n-11 01\n000 01\n.e\n");
+    let plain = named_cover(".i 3\n.o 2\n.ilb a b c\n.ob f g\n1-- 10\n-11 01\n.e\n");
+    assert!(with_dc.equivalent_to(&asserted).is_ok());
+    assert!(plain.equivalent_to(&with_dc).is_ok());
+    assert!(matches!(
+        plain.equivalent_to(&asserted),
+        Err(EquivalenceError::NotEquivalent { ref outputs, .. }) if outputs == &[1]
+    ));
+    assert!(with_dc.minimize().unwrap().equivalent_to(&with_dc).is_ok());
+
+    // An FR cover leaves everything outside its ON-set and OFF-set unspecified.
+    let fr = named_cover(".i 2\n.o 1\n.ilb a b\n.ob f\n.type fr\n11 1\n00 0\n.e\n");
+    let and = named_cover(".i 2\n.o 1\n.ilb a b\n.ob f\n11 1\n.e\n");
+    let or = named_cover(".i 2\n.o 1\n.ilb a b\n.ob f\n1- 1\n-1 1\n.e\n");
+    assert!(fr.equivalent_to(&and).is_ok());
+    assert!(or.equivalent_to(&fr).is_ok());
+    assert!(and.equivalent_to(&or).is_err());
+
+    // An output implemented in complement is compared as the function it stands for.
+    let phased = named_cover(PHASE_PLA);
+    let min = phased.minimize().unwrap();
+    assert_eq!(min.complemented_outputs(), vec![0]);
+    assert!(min.equivalent_to(&phased).is_ok());
+    assert!(phased.equivalent_to(&min).is_ok());
+}
+
+#[test]
+fn complement_swaps_on_and_off_sets_per_output() {
+    // f = a·b, g = a + c, with g a don't-care at 010.
+    let cover = named_cover(
+        ".i 3\n.o 2\n.ilb a b c\n.ob f g\n.type fd\n11- 10\n1-- 01\n--1 01\n010 0-\n.e\n",
+    );
+    let comp = cover.complement().unwrap();
+    assert_eq!(comp.cover_type(), CoverType::FR);
+    assert_eq!(comp.input_labels(), cover.input_labels());
+    assert_eq!(comp.output_labels(), cover.output_labels());
+    let expected = named_cover(
+        ".i 3\n.o 2\n.ilb a b c\n.ob f g\n.type fd\n0-- 10\n-0- 10\n000 01\n010 0-\n.e\n",
+    );
+    assert!(comp.equivalent_to(&expected).is_ok());
+    // The don't-care stays unspecified, so complementing twice gives the cover back.
+    let back = comp.complement().unwrap();
+    assert!(back.equivalent_to(&cover).is_ok());
+    assert!(back.minimize().unwrap().verify(&cover).is_ok());
+
+    // An output implemented in complement is complemented as the function it stands for.
+    let phased = named_cover(PHASE_PLA);
+    let min = phased.minimize().unwrap();
+    assert_eq!(min.complemented_outputs(), vec![0]);
+    let comp = min.complement().unwrap();
+    assert!(comp.output_phase().is_none());
+    assert!(comp.equivalent_to(&phased.complement().unwrap()).is_ok());
+}
+
+#[test]
+fn cube_calculus_aligns_columns_by_label() {
+    // f = a, g = a·b on one side; f = b, h = c over the inputs in another order on the other.
+    let ours = named_cover(".i 2\n.o 2\n.ilb a b\n.ob f g\n1- 10\n11 01\n.e\n");
+    let theirs = named_cover(".i 2\n.o 2\n.ilb c b\n.ob h f\n-1 01\n1- 10\n.e\n");
+    let header = ".i 3\n.o 3\n.ilb a b c\n.ob f g h\n";
+    let expect = |body: &str| named_cover(&format!("{header}{body}.e\n"));
+
+    let both = ours.intersect(&theirs).unwrap();
+    assert_eq!(both.cover_type(), CoverType::F);
+    assert_eq!(both.input_labels().len(), 3);
+    assert_eq!(both.output_labels().len(), 3);
+    assert!(both.equivalent_to(&expect("11- 100\n")).is_ok());
+
+    let either = ours.union(&theirs).unwrap();
+    assert!(either
+        .equivalent_to(&expect("1-- 100\n-1- 100\n11- 010\n--1 001\n"))
+        .is_ok());
+
+    let rest = ours.sharp(&theirs).unwrap();
+    assert!(rest.equivalent_to(&expect("10- 100\n11- 010\n")).is_ok());
+    let disjoint = ours.disjoint_sharp(&theirs).unwrap();
+    assert!(disjoint.equivalent_to(&rest).is_ok());
+    let back = theirs.sharp(&ours).unwrap();
+    assert!(back.equivalent_to(&expect("01- 100\n--1 001\n")).is_ok());
+}
+
+#[test]
+fn disjoint_sharp_yields_disjoint_cubes() {
+    let all = named_cover(".i 3\n.o 1\n.ilb a b c\n.ob f\n--- 1\n.e\n");
+    let some = named_cover(".i 3\n.o 1\n.ilb a b c\n.ob f\n11- 1\n-11 1\n.e\n");
+    let rest = all.disjoint_sharp(&some).unwrap();
+    let cubes: Vec<_> = rest.cubes().collect();
+    for (i, a) in cubes.iter().enumerate() {
+        for b in &cubes[i + 1..] {
+            assert!(a.inputs().is_disjoint_with(b.inputs()), "{a:?} meets {b:?}");
+        }
+    }
+    assert!(rest.union(&some).unwrap().equivalent_to(&all).is_ok());
+    assert!(rest.intersect(&some).unwrap().num_cubes() == 0);
+}
+
+#[test]
+fn cofactor_and_single_cube_containment() {
+    let cover = named_cover(".i 3\n.o 2\n.ilb a b c\n.ob f g\n11- 10\n0-1 11\n.e\n");
+    let cube = |inputs: &[(&str, Option<bool>)], outputs: &[(&str, bool)]| {
+        Cube::<Symbol, Symbol>::with_labels(inputs, outputs, CubeType::F).unwrap()
+    };
+
+    // With a = 0 (and an input the cover does not have), f = g = c.
+    let fa = cover
+        .cofactor(&cube(
+            &[("a", Some(false)), ("z", Some(true))],
+            &[("f", true)],
+        ))
+        .unwrap();
+    assert_eq!(fa.input_labels(), cover.input_labels());
+    assert!(fa
+        .equivalent_to(&named_cover(
+            ".i 3\n.o 2\n.ilb a b c\n.ob f g\n--1 11\n.e\n"
+        ))
+        .is_ok());
+
+    assert!(cover
+        .contains_cube(&cube(
+            &[("a", Some(true)), ("b", Some(true))],
+            &[("f", true)]
+        ))
+        .unwrap());
+    assert!(cover
+        .contains_cube(&cube(
+            &[("c", Some(true)), ("a", Some(false)), ("b", Some(false))],
+            &[("f", true), ("g", true)]
+        ))
+        .unwrap());
+    assert!(!cover
+        .contains_cube(&cube(
+            &[("a", Some(true)), ("b", Some(true))],
+            &[("g", true)]
+        ))
+        .unwrap());
+    assert!(!cover
+        .contains_cube(&cube(
+            &[("a", Some(true)), ("b", Some(true))],
+            &[("h", true)]
+        ))
+        .unwrap());
+
+    // A complemented output is read as the function it stands for.
+    let phased = named_cover(PHASE_PLA);
+    let min = phased.minimize().unwrap();
+    assert_eq!(min.complemented_outputs(), vec![0]);
+    for c in phased.cubes() {
+        assert!(min
+            .cofactor(c)
+            .unwrap()
+            .equivalent_to(&phased.cofactor(c).unwrap())
+            .is_ok());
+    }
+}
+
+#[test]
+fn tautology_and_containment_report_witnesses() {
+    let cover = named_cover(".i 2\n.o 2\n.ilb a b\n.ob f g\n1- 11\n00 11\n01 01\n.e\n");
+    // g is 1 everywhere; f misses a = 0, b = 1.
+    let verdict = cover.is_tautology().unwrap();
+    let (minterm, output) = verdict.witness().unwrap();
+    assert_eq!(output, 0);
+    assert_eq!(minterm.value_of("a"), Some(false));
+    assert_eq!(minterm.value_of("b"), Some(true));
+    let any = |output: &str| {
+        Cube::<Symbol, Symbol>::with_labels::<&str, _>(&[], &[(output, true)], CubeType::F).unwrap()
+    };
+    assert!(cover.covers(&any("g")).unwrap().holds());
+    assert_eq!(cover.covers(&any("f")).unwrap().witness().unwrap().1, 0);
+    // An input the cover lacks is one it does not depend on; an output it lacks is uncovered.
+    let wide = Cube::<Symbol, Symbol>::with_labels(
+        &[("b", Some(false)), ("z", Some(true))],
+        &[("h", true), ("f", true)],
+        CubeType::F,
+    )
+    .unwrap();
+    let verdict = cover.covers(&wide).unwrap();
+    let (minterm, output) = verdict.witness().unwrap();
+    assert_eq!(wide.outputs().vars()[output].as_ref(), "h");
+    assert_eq!(minterm.value_of("z"), Some(true));
+
+    // Subsets are read through each side's phase.
+    let phased = named_cover(PHASE_PLA);
+    let min = phased.minimize().unwrap();
+    assert_eq!(min.complemented_outputs(), vec![0]);
+    assert!(min.is_subset_of(&phased).unwrap().holds());
+    assert!(phased.is_subset_of(&min).unwrap().holds());
+    let smaller = named_cover(".i 3\n.o 2\n.ilb a b c\n.ob f g\n11- 10\n--1 01\n.e\n");
+    assert!(smaller.is_subset_of(&min).unwrap().holds());
+    let verdict = min.is_subset_of(&smaller).unwrap();
+    let (minterm, output) = verdict.witness().unwrap();
+    let values: Vec<bool> = ["a", "b", "c"]
+        .iter()
+        .map(|l| minterm.value_of(*l).unwrap())
+        .collect();
+    let phased_on = |o: usize| match o {
+        0 => values[0] || values[1] || !values[2],
+        _ => values[1] || values[2],
+    };
+    let smaller_on = |o: usize| match o {
+        0 => values[0] && values[1],
+        _ => values[2],
+    };
+    assert!(phased_on(output) && !smaller_on(output));
+}
+
+#[test]
+fn simplify_keeps_function_and_carried_sets() {
+    let cover = named_cover(
+        ".i 3\n.o 2\n.ilb a b c\n.ob f g\n.type fd\n111 11\n110 11\n10- 10\n011 01\n000 -1\n.e\n",
+    );
+    let simplified = cover.simplify().unwrap();
+    let count =
+        |c: &Cover<Symbol, Symbol>, t: CubeType| c.cubes().filter(|q| q.cube_type() == t).count();
+    assert!(count(&simplified, CubeType::F) < count(&cover, CubeType::F));
+    assert_eq!(count(&simplified, CubeType::D), count(&cover, CubeType::D));
+    assert_eq!(simplified.cover_type(), cover.cover_type());
+    assert!(simplified.equivalent_to(&cover).is_ok());
+
+    // The cubes stay in the phase the cover implements.
+    let min = named_cover(PHASE_PLA).minimize().unwrap();
+    let simplified = min.simplify().unwrap();
+    assert_eq!(simplified.complemented_outputs(), vec![0]);
+    assert!(simplified.equivalent_to(&min).is_ok());
+}
+
+#[test]
+fn essential_primes_carry_uncovered_minterms() {
+    // f = !b·c + a·b and g = !b·c, with a·!b·c a don't-care of f. The prime a·c of f covers only
+    // that don't-care and a·b·c, which a·b covers too, so it is not essential.
+    let cover =
+        named_cover(".i 3\n.o 2\n.ilb a b c\n.ob f g\n.type fd\n-01 11\n11- 10\n101 -0\n.e\n");
+    let essential = cover.essential_primes().unwrap();
+    let shown: Vec<String> = essential.iter().map(|e| e.prime().to_string()).collect();
+    assert_eq!(shown, ["-01 11", "11- 10"]);
+    for e in &essential {
+        let output = &cover.output_labels()[e.output()];
+        assert!(e.prime().outputs().value_of(output.as_ref()));
+        // The witness is in the prime, and is not a don't-care.
+        let single = Cover::from_cubes(CoverType::F, [e.prime().clone()]);
+        let point = Cube::new(
+            e.minterm().clone(),
+            OutputSet::labeled(&[(output.clone(), true)]).unwrap(),
+            CubeType::F,
+        );
+        assert!(single.covers(&point).unwrap().holds());
+        assert!(!cover
+            .cubes()
+            .filter(|c| c.cube_type() == CubeType::D)
+            .any(|c| c.outputs().value_of(output.as_ref())
+                && ["a", "b", "c"].iter().all(|l| {
+                    let v = c.inputs().value_of(*l);
+                    v.is_none() || v == e.minterm().value_of(*l)
+                })));
+    }
+
+    // A complemented output is explained as the function it stands for.
+    let phased = named_cover(PHASE_PLA);
+    let min = phased.minimize().unwrap();
+    assert_eq!(min.complemented_outputs(), vec![0]);
+    let primes = |c: &Cover<Symbol, Symbol>| -> Vec<String> {
+        let mut v: Vec<String> = c
+            .essential_primes()
+            .unwrap()
+            .iter()
+            .map(|e| e.prime().to_string())
+            .collect();
+        v.sort();
+        v
+    };
+    assert_eq!(primes(&min), primes(&phased));
+}
+
+#[test]
+fn malformed_phase_directive_errors() {
+    use super::pla::PLAError;
+
+    let err = |s: &str| {
+        let e = PlaCover::<Symbol>::from_pla_string(s).expect_err("should error");
+        e.pla_error().cloned().expect("a format error")
+    };
+    assert!(matches!(
+        err(".i 1\n.o 2\n.phase 0x\n1 11\n.e\n"),
+        PLAError::InvalidPhaseDirective { .. }
+    ));
+    // One flag per output, as a single run.
+    assert!(matches!(
+        err(".i 1\n.o 2\n.phase 0\n1 11\n.e\n"),
+        PLAError::InvalidPhaseDirective { .. }
+    ));
+    assert!(matches!(
+        err(".i 1\n.o 2\n.phase 0 1\n1 11\n.e\n"),
+        PLAError::InvalidPhaseDirective { .. }
+    ));
+    assert!(matches!(
+        err(".i 1\n.o 2\n.phase 01\n.phase 01\n1 11\n.e\n"),
+        PLAError::DuplicatePhaseDirective
+    ));
+}
+
+const ADDER_PLA: &str = "\
+.i 4
+.o 3
+.ilb a1 a0 b1 b0
+.ob s2 s1 s0
+.pair 2 (a1 b1) (a0 b0)
+.p 15
+0001 001
+0010 010
+0011 011
+0100 001
+0101 010
+0110 011
+0111 100
+1000 010
+1001 011
+1010 100
+1011 101
+1100 011
+1101 100
+1110 101
+1111 110
+.e
+";
+
+/// What the C `espresso` binary prints for `ADDER_PLA`, with or without `-Dpair`/`-Dpairall`.
+const PAIRED_ADDER: &str = "\
+.mv 3 0 4 4 3
+.ob s2 s1 s0
+.label var=0 a1.bar+b1.bar a1.bar+b1 a1+b1.bar a1+b1
+.label var=1 a0.bar+b0.bar a0.bar+b0 a0+b0.bar a0+b0
+.p 5
+ 1000 1111 100
+ 1001 1000 010
+ 1110 1000 100
+ 0110 0111 010
+ 1111 0110 001
+.e
+";
+
+#[test]
+fn pla_pair_is_read_and_minimised_like_the_reference() {
+    let cover = PlaCover::<Symbol>::from_pla_string(ADDER_PLA).unwrap();
+    let layout = cover.mv_layout().unwrap();
+    assert_eq!(layout.num_binary(), 0);
+    assert_eq!(layout.mv_sizes(), &[4, 4]);
+    assert_eq!(layout.num_paired(), 2);
+    assert_eq!(&*cover.input_labels()[0], "a1.bar+b1.bar");
+    assert_eq!(&*cover.input_labels()[7], "a0+b0");
+    // Pairing alone keeps one cube per row: `0001` selects `a1+b1` and `a0+b0.bar`.
+    assert!(cover
+        .to_pla_string(CoverType::F)
+        .unwrap()
+        .contains("\n 0001 0010 001\n"));
+
+    let min = cover.minimize().unwrap();
+    assert_eq!(min.to_pla_string(CoverType::F).unwrap(), PAIRED_ADDER);
+}
+
+#[test]
+fn find_input_pairing_matches_the_reference() {
+    use crate::{PairingMinimizer, PairingSearch};
+
+    let src = ADDER_PLA.replace(".pair 2 (a1 b1) (a0 b0)\n", "");
+    let cover = PlaCover::<Symbol>::from_pla_string(src).unwrap();
+    for search in [
+        PairingSearch::default(),
+        PairingSearch::Exhaustive(PairingMinimizer::Espresso),
+    ] {
+        let (paired, pairs) = cover.find_input_pairing(search).unwrap();
+        assert_eq!(pairs, vec![(0, 2), (1, 3)]);
+        assert_eq!(paired.to_pla_string(CoverType::F).unwrap(), PAIRED_ADDER);
+    }
+
+    // Pairing the same inputs by hand reaches the same cover.
+    let by_hand = cover.pair_inputs(&[(0, 2), (1, 3)]).unwrap().minimize();
+    assert_eq!(
+        by_hand.unwrap().to_pla_string(CoverType::F).unwrap(),
+        PAIRED_ADDER
+    );
+}
+
+#[test]
+fn pair_inputs_checks_and_relabels_the_pairs() {
+    use super::error::InvalidPairing;
+
+    let cover = Cover::<Symbol, Symbol>::with_labels(CoverType::F, ["a", "b", "a+b"], ["f"])
+        .unwrap()
+        .with_mv_layout(MvLayout::new(1, [2]).unwrap())
+        .unwrap();
+    // Only binary inputs pair, and each only once.
+    assert_eq!(cover.pair_inputs(&[(0, 1)]), Err(InvalidPairing { var: 1 }));
+    let binary =
+        Cover::<Symbol, Symbol>::with_labels(CoverType::F, ["a", "b", "a+b"], ["f"]).unwrap();
+    assert_eq!(
+        binary.pair_inputs(&[(0, 1), (1, 2)]),
+        Err(InvalidPairing { var: 1 })
+    );
+    assert_eq!(
+        binary.pair_inputs(&[(0, 3)]),
+        Err(InvalidPairing { var: 3 })
+    );
+    assert_eq!(binary.pair_inputs(&[]).unwrap(), binary);
+
+    // A decoder value clashing with a kept input is renamed; the kept input goes first.
+    let paired = binary.pair_inputs(&[(0, 1)]).unwrap();
+    let labels: Vec<&str> = paired.input_labels().iter().map(|l| l.as_ref()).collect();
+    assert_eq!(labels, ["a+b", "a.bar+b.bar", "a.bar+b", "a+b.bar", "a+b0"]);
+    let layout = paired.mv_layout().unwrap();
+    assert_eq!((layout.num_binary(), layout.mv_sizes()), (1, &[4][..]));
+    assert_eq!(layout.num_paired(), 1);
+}
+
+#[test]
+fn malformed_pair_directive_errors() {
+    use super::pla::PLAError;
+
+    let err = |s: &str| {
+        let e = PlaCover::<Symbol>::from_pla_string(s).expect_err("should error");
+        e.pla_error().cloned().expect("a format error")
+    };
+    let invalid = |pair: &str| {
+        matches!(
+            err(&format!(".i 3\n.o 1\n.ilb a b c\n{pair}\n111 1\n.e\n")),
+            PLAError::InvalidPairDirective { .. }
+        )
+    };
+    // Named by `.ilb` when present, `N` pairs of distinct binary inputs.
+    assert!(invalid(".pair 1 (a z)"));
+    assert!(invalid(".pair 1 (0 1)"));
+    assert!(invalid(".pair 2 (a b)"));
+    assert!(invalid(".pair 2 (a b) (b c)"));
+    assert!(invalid(".pair x"));
+    assert!(matches!(
+        err(".i 3\n.o 1\n.ilb a b c\n.pair 1 (a b)\n.pair 1 (a c)\n111 1\n.e\n"),
+        PLAError::DuplicatePairDirective
+    ));
+    // Without `.ilb`, inputs are zero-based numbers; a multiple-valued variable cannot pair.
+    let cover = PlaCover::<Symbol>::from_pla_string(".i 3\n.o 1\n.pair 1 (0 2)\n111 1\n.e\n");
+    assert_eq!(cover.unwrap().mv_layout().unwrap().mv_sizes(), &[4]);
+    assert!(matches!(
+        err(".mv 3 1 2 1\n.pair 1 (0 1)\n1 01 1\n.e\n"),
+        PLAError::InvalidPairDirective { .. }
+    ));
+}
+
+/// A two-bit state `s1 s0` read as one symbolic input, and the next state `n1 n0` as one symbolic
+/// output.
+const STATE_PLA: &str = "\
+.i 4
+.o 2
+.ilb a b s1 s0
+.ob f g
+.symbolic s1 s0 ; IDLE RUN STOP ;
+.p 5
+0100 10
+1001 01
+-110 11
+0-11 10
+1111 01
+.e
+";
+
+/// What the C `espresso` binary prints for `STATE_PLA`.
+const STATE_MINIMISED: &str = "\
+.mv 4 2 4 2
+.ilb a b
+.ob f g
+.label var=2 IDLE RUN STOP X3
+.p 5
+10 0100 01
+01 1000 10
+11 0001 01
+0- 0001 10
+-1 0010 11
+.e
+";
+
+const NEXT_STATE_PLA: &str = "\
+.i 3
+.o 3
+.ilb a b c
+.ob n1 n0 z
+.symbolic-output n1 n0 ; S0 S1 S2 S3 ;
+.p 5
+000 011
+001 100
+01- 110
+1-0 001
+111 111
+.e
+";
+
+/// What the C `espresso` binary prints for `NEXT_STATE_PLA`.
+const NEXT_STATE_MINIMISED: &str = "\
+.i 3
+.o 5
+.ilb a b c
+.ob S0 S1 S2 S3 z
+.p 5
+111 00011
+001 00100
+000 01001
+1-0 10001
+01- 00010
+.e
+";
+
+#[test]
+fn pla_symbolic_is_read_and_minimised_like_the_reference() {
+    let cover = PlaCover::<Symbol>::from_pla_string(STATE_PLA).unwrap();
+    let layout = cover.mv_layout().unwrap();
+    assert_eq!((layout.num_binary(), layout.mv_sizes()), (2, &[4][..]));
+    let labels: Vec<&str> = cover.input_labels().iter().map(|l| l.as_ref()).collect();
+    assert_eq!(labels, ["a", "b", "IDLE", "RUN", "STOP", "X3"]);
+    // `-110` selects state `10` (STOP) alone; `0-11` selects `11` (X3).
+    let read = cover.to_pla_string(CoverType::F).unwrap();
+    assert!(read.contains("\n-1 0010 11\n0- 0001 10\n"), "{read}");
+
+    let min = cover.minimize().unwrap();
+    assert_eq!(min.to_pla_string(CoverType::F).unwrap(), STATE_MINIMISED);
+}
+
+#[test]
+fn pla_symbolic_output_is_read_and_minimised_like_the_reference() {
+    let cover = PlaCover::<Symbol>::from_pla_string(NEXT_STATE_PLA).unwrap();
+    assert_eq!(cover.num_outputs(), 5);
+    let labels: Vec<&str> = cover.output_labels().iter().map(|l| l.as_ref()).collect();
+    assert_eq!(labels, ["S0", "S1", "S2", "S3", "z"]);
+
+    let min = cover.minimize().unwrap();
+    assert_eq!(
+        min.to_pla_string(CoverType::F).unwrap(),
+        NEXT_STATE_MINIMISED
+    );
+}
+
+#[test]
+fn pla_symbolic_output_recomputes_the_on_set_under_dont_cares() {
+    // With a don't-care set the ON-set is recomputed as the points neither don't-care nor OFF,
+    // both complements coming from the C core.
+    let cover = PlaCover::<Symbol>::from_pla_string(
+        ".i 3\n.o 3\n.ilb a b c\n.ob n1 n0 z\n.type fd\n\
+         .symbolic-output n1 n0 ; S0 S1 S2 S3 ;\n.p 6\n\
+         000 011\n001 100\n01- 110\n1-0 001\n111 1-1\n110 -00\n.e\n",
+    )
+    .unwrap();
+    let min = cover.minimize().unwrap();
+    // Byte-for-byte what the C `espresso` binary prints for the same file.
+    assert_eq!(
+        min.to_pla_string(CoverType::F).unwrap(),
+        "\
+.i 3
+.o 5
+.ilb a b c
+.ob S0 S1 S2 S3 z
+.p 4
+001 00100
+100 10001
+01- 00010
+000 01001
+.e
+"
+    );
+}
+
+#[test]
+fn encode_symbolic_checks_and_relabels_the_variables() {
+    use super::error::{InvalidSymbolic, SymbolicOutputError};
+
+    let cover =
+        Cover::<Symbol, Symbol>::with_labels(CoverType::F, ["a", "b", "X1"], ["f", "g"]).unwrap();
+    let symbolic = |vars: &[usize]| [SymbolicVariable::new(vars.iter().copied(), ["X0"])];
+    // Only binary inputs (or outputs) encode, each once.
+    assert_eq!(
+        cover.encode_symbolic_inputs(&[
+            SymbolicVariable::new([0], ["A"]),
+            SymbolicVariable::new([1, 0], ["B"]),
+        ]),
+        Err(InvalidSymbolic { var: 0 })
+    );
+    assert_eq!(
+        cover.encode_symbolic_inputs(&symbolic(&[3])),
+        Err(InvalidSymbolic { var: 3 })
+    );
+    assert!(matches!(
+        cover.encode_symbolic_outputs(&symbolic(&[2])),
+        Err(SymbolicOutputError::Invalid(InvalidSymbolic { var: 2 }))
+    ));
+    let mv = cover
+        .clone()
+        .with_mv_layout(MvLayout::new(1, [2]).unwrap())
+        .unwrap();
+    assert_eq!(
+        mv.encode_symbolic_inputs(&symbolic(&[1])),
+        Err(InvalidSymbolic { var: 1 })
+    );
+    assert_eq!(cover.encode_symbolic_inputs(&[]).unwrap(), cover);
+
+    // A value clashing with a kept input is renamed; the kept input goes first.
+    let encoded = cover.encode_symbolic_inputs(&symbolic(&[0, 1])).unwrap();
+    let labels: Vec<&str> = encoded.input_labels().iter().map(|l| l.as_ref()).collect();
+    assert_eq!(labels, ["X1", "X0", "X10", "X2", "X3"]);
+    let layout = encoded.mv_layout().unwrap();
+    assert_eq!((layout.num_binary(), layout.mv_sizes()), (1, &[4][..]));
+
+    // The symbolic outputs go first, then the outputs left over.
+    let encoded = cover
+        .encode_symbolic_outputs(&[SymbolicVariable::new([1], ["g0", "g1"])])
+        .unwrap();
+    let labels: Vec<&str> = encoded.output_labels().iter().map(|l| l.as_ref()).collect();
+    assert_eq!(labels, ["g0", "g1", "f"]);
+}
+
+#[test]
+fn malformed_symbolic_directive_errors() {
+    use super::pla::PLAError;
+
+    let err = |s: &str| {
+        let e = PlaCover::<Symbol>::from_pla_string(s).expect_err("should error");
+        e.pla_error().cloned().expect("a format error")
+    };
+    let read = |directives: &str| {
+        err(&format!(
+            ".i 3\n.o 2\n.ilb a b c\n.ob f g\n{directives}\n111 11\n.e\n"
+        ))
+    };
+    let invalid =
+        |directive: &str| matches!(read(directive), PLAError::InvalidSymbolicDirective { .. });
+    // Named by `.ilb` when present, then `;`, the values and `;`; each input used once.
+    assert!(invalid(".symbolic a z ; A ;"));
+    assert!(invalid(".symbolic 0 1 ; A ;"));
+    assert!(invalid(".symbolic a b ; A"));
+    assert!(invalid(".symbolic a b ; A ; B"));
+    assert!(invalid(".symbolic a b ; ;\n.symbolic b c ; ;"));
+    let invalid_output = |directive: &str| {
+        matches!(
+            read(directive),
+            PLAError::InvalidSymbolicOutputDirective { .. }
+        )
+    };
+    assert!(invalid_output(".symbolic-output f a ; ;"));
+    assert!(invalid_output(".symbolic-output f g ; ;\n.phase 01"));
+    // A paired input cannot also be symbolic.
+    assert!(matches!(
+        read(".symbolic a b ; ;\n.pair 1 (b c)"),
+        PLAError::InvalidPairDirective { .. }
+    ));
+
+    // Without `.ilb`, inputs are zero-based numbers; a `.pair` skips the symbolic inputs.
+    let cover = PlaCover::<Symbol>::from_pla_string(
+        ".i 4\n.o 1\n.symbolic 1 ; ;\n.pair 1 (0 3)\n1111 1\n.e\n",
+    )
+    .unwrap();
+    let layout = cover.mv_layout().unwrap();
+    assert_eq!((layout.num_binary(), layout.mv_sizes()), (1, &[4, 2][..]));
+}
+
+/// A full adder with one OFF-set block and an external don't-care network.
+const ADDER_BLIF: &str = "\
+# full adder, carry read in complement
+.model adder
+.inputs a b \\
+  cin
+.outputs sum cout
+.names a b cin sum
+100 1
+010 1
+001 1
+111 1
+.names a b cin cout
+00- 0
+0-0 0
+-00 0
+.exdc
+.names a b sum
+11 1
+.end
+";
+
+#[test]
+fn blif_is_read_into_a_labelled_cover() {
+    let cover = Cover::<Symbol, Symbol>::from_blif_string(ADDER_BLIF).unwrap();
+    let inputs: Vec<&str> = cover.input_labels().iter().map(|l| l.as_ref()).collect();
+    let outputs: Vec<&str> = cover.output_labels().iter().map(|l| l.as_ref()).collect();
+    assert_eq!(inputs, ["a", "b", "cin"]);
+    assert_eq!(outputs, ["sum", "cout"]);
+    assert_eq!(cover.cover_type(), CoverType::FD);
+    // `cout` is listed by its OFF-set, so it is read in complement.
+    assert_eq!(cover.complemented_outputs(), vec![1]);
+    assert_eq!(cover.num_cubes(), 7);
+
+    // `cin` is a don't-care in the `.exdc` block, which reads only `a` and `b`.
+    let dc = cover
+        .cubes()
+        .find(|c| c.cube_type() == CubeType::D)
+        .unwrap();
+    assert_eq!(dc.inputs().to_string(), "11-");
+}
+
+#[test]
+fn blif_round_trips_through_the_writer() {
+    use super::blif::BLIFWriter;
+
+    let cover = Cover::<Symbol, Symbol>::from_blif_string(ADDER_BLIF).unwrap();
+    let written = cover.to_blif_string("adder").unwrap();
+    assert_eq!(
+        written,
+        "\
+.model adder
+.inputs a b cin
+.outputs sum cout
+.names a b cin sum
+100 1
+010 1
+001 1
+111 1
+.names a b cin cout
+00- 0
+0-0 0
+-00 0
+.exdc
+.names a b sum
+11 1
+.end
+"
+    );
+    assert_eq!(
+        Cover::<Symbol, Symbol>::from_blif_string(&written).unwrap(),
+        cover
+    );
+}
+
+#[test]
+fn blif_writes_minimised_expression_covers() {
+    use super::blif::BLIFWriter;
+
+    let mut cover = Cover::new(CoverType::F);
+    cover.add_expr(&expr!("a" * "b"), "f").unwrap();
+    cover.add_expr(&expr!("a" + !"a"), "one").unwrap();
+    cover.add_expr(&expr!("a" * !"a"), "zero").unwrap();
+    let min = cover.minimize().unwrap();
+    // Each block reads only the inputs its cubes depend on; constants read none.
+    assert_eq!(
+        min.to_blif_string("top").unwrap(),
+        ".model top\n.inputs a b\n.outputs f one zero\n.names a b f\n11 1\n.names one\n1\n\
+         .names zero\n.end\n"
+    );
+
+    let paired = cover.pair_inputs(&[(0, 1)]).unwrap();
+    assert!(matches!(
+        paired.to_blif_string("top"),
+        Err(super::blif::BLIFWriteError::MultipleValuedInputs)
+    ));
+}
+
+#[test]
+fn blif_reader_rejects_what_a_cover_cannot_hold() {
+    use super::blif::{BLIFError, BLIFReadError};
+
+    let err = |s: &str| match Cover::<Symbol, Symbol>::from_blif_string(s) {
+        Err(BLIFReadError::BLIF(e)) => e,
+        other => panic!("expected a BLIF error, got {other:?}"),
+    };
+    let model = |body: &str| format!(".model m\n.inputs a b\n.outputs f\n{body}\n.end\n");
+    assert!(matches!(
+        err(&model(".latch a f 0")),
+        BLIFError::UnsupportedDirective { .. }
+    ));
+    assert!(matches!(
+        err(&model(".names a t\n1 1\n.names t f\n1 1")),
+        BLIFError::InternalSignal { .. }
+    ));
+    assert!(matches!(
+        err(&model(".names a c f\n11 1")),
+        BLIFError::UnknownSignal { .. }
+    ));
+    assert!(matches!(
+        err(&model(".names a f\n1 1\n.names b f\n1 1")),
+        BLIFError::DuplicateSignal { .. }
+    ));
+    assert!(matches!(err(&model("")), BLIFError::UndrivenOutput { .. }));
+    assert!(matches!(
+        err(&model(".names a b f\n1 1")),
+        BLIFError::CubeWidthMismatch {
+            expected: 2,
+            actual: 1
+        }
+    ));
+    assert!(matches!(
+        err(&model(".names a b f\n1x 1")),
+        BLIFError::InvalidInputCharacter {
+            character: 'x',
+            position: 1
+        }
+    ));
+    assert!(matches!(
+        err(&model(".names a b f\n11")),
+        BLIFError::InvalidOutputValue { .. }
+    ));
+    assert!(matches!(
+        err(&model(".names a b f\n11 1\n00 0")),
+        BLIFError::MixedOutputValues { .. }
+    ));
+    assert!(matches!(
+        err(".inputs a\n.outputs f\n1 1\n"),
+        BLIFError::CubeOutsideNames
+    ));
+}
+
+#[test]
+fn eqntott_writes_equations_like_the_c_espresso() {
+    use super::eqntott::EqntottWriter;
+
+    // Don't-cares are not written, and the complemented `cout` is negated.
+    let cover = Cover::<Symbol, Symbol>::from_blif_string(ADDER_BLIF).unwrap();
+    assert_eq!(
+        cover.to_eqntott_string().unwrap(),
+        "INORDER = a b cin;\nOUTORDER = sum cout;\n\n\
+         sum = (a&!b&!cin) | (!a&b&!cin) | (!a&!b&cin) | (a&b&cin);\n\n\
+         cout = !((!a&!b) | (!a&!cin) | (!b&!cin));\n"
+    );
+
+    // Long terms wrap before column 72; constants are `0` and `(1)`.
+    let mut cover = Cover::new(CoverType::F);
+    let names: Vec<String> = (0..8).map(|i| format!("signal_{i}")).collect();
+    let product = crate::BoolExpr::parse(names.join(" & ")).unwrap();
+    cover.add_expr(&product, "f").unwrap();
+    cover
+        .add_expr(&expr!("signal_0" + !"signal_0"), "one")
+        .unwrap();
+    cover
+        .add_expr(&expr!("signal_0" * !"signal_0"), "zero")
+        .unwrap();
+    let written = cover.to_eqntott_string().unwrap();
+    assert!(written.contains("&signal_6\n    &signal_7);\n"));
+    assert!(written
+        .lines()
+        .filter(|line| !line.starts_with("INORDER"))
+        .all(|line| line.len() <= 72));
+    assert!(written.ends_with("\none = (1);\n\nzero = 0;\n"));
+    assert_eq!(
+        Cover::<Symbol, Symbol>::from_eqntott_string(&written).unwrap(),
+        cover
+    );
+
+    let mut spaced = Cover::new(CoverType::F);
+    spaced.add_expr(&expr!("a b"), "f").unwrap();
+    assert!(matches!(
+        spaced.to_eqntott_string(),
+        Err(super::eqntott::EqntottWriteError::InvalidName { name }) if &*name == "a b"
+    ));
+
+    // An output named like an input would be read back as a signal declared twice.
+    let mut shared = Cover::new(CoverType::F);
+    shared.add_expr(&expr!("a" * "b"), "a").unwrap();
+    assert!(matches!(
+        shared.to_eqntott_string(),
+        Err(super::eqntott::EqntottWriteError::DuplicateSignal { name }) if &*name == "a"
+    ));
+}
+
+#[test]
+fn eqntott_is_read_into_a_labelled_cover() {
+    let text = "\
+# a two-output example
+INORDER = a b c d;   /* d is read by nothing */
+OUTORDER = g f;
+f = a & b
+  | !a & c;
+g = a ^ b;  # parity
+";
+    let cover = Cover::<Symbol, Symbol>::from_eqntott_string(text).unwrap();
+    let inputs: Vec<&str> = cover.input_labels().iter().map(|l| l.as_ref()).collect();
+    let outputs: Vec<&str> = cover.output_labels().iter().map(|l| l.as_ref()).collect();
+    assert_eq!(inputs, ["a", "b", "c", "d"]);
+    assert_eq!(outputs, ["g", "f"]);
+    assert_eq!(cover.cover_type(), CoverType::F);
+
+    let mut expected =
+        Cover::with_labels(CoverType::F, ["a", "b", "c", "d"], Vec::<&str>::new()).unwrap();
+    expected
+        .add_expr(&expr!("a" * !"b" + !"a" * "b"), "g")
+        .unwrap();
+    expected
+        .add_expr(&expr!("a" * "b" + !"a" * "c"), "f")
+        .unwrap();
+    assert_eq!(cover, expected);
+
+    // Without INORDER/OUTORDER, signals and equations keep their order of appearance.
+    let cover = Cover::<Symbol, Symbol>::from_eqntott_string("y = c & a;\nx = b;").unwrap();
+    let inputs: Vec<&str> = cover.input_labels().iter().map(|l| l.as_ref()).collect();
+    assert_eq!(inputs, ["c", "a", "b"]);
+    assert_eq!(cover.output_labels()[0].as_ref(), "y");
+}
+
+#[test]
+fn eqntott_reader_rejects_what_a_cover_cannot_hold() {
+    use super::eqntott::{EqntottError, EqntottReadError};
+
+    let err = |s: &str| match Cover::<Symbol, Symbol>::from_eqntott_string(s) {
+        Err(EqntottReadError::Eqntott(e)) => e,
+        other => panic!("expected an eqntott error, got {other:?}"),
+    };
+    assert!(matches!(
+        err("f = a & b"),
+        EqntottError::MissingSemicolon { .. }
+    ));
+    assert!(matches!(err("f a;"), EqntottError::MissingEquals { .. }));
+    assert!(matches!(
+        err("f[0] = a;"),
+        EqntottError::InvalidName { name } if &*name == "f[0]"
+    ));
+    assert!(matches!(
+        err("f = a &;"),
+        EqntottError::InvalidExpression { output, .. } if &*output == "f"
+    ));
+    assert!(matches!(
+        err("f = a;\nf = b;"),
+        EqntottError::DuplicateSignal { .. }
+    ));
+    assert!(matches!(
+        err("INORDER = a;\nINORDER = b;"),
+        EqntottError::DuplicateOrder { .. }
+    ));
+    assert!(matches!(
+        err("INORDER = a;\nf = a & b;"),
+        EqntottError::UndeclaredInput { name } if &*name == "b"
+    ));
+    assert!(matches!(
+        err("OUTORDER = f;\nf = a;\ng = a;"),
+        EqntottError::UndeclaredOutput { name } if &*name == "g"
+    ));
+    assert!(matches!(
+        err("OUTORDER = f g;\nf = a;"),
+        EqntottError::UndrivenOutput { name } if &*name == "g"
+    ));
+    assert!(matches!(
+        err("t = a & b;\nf = t | c;"),
+        EqntottError::InternalSignal { name } if &*name == "t"
+    ));
+}
+
+const KISS_TABLE: &str = "\
+# A four-state controller
+.i 2
+.o 1
+.p 8
+.s 4
+.r st0
+00 st0 st0 0
+01 st0 st1 0
+1- st0 st2 0
+-- st1 st3 1
+0- st2 st0 -
+1- st2 st3 0
+-1 st3 st0 1
+-0 st3 * 0
+.e
+";
+
+/// Evaluate the outputs of an F cover at one input assignment.
+fn evaluate(cover: &Cover<Symbol, Symbol>, assignment: &[bool]) -> Vec<bool> {
+    (0..cover.num_outputs())
+        .map(|j| {
+            cover.cubes().any(|cube| {
+                cube.outputs().value_at(j)
+                    && (0..assignment.len())
+                        .all(|i| cube.inputs().value_at(i).is_none_or(|v| v == assignment[i]))
+            })
+        })
+        .collect()
+}
+
+#[test]
+fn kiss_is_read_into_a_symbolic_machine() {
+    let fsm = kiss::Fsm::from_kiss_string(KISS_TABLE).unwrap();
+    assert_eq!((fsm.num_inputs(), fsm.num_outputs()), (2, 1));
+    assert_eq!(fsm.input_labels(), ["x0", "x1"].map(Symbol::from));
+    assert_eq!(fsm.output_labels(), [Symbol::from("z0")]);
+    assert_eq!(fsm.states(), ["st0", "st1", "st2", "st3"].map(Symbol::from));
+    assert_eq!(fsm.reset_state(), Some(&Symbol::from("st0")));
+    assert_eq!(fsm.num_transitions(), 8);
+
+    // The reset state is numbered first wherever it appears.
+    let fsm = kiss::Fsm::from_kiss_string(
+        ".i 1\n.o 1\n.ilb go\n.ob busy\n.r b\n0 a a 0\n1 a b 1\n- b a 1\n",
+    )
+    .unwrap();
+    assert_eq!(fsm.states(), ["b", "a"].map(Symbol::from));
+    assert_eq!(fsm.input_labels(), [Symbol::from("go")]);
+}
+
+#[test]
+fn kiss_encodings_implement_the_state_table() {
+    let fsm = kiss::Fsm::from_kiss_string(KISS_TABLE).unwrap();
+    let table: Vec<(&str, &str, &str, &str)> = KISS_TABLE
+        .lines()
+        .filter(|line| !line.starts_with(['#', '.']))
+        .filter_map(
+            |line| match line.split_whitespace().collect::<Vec<_>>()[..] {
+                [i, p, n, o] => Some((i, p, n, o)),
+                _ => None,
+            },
+        )
+        .collect();
+
+    let mut counts = Vec::new();
+    for encoding in [
+        kiss::StateEncoding::Binary,
+        kiss::StateEncoding::Gray,
+        kiss::StateEncoding::OneHot,
+        kiss::StateEncoding::FewestCubes,
+    ] {
+        let encoded = fsm.encode(encoding).unwrap();
+        let width = encoded.num_state_bits();
+        assert_eq!(
+            width,
+            if encoding == kiss::StateEncoding::OneHot {
+                4
+            } else {
+                2
+            }
+        );
+        // The reset state keeps the all-zero code, or the first bit when one-hot.
+        let reset_code: Vec<bool> = (0..width)
+            .map(|b| b == 0 && encoding == kiss::StateEncoding::OneHot)
+            .collect();
+        assert_eq!(encoded.state_code("st0"), Some(&reset_code[..]));
+        let cover = encoded.cover();
+        assert_eq!(cover.input_labels()[2].as_ref(), "y0");
+        assert_eq!(cover.output_labels()[0].as_ref(), "y0_next");
+
+        // Every specified entry of the table holds at every input the transition covers.
+        for &(inputs, present, next, outputs) in &table {
+            for x in 0..4usize {
+                let x = [x & 2 != 0, x & 1 != 0];
+                let fires = inputs
+                    .chars()
+                    .zip(x)
+                    .all(|(c, v)| c == '-' || (c == '1') == v);
+                if !fires {
+                    continue;
+                }
+                let mut assignment = x.to_vec();
+                assignment.extend_from_slice(encoded.state_code(present).unwrap());
+                let values = evaluate(cover, &assignment);
+                if next != "*" {
+                    assert_eq!(&values[..width], encoded.state_code(next).unwrap());
+                }
+                if outputs != "-" {
+                    assert_eq!(values[width], outputs == "1");
+                }
+            }
+        }
+        counts.push(cover.num_cubes());
+    }
+    assert!(counts[3] <= counts[0].min(counts[1]));
+}
+
+#[test]
+fn kiss_encodings_handle_more_states_than_word_bits() {
+    // A 70-state ring: each state steps to the next when the input is high.
+    let n = 70;
+    let mut text = String::from(".i 1\n.o 0\n");
+    for k in 0..n {
+        text += &format!("0 s{k} s{k}\n1 s{k} s{}\n", (k + 1) % n);
+    }
+    let fsm = kiss::Fsm::from_kiss_string(text).unwrap();
+    assert_eq!(fsm.states().len(), n);
+
+    for (encoding, width) in [
+        (kiss::StateEncoding::Binary, 7),
+        (kiss::StateEncoding::Gray, 7),
+        (kiss::StateEncoding::OneHot, n),
+    ] {
+        let encoded = fsm.encode(encoding).unwrap();
+        assert_eq!(encoded.num_state_bits(), width);
+        let codes: Vec<&[bool]> = (0..n)
+            .map(|k| encoded.state_code(format!("s{k}").as_str()).unwrap())
+            .collect();
+        assert!(codes.iter().all(|code| code.len() == width));
+        let distinct: std::collections::HashSet<&[bool]> = codes.iter().copied().collect();
+        assert_eq!(distinct.len(), n);
+        if encoding == kiss::StateEncoding::OneHot {
+            for (k, code) in codes.iter().enumerate() {
+                assert!(code.iter().enumerate().all(|(j, &bit)| bit == (j == k)));
+            }
+        }
+    }
+}
+
+#[test]
+fn kiss_reader_rejects_malformed_tables() {
+    let err = |text: &str| match kiss::Fsm::from_kiss_string(text).unwrap_err() {
+        kiss::KISSReadError::KISS(e) => e,
+        other => panic!("expected a KISS2 error, got {other:?}"),
+    };
+    assert!(matches!(
+        err("0 a b 1\n"),
+        kiss::KISSError::MissingDimension { directive } if &*directive == ".i"
+    ));
+    assert!(matches!(
+        err(".i 1\n.o 1\n0 a 1\n"),
+        kiss::KISSError::InvalidTransition { .. }
+    ));
+    assert!(matches!(
+        err(".i 2\n.o 1\n0 a b 1\n"),
+        kiss::KISSError::InputWidthMismatch {
+            expected: 2,
+            actual: 1
+        }
+    ));
+    assert!(matches!(
+        err(".i 1\n.o 1\n0 a b x\n"),
+        kiss::KISSError::InvalidOutputCharacter {
+            character: 'x',
+            position: 0
+        }
+    ));
+    assert!(matches!(
+        err(".i one\n"),
+        kiss::KISSError::InvalidDirectiveValue { .. }
+    ));
+    assert!(matches!(
+        err(".i 1\n.i 1\n"),
+        kiss::KISSError::DuplicateDirective { .. }
+    ));
+    assert!(matches!(
+        err(".i 1\n.o 1\n.s 3\n0 a b 1\n"),
+        kiss::KISSError::CountMismatch {
+            declared: 3,
+            found: 2,
+            ..
+        }
+    ));
+    assert!(matches!(
+        err(".i 1\n.o 1\n.ilb a b\n"),
+        kiss::KISSError::LabelCountMismatch {
+            expected: 1,
+            actual: 2,
+            ..
+        }
+    ));
+    assert!(matches!(
+        err(".i 1\n.o 1\n.r c\n0 a b 1\n"),
+        kiss::KISSError::UnknownResetState { name } if &*name == "c"
+    ));
+    assert!(matches!(
+        err(".i 1\n.o 1\n0 a b 1\n- a a 1\n"),
+        kiss::KISSError::ConflictingTransitions { state } if &*state == "a"
+    ));
+    assert!(matches!(
+        err(".i 1\n.o 1\n0 a b 1\n0 * b 0\n"),
+        kiss::KISSError::ConflictingTransitions { state } if &*state == "*"
+    ));
+}
+
+const HDL_BLIF: &str =
+    ".model m\n.inputs a b c\n.outputs f g\n.names a b c f\n11- 1\n1-1 1\n.names c g\n0 1\n.end\n";
+
+#[test]
+fn hdl_writes_a_verilog_module() {
+    use super::hdl::{HDLForm, HDLWriter};
+
+    let cover = Cover::<Symbol, Symbol>::from_blif_string(HDL_BLIF).unwrap();
+    assert_eq!(
+        cover
+            .to_verilog_string("top", HDLForm::SumOfProducts)
+            .unwrap(),
+        "module top (\n    input  wire a,\n    input  wire b,\n    input  wire c,\n    \
+         output wire f,\n    output wire g\n);\n\n    assign f = (a & b) | (a & c);\n    \
+         assign g = ~c;\n\nendmodule\n"
+    );
+    let factored = cover.to_verilog_string("top", HDLForm::Factored).unwrap();
+    assert!(factored.contains("    assign f = a & (b | c);\n"));
+    assert!(factored.contains("    assign g = ~c;\n"));
+}
+
+#[test]
+fn hdl_writes_a_vhdl_entity() {
+    use super::hdl::{HDLForm, HDLWriter};
+
+    let mut cover = Cover::new(CoverType::F);
+    cover.add_expr(&expr!("a" * "b"), "f").unwrap();
+    cover.add_expr(&expr!("a" + !"a"), "one").unwrap();
+    cover.add_expr(&expr!("a" * !"a"), "zero").unwrap();
+    let min = cover.minimize().unwrap();
+    assert_eq!(
+        min.to_vhdl_string("top", HDLForm::Factored).unwrap(),
+        "library ieee;\nuse ieee.std_logic_1164.all;\n\nentity top is\n    port (\n        \
+         a : in  std_logic;\n        b : in  std_logic;\n        f : out std_logic;\n        \
+         one : out std_logic;\n        zero : out std_logic\n    );\nend entity top;\n\n\
+         architecture rtl of top is\nbegin\n    f <= a and b;\n    one <= '1';\n    \
+         zero <= '0';\nend architecture rtl;\n"
+    );
+
+    // An output minimised in complement is negated back to the true function.
+    let complemented = Cover::<Symbol, Symbol>::from_blif_string(HDL_BLIF)
+        .unwrap()
+        .with_output_phase([false, true])
+        .unwrap()
+        .minimize()
+        .unwrap();
+    assert_eq!(complemented.complemented_outputs(), vec![0]);
+    let vhdl = complemented
+        .to_vhdl_string("top", HDLForm::SumOfProducts)
+        .unwrap();
+    assert!(vhdl.contains("    f <= not ("), "{vhdl}");
+}
+
+#[test]
+fn hdl_escapes_labels_and_rejects_unwritable_covers() {
+    use super::hdl::{HDLForm, HDLWriteError, HDLWriter};
+
+    let mut cover = Cover::new(CoverType::F);
+    cover.add_expr(&expr!("x[0]" * "wire"), "Out").unwrap();
+    cover.add_expr(&expr!(!"OUT"), "y").unwrap();
+    let verilog = cover
+        .to_verilog_string("m", HDLForm::SumOfProducts)
+        .unwrap();
+    assert!(
+        verilog.contains("    assign Out = \\wire  & \\x[0] ;\n"),
+        "{verilog}"
+    );
+    // VHDL folds case, so `Out` and `OUT` are only distinct as extended identifiers.
+    let vhdl = cover.to_vhdl_string("m", HDLForm::SumOfProducts).unwrap();
+    assert!(
+        vhdl.contains("    \\Out\\ <= wire and \\x[0]\\;\n"),
+        "{vhdl}"
+    );
+    assert!(vhdl.contains("    y <= not \\OUT\\;\n"), "{vhdl}");
+
+    let mut spaced = Cover::new(CoverType::F);
+    spaced.add_expr(&expr!("a b"), "f").unwrap();
+    assert!(matches!(
+        spaced.to_verilog_string("m", HDLForm::Factored),
+        Err(HDLWriteError::InvalidIdentifier { name }) if &*name == "a b"
+    ));
+
+    let mut shared = Cover::new(CoverType::F);
+    shared.add_expr(&expr!("a"), "a").unwrap();
+    assert!(matches!(
+        shared.to_vhdl_string("m", HDLForm::Factored),
+        Err(HDLWriteError::DuplicatePort { name }) if &*name == "a"
+    ));
+
+    let paired = cover.pair_inputs(&[(0, 1)]).unwrap();
+    assert!(matches!(
+        paired.to_verilog_string("m", HDLForm::Factored),
+        Err(HDLWriteError::MultipleValuedInputs)
+    ));
+}
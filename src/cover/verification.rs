@@ -0,0 +1,662 @@
+//! Verification of a minimised cover against the function it was minimised from.
+//!
+//! This is the reference tool's `verify.c` check: a minimised ON-set `F'` implements the original
+//! `(F, D)` exactly when `F ⊆ F' ⊆ F ∪ D`. The C core runs both containments; on a failure it also
+//! extracts a minterm where they break, which is returned as the witness.
+//!
+//! Equivalence of two covers is the same check after aligning their columns by label, as the
+//! reference tool's `PLA_verify` does, with both covers' don't-care sets pooled. The yes/no
+//! queries — tautology, and containment of a cube or a cover — are one containment check each,
+//! answered by the unate recursive tautology checker with a witness [`Verdict`] on a no.
+//! Essential primes are explained the same way: each [`EssentialPrime`] carries an ON-set minterm
+//! that no other prime covers.
+
+use super::error::{EquivalenceError, VerifyError};
+use super::label::{Anonymous, Label};
+use super::minimisation::{cube_layout, marshal_sets, rehome_cube};
+use super::minterm::Minterm;
+use super::{
+    assert_mask, overlay_outputs, union_inputs, Cover, CoverType, Cube, CubeType, Symbols,
+};
+use crate::error::MinimizationError;
+use crate::espresso::{Espresso, EspressoCover, VerifyCheck};
+use std::fmt;
+use std::sync::Arc;
+
+/// The answer to a yes/no query on a cover, such as [`Cover::is_tautology`], with a witness when
+/// the answer is no.
+pub enum Verdict<I> {
+    /// The property holds.
+    Holds,
+    /// The property fails at `minterm` on `output`.
+    Fails {
+        /// The witness input minterm, fully specified
+        minterm: Minterm<I>,
+        /// The output at which the property fails, as a position among the outputs of the cover or
+        /// cube being tested
+        output: usize,
+    },
+}
+
+impl<I> Verdict<I> {
+    /// Whether the property holds.
+    #[must_use]
+    pub fn holds(&self) -> bool {
+        matches!(self, Verdict::Holds)
+    }
+
+    /// The witness minterm and output, if the property fails.
+    #[must_use]
+    pub fn witness(&self) -> Option<(&Minterm<I>, usize)> {
+        match self {
+            Verdict::Holds => None,
+            Verdict::Fails { minterm, output } => Some((minterm, *output)),
+        }
+    }
+}
+
+impl<I: Label + fmt::Debug> fmt::Debug for Verdict<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Holds => write!(f, "Holds"),
+            Verdict::Fails { minterm, output } => f
+                .debug_struct("Fails")
+                .field("minterm", minterm)
+                .field("output", output)
+                .finish(),
+        }
+    }
+}
+
+/// A prime implicant that every cover of the function must use, with the reason why.
+///
+/// Returned by [`Cover::essential_primes`]. The minterm lies in the ON-set of `output`, and no other
+/// prime implicant and no don't-care covers it there, so dropping the prime would uncover it.
+#[derive(Clone)]
+pub struct EssentialPrime<I, O> {
+    prime: Cube<I, O>,
+    minterm: Minterm<I>,
+    output: usize,
+}
+
+impl<I, O> EssentialPrime<I, O> {
+    /// The essential prime implicant.
+    #[must_use]
+    pub fn prime(&self) -> &Cube<I, O> {
+        &self.prime
+    }
+
+    /// An ON-set minterm that only this prime covers, fully specified.
+    #[must_use]
+    pub fn minterm(&self) -> &Minterm<I> {
+        &self.minterm
+    }
+
+    /// The output, as a position in the cover, on which only this prime covers
+    /// [`minterm`](Self::minterm).
+    #[must_use]
+    pub fn output(&self) -> usize {
+        self.output
+    }
+}
+
+impl<I: Label + fmt::Debug, O> fmt::Debug for EssentialPrime<I, O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EssentialPrime")
+            .field("prime", &self.prime)
+            .field("minterm", &self.minterm)
+            .field("output", &self.output)
+            .finish()
+    }
+}
+
+impl<I: Label, O: Label> Cover<I, O> {
+    /// Check that this cover implements `original`: every minterm of the original's ON-set is
+    /// covered, and nothing is asserted outside its ON-set and don't-care set.
+    ///
+    /// The cover is typically the result of minimising `original`, so both must have the same
+    /// inputs, outputs and [`MvLayout`](crate::MvLayout). Only this cover's ON-set is checked; the
+    /// original's don't-care set is its D cubes, or, for an `FR` cover, whatever neither its ON-set
+    /// nor its OFF-set covers. An output this cover implements in complement (see
+    /// [`complemented_outputs`](Self::complemented_outputs)) is checked against the original's
+    /// OFF-set instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VerifyError::OutsideCareSet`] or [`VerifyError::Uncovered`] with a witness minterm
+    /// and output if the check fails, [`VerifyError::HeaderMismatch`] if the covers are not over
+    /// the same variables, and [`VerifyError::Minimization`] if the check cannot run (a
+    /// contradictory cover, a low-level Espresso instance of different dimensions live on this
+    /// thread, or a fatal condition in the C core).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use espresso_logic::error::VerifyError;
+    /// use espresso_logic::{Anonymous, Cover, CoverType, Cube, CubeType, Minimizable};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let original = Cover::<Anonymous, Anonymous>::from_cubes(CoverType::F, [
+    ///     Cube::anonymous(&[Some(true), Some(true)], &[true], CubeType::F),
+    ///     Cube::anonymous(&[Some(true), Some(false)], &[true], CubeType::F),
+    /// ]);
+    /// let minimised = original.minimize()?;
+    /// assert!(minimised.verify(&original).is_ok());
+    ///
+    /// // Dropping a cube loses part of the ON-set.
+    /// let broken = Cover::<Anonymous, Anonymous>::from_cubes(CoverType::F, [
+    ///     Cube::anonymous(&[Some(true), Some(true)], &[true], CubeType::F),
+    /// ]);
+    /// match broken.verify(&original) {
+    ///     Err(VerifyError::Uncovered { minterm, output }) => {
+    ///         assert_eq!(minterm.to_string(), "10");
+    ///         assert_eq!(output, 0);
+    ///     }
+    ///     other => panic!("unexpected {other:?}"),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn verify(&self, original: &Cover<I, O>) -> Result<(), VerifyError<I>> {
+        if self.input_symbols() != original.input_symbols()
+            || self.output_symbols() != original.output_symbols()
+            || self.mv != original.mv
+        {
+            return Err(VerifyError::HeaderMismatch);
+        }
+        let no = self.num_outputs();
+
+        let esp = Espresso::try_new_mv(&cube_layout(self), no, None)?;
+        // Only the ON-set is checked, so any sets the cover carries are left out.
+        let mut on_set = Cover::from_parts(
+            Arc::clone(self.input_symbols()),
+            Arc::clone(self.output_symbols()),
+            self.cubes
+                .iter()
+                .filter(|cube| cube.cube_type() == CubeType::F)
+                .cloned()
+                .collect(),
+            CoverType::F,
+        );
+        on_set.mv = self.mv.clone();
+        let (f, _, _) = marshal_sets(&on_set)?;
+        let (f_old, d_old, r_old) = marshal_sets(original)?;
+
+        // Without D cubes, an FR original leaves everything outside F ∪ R unspecified.
+        let d_old = match d_old {
+            None if original.cover_type.has_r() => Some(esp.try_offset(&f_old, r_old.as_ref())?),
+            d => d,
+        };
+        // Compare each output in the phase this cover implements it in.
+        let complemented = self.complemented_outputs();
+        let original_complemented = original.complemented_outputs();
+        let positive: Vec<bool> = (0..no)
+            .map(|j| complemented.contains(&j) == original_complemented.contains(&j))
+            .collect();
+        let f_old = if positive.contains(&false) {
+            esp.try_apply_output_phase(&f_old, d_old.as_ref(), r_old.as_ref(), &positive)?
+                .0
+        } else {
+            f_old
+        };
+
+        let Some((check, witness)) = esp.try_verify(&f, &f_old, d_old.as_ref())? else {
+            return Ok(());
+        };
+        let (minterm, output) = witness_point(&witness, original.input_symbols(), no);
+        Err(match check {
+            VerifyCheck::OutsideCareSet => VerifyError::OutsideCareSet { minterm, output },
+            VerifyCheck::Uncovered => VerifyError::Uncovered { minterm, output },
+        })
+    }
+
+    /// Check that this cover and `other` implement the same multi-output function.
+    ///
+    /// Columns are matched by label (by position for [`Anonymous`] labels), so the covers may list
+    /// their inputs and outputs in different orders. An input only one cover mentions is one the
+    /// other does not depend on. The outputs must be the same on both sides. Each cover's
+    /// don't-care set (its D cubes, or for an `FR` cover whatever neither its ON-set nor its
+    /// OFF-set covers) is a don't-care for the comparison, and an output a cover implements in
+    /// complement (see [`complemented_outputs`](Self::complemented_outputs)) is compared as the
+    /// function it stands for.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EquivalenceError::NotEquivalent`] with a distinguishing input minterm, over the
+    /// union of both covers' inputs, and every output (as a position in this cover) that differs
+    /// there. Returns [`EquivalenceError::HeaderMismatch`] if the covers do not have the same
+    /// outputs, or carry different [`MvLayout`](crate::MvLayout)s or inputs alongside one, and
+    /// [`EquivalenceError::Minimization`] if the check cannot run.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use espresso_logic::error::EquivalenceError;
+    /// use espresso_logic::{Cover, CoverType, Cube, CubeType, Symbol};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// // f = a·b and g = a + b, with the inputs listed in either order.
+    /// let mut ab = Cover::<Symbol, Symbol>::new(CoverType::F);
+    /// ab.push(Cube::with_labels(&[("a", Some(true)), ("b", Some(true))], &[("f", true)], CubeType::F)?);
+    /// ab.push(Cube::with_labels(&[("a", Some(true))], &[("g", true)], CubeType::F)?);
+    /// ab.push(Cube::with_labels(&[("b", Some(true))], &[("g", true)], CubeType::F)?);
+    ///
+    /// let mut ba = Cover::<Symbol, Symbol>::new(CoverType::F);
+    /// ba.push(Cube::with_labels(&[("b", Some(true)), ("a", None)], &[("g", true)], CubeType::F)?);
+    /// ba.push(Cube::with_labels(&[("b", Some(true)), ("a", Some(true))], &[("f", true)], CubeType::F)?);
+    /// ba.push(Cube::with_labels(&[("b", None), ("a", Some(true))], &[("g", true)], CubeType::F)?);
+    /// assert!(ab.equivalent_to(&ba).is_ok());
+    ///
+    /// // Widening f to a differs from a·b where a = 1, b = 0.
+    /// ba.push(Cube::with_labels(&[("a", Some(true))], &[("f", true)], CubeType::F)?);
+    /// match ab.equivalent_to(&ba) {
+    ///     Err(EquivalenceError::NotEquivalent { minterm, outputs }) => {
+    ///         assert_eq!(minterm.value_of("a"), Some(true));
+    ///         assert_eq!(minterm.value_of("b"), Some(false));
+    ///         assert_eq!(outputs, vec![0]);
+    ///     }
+    ///     other => panic!("unexpected {other:?}"),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn equivalent_to(&self, other: &Cover<I, O>) -> Result<(), EquivalenceError<I>> {
+        if self.mv != other.mv
+            || (self.mv.is_some() && self.input_symbols() != other.input_symbols())
+            || self.num_outputs() != other.num_outputs()
+        {
+            return Err(EquivalenceError::HeaderMismatch);
+        }
+        let no = self.num_outputs();
+        // Where each of `other`'s outputs lands in this cover.
+        let mut out_map = Vec::with_capacity(no);
+        for (j, label) in other.output_labels().iter().enumerate() {
+            match self
+                .output_symbols()
+                .position_of_identity(&label.identity(j))
+            {
+                Some(pos) => out_map.push(pos as usize),
+                None => return Err(EquivalenceError::HeaderMismatch),
+            }
+        }
+        let inputs = union_inputs(self.input_symbols(), other.input_symbols());
+        let ni = inputs.arity();
+        let outputs = self.output_symbols();
+        let ours = aligned(self, &inputs, outputs, &(0..no).collect::<Vec<_>>());
+        let theirs = aligned(other, &inputs, outputs, &out_map);
+        let mut their_complemented: Vec<usize> = other
+            .complemented_outputs()
+            .into_iter()
+            .map(|j| out_map[j])
+            .collect();
+        their_complemented.sort_unstable();
+
+        let layout = cube_layout(&ours);
+        let esp = Espresso::try_new_mv(&layout, no, None)?;
+        let (f_ours, d_ours) = function_sets(&esp, &ours, &self.complemented_outputs())?;
+        let (f_theirs, d_theirs) = function_sets(&esp, &theirs, &their_complemented)?;
+
+        // Either side's don't-care frees the comparison there.
+        let dc: Vec<Cube<Anonymous, Anonymous>> = d_ours
+            .iter()
+            .chain(d_theirs.iter())
+            .flat_map(|d| d.to_cubes(ni, no, CubeType::D))
+            .collect();
+        let d = if dc.is_empty() {
+            None
+        } else {
+            let data: Vec<(&[u64], Vec<bool>)> = dc
+                .iter()
+                .map(|c| {
+                    (
+                        c.inputs().raw_words(),
+                        (0..no).map(|j| c.asserts(j)).collect(),
+                    )
+                })
+                .collect();
+            let refs: Vec<(&[u64], &[bool])> =
+                data.iter().map(|(w, o)| (*w, o.as_slice())).collect();
+            Some(EspressoCover::from_packed_cubes(&refs, &layout, no)?)
+        };
+
+        let Some((_, witness)) = esp.try_verify(&f_theirs, &f_ours, d.as_ref())? else {
+            return Ok(());
+        };
+        let point = witness
+            .to_cubes(ni, no, CubeType::F)
+            .next()
+            .expect("a failed verification yields one witness cube")
+            .inputs()
+            .clone();
+        let f_ours: Vec<_> = f_ours.to_cubes(ni, no, CubeType::F).collect();
+        let f_theirs: Vec<_> = f_theirs.to_cubes(ni, no, CubeType::F).collect();
+        let asserted = |cubes: &[Cube<Anonymous, Anonymous>], j: usize| {
+            cubes
+                .iter()
+                .any(|c| c.asserts(j) && point.is_subset_of(c.inputs()))
+        };
+        let outputs = (0..no)
+            .filter(|&j| !asserted(&dc, j) && asserted(&f_ours, j) != asserted(&f_theirs, j))
+            .collect();
+        Err(EquivalenceError::NotEquivalent {
+            minterm: Minterm::from_packed_words(inputs, Arc::clone(point.packed())),
+            outputs,
+        })
+    }
+
+    /// Whether every output of this cover is a tautology: 1 on every input minterm, counting the
+    /// don't-care set as 1.
+    ///
+    /// The check is the C unate recursive tautology checker, so it needs no BDD. To ask about one
+    /// output, ask whether the cover [`covers`](Self::covers) a cube that fixes no input and
+    /// asserts just that output. The don't-care set is read as for [`verify`](Self::verify), and an
+    /// output the cover implements in complement as the function it stands for.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`MinimizationError`] if the cover is contradictory, a low-level Espresso instance
+    /// of different dimensions is live on this thread, or the C core hits a fatal condition.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use espresso_logic::{Anonymous, Cover, CoverType, Cube, CubeType};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// // a + !a·b + !b is always 1; a + b is not.
+    /// let taut = Cover::<Anonymous, Anonymous>::from_cubes(CoverType::F, [
+    ///     Cube::anonymous(&[Some(true), None], &[true], CubeType::F),
+    ///     Cube::anonymous(&[Some(false), Some(true)], &[true], CubeType::F),
+    ///     Cube::anonymous(&[None, Some(false)], &[true], CubeType::F),
+    /// ]);
+    /// assert!(taut.is_tautology()?.holds());
+    ///
+    /// let or = Cover::<Anonymous, Anonymous>::from_cubes(CoverType::F, [
+    ///     Cube::anonymous(&[Some(true), None], &[true], CubeType::F),
+    ///     Cube::anonymous(&[None, Some(true)], &[true], CubeType::F),
+    /// ]);
+    /// let verdict = or.is_tautology()?;
+    /// let (minterm, output) = verdict.witness().unwrap();
+    /// assert_eq!(minterm.to_string(), "00");
+    /// assert_eq!(output, 0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_tautology(&self) -> Result<Verdict<I>, MinimizationError> {
+        let no = self.num_outputs();
+        let esp = Espresso::try_new_mv(&cube_layout(self), no, None)?;
+        let (f, d) = function_sets(&esp, self, &self.complemented_outputs())?;
+        Ok(match esp.try_uncovered(None, &f, d.as_ref())? {
+            None => Verdict::Holds,
+            Some(witness) => {
+                let (minterm, output) = witness_point(&witness, self.input_symbols(), no);
+                Verdict::Fails { minterm, output }
+            }
+        })
+    }
+
+    /// Whether this cover's ON-set and don't-care set together cover every minterm of `cube`, on
+    /// every output the cube asserts.
+    ///
+    /// Unlike [`contains_cube`](Self::contains_cube), the cube may be spread over several cubes of
+    /// the cover. Columns are aligned by label: an input only the cube has is one the cover does
+    /// not depend on, and an output only the cube asserts is uncovered. A failing
+    /// [`Verdict`]'s output is a position among the cube's outputs, and its minterm is over both
+    /// headers' inputs. Sets are read as for [`is_tautology`](Self::is_tautology).
+    ///
+    /// # Errors
+    ///
+    /// As for [`is_tautology`](Self::is_tautology).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use espresso_logic::{Anonymous, Cover, CoverType, Cube, CubeType};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let cover = Cover::<Anonymous, Anonymous>::from_cubes(CoverType::F, [
+    ///     Cube::anonymous(&[Some(true), None], &[true], CubeType::F),
+    ///     Cube::anonymous(&[Some(false), Some(false)], &[true], CubeType::F),
+    /// ]);
+    /// // b = 0 is covered by the two cubes together.
+    /// assert!(cover.covers(&Cube::anonymous(&[None, Some(false)], &[true], CubeType::F))?.holds());
+    /// // Output 0 is not a tautology: 01 is missing.
+    /// let any = Cube::anonymous(&[None, None], &[true], CubeType::F);
+    /// assert_eq!(cover.covers(&any)?.witness().unwrap().0.to_string(), "01");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn covers(&self, cube: &Cube<I, O>) -> Result<Verdict<I>, MinimizationError> {
+        let subject = Cover::from_parts(
+            Arc::clone(cube.inputs().symbols()),
+            Arc::clone(cube.outputs().symbols()),
+            vec![Cube::new(
+                cube.inputs().clone(),
+                cube.outputs().clone(),
+                CubeType::F,
+            )],
+            CoverType::F,
+        );
+        self.contains(&subject, true)
+    }
+
+    /// Whether this cover's function lies within `other`'s: every minterm of its ON-set is in
+    /// `other`'s ON-set or don't-care set, on every output.
+    ///
+    /// Columns are aligned by label as in [`merge`](Self::merge), so an output only this cover has
+    /// must be empty. A failing [`Verdict`]'s output is a position in this cover, and its minterm
+    /// is over both covers' inputs. This cover's ON-set is read as for
+    /// [`intersect`](Self::intersect) and `other`'s sets as for [`is_tautology`](Self::is_tautology).
+    ///
+    /// # Errors
+    ///
+    /// As for [`is_tautology`](Self::is_tautology).
+    pub fn is_subset_of(&self, other: &Cover<I, O>) -> Result<Verdict<I>, MinimizationError> {
+        other.contains(self, self.mv == other.mv)
+    }
+
+    /// The essential prime implicants of this cover's function, each with an ON-set minterm that
+    /// only it covers.
+    ///
+    /// A prime is essential when some ON-set minterm lies in no other prime and is not a
+    /// don't-care, so every cover of the function, minimal or not, must include it. The primes are
+    /// those of [`primes`](Self::primes), multi-output as Espresso forms them, and each is tested
+    /// with the consensus check of the C core's `essen.c`. This is the information
+    /// [`EspressoConfig::remove_essential`](crate::EspressoConfig) acts on during minimisation. The
+    /// don't-care set is read as for [`verify`](Self::verify), and an output the cover implements
+    /// in complement as the function it stands for.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`MinimizationError`] if the cover is contradictory, a low-level Espresso instance
+    /// of different dimensions is live on this thread, or the C core hits a fatal condition.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use espresso_logic::{Cover, CoverType, Cube, CubeType, Symbol};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// // f = a·x + b·x̄ + a·b: a·x and b·x̄ are essential, the consensus prime a·b is not.
+    /// let f = Cover::<Symbol, Symbol>::from_cubes(
+    ///     CoverType::F,
+    ///     [
+    ///         Cube::with_labels(&[("a", Some(true)), ("x", Some(true))], &[("o", true)], CubeType::F)?,
+    ///         Cube::with_labels(&[("b", Some(true)), ("x", Some(false))], &[("o", true)], CubeType::F)?,
+    ///         Cube::with_labels(&[("a", Some(true)), ("b", Some(true))], &[("o", true)], CubeType::F)?,
+    ///     ],
+    /// );
+    /// let essential = f.essential_primes()?;
+    /// assert_eq!(essential.len(), 2);
+    /// for e in &essential {
+    ///     // a·x alone covers a·!b·x; b·x̄ alone covers !a·b·x̄.
+    ///     assert_eq!(e.minterm().value_of("a"), Some(e.prime().inputs().value_of("a").is_some()));
+    ///     assert_eq!(e.minterm().value_of("b"), Some(e.prime().inputs().value_of("b").is_some()));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn essential_primes(&self) -> Result<Vec<EssentialPrime<I, O>>, MinimizationError> {
+        let ni = self.num_inputs();
+        let no = self.num_outputs();
+        let esp = Espresso::try_new_mv(&cube_layout(self), no, None)?;
+        let (f, d) = function_sets(&esp, self, &self.complemented_outputs())?;
+        let primes = esp.try_primes(&f, d.as_ref())?;
+        let (essential, witnesses) = esp.try_essential_primes(&primes, d.as_ref())?;
+
+        let inputs = self.input_symbols();
+        let outputs = self.output_symbols();
+        Ok(essential
+            .to_cubes(ni, no, CubeType::F)
+            .zip(witnesses.to_cubes(ni, no, CubeType::F))
+            .map(|(prime, witness)| {
+                let (minterm, output) = minterm_point(&witness, inputs, no);
+                EssentialPrime {
+                    prime: rehome_cube(inputs, outputs, prime),
+                    minterm,
+                    output,
+                }
+            })
+            .collect())
+    }
+
+    /// Whether this cover's ON-set and don't-care set cover `subject`'s ON-set, aligned by label.
+    /// The multiple-valued layout is kept when `mv_agrees` and `subject` adds no input.
+    fn contains(
+        &self,
+        subject: &Cover<I, O>,
+        mv_agrees: bool,
+    ) -> Result<Verdict<I>, MinimizationError> {
+        let inputs = union_inputs(self.input_symbols(), subject.input_symbols());
+        // The subject's outputs come first, so a witness output is already one of its positions.
+        let (outputs, subject_map, container_map) =
+            overlay_outputs(subject.output_symbols(), self.output_symbols());
+        let no = outputs.arity();
+        let mv = if mv_agrees && inputs.arity() == self.num_inputs() {
+            self.mv.clone()
+        } else {
+            None
+        };
+        let mut container = aligned(self, &inputs, &outputs, &container_map);
+        let mut tested = aligned(subject, &inputs, &outputs, &subject_map);
+        container.mv = mv.clone();
+        tested.mv = mv;
+        let remap = |complemented: Vec<usize>, map: &[usize]| -> Vec<usize> {
+            complemented.into_iter().map(|j| map[j]).collect()
+        };
+
+        let esp = Espresso::try_new_mv(&cube_layout(&container), no, None)?;
+        let a = on_set(
+            &esp,
+            &tested,
+            &remap(subject.complemented_outputs(), &subject_map),
+        )?;
+        let (b, c) = function_sets(
+            &esp,
+            &container,
+            &remap(self.complemented_outputs(), &container_map),
+        )?;
+        Ok(match esp.try_uncovered(Some(&a), &b, c.as_ref())? {
+            None => Verdict::Holds,
+            Some(witness) => {
+                let (minterm, output) = witness_point(&witness, &inputs, no);
+                Verdict::Fails { minterm, output }
+            }
+        })
+    }
+}
+
+/// `cover`'s cubes re-homed onto `inputs` (by identity) and onto `outputs`, where `cover`'s output
+/// `j` lands on `out_map[j]`. The multiple-valued layout is copied from `cover`; a caller whose
+/// `inputs` add a column must reset it.
+pub(super) fn aligned<I: Label, O: Label>(
+    cover: &Cover<I, O>,
+    inputs: &Arc<Symbols<I>>,
+    outputs: &Arc<Symbols<O>>,
+    out_map: &[usize],
+) -> Cover<I, O> {
+    let no = outputs.arity();
+    let cubes = cover
+        .cubes
+        .iter()
+        .map(|c| {
+            Cube::new(
+                c.inputs().project_onto(inputs),
+                assert_mask(c, outputs, no, no, |old| out_map[old]),
+                c.cube_type(),
+            )
+        })
+        .collect();
+    let mut aligned = Cover::from_parts(
+        Arc::clone(inputs),
+        Arc::clone(outputs),
+        cubes,
+        cover.cover_type,
+    );
+    aligned.mv = cover.mv.clone();
+    aligned
+}
+
+/// The ON-set and don't-care set of the function `cover` implements. An `FR` cover's don't-care
+/// set is what neither its ON-set nor its OFF-set covers, and each output in `complemented` is
+/// flipped back to its ON-set.
+pub(super) fn function_sets<I: Label, O: Label>(
+    esp: &Espresso,
+    cover: &Cover<I, O>,
+    complemented: &[usize],
+) -> Result<(EspressoCover, Option<EspressoCover>), MinimizationError> {
+    let (f, d, r) = marshal_sets(cover)?;
+    let d = match d {
+        None if cover.cover_type.has_r() => Some(esp.try_offset(&f, r.as_ref())?),
+        d => d,
+    };
+    if complemented.is_empty() {
+        return Ok((f, d));
+    }
+    let positive: Vec<bool> = (0..cover.num_outputs())
+        .map(|j| !complemented.contains(&j))
+        .collect();
+    let (f, _) = esp.try_apply_output_phase(&f, d.as_ref(), r.as_ref(), &positive)?;
+    Ok((f, d))
+}
+
+/// The ON-set of the function `cover` implements: its `F` cubes, with each output in
+/// `complemented` flipped back from the complement.
+pub(super) fn on_set<I: Label, O: Label>(
+    esp: &Espresso,
+    cover: &Cover<I, O>,
+    complemented: &[usize],
+) -> Result<EspressoCover, MinimizationError> {
+    if complemented.is_empty() {
+        Ok(marshal_sets(cover)?.0)
+    } else {
+        Ok(function_sets(esp, cover, complemented)?.0)
+    }
+}
+
+/// The minterm and output a one-cube witness cover names, the minterm over `inputs`.
+fn witness_point<I: Label>(
+    witness: &EspressoCover,
+    inputs: &Arc<Symbols<I>>,
+    no: usize,
+) -> (Minterm<I>, usize) {
+    let cube = witness
+        .to_cubes(inputs.arity(), no, CubeType::F)
+        .next()
+        .expect("a witness cover holds one cube");
+    minterm_point(&cube, inputs, no)
+}
+
+/// The minterm and output of a minterm cube Espresso returned, the minterm over `inputs`.
+fn minterm_point<I: Label>(
+    cube: &Cube<Anonymous, Anonymous>,
+    inputs: &Arc<Symbols<I>>,
+    no: usize,
+) -> (Minterm<I>, usize) {
+    let minterm =
+        Minterm::from_packed_words(Arc::clone(inputs), Arc::clone(cube.inputs().packed()));
+    let output = (0..no)
+        .find(|&j| cube.outputs().value_at(j))
+        .expect("the witness names one output");
+    (minterm, output)
+}
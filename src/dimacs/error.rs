@@ -0,0 +1,240 @@
+//! Error types for DIMACS CNF parsing and writing
+
+use std::fmt;
+use std::io;
+use std::sync::Arc;
+
+use crate::cover::IndexOutOfRange;
+
+/// Errors related to DIMACS CNF parsing and validation
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DIMACSError {
+    /// A clause appears before the `p cnf` problem line, or the file has none.
+    MissingProblemLine,
+    /// The problem line is not `p cnf <variables> <clauses>`.
+    InvalidProblemLine {
+        /// The line as read
+        line: Arc<str>,
+    },
+    /// The file has a second problem line.
+    DuplicateProblemLine,
+    /// A clause holds something other than an integer literal.
+    InvalidToken {
+        /// The token as read
+        token: Arc<str>,
+    },
+    /// A literal names a variable beyond the count the problem line declares.
+    InvalidLiteral {
+        /// The offending literal
+        literal: i64,
+    },
+    /// The last clause is not terminated by `0`.
+    UnterminatedClause,
+    /// The file holds a different number of clauses than the problem line declares.
+    ClauseCountMismatch {
+        /// Clauses the problem line declares
+        declared: usize,
+        /// Clauses the file holds
+        found: usize,
+    },
+    /// Two `c var` lines give different variables the same name.
+    DuplicateName {
+        /// The shared name
+        name: Arc<str>,
+    },
+}
+
+impl fmt::Display for DIMACSError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DIMACSError::MissingProblemLine => {
+                write!(f, "DIMACS data has no 'p cnf' line before its clauses")
+            }
+            DIMACSError::InvalidProblemLine { line } => {
+                write!(f, "Invalid DIMACS problem line: '{}'", line)
+            }
+            DIMACSError::DuplicateProblemLine => {
+                write!(f, "DIMACS data has more than one problem line")
+            }
+            DIMACSError::InvalidToken { token } => write!(f, "Invalid DIMACS literal: '{}'", token),
+            DIMACSError::InvalidLiteral { literal } => write!(
+                f,
+                "Literal {} names a variable the problem line does not declare",
+                literal
+            ),
+            DIMACSError::UnterminatedClause => write!(f, "The last clause is not terminated by 0"),
+            DIMACSError::ClauseCountMismatch { declared, found } => write!(
+                f,
+                "Problem line declares {} clauses but the data holds {}",
+                declared, found
+            ),
+            DIMACSError::DuplicateName { name } => {
+                write!(f, "More than one variable is named '{}'", name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for DIMACSError {}
+
+impl From<DIMACSError> for io::Error {
+    fn from(err: DIMACSError) -> Self {
+        io::Error::new(io::ErrorKind::InvalidData, err)
+    }
+}
+
+/// Errors that can occur when reading DIMACS CNF data
+///
+/// This error type is returned by the `Cnf::from_dimacs_*` methods.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum DIMACSReadError {
+    /// DIMACS format error
+    DIMACS(DIMACSError),
+    /// IO error during reading
+    Io(io::Error),
+}
+
+impl fmt::Display for DIMACSReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DIMACSReadError::DIMACS(e) => write!(f, "DIMACS format error: {}", e),
+            DIMACSReadError::Io(e) => write!(f, "IO error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for DIMACSReadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DIMACSReadError::DIMACS(e) => Some(e),
+            DIMACSReadError::Io(e) => Some(e),
+        }
+    }
+}
+
+impl From<DIMACSError> for DIMACSReadError {
+    fn from(err: DIMACSError) -> Self {
+        DIMACSReadError::DIMACS(err)
+    }
+}
+
+impl From<io::Error> for DIMACSReadError {
+    fn from(err: io::Error) -> Self {
+        DIMACSReadError::Io(err)
+    }
+}
+
+impl From<DIMACSReadError> for io::Error {
+    fn from(err: DIMACSReadError) -> Self {
+        match err {
+            DIMACSReadError::Io(e) => e,
+            DIMACSReadError::DIMACS(e) => io::Error::new(io::ErrorKind::InvalidData, e),
+        }
+    }
+}
+
+/// Errors that can occur when building or writing DIMACS CNF data
+///
+/// This error type is returned by [`Cnf::from_cover`](crate::dimacs::Cnf::from_cover) and the
+/// [`DIMACSWriter`](crate::DIMACSWriter) methods.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum DIMACSWriteError {
+    /// The cover has multiple-valued inputs (see [`Cover::mv_layout`](crate::Cover::mv_layout)),
+    /// which CNF's Boolean variables cannot carry.
+    MultipleValuedInputs,
+    /// The requested output is not one of the cover's.
+    OutputOutOfRange(IndexOutOfRange),
+    /// A variable name contains a line break, which a comment line cannot hold.
+    InvalidName {
+        /// The name as given
+        name: Arc<str>,
+    },
+    /// IO error during writing
+    Io(io::Error),
+}
+
+impl fmt::Display for DIMACSWriteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DIMACSWriteError::MultipleValuedInputs => {
+                write!(f, "CNF cannot express a cover with multiple-valued inputs")
+            }
+            DIMACSWriteError::OutputOutOfRange(e) => write!(f, "output {}", e),
+            DIMACSWriteError::InvalidName { name } => {
+                write!(f, "DIMACS variable name {:?} contains a line break", name)
+            }
+            DIMACSWriteError::Io(e) => write!(f, "IO error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for DIMACSWriteError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DIMACSWriteError::OutputOutOfRange(e) => Some(e),
+            DIMACSWriteError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<IndexOutOfRange> for DIMACSWriteError {
+    fn from(err: IndexOutOfRange) -> Self {
+        DIMACSWriteError::OutputOutOfRange(err)
+    }
+}
+
+impl From<io::Error> for DIMACSWriteError {
+    fn from(err: io::Error) -> Self {
+        DIMACSWriteError::Io(err)
+    }
+}
+
+impl From<DIMACSWriteError> for io::Error {
+    fn from(err: DIMACSWriteError) -> Self {
+        match err {
+            DIMACSWriteError::Io(e) => e,
+            e => io::Error::new(io::ErrorKind::InvalidInput, e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dimacs_error_messages() {
+        let err = DIMACSError::ClauseCountMismatch {
+            declared: 3,
+            found: 2,
+        };
+        assert!(err.to_string().contains("declares 3"));
+        assert!(err.to_string().contains("holds 2"));
+        let err = DIMACSError::InvalidToken {
+            token: Arc::from("x"),
+        };
+        assert!(err.to_string().contains("'x'"));
+        let err = DIMACSWriteError::OutputOutOfRange(IndexOutOfRange { index: 2, arity: 1 });
+        assert!(err.to_string().starts_with("output index 2"));
+    }
+
+    #[test]
+    fn test_dimacs_read_error_conversions() {
+        let read_err: DIMACSReadError = DIMACSError::MissingProblemLine.into();
+        assert!(matches!(read_err, DIMACSReadError::DIMACS(_)));
+        let io_err: io::Error = read_err.into();
+        assert_eq!(io_err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_dimacs_write_error_to_io_error() {
+        let io_err: io::Error = DIMACSWriteError::MultipleValuedInputs.into();
+        assert_eq!(io_err.kind(), io::ErrorKind::InvalidInput);
+        let write_err: DIMACSWriteError = io::Error::other("disk full").into();
+        assert!(matches!(write_err, DIMACSWriteError::Io(_)));
+    }
+}
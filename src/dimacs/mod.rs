@@ -0,0 +1,515 @@
+//! DIMACS CNF support
+//!
+//! This module exchanges Boolean functions with SAT solvers and model counters in DIMACS CNF, the
+//! format of the SAT competitions: a `p cnf <variables> <clauses>` problem line, then clauses of
+//! signed variable numbers, each ending in `0`. A [`Cnf`] holds one such formula.
+//!
+//! - [`Cnf::from_bdd`] encodes a [`Bdd`] with one Tseitin variable per decision node, so the formula
+//!   stays linear in the diagram; its models, restricted to the input variables, are the
+//!   function's ON-set.
+//! - [`Cnf::from_cover`] encodes one output of a [`Cover`] as the complement of its OFF-set: each
+//!   OFF-set cube becomes the clause that excludes it, so no extra variables are needed.
+//! - [`DIMACSWriter`] writes a [`Cnf`], or a [`Bdd`] directly.
+//! - [`Cnf::from_dimacs_reader`] (and the `_string`/`_file` forms) reads a formula, and
+//!   [`BddBuilder::build_cnf`] builds it into a [`Bdd`], where it can be counted
+//!   ([`Bdd::count_minterms`]) and minimised ([`Bdd::minimize`]).
+//!
+//! # Variable Names
+//!
+//! DIMACS variables are numbers. To keep names across a round trip, the writer adds a comment line
+//! `c var <n> <name>` for each named variable, and the reader takes names from such lines; solvers
+//! ignore comments. A variable without one, which includes the Tseitin variables, is called `x<n>`,
+//! prefixed with `_` until no named variable has that name. Two variables may not share a name:
+//! [`BddBuilder::build_cnf`] would take them for one.
+//!
+//! # Quick Example
+//!
+//! ```
+//! use espresso_logic::dimacs::Cnf;
+//! use espresso_logic::{bdd_builder, DIMACSWriter};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let builder = bdd_builder!();
+//! let f = builder.parse("(a | b) & !c")?;
+//!
+//! let text = f.to_dimacs_string()?;
+//! let cnf = Cnf::from_dimacs_string(&text)?;
+//! let models = builder.build_cnf(&cnf);
+//!
+//! // The Tseitin variables are determined by the inputs, so the models are f's ON-set.
+//! assert_eq!(models.count_minterms(cnf.num_vars()), f.count_minterms(3));
+//! assert!(models.exists(cnf.variables().iter().skip(3)).equivalent_to(&f));
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod error;
+
+pub use error::{DIMACSError, DIMACSReadError, DIMACSWriteError};
+
+use std::cell::RefCell;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Cursor, Write};
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::bdd::{Bdd, BddBuilder, BddNode, Brand, ManagerCell};
+use crate::cover::{Cover, CubeType, IndexOutOfRange, InputField, Label};
+use crate::Symbol;
+
+/// A formula in conjunctive normal form over numbered variables
+///
+/// Variables are numbered from 1; a clause is a list of literals, `n` for variable `n` and `-n` for
+/// its negation. See the [module documentation](self).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cnf {
+    /// One name per variable; `variables[n - 1]` names variable `n`.
+    variables: Vec<Symbol>,
+    /// Which variables carry a name of their own, written as `c var` lines.
+    named: Vec<bool>,
+    clauses: Vec<Vec<i32>>,
+}
+
+/// A signal while encoding: a constant, or a literal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Signal {
+    Constant(bool),
+    Literal(i32),
+}
+
+impl std::ops::Not for Signal {
+    type Output = Signal;
+
+    fn not(self) -> Signal {
+        match self {
+            Signal::Constant(value) => Signal::Constant(!value),
+            Signal::Literal(literal) => Signal::Literal(-literal),
+        }
+    }
+}
+
+impl Cnf {
+    /// Number of variables
+    pub fn num_vars(&self) -> usize {
+        self.variables.len()
+    }
+
+    /// Number of clauses
+    pub fn num_clauses(&self) -> usize {
+        self.clauses.len()
+    }
+
+    /// The variable names; the name of variable `n` is at index `n - 1`
+    pub fn variables(&self) -> &[Symbol] {
+        &self.variables
+    }
+
+    /// The clauses, as DIMACS literals
+    pub fn clauses(&self) -> &[Vec<i32>] {
+        &self.clauses
+    }
+
+    /// Encode a [`Bdd`] with Tseitin variables
+    ///
+    /// Variables `1..=k` are the function's support variables, sorted by name; each decision node
+    /// that is not a bare literal gets a further variable, tied to its multiplexer by four clauses,
+    /// and a unit clause asserts the root. A constant `true` is the empty formula, and a constant
+    /// `false` the single empty clause.
+    pub fn from_bdd<B: Brand, C: ManagerCell>(bdd: &Bdd<B, C>) -> Self {
+        let support: BTreeSet<Symbol> = bdd.variables().collect();
+        let cnf = Cnf {
+            named: vec![true; support.len()],
+            variables: support.into_iter().collect(),
+            clauses: Vec::new(),
+        };
+        let inputs: HashMap<Symbol, i32> = cnf
+            .variables
+            .iter()
+            .enumerate()
+            .map(|(n, name)| (name.clone(), n as i32 + 1))
+            .collect();
+
+        // `fold` takes a `Copy` closure, so the formula is reached through a shared `RefCell`.
+        let cell = RefCell::new(cnf);
+        let root = bdd.fold(|node| match node {
+            BddNode::Terminal(value) => Signal::Constant(value),
+            BddNode::Decision {
+                variable,
+                low,
+                high,
+            } => {
+                let v = Signal::Literal(inputs[variable]);
+                match (low, high) {
+                    (Signal::Constant(false), Signal::Constant(true)) => v,
+                    (Signal::Constant(true), Signal::Constant(false)) => !v,
+                    _ => {
+                        let mut cnf = cell.borrow_mut();
+                        let n = cnf.fresh_variable(&inputs);
+                        cnf.add_clause(&[!v, !high, n]);
+                        cnf.add_clause(&[!v, high, !n]);
+                        cnf.add_clause(&[v, !low, n]);
+                        cnf.add_clause(&[v, low, !n]);
+                        n
+                    }
+                }
+            }
+        });
+        let mut cnf = cell.into_inner();
+        cnf.add_clause(&[root]);
+        cnf
+    }
+
+    /// Encode output `output` of a cover as the complement of its OFF-set
+    ///
+    /// Variable `i + 1` is input `i`, named by its label. The OFF-set is, in order of preference:
+    /// the cover's own cubes when the output is complemented (see
+    /// [`Cover::complemented_outputs`]), its OFF-set (`R`) cubes when the cover type has them, and
+    /// otherwise the complement of its ON-set and don't-care cubes, computed through a BDD. The
+    /// formula's models are then the points the output may take as `1`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DIMACSWriteError::OutputOutOfRange`] if `output` is not an output index, and
+    /// [`DIMACSWriteError::MultipleValuedInputs`] for a cover with multiple-valued inputs.
+    pub fn from_cover<I: Label + AsRef<str>, O: Label>(
+        cover: &Cover<I, O>,
+        output: usize,
+    ) -> Result<Self, DIMACSWriteError> {
+        if output >= cover.num_outputs() {
+            return Err(IndexOutOfRange {
+                index: output,
+                arity: cover.num_outputs(),
+            }
+            .into());
+        }
+        if cover.mv_layout().is_some_and(|l| !l.mv_sizes().is_empty()) {
+            return Err(DIMACSWriteError::MultipleValuedInputs);
+        }
+        let labels = cover.input_labels();
+        let mut cnf = Cnf {
+            variables: labels.iter().map(|l| Symbol::from(l.as_ref())).collect(),
+            named: vec![true; labels.len()],
+            clauses: Vec::new(),
+        };
+
+        // Each OFF-set cube as its input fields; an empty field covers nothing and adds no clause.
+        let cubes_of = |set: CubeType| -> Vec<Vec<InputField>> {
+            cover
+                .cubes()
+                .filter(|cube| cube.set == set && cube.asserts(output))
+                .map(|cube| cube.inputs.fields().collect())
+                .collect()
+        };
+        let off_set = if cover.complemented_outputs().contains(&output) {
+            cubes_of(CubeType::F)
+        } else if cover.cover_type.has_r() {
+            cubes_of(CubeType::R)
+        } else {
+            let builder = crate::bdd_builder!();
+            let on_set = builder.scope(|s| {
+                let mut acc = s.constant(false);
+                for cube in cover.cubes() {
+                    if cube.set == CubeType::R || !cube.asserts(output) {
+                        continue;
+                    }
+                    let mut term = s.constant(true);
+                    for (i, field) in cube.inputs.fields().enumerate() {
+                        match field {
+                            InputField::Zero => term = term & !s.var(labels[i].as_ref()),
+                            InputField::One => term = term & s.var(labels[i].as_ref()),
+                            InputField::DontCare => {}
+                            InputField::Empty => term = s.constant(false),
+                        }
+                    }
+                    acc = acc | term;
+                }
+                acc
+            });
+            let position: HashMap<&str, usize> = labels
+                .iter()
+                .enumerate()
+                .map(|(i, label)| (label.as_ref(), i))
+                .collect();
+            (!on_set)
+                .cover()
+                .cubes()
+                .map(|cube| {
+                    let mut fields = vec![InputField::DontCare; labels.len()];
+                    for (name, value) in cube.inputs().vars().iter().zip(cube.inputs().iter()) {
+                        fields[position[name.as_ref()]] = match value {
+                            Some(true) => InputField::One,
+                            Some(false) => InputField::Zero,
+                            None => InputField::DontCare,
+                        };
+                    }
+                    fields
+                })
+                .collect()
+        };
+
+        for fields in off_set {
+            if fields.contains(&InputField::Empty) {
+                continue;
+            }
+            let clause: Vec<i32> = fields
+                .iter()
+                .enumerate()
+                .filter_map(|(i, field)| match field {
+                    InputField::One => Some(-(i as i32 + 1)),
+                    InputField::Zero => Some(i as i32 + 1),
+                    _ => None,
+                })
+                .collect();
+            cnf.clauses.push(clause);
+        }
+        Ok(cnf)
+    }
+
+    /// Read a formula from a reader
+    pub fn from_dimacs_reader<R: BufRead>(reader: R) -> Result<Self, DIMACSReadError> {
+        parse_dimacs(reader)
+    }
+
+    /// Read a formula from a string
+    pub fn from_dimacs_string<T: AsRef<str>>(s: T) -> Result<Self, DIMACSReadError> {
+        Self::from_dimacs_reader(Cursor::new(s.as_ref().as_bytes()))
+    }
+
+    /// Read a formula from a file
+    pub fn from_dimacs_file<P: AsRef<Path>>(path: P) -> Result<Self, DIMACSReadError> {
+        Self::from_dimacs_reader(BufReader::new(File::open(path)?))
+    }
+
+    /// Add an unnamed variable, called clear of the `inputs`, and return its literal.
+    fn fresh_variable(&mut self, inputs: &HashMap<Symbol, i32>) -> Signal {
+        let n = self.variables.len() + 1;
+        self.variables
+            .push(unnamed(n, |name| inputs.contains_key(name)));
+        self.named.push(false);
+        Signal::Literal(n as i32)
+    }
+
+    /// Add a clause over signals: a true constant satisfies it, so it is dropped, and false
+    /// constants are left out.
+    fn add_clause(&mut self, signals: &[Signal]) {
+        if signals.contains(&Signal::Constant(true)) {
+            return;
+        }
+        self.clauses.push(
+            signals
+                .iter()
+                .filter_map(|signal| match signal {
+                    Signal::Literal(literal) => Some(*literal),
+                    Signal::Constant(_) => None,
+                })
+                .collect(),
+        );
+    }
+}
+
+impl<B: Brand, C: ManagerCell> BddBuilder<B, C> {
+    /// Build a [`Cnf`] into a [`Bdd`] in this builder: the AND of its clauses
+    ///
+    /// Variable `n` resolves by its name ([`Cnf::variables`]), like
+    /// [`build_cover`](Self::build_cover). The result depends only on the variables the clauses
+    /// mention, so count its models over [`Cnf::num_vars`] with
+    /// [`Bdd::count_minterms`](crate::Bdd::count_minterms).
+    #[must_use]
+    pub fn build_cnf(&self, cnf: &Cnf) -> Bdd<B, C> {
+        self.scope(|s| {
+            let mut acc = s.constant(true);
+            for clause in &cnf.clauses {
+                let mut sum = s.constant(false);
+                for &literal in clause {
+                    let var = s.var(&cnf.variables[literal.unsigned_abs() as usize - 1]);
+                    sum = sum | if literal < 0 { !var } else { var };
+                }
+                acc = acc & sum;
+            }
+            acc
+        })
+    }
+}
+
+/// Trait for types that support DIMACS CNF serialisation (writing)
+///
+/// Implemented for [`Cnf`], and for [`Bdd`] through [`Cnf::from_bdd`]. A cover has one formula per
+/// output, so it is written through [`Cnf::from_cover`].
+pub trait DIMACSWriter {
+    /// Write this formula in DIMACS CNF
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DIMACSWriteError::InvalidName`] if a variable name contains a line break, and
+    /// [`DIMACSWriteError::Io`] if writing fails.
+    fn write_dimacs<W: Write>(&self, writer: &mut W) -> Result<(), DIMACSWriteError>;
+
+    /// Convert this formula to a DIMACS string
+    ///
+    /// This is a convenience method that delegates to `write_dimacs`.
+    fn to_dimacs_string(&self) -> Result<String, DIMACSWriteError> {
+        let mut buffer = Vec::new();
+        self.write_dimacs(&mut buffer)?;
+        Ok(String::from_utf8(buffer).expect("DIMACS output is built from UTF-8 Rust strings"))
+    }
+
+    /// Write this formula to a DIMACS file
+    fn to_dimacs_file<P: AsRef<Path>>(&self, path: P) -> Result<(), DIMACSWriteError> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_dimacs(&mut writer)?;
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+impl DIMACSWriter for Cnf {
+    fn write_dimacs<W: Write>(&self, writer: &mut W) -> Result<(), DIMACSWriteError> {
+        let named = self
+            .variables
+            .iter()
+            .zip(&self.named)
+            .enumerate()
+            .filter(|(_, (_, &named))| named);
+        for (n, (name, _)) in named.clone() {
+            if name.contains(['\n', '\r']) {
+                return Err(DIMACSWriteError::InvalidName {
+                    name: Arc::from(name.as_ref()),
+                });
+            }
+            writeln!(writer, "c var {} {}", n + 1, name)?;
+        }
+        writeln!(
+            writer,
+            "p cnf {} {}",
+            self.variables.len(),
+            self.clauses.len()
+        )?;
+        for clause in &self.clauses {
+            for literal in clause {
+                write!(writer, "{} ", literal)?;
+            }
+            writeln!(writer, "0")?;
+        }
+        Ok(())
+    }
+}
+
+impl<B: Brand, C: ManagerCell> DIMACSWriter for Bdd<B, C> {
+    fn write_dimacs<W: Write>(&self, writer: &mut W) -> Result<(), DIMACSWriteError> {
+        Cnf::from_bdd(self).write_dimacs(writer)
+    }
+}
+
+/// The name of unnamed variable `n`: `x<n>`, prefixed with `_` until `taken` holds no such name.
+fn unnamed(n: usize, taken: impl Fn(&str) -> bool) -> Symbol {
+    let mut name = format!("x{}", n);
+    while taken(&name) {
+        name.insert(0, '_');
+    }
+    Symbol::from(name)
+}
+
+/// Parse DIMACS CNF text.
+fn parse_dimacs<R: BufRead>(reader: R) -> Result<Cnf, DIMACSReadError> {
+    let mut declared: Option<(usize, usize)> = None;
+    let mut names: HashMap<usize, Symbol> = HashMap::new();
+    let mut clauses: Vec<Vec<i32>> = Vec::new();
+    let mut clause: Vec<i32> = Vec::new();
+
+    for line in reader.lines() {
+        let line = line?;
+        let trimmed = line.trim();
+        // SATLIB files end their data with a `%` line.
+        if trimmed == "%" {
+            break;
+        }
+        if trimmed.is_empty() {
+            continue;
+        }
+        if let Some(comment) = trimmed.strip_prefix('c') {
+            let mut fields = comment.trim_start().splitn(3, ' ');
+            if let (Some("var"), Some(n), Some(name)) =
+                (fields.next(), fields.next(), fields.next())
+            {
+                if let Ok(n) = n.parse() {
+                    names.insert(n, Symbol::from(name));
+                }
+            }
+            continue;
+        }
+        if trimmed.starts_with('p') {
+            if declared.is_some() {
+                return Err(DIMACSError::DuplicateProblemLine.into());
+            }
+            let fields: Vec<&str> = trimmed.split_ascii_whitespace().collect();
+            let counts = match fields[..] {
+                ["p", "cnf", vars, clauses] => vars.parse::<usize>().ok().zip(clauses.parse().ok()),
+                _ => None,
+            };
+            match counts {
+                Some((vars, clauses)) if vars <= i32::MAX as usize => {
+                    declared = Some((vars, clauses));
+                }
+                _ => {
+                    return Err(DIMACSError::InvalidProblemLine {
+                        line: Arc::from(trimmed),
+                    }
+                    .into())
+                }
+            }
+            continue;
+        }
+
+        let (num_vars, _) = declared.ok_or(DIMACSError::MissingProblemLine)?;
+        for token in trimmed.split_ascii_whitespace() {
+            let literal: i64 = token.parse().map_err(|_| DIMACSError::InvalidToken {
+                token: Arc::from(token),
+            })?;
+            if literal == 0 {
+                clauses.push(std::mem::take(&mut clause));
+            } else if literal.unsigned_abs() > num_vars as u64 {
+                return Err(DIMACSError::InvalidLiteral { literal }.into());
+            } else {
+                clause.push(literal as i32);
+            }
+        }
+    }
+
+    if !clause.is_empty() {
+        return Err(DIMACSError::UnterminatedClause.into());
+    }
+    let (num_vars, num_clauses) = declared.ok_or(DIMACSError::MissingProblemLine)?;
+    if clauses.len() != num_clauses {
+        return Err(DIMACSError::ClauseCountMismatch {
+            declared: num_clauses,
+            found: clauses.len(),
+        }
+        .into());
+    }
+    let mut taken: HashSet<&str> = HashSet::new();
+    for n in 1..=num_vars {
+        if let Some(name) = names.get(&n) {
+            if !taken.insert(name.as_ref()) {
+                return Err(DIMACSError::DuplicateName {
+                    name: Arc::from(name.as_ref()),
+                }
+                .into());
+            }
+        }
+    }
+    let (variables, named) = (1..=num_vars)
+        .map(|n| match names.get(&n) {
+            Some(name) => (name.clone(), true),
+            None => (unnamed(n, |name| taken.contains(name)), false),
+        })
+        .unzip();
+    Ok(Cnf {
+        variables,
+        named,
+        clauses,
+    })
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,211 @@
+//! Tests for DIMACS CNF reading and writing.
+
+use super::*;
+use crate::{expr, CoverType, Minimizable};
+
+/// `f = a & b | c` over inputs `a b c`, with its OFF-set listed explicitly when `fr` is set.
+fn sample_pla(cover_type: &str) -> String {
+    format!(".i 3\n.o 1\n.ilb a b c\n.ob f\n.type {cover_type}\n11- 1\n--1 1\n0-0 0\n-00 0\n.e\n")
+}
+
+fn read_cover(text: &str) -> Cover<Symbol, Symbol> {
+    match crate::PlaCover::<Symbol>::from_pla_string(text).unwrap() {
+        crate::PlaCover::InputsOutputsNamed(cover) => cover,
+        other => panic!("expected a named cover, got {other:?}"),
+    }
+}
+
+#[test]
+fn bdd_is_written_with_tseitin_variables() {
+    let builder = crate::bdd_builder!();
+    let f = builder.parse("a & b").unwrap();
+    // A literal node needs no variable of its own; the `a` node does.
+    assert_eq!(
+        f.to_dimacs_string().unwrap(),
+        "c var 1 a\nc var 2 b\np cnf 3 4\n-1 -2 3 0\n-1 2 -3 0\n1 -3 0\n3 0\n"
+    );
+
+    // Constants need no variables: true is the empty formula, false the empty clause.
+    assert_eq!(
+        builder.constant(true).to_dimacs_string().unwrap(),
+        "p cnf 0 0\n"
+    );
+    assert_eq!(
+        builder.constant(false).to_dimacs_string().unwrap(),
+        "p cnf 0 1\n0\n"
+    );
+    assert_eq!(
+        (!builder.var("a")).to_dimacs_string().unwrap(),
+        "c var 1 a\np cnf 1 1\n-1 0\n"
+    );
+}
+
+#[test]
+fn tseitin_models_project_onto_the_function() {
+    let builder = crate::bdd_builder!();
+    for text in ["a ^ b ^ c", "a & b | a & c | b & c", "(a | !d) & (b ^ c)"] {
+        let f = builder.parse(text).unwrap();
+        let cnf = Cnf::from_dimacs_string(f.to_dimacs_string().unwrap()).unwrap();
+        let support = f.var_count();
+        assert_eq!(&cnf.variables()[..support], {
+            let mut names: Vec<Symbol> = f.variables().collect();
+            names.sort();
+            names
+        });
+
+        // Every Tseitin variable is fixed by the inputs, so the model count is unchanged.
+        let models = builder.build_cnf(&cnf);
+        assert_eq!(
+            models.count_minterms(cnf.num_vars()),
+            f.count_minterms(support),
+            "{text}"
+        );
+        assert!(models
+            .exists(cnf.variables()[support..].iter())
+            .equivalent_to(&f));
+    }
+}
+
+#[test]
+fn tseitin_variables_are_named_clear_of_the_inputs() {
+    let builder = crate::bdd_builder!();
+    let f = builder.parse("a ^ b ^ x4").unwrap();
+    let cnf = Cnf::from_bdd(&f);
+    // The fourth variable would be `x4`, which an input already has.
+    assert_eq!(
+        &cnf.variables()[..4],
+        ["a", "b", "x4", "_x4"].map(Symbol::from)
+    );
+
+    let cnf = Cnf::from_dimacs_string(f.to_dimacs_string().unwrap()).unwrap();
+    let models = builder.build_cnf(&cnf);
+    assert_eq!(models.count_minterms(cnf.num_vars()), f.count_minterms(3));
+    assert!(models.exists(cnf.variables()[3..].iter()).equivalent_to(&f));
+}
+
+#[test]
+fn cover_off_set_comes_from_the_complement() {
+    let cover = read_cover(&sample_pla("f"));
+    let cnf = Cnf::from_cover(&cover, 0).unwrap();
+    assert_eq!(cnf.variables(), ["a", "b", "c"]);
+    assert_eq!(cnf.num_vars(), 3);
+
+    let builder = crate::bdd_builder!();
+    let f = builder.build_cnf(&cnf);
+    assert!(f.equivalent_to(&builder.parse("a & b | c").unwrap()));
+    assert_eq!(f.count_minterms(3), 5);
+}
+
+#[test]
+fn cover_off_set_comes_from_r_cubes() {
+    let cover = read_cover(&sample_pla("fr"));
+    let cnf = Cnf::from_cover(&cover, 0).unwrap();
+    // One clause per OFF-set cube: `0-0` excludes `!a & !c`, `-00` excludes `!b & !c`.
+    assert_eq!(cnf.clauses(), [vec![1, 3], vec![2, 3]]);
+    assert_eq!(
+        cnf.to_dimacs_string().unwrap(),
+        "c var 1 a\nc var 2 b\nc var 3 c\np cnf 3 2\n1 3 0\n2 3 0\n"
+    );
+}
+
+#[test]
+fn complemented_outputs_are_their_own_off_set() {
+    let mut cover = Cover::new(CoverType::F);
+    cover.add_expr(&expr!("a" + "b" + "c"), "f").unwrap();
+    let min = cover
+        .with_output_phase([false])
+        .unwrap()
+        .minimize()
+        .unwrap();
+    assert_eq!(min.complemented_outputs(), vec![0]);
+    let cnf = Cnf::from_cover(&min, 0).unwrap();
+    // The complemented cover is the single cube `!a & !b & !c`.
+    assert_eq!(cnf.clauses(), [vec![1, 2, 3]]);
+}
+
+#[test]
+fn cover_export_rejects_bad_requests() {
+    let cover = read_cover(&sample_pla("f"));
+    assert!(matches!(
+        Cnf::from_cover(&cover, 1),
+        Err(DIMACSWriteError::OutputOutOfRange(IndexOutOfRange {
+            index: 1,
+            arity: 1
+        }))
+    ));
+    let paired = cover.pair_inputs(&[(0, 1)]).unwrap();
+    assert!(matches!(
+        Cnf::from_cover(&paired, 0),
+        Err(DIMACSWriteError::MultipleValuedInputs)
+    ));
+
+    let builder = crate::bdd_builder!();
+    assert!(matches!(
+        builder.var("two\nlines").to_dimacs_string(),
+        Err(DIMACSWriteError::InvalidName { name }) if &*name == "two\nlines"
+    ));
+}
+
+#[test]
+fn reader_accepts_comments_split_clauses_and_satlib_trailers() {
+    let text = "c a comment\nc var 2 b\np cnf 3 2\n1 -2\n 0 3\n0\n%\n0\n";
+    let cnf = Cnf::from_dimacs_string(text).unwrap();
+    assert_eq!(cnf.variables(), ["x1", "b", "x3"]);
+    assert_eq!(cnf.clauses(), [vec![1, -2], vec![3]]);
+    // Only the named variable gets a `c var` line back.
+    assert_eq!(
+        cnf.to_dimacs_string().unwrap(),
+        "c var 2 b\np cnf 3 2\n1 -2 0\n3 0\n"
+    );
+
+    let builder = crate::bdd_builder!();
+    let f = builder.build_cnf(&cnf);
+    assert!(f.equivalent_to(&builder.parse("(x1 | !b) & x3").unwrap()));
+    assert_eq!(f.count_minterms(cnf.num_vars()), 3);
+
+    // An unnamed variable steps aside for a named one that took its default name.
+    let cnf = Cnf::from_dimacs_string("c var 1 x2\np cnf 2 1\n1 2 0\n").unwrap();
+    assert_eq!(cnf.variables(), ["x2", "_x2"]);
+}
+
+#[test]
+fn reader_rejects_malformed_data() {
+    let err = |s: &str| match Cnf::from_dimacs_string(s) {
+        Err(DIMACSReadError::DIMACS(e)) => e,
+        other => panic!("expected a DIMACS error, got {other:?}"),
+    };
+    assert_eq!(err("1 0\n"), DIMACSError::MissingProblemLine);
+    assert_eq!(err(""), DIMACSError::MissingProblemLine);
+    assert!(matches!(
+        err("p dnf 1 1\n"),
+        DIMACSError::InvalidProblemLine { .. }
+    ));
+    assert!(matches!(
+        err("p cnf 1\n"),
+        DIMACSError::InvalidProblemLine { .. }
+    ));
+    assert_eq!(
+        err("p cnf 1 0\np cnf 1 0\n"),
+        DIMACSError::DuplicateProblemLine
+    );
+    assert!(matches!(
+        err("p cnf 1 1\n1 x 0\n"),
+        DIMACSError::InvalidToken { .. }
+    ));
+    assert_eq!(
+        err("p cnf 1 1\n-2 0\n"),
+        DIMACSError::InvalidLiteral { literal: -2 }
+    );
+    assert_eq!(err("p cnf 1 1\n1\n"), DIMACSError::UnterminatedClause);
+    assert_eq!(
+        err("c var 1 a\nc var 2 a\np cnf 2 0\n"),
+        DIMACSError::DuplicateName { name: "a".into() }
+    );
+    assert_eq!(
+        err("p cnf 1 2\n1 0\n"),
+        DIMACSError::ClauseCountMismatch {
+            declared: 2,
+            found: 1
+        }
+    );
+}
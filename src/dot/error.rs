@@ -0,0 +1,59 @@
+//! Error types for Graphviz DOT writing
+
+use std::fmt;
+use std::io;
+
+/// Errors that can occur when writing Graphviz DOT data
+///
+/// This error type is returned by the [`DOTWriter`](crate::DOTWriter) methods
+/// (`write_dot`, `to_dot_string`, `to_dot_file`).
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum DOTWriteError {
+    /// IO error during writing
+    Io(io::Error),
+}
+
+impl fmt::Display for DOTWriteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DOTWriteError::Io(e) => write!(f, "IO error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for DOTWriteError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DOTWriteError::Io(e) => Some(e),
+        }
+    }
+}
+
+impl From<io::Error> for DOTWriteError {
+    fn from(err: io::Error) -> Self {
+        DOTWriteError::Io(err)
+    }
+}
+
+impl From<DOTWriteError> for io::Error {
+    fn from(err: DOTWriteError) -> Self {
+        match err {
+            // DOTWriteError only contains IO errors, so return it directly
+            DOTWriteError::Io(e) => e,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dot_write_error_round_trips_io_error() {
+        let write_err: DOTWriteError = io::Error::other("disk full").into();
+        assert!(write_err.to_string().contains("disk full"));
+        let io_err: io::Error = write_err.into();
+        assert_eq!(io_err.kind(), io::ErrorKind::Other);
+    }
+}
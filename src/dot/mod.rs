@@ -0,0 +1,303 @@
+//! Graphviz DOT support
+//!
+//! This module draws binary decision diagrams as [Graphviz](https://graphviz.org) DOT, for
+//! inspecting variable orders and sharing. A [`DotGraph`] holds one drawing.
+//!
+//! - [`DotGraph::from_bdd`] draws a single [`Bdd`].
+//! - [`DotGraph::from_bdds`] draws several named roots of one [`BddBuilder`](crate::BddBuilder)
+//!   together; a node they share appears once, so [`DotGraph::num_nodes`] against the sum of
+//!   [`Bdd::node_count`] shows how much the builder shared.
+//! - [`DOTWriter`] writes a [`DotGraph`], or a [`Bdd`] directly.
+//!
+//! # Drawing
+//!
+//! Each decision node is a circle labelled with its variable's name, with a solid edge to its
+//! `high` child and a dashed edge to its `low` child. The terminals are boxes `0` and `1`, drawn
+//! only when reached. Nodes testing the same variable share a rank, so the variable order reads from
+//! top to bottom, and each named root is a plain-text label with an edge to its node.
+//!
+//! # Quick Example
+//!
+//! ```
+//! use espresso_logic::dot::DotGraph;
+//! use espresso_logic::{bdd_builder, DOTWriter};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let builder = bdd_builder!();
+//! let f = builder.parse("a & b | c")?;
+//! let g = builder.parse("b | c")?;
+//!
+//! // `g` is `f`'s `b` node, so together they need no node beyond `f`'s.
+//! let graph = DotGraph::from_bdds([("f", &f), ("g", &g)]);
+//! assert_eq!(graph.num_nodes(), f.node_count() + g.node_count() - 4);
+//!
+//! let dot = graph.to_dot_string()?;
+//! assert!(dot.starts_with("digraph bdd {"));
+//! assert!(dot.contains("style=dashed"));
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod error;
+
+pub use error::DOTWriteError;
+
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use crate::bdd::{Bdd, BddNode, Brand, ManagerCell};
+use crate::Symbol;
+
+/// A drawing of one or more decision diagrams
+///
+/// See the [module documentation](self).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DotGraph {
+    /// Decision nodes, each child before its parents.
+    nodes: Vec<DotNode>,
+    /// The drawn roots; an unnamed root (from [`DotGraph::from_bdd`]) gets no label.
+    roots: Vec<(Option<Symbol>, Vertex)>,
+}
+
+/// A node of the drawing: a terminal, or an index into `DotGraph::nodes`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Vertex {
+    Terminal(bool),
+    Node(usize),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct DotNode {
+    variable: Symbol,
+    low: Vertex,
+    high: Vertex,
+}
+
+impl std::fmt::Display for Vertex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Vertex::Terminal(value) => write!(f, "t{}", u8::from(*value)),
+            Vertex::Node(index) => write!(f, "n{}", index),
+        }
+    }
+}
+
+impl DotGraph {
+    /// Draw a single [`Bdd`], without a root label
+    pub fn from_bdd<B: Brand, C: ManagerCell>(bdd: &Bdd<B, C>) -> Self {
+        let mut graph = DotGraph {
+            nodes: Vec::new(),
+            roots: Vec::new(),
+        };
+        let mut unique = HashMap::new();
+        let root = graph.add(bdd, &mut unique);
+        graph.roots.push((None, root));
+        graph
+    }
+
+    /// Draw named [`Bdd`] roots together, one label per root
+    ///
+    /// Each root is folded with [`Bdd::fold`], and decision nodes are kept unique by variable and
+    /// children across all roots. Within one builder that is exactly the builder's own node
+    /// identity, so the drawing shows the sharing the builder achieved.
+    pub fn from_bdds<'a, S: AsRef<str>, B: Brand + 'a, C: ManagerCell + 'a>(
+        roots: impl IntoIterator<Item = (S, &'a Bdd<B, C>)>,
+    ) -> Self {
+        let mut graph = DotGraph {
+            nodes: Vec::new(),
+            roots: Vec::new(),
+        };
+        let mut unique = HashMap::new();
+        for (name, root) in roots {
+            let vertex = graph.add(root, &mut unique);
+            graph
+                .roots
+                .push((Some(Symbol::from(name.as_ref())), vertex));
+        }
+        graph
+    }
+
+    /// Number of distinct nodes drawn, including the terminals reached
+    ///
+    /// For a single root this is [`Bdd::node_count`].
+    pub fn num_nodes(&self) -> usize {
+        let terminals = self.terminals();
+        self.nodes.len() + terminals.iter().filter(|&&reached| reached).count()
+    }
+
+    /// Number of roots drawn
+    pub fn num_roots(&self) -> usize {
+        self.roots.len()
+    }
+
+    /// Fold `bdd` into the drawing, reusing the nodes already in `unique`.
+    fn add<B: Brand, C: ManagerCell>(
+        &mut self,
+        bdd: &Bdd<B, C>,
+        unique: &mut HashMap<(Symbol, Vertex, Vertex), usize>,
+    ) -> Vertex {
+        // `fold` takes a `Copy` closure, so the node table is reached through a shared `RefCell`.
+        let state = RefCell::new((&mut self.nodes, unique));
+        bdd.fold(|node| match node {
+            BddNode::Terminal(value) => Vertex::Terminal(value),
+            BddNode::Decision {
+                variable,
+                low,
+                high,
+            } => {
+                let (nodes, unique) = &mut *state.borrow_mut();
+                let variable = Symbol::from(variable);
+                let index = *unique
+                    .entry((variable.clone(), low, high))
+                    .or_insert_with(|| {
+                        nodes.push(DotNode {
+                            variable,
+                            low,
+                            high,
+                        });
+                        nodes.len() - 1
+                    });
+                Vertex::Node(index)
+            }
+        })
+    }
+
+    /// Which terminals, `[false, true]`, some edge or root reaches.
+    fn terminals(&self) -> [bool; 2] {
+        let mut reached = [false; 2];
+        let targets = self
+            .nodes
+            .iter()
+            .flat_map(|node| [node.low, node.high])
+            .chain(self.roots.iter().map(|(_, vertex)| *vertex));
+        for vertex in targets {
+            if let Vertex::Terminal(value) = vertex {
+                reached[usize::from(value)] = true;
+            }
+        }
+        reached
+    }
+}
+
+/// Quote a name as a DOT string, escaping quotes, backslashes and line breaks.
+fn quote(name: &str) -> String {
+    let mut quoted = String::with_capacity(name.len() + 2);
+    quoted.push('"');
+    for c in name.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => {}
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Trait for types that support Graphviz DOT serialisation (writing)
+///
+/// Implemented for [`DotGraph`], and for [`Bdd`] through [`DotGraph::from_bdd`]. Several roots are
+/// drawn together through [`DotGraph::from_bdds`].
+pub trait DOTWriter {
+    /// Write this diagram as a Graphviz DOT digraph
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DOTWriteError::Io`] if writing fails.
+    fn write_dot<W: Write>(&self, writer: &mut W) -> Result<(), DOTWriteError>;
+
+    /// Convert this diagram to a DOT string
+    ///
+    /// This is a convenience method that delegates to `write_dot`.
+    fn to_dot_string(&self) -> Result<String, DOTWriteError> {
+        let mut buffer = Vec::new();
+        self.write_dot(&mut buffer)?;
+        Ok(String::from_utf8(buffer).expect("DOT output is built from UTF-8 Rust strings"))
+    }
+
+    /// Write this diagram to a DOT file
+    fn to_dot_file<P: AsRef<Path>>(&self, path: P) -> Result<(), DOTWriteError> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_dot(&mut writer)?;
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+impl DOTWriter for DotGraph {
+    fn write_dot<W: Write>(&self, writer: &mut W) -> Result<(), DOTWriteError> {
+        writeln!(writer, "digraph bdd {{")?;
+        writeln!(writer, "  node [shape=circle];")?;
+
+        // Rank groups in order of first appearance, so the output is deterministic.
+        let mut ranks: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+        let mut first: HashMap<&Symbol, usize> = HashMap::new();
+        for (index, node) in self.nodes.iter().enumerate() {
+            let rank = *first.entry(&node.variable).or_insert(index);
+            ranks.entry(rank).or_default().push(index);
+            writeln!(
+                writer,
+                "  {} [label={}];",
+                Vertex::Node(index),
+                quote(&node.variable)
+            )?;
+            writeln!(writer, "  {} -> {};", Vertex::Node(index), node.high)?;
+            writeln!(
+                writer,
+                "  {} -> {} [style=dashed];",
+                Vertex::Node(index),
+                node.low
+            )?;
+        }
+        for members in ranks.values().filter(|members| members.len() > 1) {
+            write!(writer, "  {{ rank=same;")?;
+            for &index in members {
+                write!(writer, " {};", Vertex::Node(index))?;
+            }
+            writeln!(writer, " }}")?;
+        }
+
+        let terminals = self.terminals();
+        for value in [false, true] {
+            if terminals[usize::from(value)] {
+                writeln!(
+                    writer,
+                    "  {} [shape=box, label=\"{}\"];",
+                    Vertex::Terminal(value),
+                    u8::from(value)
+                )?;
+            }
+        }
+        if terminals == [true, true] {
+            writeln!(writer, "  {{ rank=sink; t0; t1; }}")?;
+        }
+
+        for (index, (name, vertex)) in self.roots.iter().enumerate() {
+            if let Some(name) = name {
+                writeln!(
+                    writer,
+                    "  r{} [shape=plaintext, label={}];",
+                    index,
+                    quote(name)
+                )?;
+                writeln!(writer, "  r{} -> {};", index, vertex)?;
+            }
+        }
+        writeln!(writer, "}}")?;
+        Ok(())
+    }
+}
+
+impl<B: Brand, C: ManagerCell> DOTWriter for Bdd<B, C> {
+    fn write_dot<W: Write>(&self, writer: &mut W) -> Result<(), DOTWriteError> {
+        DotGraph::from_bdd(self).write_dot(writer)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,66 @@
+//! Tests for Graphviz DOT writing.
+
+use super::*;
+
+#[test]
+fn bdd_is_drawn_with_dashed_low_edges() {
+    let builder = crate::bdd_builder!();
+    let f = builder.parse("a & b").unwrap();
+    assert_eq!(
+        f.to_dot_string().unwrap(),
+        "digraph bdd {\n  node [shape=circle];\n\
+         \x20 n0 [label=\"b\"];\n  n0 -> t1;\n  n0 -> t0 [style=dashed];\n\
+         \x20 n1 [label=\"a\"];\n  n1 -> n0;\n  n1 -> t0 [style=dashed];\n\
+         \x20 t0 [shape=box, label=\"0\"];\n  t1 [shape=box, label=\"1\"];\n\
+         \x20 { rank=sink; t0; t1; }\n}\n"
+    );
+    assert_eq!(DotGraph::from_bdd(&f).num_nodes(), f.node_count());
+
+    // A constant is its terminal alone.
+    let dot = builder.constant(false).to_dot_string().unwrap();
+    assert_eq!(
+        dot,
+        "digraph bdd {\n  node [shape=circle];\n  t0 [shape=box, label=\"0\"];\n}\n"
+    );
+}
+
+#[test]
+fn shared_nodes_are_drawn_once() {
+    let builder = crate::bdd_builder!();
+    let f = builder.parse("a & b | c").unwrap();
+    let g = builder.parse("b | c").unwrap();
+    let h = builder.parse("!c").unwrap();
+
+    let graph = DotGraph::from_bdds([("f", &f), ("g", &g), ("h", &h)]);
+    assert_eq!(graph.num_roots(), 3);
+    // `g` is inside `f`; `h`'s single node is not, since its children are swapped.
+    assert_eq!(graph.num_nodes(), f.node_count() + 1);
+
+    let dot = graph.to_dot_string().unwrap();
+    assert_eq!(dot.matches("[label=\"b\"]").count(), 1);
+    assert_eq!(dot.matches("[label=\"c\"]").count(), 2);
+    assert!(dot.contains("{ rank=same; n0; n3; }"));
+    for (index, name) in ["f", "g", "h"].iter().enumerate() {
+        assert!(dot.contains(&format!("r{index} [shape=plaintext, label=\"{name}\"];")));
+    }
+    assert!(dot.contains("r1 -> n1;"));
+
+    // The same root drawn twice shares everything.
+    let twice = DotGraph::from_bdds([("f", &f), ("f2", &f)]);
+    assert_eq!(twice.num_nodes(), f.node_count());
+}
+
+#[test]
+fn names_are_quoted() {
+    assert_eq!(quote("plain"), "\"plain\"");
+    assert_eq!(quote("say \"hi\""), "\"say \\\"hi\\\"\"");
+    assert_eq!(quote("a\\b"), "\"a\\\\b\"");
+    assert_eq!(quote("two\nlines"), "\"two\\nlines\"");
+
+    let builder = crate::bdd_builder!();
+    let f = builder.parse("x").unwrap();
+    let dot = DotGraph::from_bdds([("out \"1\"", &f)])
+        .to_dot_string()
+        .unwrap();
+    assert!(dot.contains("label=\"out \\\"1\\\"\""));
+}
@@ -28,6 +28,8 @@
 //! - [`DuplicateLabel`] - A labelled cube/cover constructor was handed a repeated input or output label
 //! - [`IndexOutOfRange`] - A positional `Minterm`/`OutputSet` setter was given an out-of-range index
 //! - [`LabelNotFound`] - A by-label `Minterm`/`OutputSet` setter was given a label absent from the row
+//! - [`VerifyError`] - A cover failed verification against the function it was minimised from
+//! - [`EquivalenceError`] - Two covers differ, with a distinguishing minterm
 //!
 //! ## Expression Module Errors
 //!
@@ -42,8 +44,8 @@
 
 // Re-export error types from submodules for backward compatibility
 pub use crate::cover::error::{
-    AddExprError, ArityMismatch, CoverError, DuplicateLabel, IndexOutOfRange, LabelNotFound,
-    RelabelError, ToExprError,
+    AddExprError, ArityMismatch, CoverError, DuplicateLabel, EquivalenceError, IndexOutOfRange,
+    LabelNotFound, RelabelError, ToExprError, VerifyError,
 };
 pub use crate::cover::pla::error::{PLAError, PLAReadError, PLAWriteError};
 pub use crate::espresso::error::{CubeError, InstanceError, MinimizationError};
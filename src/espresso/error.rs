@@ -12,6 +12,9 @@ use std::io;
 #[non_exhaustive]
 pub enum InstanceError {
     /// The requested dimensions don't match the existing thread-local instance
+    ///
+    /// Also returned when the dimensions agree but the grouping of the inputs into
+    /// multiple-valued variables (see [`MvLayout`](crate::MvLayout)) differs.
     DimensionMismatch {
         /// The requested dimensions (num_inputs, num_outputs)
         requested: (usize, usize),
@@ -184,6 +187,15 @@ pub enum MinimizationError {
         /// stderr before exiting).
         message: String,
     },
+    /// Minimisation was abandoned because the configuration's
+    /// [`deadline`](crate::EspressoConfig::deadline) passed or its
+    /// [`cancellation`](crate::EspressoConfig::cancellation) token was cancelled. Everything the C
+    /// core had allocated for the call has been released, and the thread can run further
+    /// minimisations.
+    Cancelled {
+        /// `true` if the deadline passed, `false` if the token was cancelled.
+        timed_out: bool,
+    },
 }
 
 impl From<CoverError> for MinimizationError {
@@ -207,6 +219,12 @@ impl fmt::Display for MinimizationError {
             MinimizationError::EspressoFatal { message } => {
                 write!(f, "Espresso reported a fatal error: {}", message)
             }
+            MinimizationError::Cancelled { timed_out: true } => {
+                write!(f, "minimisation timed out")
+            }
+            MinimizationError::Cancelled { timed_out: false } => {
+                write!(f, "minimisation was cancelled")
+            }
         }
     }
 }
@@ -220,6 +238,7 @@ impl std::error::Error for MinimizationError {
             MinimizationError::Io(e) => Some(e),
             MinimizationError::NonOrthogonal { .. } => None,
             MinimizationError::EspressoFatal { .. } => None,
+            MinimizationError::Cancelled { .. } => None,
         }
     }
 }
@@ -257,6 +276,14 @@ impl From<MinimizationError> for io::Error {
             e @ MinimizationError::EspressoFatal { .. } => {
                 io::Error::new(io::ErrorKind::InvalidData, e.to_string())
             }
+            e @ MinimizationError::Cancelled { timed_out } => {
+                let kind = if timed_out {
+                    io::ErrorKind::TimedOut
+                } else {
+                    io::ErrorKind::Interrupted
+                };
+                io::Error::new(kind, e.to_string())
+            }
         }
     }
 }
@@ -402,4 +429,14 @@ mod tests {
         assert!(matches!(min_err, MinimizationError::Instance(_)));
         assert!(min_err.source().is_some());
     }
+
+    #[test]
+    fn test_minimization_error_cancelled_to_io_error() {
+        let err = MinimizationError::Cancelled { timed_out: true };
+        assert_eq!(err.to_string(), "minimisation timed out");
+        let io_err: io::Error = err.into();
+        assert_eq!(io_err.kind(), io::ErrorKind::TimedOut);
+        let io_err: io::Error = MinimizationError::Cancelled { timed_out: false }.into();
+        assert_eq!(io_err.kind(), io::ErrorKind::Interrupted);
+    }
 }
@@ -376,15 +376,18 @@
 
 pub mod error;
 
-use crate::cover::{Anonymous, Minterm, OutputSet, Symbols};
+use crate::cover::{Anonymous, Minterm, MvLayout, OutputSet, Symbols};
 pub use crate::cover::{Cube, CubeType};
 use crate::sys;
 pub use error::{CubeError, InstanceError, MinimizationError};
+use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
-use std::os::raw::{c_char, c_int};
+use std::os::raw::{c_char, c_int, c_void};
 use std::ptr;
 use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use std::time::Instant;
 
 /// A single Espresso cube word, matching `espresso_word` in the generated bindings.
 /// `espresso.h` derives the word width from the native machine word via `UINTPTR_MAX`, so this
@@ -493,14 +496,17 @@ pub struct EspressoCubes<'a> {
     cube_type: CubeType,
     /// Cube word stride (`set_family.wsize`), snapshotted once — constant for the cover's life.
     wsize: usize,
-    /// Bit offset of the first output field (`num_inputs * 2`).
+    /// Bit offset of the first output field (`num_binary * 2` plus the multiple-valued part sizes).
     output_start: usize,
     /// `u64` words per decoded input minterm (`num_inputs.div_ceil(32)`).
     input_u64_words: usize,
-    /// BPI-wide cube words spanning the input region (`(2 * num_inputs).div_ceil(BPI)`).
+    /// BPI-wide cube words spanning the binary input region (`(2 * num_binary).div_ceil(BPI)`).
     input_cube_words: usize,
-    /// Total input bit-width (`2 * num_inputs`), the packing bound for the last `u64`.
+    /// Binary input bit-width (`2 * num_binary`), the packing bound for the word-copy.
     total_input_bits: usize,
+    /// `(first C bit, first minterm position, size)` of each multiple-valued variable of the
+    /// instance; empty for an all-binary cover.
+    mv_parts: Vec<(usize, usize, usize)>,
     /// Next cube index to decode, in `0..count`.
     idx: usize,
     /// Total cube count, snapshotted at construction.
@@ -552,6 +558,15 @@ impl EspressoCubes<'_> {
             }
             *slot = word;
         }
+        // Each multiple-valued part is one bit per value in C; spread it onto its one-hot minterm
+        // positions (`10` = value in the set, `01` = not).
+        for &(first_bit, first_pos, size) in &self.mv_parts {
+            for j in 0..size {
+                let pos = first_pos + j;
+                let field: u64 = if bit_at(first_bit + j) { 0b10 } else { 0b01 };
+                iwords[pos / 32] |= field << ((pos % 32) * 2);
+            }
+        }
         let im = Minterm::from_packed_words(Arc::clone(&self.input_syms), iwords.into());
 
         // Decode the output membership — one C bit per output, the same 1-bit-per-output packing as
@@ -840,13 +855,19 @@ impl EspressoCover {
     /// `ceil(num_inputs / 32)`), `output_assertions[i]` is whether output `i` is asserted. Empty (`?`,
     /// `00`) input fields are copied verbatim, so they reach C as the empty literal with no recoding.
     ///
+    /// The first `layout.num_binary()` positions are binary variables; the remaining positions are
+    /// the multiple-valued variables of `layout`, each stored one-hot (see [`MvLayout`]) and
+    /// scattered onto one C bit per value.
+    ///
     /// Crate-internal: used by the high-level cover minimisation path. The public `from_cubes` stays.
     pub(crate) fn from_packed_cubes(
         cubes: &[(&[u64], &[bool])],
-        num_inputs: usize,
+        layout: &MvLayout,
         num_outputs: usize,
     ) -> Result<Self, MinimizationError> {
-        let espresso = Espresso::try_new(num_inputs, num_outputs, None)?;
+        let espresso = Espresso::try_new_mv(layout, num_outputs, None)?;
+        let (num_binary, mv_sizes) = (layout.num_binary(), layout.mv_sizes());
+        let num_inputs = espresso.num_inputs();
         let cube_size = unsafe { (*sys::get_cube()).size as usize };
 
         let ptr = check_alloc(
@@ -858,10 +879,11 @@ impl EspressoCover {
             _espresso: espresso.inner,
         };
 
-        // Number of cube words the input region (bits `0..2*num_inputs`) spans; copying is bounded by
-        // this so we never run past the cube or clobber the word the input region may share with the
-        // start of the output region.
-        let input_cube_words = (2 * num_inputs).div_ceil(BPI);
+        // Number of cube words the binary input region (bits `0..2*num_binary`) spans; copying is
+        // bounded by this so we never run past the cube or clobber the word the binary region may
+        // share with the start of the multiple-valued or output region.
+        let binary_bits = 2 * num_binary;
+        let input_cube_words = binary_bits.div_ceil(BPI);
         // Each input minterm packs 32 variables (2 bits each) per `u64` word.
         const VARS_PER_U64: usize = 32;
         let expected_u64_words = num_inputs.div_ceil(VARS_PER_U64);
@@ -885,12 +907,34 @@ impl EspressoCover {
                 // encoding as a C cube, so no recoding. Each `u64` is sliced into `cube_words_per_u64`
                 // BPI-wide chunks; masking to BPI bits keeps it correct even if `CubeWord` is wider than
                 // BPI. `set_clear` zeroed the cube, so `|=` is a copy that leaves the (possibly shared)
-                // boundary word's output bits untouched — they are set below.
+                // boundary word's output bits untouched — they are set below. The boundary word is
+                // masked to the binary region: past it the minterm holds one-hot multiple-valued
+                // positions, which are not in C's layout (zero for an all-binary cover).
                 for w in 0..input_cube_words {
                     let mword = input_words[w / cube_words_per_u64];
-                    let chunk =
-                        ((mword >> ((w % cube_words_per_u64) * BPI)) & BPI_MASK) as CubeWord;
-                    *cf.add(w + 1) |= chunk;
+                    let mut chunk = (mword >> ((w % cube_words_per_u64) * BPI)) & BPI_MASK;
+                    let valid = binary_bits - w * BPI;
+                    if valid < BPI {
+                        chunk &= (1u64 << valid) - 1;
+                    }
+                    *cf.add(w + 1) |= chunk as CubeWord;
+                }
+
+                // Scatter each multiple-valued variable: one C bit per value, set where the one-hot
+                // position allows `1` (the odd bit of its 2-bit field).
+                let mut pos = num_binary;
+                for (var, &size) in mv_sizes.iter().enumerate() {
+                    let first = *(*sys::get_cube()).first_part.add(num_binary + var) as usize;
+                    for j in 0..size {
+                        let p = pos + j;
+                        if (input_words[p / VARS_PER_U64] >> ((p % VARS_PER_U64) * 2 + 1)) & 1 != 0
+                        {
+                            let bit_pos = first + j;
+                            *cf.add((bit_pos >> LOGBPI) + 1) |=
+                                (1 as CubeWord) << (bit_pos & (BPI - 1));
+                        }
+                    }
+                    pos += size;
                 }
 
                 // Set one bit per asserted output at `output_first + i` (the C WHICH_WORD/WHICH_BIT).
@@ -1005,6 +1049,17 @@ impl EspressoCover {
         // rather than recomputing them (and re-dereferencing the C `set_family`) on every cube.
         let count = unsafe { (*self.ptr).count as usize };
         let wsize = unsafe { (*self.ptr).wsize as usize };
+        // The C layout is the instance's: trailing inputs grouped into multiple-valued variables
+        // decode one-hot, one position per value.
+        let mv_sizes = self._espresso.layout.mv_sizes();
+        let num_binary = num_inputs.saturating_sub(mv_sizes.iter().sum());
+        let mut mv_parts = Vec::with_capacity(mv_sizes.len());
+        let (mut first_bit, mut first_pos) = (2 * num_binary, num_binary);
+        for &size in mv_sizes.iter() {
+            mv_parts.push((first_bit, first_pos, size));
+            first_bit += size;
+            first_pos += size;
+        }
         EspressoCubes {
             cover: self,
             input_syms: Symbols::<Anonymous>::anonymous(num_inputs),
@@ -1012,10 +1067,11 @@ impl EspressoCover {
             num_outputs,
             cube_type,
             wsize,
-            output_start: num_inputs * 2,
+            output_start: first_bit,
             input_u64_words: num_inputs.div_ceil(32),
-            input_cube_words: (2 * num_inputs).div_ceil(BPI),
-            total_input_bits: 2 * num_inputs,
+            input_cube_words: (2 * num_binary).div_ceil(BPI),
+            total_input_bits: 2 * num_binary,
+            mv_parts,
             idx: 0,
             count,
         }
@@ -1222,7 +1278,7 @@ impl EspressoCover {
 
 // Thread-local singleton to ensure only one Espresso instance per thread
 // Uses Weak to allow clean destruction when all Espresso handles are dropped
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 thread_local! {
     static ESPRESSO_INSTANCE: RefCell<std::rc::Weak<InnerEspresso>> = const { RefCell::new(std::rc::Weak::new()) };
 }
@@ -1259,8 +1315,12 @@ thread_local! {
 /// 3. **Cleanup**: When the last `Rc` is dropped, `Drop` implementation cleans up C state
 #[derive(Debug)]
 struct InnerEspresso {
+    /// Input positions: the binary variables plus one per value of each multiple-valued variable.
     num_inputs: usize,
     num_outputs: usize,
+    /// Grouping of the input positions into variables; all-binary unless the instance was made for
+    /// a multiple-valued cover.
+    layout: MvLayout,
     config: EspressoConfig,
     initialized: bool,
     // Make this type !Send and !Sync since it manages thread-local state
@@ -1483,20 +1543,47 @@ impl Espresso {
         num_outputs: usize,
         config: Option<&EspressoConfig>,
     ) -> Result<Self, MinimizationError> {
-        // `cube_setup` casts the dimensions to signed `c_int`, allocates `num_inputs + 1` variables,
-        // and accumulates `cube.size = 2*num_inputs + num_outputs` (cubestr.c) — every one of these as
-        // `c_int`. Any of them overflowing would wrap negative and abort the process inside
-        // `cube_setup`. Reject up front (with checked arithmetic, so a pair that is individually in
-        // range but whose `2*num_inputs + num_outputs` sum overflows is still caught) so the safe API
-        // returns an error instead of taking down the process.
+        Self::try_new_mv(&MvLayout::binary(num_inputs), num_outputs, config)
+    }
+
+    /// [`try_new`](Self::try_new) for a cube structure with multiple-valued input variables: the
+    /// binary variables of `layout` followed by one variable per multiple-valued one, then the
+    /// output part. The instance's input count is `layout.num_positions()`. Paired variables (see
+    /// [`MvLayout::num_paired`]) are marked dense, as the C `set_pair` leaves them.
+    ///
+    /// An existing instance is reused only if its grouping is identical; otherwise this is a
+    /// [`InstanceError::DimensionMismatch`]. Crate-internal: the high-level [`Cover`](crate::Cover)
+    /// path is the only producer of multiple-valued cubes.
+    pub(crate) fn try_new_mv(
+        layout: &MvLayout,
+        num_outputs: usize,
+        config: Option<&EspressoConfig>,
+    ) -> Result<Self, MinimizationError> {
+        let (num_binary, mv_sizes) = (layout.num_binary(), layout.mv_sizes());
+        let mv_total = mv_sizes
+            .iter()
+            .try_fold(0usize, |acc, &size| acc.checked_add(size));
+        let num_inputs = mv_total
+            .and_then(|total| total.checked_add(num_binary))
+            .unwrap_or(usize::MAX);
+
+        // `cube_setup` casts the dimensions to signed `c_int`, allocates `num_binary + mv + 1`
+        // variables, and accumulates `cube.size = 2*num_binary + sum(mv_sizes) + num_outputs`
+        // (cubestr.c) — every one of these as `c_int`. Any of them overflowing would wrap negative and
+        // abort the process inside `cube_setup`. Reject up front (with checked arithmetic, so a pair
+        // that is individually in range but whose cube-size sum overflows is still caught) so the safe
+        // API returns an error instead of taking down the process.
         let max_dim = c_int::MAX as usize;
         let fits = num_inputs <= max_dim
             && num_outputs <= max_dim
-            && num_inputs
-                .checked_add(1)
+            && num_binary
+                .checked_add(mv_sizes.len())
+                .and_then(|n| n.checked_add(1))
                 .is_some_and(|num_vars| num_vars <= max_dim)
-            && num_inputs
+            && num_binary
                 .checked_mul(2)
+                .zip(mv_total)
+                .and_then(|(n, total)| n.checked_add(total))
                 .and_then(|n| n.checked_add(num_outputs))
                 .is_some_and(|cube_size| cube_size <= max_dim);
         if !fits {
@@ -1512,7 +1599,10 @@ impl Espresso {
         let inner = ESPRESSO_INSTANCE.with(|instance| {
             if let Some(existing) = instance.borrow().upgrade() {
                 // Check dimensions
-                if existing.num_inputs != num_inputs || existing.num_outputs != num_outputs {
+                if existing.num_inputs != num_inputs
+                    || existing.num_outputs != num_outputs
+                    || existing.layout != *layout
+                {
                     return Err(MinimizationError::Instance(
                         InstanceError::DimensionMismatch {
                             requested: (num_inputs, num_outputs),
@@ -1547,9 +1637,10 @@ impl Espresso {
                     teardown_cube_state();
                 }
 
-                // Initialize the cube structure
-                (*cube).num_binary_vars = num_inputs as c_int;
-                (*cube).num_vars = (num_inputs + 1) as c_int;
+                // Initialize the cube structure: the binary variables, one variable per
+                // multiple-valued input, then the output part.
+                (*cube).num_binary_vars = num_binary as c_int;
+                (*cube).num_vars = (num_binary + mv_sizes.len() + 1) as c_int;
 
                 // Allocate part_size array
                 let part_size_ptr =
@@ -1579,11 +1670,17 @@ impl Espresso {
                 }
                 (*cube).part_size = part_size_ptr;
 
-                // Set the output size
-                *(*cube).part_size.add(num_inputs) = num_outputs as c_int;
+                // Set the multiple-valued part sizes, then the output size
+                for (var, &size) in mv_sizes.iter().enumerate() {
+                    *(*cube).part_size.add(num_binary + var) = size as c_int;
+                }
+                *(*cube).part_size.add(num_binary + mv_sizes.len()) = num_outputs as c_int;
 
                 // Setup cube
                 sys::cube_setup();
+                for var in 0..layout.num_paired() {
+                    *(*cube).sparse.add(num_binary + var) = 0;
+                }
 
                 // Apply custom configuration using accessor functions
                 sys::set_debug(if actual_config.debug { 1 } else { 0 });
@@ -1608,6 +1705,7 @@ impl Espresso {
             let inner = Rc::new(InnerEspresso {
                 num_inputs,
                 num_outputs,
+                layout: layout.clone(),
                 config: actual_config,
                 initialized: true,
                 _marker: PhantomData,
@@ -1801,7 +1899,9 @@ impl Espresso {
     /// # Errors
     ///
     /// Returns [`MinimizationError::EspressoFatal`] if the C minimiser reports a fatal condition for
-    /// the given covers.
+    /// the given covers, or [`MinimizationError::Cancelled`] if the instance's
+    /// [`deadline`](EspressoConfig::deadline) passes or its
+    /// [`cancellation`](EspressoConfig::cancellation) token is cancelled first.
     ///
     /// # Examples
     ///
@@ -1940,6 +2040,69 @@ impl Espresso {
         Ok(unsafe { EspressoCover::from_raw(p_ptr, self) })
     }
 
+    /// Quickly simplify a cover without full minimisation
+    ///
+    /// Runs the C core's unate recursive `simplify` over the ON-set `f`, the operation behind the
+    /// reference tool's `-Dsimplify` mode. It splits on the most binate variable, simplifies each
+    /// cofactor and merges the halves back, much as `complement` does, but never computes an
+    /// OFF-set. The result implements the same function as `f` with no more cubes, though it need
+    /// not be prime or irredundant. This makes it a cheap first pass over a large intermediate
+    /// cover. There is no don't-care set: `simplify` reads the ON-set alone. `f` is read only and
+    /// left unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the C core reports a fatal condition. Use [`try_simplify()`](Self::try_simplify)
+    /// to recover from such inputs as a [`MinimizationError`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use espresso_logic::espresso::{Espresso, EspressoCover, CubeType};
+    /// use espresso_logic::EspressoConfig;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let esp = Espresso::new(2, 1, &EspressoConfig::default());
+    /// // a·b + a·!b is just a.
+    /// let cubes = [(&[1, 1][..], &[1][..]), (&[1, 0][..], &[1][..])];
+    /// let f = EspressoCover::from_cubes(&cubes, 2, 1)?;
+    ///
+    /// let simplified = esp.simplify(&f);
+    /// assert_eq!(simplified.to_cubes(2, 1, CubeType::F).count(), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn simplify(&self, f: &EspressoCover) -> EspressoCover {
+        self.try_simplify(f).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Quickly simplify a cover, returning an error instead of aborting on invalid input.
+    ///
+    /// Fallible counterpart of [`simplify()`](Self::simplify): it returns the same cover but
+    /// surfaces a [`MinimizationError`] where `simplify()` would panic. Like the other low-level
+    /// entry points it performs no input pre-validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MinimizationError::EspressoFatal`] if the C core reports a fatal condition for the
+    /// given cover.
+    pub fn try_simplify(&self, f: &EspressoCover) -> Result<EspressoCover, MinimizationError> {
+        // MEMORY OWNERSHIP: as in `try_primes`, `f` is BORROWED. `cube1list` only builds a pointer
+        // list into its cubes, and `simplify` frees that list and copies any cube it keeps
+        // (espresso-src/compl.c), so F stays owned by the caller's cover.
+        let mut msg: *const c_char = ptr::null();
+        let s_ptr = unsafe {
+            let cube_list = sys::cube1list(f.ptr);
+            sys::guarded_simplify(cube_list, &mut msg)
+        };
+        if s_ptr.is_null() {
+            // Caught fatal: the cube list is leaked, as in `try_primes`.
+            return Err(unsafe { guarded_result_error(msg, "guarded_simplify") });
+        }
+        Ok(unsafe { EspressoCover::from_raw(s_ptr, self) })
+    }
+
     /// Minimise a boolean function using exact minimisation
     ///
     /// This method uses the exact minimisation algorithm which guarantees minimal results
@@ -2016,7 +2179,9 @@ impl Espresso {
     /// # Errors
     ///
     /// Returns [`MinimizationError::EspressoFatal`] if the C minimiser reports a fatal condition for
-    /// the given covers.
+    /// the given covers, or [`MinimizationError::Cancelled`] if the instance's
+    /// [`deadline`](EspressoConfig::deadline) passes or its
+    /// [`cancellation`](EspressoConfig::cancellation) token is cancelled first.
     ///
     /// # Examples
     ///
@@ -2044,6 +2209,611 @@ impl Espresso {
             sys::guarded_minimize_exact(f_ptr, d_ptr, r_ptr, 1, msg)
         })
     }
+
+    /// Minimise a boolean function exactly with the signature cube algorithm
+    ///
+    /// An alternative exact algorithm to [`minimize_exact()`](Self::minimize_exact), the reference
+    /// tool's `-Dsignature` mode (McGeer et al.). Instead of generating every prime implicant, it
+    /// finds the essential signature cubes of the function and generates only the primes that
+    /// contain them, so the covering table it solves is often far smaller. That makes it much
+    /// faster on some multi-output functions with many primes, and slower on others. The result has
+    /// the same, minimum number of cubes, though it need not be the same cover.
+    ///
+    /// Takes and returns the same covers as [`minimize_exact()`](Self::minimize_exact). The
+    /// algorithm works from the OFF-set, so when `r` is `None` it is computed first. Only binary
+    /// inputs are supported: on a cover with multiple-valued inputs the C core's signature code
+    /// would return a wrong cover, so it is rejected as a fatal condition instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the C minimiser reports a fatal condition. Use
+    /// [`try_minimize_signature()`](Self::try_minimize_signature) to recover from such inputs as a
+    /// [`MinimizationError`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use espresso_logic::espresso::{Espresso, EspressoCover, CubeType};
+    /// use espresso_logic::EspressoConfig;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let esp = Espresso::new(2, 1, &EspressoConfig::default());
+    /// // a·b + a·!b + !a·b is a + b.
+    /// let cubes = [
+    ///     (&[1, 1][..], &[1][..]),
+    ///     (&[1, 0][..], &[1][..]),
+    ///     (&[0, 1][..], &[1][..]),
+    /// ];
+    /// let f = EspressoCover::from_cubes(&cubes, 2, 1)?;
+    ///
+    /// let (minimized, _, _) = esp.minimize_signature(&f, None, None);
+    /// assert_eq!(minimized.to_cubes(2, 1, CubeType::F).count(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn minimize_signature(
+        &self,
+        f: &EspressoCover,
+        d: Option<&EspressoCover>,
+        r: Option<&EspressoCover>,
+    ) -> (EspressoCover, EspressoCover, EspressoCover) {
+        self.try_minimize_signature(f, d, r)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Exactly minimise with the signature cube algorithm, returning an error instead of aborting
+    /// on invalid input.
+    ///
+    /// Fallible counterpart of [`minimize_signature()`](Self::minimize_signature): same arguments
+    /// and same `(minimized_f, d, r)` result, but surfacing a [`MinimizationError`] where
+    /// `minimize_signature()` would panic.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MinimizationError::EspressoFatal`] if the C minimiser reports a fatal condition for
+    /// the given covers, or if the instance has multiple-valued inputs, and
+    /// [`MinimizationError::Cancelled`] if the instance's [`deadline`](EspressoConfig::deadline)
+    /// passes or its [`cancellation`](EspressoConfig::cancellation) token is cancelled first.
+    pub fn try_minimize_signature(
+        &self,
+        f: &EspressoCover,
+        d: Option<&EspressoCover>,
+        r: Option<&EspressoCover>,
+    ) -> Result<(EspressoCover, EspressoCover, EspressoCover), MinimizationError> {
+        try_minimize_with_algorithm(self, f, d, r, |f_ptr, d_ptr, r_ptr, msg| unsafe {
+            sys::guarded_signature(f_ptr, d_ptr, r_ptr, msg)
+        })
+    }
+
+    /// Choose an output phase assignment and minimise under it (Sasao's technique, `opo.c`).
+    ///
+    /// Each output may be implemented either as given or in complement — a PLA with output
+    /// inverters can then realise the function from whichever phase needs fewer product terms.
+    /// This builds the double-phase function (every output alongside its complement), minimises it,
+    /// keeps the smallest set of primes that realises one phase of every output, and minimises
+    /// again with that assignment. It is the reference tool's `-Dopo` mode.
+    ///
+    /// Returns `(minimized_f, d, r, positive)`: `positive[j]` is `false` where output `j` was
+    /// complemented, in which case its ON-set and OFF-set have been swapped — `minimized_f` covers
+    /// the complement of that output. `f`, `d` and `r` are read only; `r` is computed as the
+    /// complement of `f ∪ d` when `None`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the C core reports a fatal condition. Use
+    /// [`try_phase_assignment()`](Self::try_phase_assignment) to recover from such inputs as a
+    /// [`MinimizationError`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use espresso_logic::espresso::{CubeType, Espresso, EspressoCover};
+    /// use espresso_logic::{EspressoConfig, PhaseStrategy};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let esp = Espresso::new(3, 2, &EspressoConfig::default());
+    /// // Both outputs are cheaper to implement complemented.
+    /// let cubes = [
+    ///     (&[1, 2, 2][..], &[1, 0][..]),
+    ///     (&[2, 1, 2][..], &[1, 1][..]),
+    ///     (&[2, 2, 1][..], &[0, 1][..]),
+    ///     (&[0, 0, 0][..], &[1, 0][..]),
+    /// ];
+    /// let f = EspressoCover::from_cubes(&cubes, 3, 2)?;
+    ///
+    /// let (minimized, _, _, positive) = esp.phase_assignment(&f, None, None, PhaseStrategy::default());
+    /// assert_eq!(positive, vec![false, false]);
+    /// assert_eq!(minimized.to_cubes(3, 2, CubeType::F).count(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn phase_assignment(
+        &self,
+        f: &EspressoCover,
+        d: Option<&EspressoCover>,
+        r: Option<&EspressoCover>,
+        strategy: PhaseStrategy,
+    ) -> (EspressoCover, EspressoCover, EspressoCover, Vec<bool>) {
+        self.try_phase_assignment(f, d, r, strategy)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Choose an output phase assignment, returning an error instead of aborting on invalid input.
+    ///
+    /// Fallible counterpart of [`phase_assignment()`](Self::phase_assignment). Like the other
+    /// low-level entry points it performs no input pre-validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MinimizationError::EspressoFatal`] if the C core reports a fatal condition for the
+    /// given covers.
+    pub fn try_phase_assignment(
+        &self,
+        f: &EspressoCover,
+        d: Option<&EspressoCover>,
+        r: Option<&EspressoCover>,
+        strategy: PhaseStrategy,
+    ) -> Result<(EspressoCover, EspressoCover, EspressoCover, Vec<bool>), MinimizationError> {
+        let no = self.num_outputs();
+        // OWNERSHIP: as in `try_minimize_with_algorithm`, F and R are handed to C (the phase swap
+        // frees and replaces them) and D is borrowed. On a caught fatal all three are leaked.
+        let f_ptr = f.clone().into_raw();
+        let d_ptr = d
+            .map(|c| c.clone().into_raw())
+            .unwrap_or_else(|| empty_family("try_phase_assignment"));
+        let r_ptr = match r {
+            Some(c) => c.clone().into_raw(),
+            None => guarded_offset(f_ptr, d_ptr)?,
+        };
+
+        let mut positive: Vec<c_int> = vec![1; no];
+        let mut r_out: sys::pset_family = ptr::null_mut();
+        let mut msg: *const c_char = ptr::null();
+        let f_result = unsafe {
+            sys::guarded_phase_assignment(
+                f_ptr,
+                d_ptr,
+                r_ptr,
+                strategy.bits(),
+                &mut r_out,
+                positive.as_mut_ptr(),
+                &mut msg,
+            )
+        };
+        if f_result.is_null() {
+            return Err(unsafe { guarded_result_error(msg, "guarded_phase_assignment") });
+        }
+        Ok(unsafe {
+            (
+                EspressoCover::from_raw(f_result, self),
+                EspressoCover::from_raw(d_ptr, self),
+                EspressoCover::from_raw(r_out, self),
+                positive.into_iter().map(|p| p != 0).collect(),
+            )
+        })
+    }
+
+    /// Swap the ON-set and OFF-set of every output whose `positive` flag is `false` (C `set_phase`),
+    /// returning the new `(f, r)`. `r` is computed as the complement of `f ∪ d` when `None`.
+    pub(crate) fn try_apply_output_phase(
+        &self,
+        f: &EspressoCover,
+        d: Option<&EspressoCover>,
+        r: Option<&EspressoCover>,
+        positive: &[bool],
+    ) -> Result<(EspressoCover, EspressoCover), MinimizationError> {
+        debug_assert_eq!(positive.len(), self.num_outputs());
+        let mut f_ptr = f.clone().into_raw();
+        let mut r_ptr = match r {
+            Some(c) => c.clone().into_raw(),
+            None => {
+                let d_ptr = d
+                    .map(|c| c.clone().into_raw())
+                    .unwrap_or_else(|| empty_family("try_apply_output_phase"));
+                // Keep D wrapped so it is freed on both paths; the offset only borrows it.
+                let d_cover = unsafe { EspressoCover::from_raw(d_ptr, self) };
+                guarded_offset(f_ptr, d_cover.ptr)?
+            }
+        };
+        let flags: Vec<c_int> = positive.iter().map(|&p| c_int::from(p)).collect();
+        unsafe {
+            sys::apply_output_phase(&mut f_ptr, &mut r_ptr, flags.as_ptr());
+            Ok((
+                EspressoCover::from_raw(f_ptr, self),
+                EspressoCover::from_raw(r_ptr, self),
+            ))
+        }
+    }
+}
+
+impl Espresso {
+    /// Search for a pairing of the binary inputs into two-bit decoders (`pair.c`).
+    ///
+    /// A PLA whose inputs pass through two-bit decoders sees each pair of inputs as one 4-valued
+    /// variable, which often needs fewer product terms than the two inputs separately. This runs
+    /// the reference tool's search — `-Dpair` for [`PairingSearch::Estimated`], `-Dpairall` for
+    /// [`PairingSearch::Exhaustive`] — and returns the chosen pairs as zero-based input indices.
+    /// Inputs left out of every pair stay binary; an instance with fewer than two binary inputs
+    /// yields no pairs.
+    ///
+    /// `f`, `d` and `r` are read only; `r` is computed as the complement of `f ∪ d` when `None`.
+    /// The covers stay in this instance's shape: apply the pairing with
+    /// [`Cover::pair_inputs`](crate::Cover::pair_inputs), or use
+    /// [`Cover::find_input_pairing`](crate::Cover::find_input_pairing) to search and minimise the
+    /// paired cover in one step.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the C core reports a fatal condition. Use
+    /// [`try_input_pairing()`](Self::try_input_pairing) to recover from such inputs as a
+    /// [`MinimizationError`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use espresso_logic::espresso::{Espresso, EspressoCover};
+    /// use espresso_logic::{EspressoConfig, PairingSearch};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let esp = Espresso::new(4, 1, &EspressoConfig::default());
+    /// // (a ⊕ c) · (b ⊕ d): each XOR is a single decoder literal once its inputs are paired.
+    /// let cubes = [
+    ///     (&[0, 0, 1, 1][..], &[1][..]),
+    ///     (&[0, 1, 1, 0][..], &[1][..]),
+    ///     (&[1, 0, 0, 1][..], &[1][..]),
+    ///     (&[1, 1, 0, 0][..], &[1][..]),
+    /// ];
+    /// let f = EspressoCover::from_cubes(&cubes, 4, 1)?;
+    ///
+    /// let pairs = esp.input_pairing(&f, None, None, PairingSearch::default());
+    /// assert_eq!(pairs, vec![(0, 2), (1, 3)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn input_pairing(
+        &self,
+        f: &EspressoCover,
+        d: Option<&EspressoCover>,
+        r: Option<&EspressoCover>,
+        search: PairingSearch,
+    ) -> Vec<(usize, usize)> {
+        self.try_input_pairing(f, d, r, search)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Search for an input pairing, returning an error instead of aborting on invalid input.
+    ///
+    /// Fallible counterpart of [`input_pairing()`](Self::input_pairing). Like the other low-level
+    /// entry points it performs no input pre-validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MinimizationError::EspressoFatal`] if the C core reports a fatal condition for the
+    /// given covers.
+    pub fn try_input_pairing(
+        &self,
+        f: &EspressoCover,
+        d: Option<&EspressoCover>,
+        r: Option<&EspressoCover>,
+        search: PairingSearch,
+    ) -> Result<Vec<(usize, usize)>, MinimizationError> {
+        let num_binary = self.inner.layout.num_binary();
+        if num_binary < 2 {
+            return Ok(Vec::new());
+        }
+        // All three covers are borrowed (the search works on copies); the ones made here are
+        // wrapped so they are freed on every path.
+        let d_cover = match d {
+            Some(c) => c.clone(),
+            None => unsafe { EspressoCover::from_raw(empty_family("try_input_pairing"), self) },
+        };
+        let r_cover = match r {
+            Some(c) => c.clone(),
+            None => unsafe { EspressoCover::from_raw(guarded_offset(f.ptr, d_cover.ptr)?, self) },
+        };
+
+        let (exhaustive, strategy) = search.key();
+        let mut var1: Vec<c_int> = vec![0; num_binary / 2];
+        let mut var2: Vec<c_int> = vec![0; num_binary / 2];
+        let mut msg: *const c_char = ptr::null();
+        let count = unsafe {
+            sys::guarded_pairing(
+                f.ptr,
+                d_cover.ptr,
+                r_cover.ptr,
+                c_int::from(exhaustive),
+                strategy,
+                var1.as_mut_ptr(),
+                var2.as_mut_ptr(),
+                &mut msg,
+            )
+        };
+        if count < 0 {
+            return Err(unsafe { espresso_fatal_error(msg) });
+        }
+        Ok(var1
+            .into_iter()
+            .zip(var2)
+            .take(count as usize)
+            .map(|(a, b)| (a as usize, b as usize))
+            .collect())
+    }
+
+    /// Minimise every output on its own, sharing no product terms between outputs (`cvrm.c`).
+    ///
+    /// This is the reference tool's `-Dso` mode, for targets that cannot share a product term
+    /// between outputs: each output's ON-set is minimised as a single-output function, heuristically
+    /// or exactly as `strategy` asks, and every cube of `minimized_f` asserts exactly one output.
+    /// With [`SingleOutputStrategy::both_phases`] it is `-Dso_both`: each output's complement is
+    /// minimised too, and the output is implemented in whichever phase needs fewer cubes.
+    ///
+    /// Returns `(minimized_f, d, r, positive)` as [`phase_assignment()`](Self::phase_assignment)
+    /// does: `positive[j]` is `false` where output `j` was complemented, in which case its ON-set
+    /// and OFF-set have been swapped. `f`, `d` and `r` are read only; `r` is computed as the
+    /// complement of `f ∪ d` when `None`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the C core reports a fatal condition. Use
+    /// [`try_minimize_single_output()`](Self::try_minimize_single_output) to recover from such
+    /// inputs as a [`MinimizationError`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use espresso_logic::espresso::{CubeType, Espresso, EspressoCover};
+    /// use espresso_logic::{EspressoConfig, SingleOutputStrategy};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let esp = Espresso::new(2, 2, &EspressoConfig::default());
+    /// // a·b drives both outputs; minimised apart, each output gets its own copy.
+    /// let cubes = [
+    ///     (&[1, 1][..], &[1, 1][..]),
+    ///     (&[0, 0][..], &[1, 0][..]),
+    /// ];
+    /// let f = EspressoCover::from_cubes(&cubes, 2, 2)?;
+    ///
+    /// let (minimized, _, _, positive) =
+    ///     esp.minimize_single_output(&f, None, None, SingleOutputStrategy::default());
+    /// assert_eq!(positive, vec![true, true]);
+    /// let cubes: Vec<_> = minimized.to_cubes(2, 2, CubeType::F).collect();
+    /// assert_eq!(cubes.len(), 3);
+    /// assert!(cubes.iter().all(|c| c.outputs().iter().filter(|&o| o).count() == 1));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn minimize_single_output(
+        &self,
+        f: &EspressoCover,
+        d: Option<&EspressoCover>,
+        r: Option<&EspressoCover>,
+        strategy: SingleOutputStrategy,
+    ) -> (EspressoCover, EspressoCover, EspressoCover, Vec<bool>) {
+        self.try_minimize_single_output(f, d, r, strategy)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Minimise every output on its own, returning an error instead of aborting on invalid input.
+    ///
+    /// Fallible counterpart of [`minimize_single_output()`](Self::minimize_single_output). Like the
+    /// other low-level entry points it performs no input pre-validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MinimizationError::EspressoFatal`] if the C core reports a fatal condition for the
+    /// given covers.
+    pub fn try_minimize_single_output(
+        &self,
+        f: &EspressoCover,
+        d: Option<&EspressoCover>,
+        r: Option<&EspressoCover>,
+        strategy: SingleOutputStrategy,
+    ) -> Result<(EspressoCover, EspressoCover, EspressoCover, Vec<bool>), MinimizationError> {
+        // F is handed to C (it is freed and replaced by the per-output covers); D and R are only
+        // cofactored, so they are borrowed and wrapped to be freed on every path.
+        let d_cover = match d {
+            Some(c) => c.clone(),
+            None => unsafe {
+                EspressoCover::from_raw(empty_family("try_minimize_single_output"), self)
+            },
+        };
+        let r_cover = match r {
+            Some(c) => c.clone(),
+            None => unsafe { EspressoCover::from_raw(guarded_offset(f.ptr, d_cover.ptr)?, self) },
+        };
+
+        let mut positive: Vec<c_int> = vec![1; self.num_outputs()];
+        let mut msg: *const c_char = ptr::null();
+        let f_result = unsafe {
+            sys::guarded_single_output(
+                f.clone().into_raw(),
+                d_cover.ptr,
+                r_cover.ptr,
+                c_int::from(strategy.both_phases),
+                c_int::from(strategy.exact),
+                positive.as_mut_ptr(),
+                &mut msg,
+            )
+        };
+        if f_result.is_null() {
+            return Err(unsafe { guarded_result_error(msg, "guarded_single_output") });
+        }
+        let f_result = unsafe { EspressoCover::from_raw(f_result, self) };
+        let positive: Vec<bool> = positive.into_iter().map(|p| p != 0).collect();
+
+        // C leaves R as given; a complemented output's OFF-set is its original ON-set.
+        let r_result = if positive.contains(&false) {
+            self.try_apply_output_phase(f, Some(&d_cover), Some(&r_cover), &positive)?
+                .1
+        } else {
+            r_cover
+        };
+        Ok((f_result, d_cover, r_result, positive))
+    }
+
+    /// The `OFF-set` `complement(f ∪ d)` of this instance's covers.
+    pub(crate) fn try_offset(
+        &self,
+        f: &EspressoCover,
+        d: Option<&EspressoCover>,
+    ) -> Result<EspressoCover, MinimizationError> {
+        let d_cover = match d {
+            Some(c) => c.clone(),
+            None => unsafe { EspressoCover::from_raw(empty_family("try_offset"), self) },
+        };
+        Ok(unsafe { EspressoCover::from_raw(guarded_offset(f.ptr, d_cover.ptr)?, self) })
+    }
+
+    /// Check `f ⊆ f_old ∪ d_old` and `f_old ⊆ f ∪ d_old` (C `verify`), returning the first check
+    /// that fails with a one-cube cover holding a witness minterm, or `None` if both hold.
+    pub(crate) fn try_verify(
+        &self,
+        f: &EspressoCover,
+        f_old: &EspressoCover,
+        d_old: Option<&EspressoCover>,
+    ) -> Result<Option<(VerifyCheck, EspressoCover)>, MinimizationError> {
+        let d_cover = match d_old {
+            Some(c) => c.clone(),
+            None => unsafe { EspressoCover::from_raw(empty_family("try_verify"), self) },
+        };
+        let mut witness: sys::pset_family = ptr::null_mut();
+        let mut msg: *const c_char = ptr::null();
+        // All three covers are borrowed: the trampoline only builds cube lists into them.
+        let status =
+            unsafe { sys::guarded_verify(f.ptr, f_old.ptr, d_cover.ptr, &mut witness, &mut msg) };
+        let check = match status {
+            0 => return Ok(None),
+            1 => VerifyCheck::OutsideCareSet,
+            2 => VerifyCheck::Uncovered,
+            _ => return Err(unsafe { espresso_fatal_error(msg) }),
+        };
+        Ok(Some((check, unsafe {
+            EspressoCover::from_raw(witness, self)
+        })))
+    }
+
+    /// Check `a ⊆ b ∪ c` (C `cube_is_covered` on each cube of `a`), returning a one-cube cover
+    /// holding a minterm of `a` outside `b ∪ c`, or `None` if there is none. `a` is the whole space
+    /// when `None`.
+    pub(crate) fn try_uncovered(
+        &self,
+        a: Option<&EspressoCover>,
+        b: &EspressoCover,
+        c: Option<&EspressoCover>,
+    ) -> Result<Option<EspressoCover>, MinimizationError> {
+        let c_cover = match c {
+            Some(c) => c.clone(),
+            None => unsafe { EspressoCover::from_raw(empty_family("try_uncovered"), self) },
+        };
+        let a_ptr = a.map_or(ptr::null_mut(), |a| a.ptr);
+        let mut witness: sys::pset_family = ptr::null_mut();
+        let mut msg: *const c_char = ptr::null();
+        // All three covers are borrowed: the trampoline only builds cube lists into them.
+        let status =
+            unsafe { sys::guarded_covered(a_ptr, b.ptr, c_cover.ptr, &mut witness, &mut msg) };
+        match status {
+            1 => Ok(None),
+            0 => Ok(Some(unsafe { EspressoCover::from_raw(witness, self) })),
+            _ => Err(unsafe { espresso_fatal_error(msg) }),
+        }
+    }
+
+    /// The essential primes of the prime cover `f` with don't-cares `d` (the test of C
+    /// `essen_cube`), each paired with a one-cube cover holding a minterm only it covers.
+    pub(crate) fn try_essential_primes(
+        &self,
+        f: &EspressoCover,
+        d: Option<&EspressoCover>,
+    ) -> Result<(EspressoCover, EspressoCover), MinimizationError> {
+        let d_cover = match d {
+            Some(c) => c.clone(),
+            None => unsafe { EspressoCover::from_raw(empty_family("try_essential_primes"), self) },
+        };
+        let mut witnesses: sys::pset_family = ptr::null_mut();
+        let mut msg: *const c_char = ptr::null();
+        // Both covers are borrowed: the trampoline only joins and reads them.
+        let essential =
+            unsafe { sys::guarded_essential_primes(f.ptr, d_cover.ptr, &mut witnesses, &mut msg) };
+        if essential.is_null() {
+            return Err(unsafe { guarded_result_error(msg, "guarded_essential_primes") });
+        }
+        Ok(unsafe {
+            (
+                EspressoCover::from_raw(essential, self),
+                EspressoCover::from_raw(witnesses, self),
+            )
+        })
+    }
+
+    /// Run one cube-calculus operation of `sharp.c`/`cofactor.c` on `a` and `b`, returning the
+    /// resulting cover. For [`SetOperation::Cofactor`], `b` holds the one cube to cofactor against.
+    pub(crate) fn try_set_operation(
+        &self,
+        op: SetOperation,
+        a: &EspressoCover,
+        b: &EspressoCover,
+    ) -> Result<EspressoCover, MinimizationError> {
+        let mut msg: *const c_char = ptr::null();
+        // Both covers are borrowed: the operations only read them and build a new family.
+        let result = unsafe { sys::guarded_set_operation(op as c_int, a.ptr, b.ptr, &mut msg) };
+        if result.is_null() {
+            return Err(unsafe { guarded_result_error(msg, "guarded_set_operation") });
+        }
+        Ok(unsafe { EspressoCover::from_raw(result, self) })
+    }
+}
+
+/// The check of [`Espresso::try_verify`] that failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum VerifyCheck {
+    /// Some minterm of `f` lies outside `f_old ∪ d_old`.
+    OutsideCareSet,
+    /// Some minterm of `f_old` is not covered by `f ∪ d_old`.
+    Uncovered,
+}
+
+/// The operation of [`Espresso::try_set_operation`], numbered as `guarded_set_operation` expects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SetOperation {
+    /// `a ∩ b` (C `cv_intersect`).
+    Intersect = 0,
+    /// `a # b`, the minterms of `a` outside `b` (C `cv_sharp`).
+    Sharp = 1,
+    /// `a # b` as pairwise disjoint cubes (C `cv_dsharp`).
+    DisjointSharp = 2,
+    /// `a ∪ b`, without cubes another one contains.
+    Union = 3,
+    /// The cofactor of `a` against the first cube of `b` (C `cofactor`).
+    Cofactor = 4,
+}
+
+/// Allocate an empty cube family at the current cube size, for an absent D cover.
+fn empty_family(context: &str) -> sys::pset_family {
+    check_alloc(
+        unsafe { sys::sf_new(0, (*sys::get_cube()).size as c_int) },
+        &format!("sf_new for empty D cover in {context}"),
+    )
+}
+
+/// The OFF-set `complement(f ∪ d)`, computed through the guarded trampoline. `f_ptr` and `d_ptr` are
+/// borrowed: `cube2list` only builds a pointer list into them, which `complement` frees.
+fn guarded_offset(
+    f_ptr: sys::pset_family,
+    d_ptr: sys::pset_family,
+) -> Result<sys::pset_family, MinimizationError> {
+    let mut msg: *const c_char = ptr::null();
+    let r_ptr = unsafe {
+        let cube_list = sys::cube2list(f_ptr, d_ptr);
+        sys::guarded_complement(cube_list, &mut msg)
+    };
+    if r_ptr.is_null() {
+        return Err(unsafe { guarded_result_error(msg, "guarded_complement") });
+    }
+    Ok(r_ptr)
 }
 
 /// Turn a C `fatal` diagnostic captured by a guarded trampoline into a [`MinimizationError`].
@@ -2087,11 +2857,68 @@ unsafe fn guarded_result_error(msg: *const c_char, context: &str) -> Minimizatio
     espresso_fatal_error(msg)
 }
 
-/// Private helper shared by `try_minimize()` and `try_minimize_exact()`.
+/// The deadline and cancellation token of an instance's configuration, polled from the C core's
+/// main loops through `espresso_set_interrupt` while a minimisation runs.
+struct Interrupt<'a> {
+    deadline: Option<Instant>,
+    cancellation: Option<&'a CancellationToken>,
+    /// Set when the hook fires: `Some(true)` if the deadline passed, `Some(false)` if cancelled.
+    fired: Cell<Option<bool>>,
+}
+
+impl<'a> Interrupt<'a> {
+    /// The limits `config` sets, or `None` if it sets neither.
+    fn new(config: &'a EspressoConfig) -> Option<Self> {
+        (config.deadline.is_some() || config.cancellation.is_some()).then(|| Interrupt {
+            deadline: config.deadline,
+            cancellation: config.cancellation.as_ref(),
+            fired: Cell::new(None),
+        })
+    }
+
+    /// The C hook: non-zero once either limit has been reached. `data` is the `Interrupt`.
+    unsafe extern "C" fn poll(data: *mut c_void) -> c_int {
+        let interrupt = &*(data as *const Interrupt);
+        let timed_out = interrupt.deadline.is_some_and(|d| Instant::now() >= d);
+        if timed_out
+            || interrupt
+                .cancellation
+                .is_some_and(CancellationToken::is_cancelled)
+        {
+            interrupt.fired.set(Some(timed_out));
+            return 1;
+        }
+        0
+    }
+
+    /// Install the hook for this thread until the returned guard is dropped.
+    fn install(&self) -> InterruptGuard<'_> {
+        unsafe {
+            sys::espresso_set_interrupt(
+                Some(Interrupt::poll),
+                self as *const Interrupt as *mut c_void,
+            )
+        };
+        InterruptGuard(PhantomData)
+    }
+}
+
+/// Removes the thread's interrupt hook on drop, before the `Interrupt` it points at goes away.
+struct InterruptGuard<'a>(PhantomData<&'a Interrupt<'a>>);
+
+impl Drop for InterruptGuard<'_> {
+    fn drop(&mut self) {
+        unsafe { sys::espresso_set_interrupt(None, ptr::null_mut()) };
+    }
+}
+
+/// Private helper shared by `try_minimize()`, `try_minimize_exact()` and `try_minimize_signature()`.
 ///
 /// `algorithm_fn` invokes the appropriate guarded C trampoline (`guarded_espresso` /
-/// `guarded_minimize_exact`); it returns the result `pset_family` on success, or null after a caught
-/// fatal, writing the captured diagnostic pointer through its `*mut *const c_char` out-parameter.
+/// `guarded_minimize_exact` / `guarded_signature`); it returns the result `pset_family` on success,
+/// or null after a caught fatal, writing the captured diagnostic pointer through its
+/// `*mut *const c_char` out-parameter. Each trampoline honours the instance's deadline and
+/// cancellation token (see [`Interrupt`]).
 fn try_minimize_with_algorithm<F>(
     espresso: &Espresso,
     f: &EspressoCover,
@@ -2116,7 +2943,8 @@ where
     // covers mid-pipeline (e.g. espresso() frees F), leaving them in an indeterminate state. The
     // raw pointers are then DELIBERATELY LEAKED: they are never re-wrapped in an EspressoCover and
     // never freed, which trades a bounded one-off leak on this rare error path for the certainty of
-    // no double-free or use-after-free.
+    // no double-free or use-after-free. The exception is a call made with a deadline or
+    // cancellation token installed, whose trampoline releases what it allocated (see below).
     let f_ptr = f.clone().into_raw();
 
     // MEMORY OWNERSHIP: D cover
@@ -2124,12 +2952,9 @@ where
     // - If not provided: allocate empty cover with sf_new()
     // - C algorithm function uses but does NOT free D (makes internal copy)
     // - We must free d_ptr after algorithm returns (via EspressoCover wrapper)
-    let d_ptr = d.map(|c| c.clone().into_raw()).unwrap_or_else(|| {
-        check_alloc(
-            unsafe { sys::sf_new(0, (*sys::get_cube()).size as c_int) },
-            "sf_new for empty D cover in try_minimize_with_algorithm",
-        )
-    });
+    let d_ptr = d
+        .map(|c| c.clone().into_raw())
+        .unwrap_or_else(|| empty_family("try_minimize_with_algorithm"));
 
     // MEMORY OWNERSHIP: R cover
     // - If provided: clone and transfer ownership via into_raw()
@@ -2142,24 +2967,37 @@ where
     // returned before the algorithm runs.
     let r_ptr = match r {
         Some(c) => c.clone().into_raw(),
-        None => {
-            let mut msg: *const c_char = ptr::null();
-            let r_ptr = unsafe {
-                let cube_list = sys::cube2list(f_ptr, d_ptr);
-                sys::guarded_complement(cube_list, &mut msg)
-            };
-            if r_ptr.is_null() {
-                return Err(unsafe { guarded_result_error(msg, "guarded_complement") });
-            }
-            r_ptr
-        }
+        None => guarded_offset(f_ptr, d_ptr)?,
     };
 
-    // Call the provided algorithm through its guarded trampoline (espresso or minimize_exact).
+    // With a deadline or cancellation token configured, install the hook the C loops poll. While
+    // it is installed the trampoline records every block it allocates, and on a caught fatal frees
+    // them all, F included, so that path no longer leaves anything behind (see below).
+    let interrupt = Interrupt::new(espresso.config());
+    let installed = interrupt.as_ref().map(Interrupt::install);
+
+    // Call the provided algorithm through its guarded trampoline (espresso, minimize_exact or
+    // signature).
     // OWNERSHIP: algorithm_fn takes ownership of f_ptr, returns new/modified pointer (or null on a
     // caught fatal). BORROWING: algorithm_fn uses but does not free d_ptr and r_ptr.
     let mut msg: *const c_char = ptr::null();
     let f_result = algorithm_fn(f_ptr, d_ptr, r_ptr, &mut msg);
+    drop(installed);
+    if f_result.is_null() && !msg.is_null() {
+        if let Some(interrupt) = &interrupt {
+            // Caught fatal with the hook installed: the trampoline released F and everything
+            // derived from it, while D and R were only borrowed, so they are intact and still ours
+            // to free.
+            unsafe {
+                drop(EspressoCover::from_raw(d_ptr, espresso));
+                drop(EspressoCover::from_raw(r_ptr, espresso));
+            }
+            return Err(match interrupt.fired.get() {
+                Some(timed_out) => MinimizationError::Cancelled { timed_out },
+                None => unsafe { espresso_fatal_error(msg) },
+            });
+        }
+    }
     if f_result.is_null() {
         // Caught fatal: all three C covers are indeterminate — leak them (see above). A null
         // result with no captured message is an unchecked allocation failure, not a caught fatal;
@@ -2248,6 +3086,26 @@ mod tests {
         assert_eq!(primes, expected);
     }
 
+    #[test]
+    fn try_simplify_merges_without_growing() {
+        // f(a,b,c) = a·b·c + a·b·c̄ + a·b̄ + ā·b·c simplifies to a + b·c.
+        let esp = Espresso::new(3, 1, &EspressoConfig::default());
+        let cubes = [
+            (&[1u8, 1, 1][..], &[1u8][..]),
+            (&[1u8, 1, 0][..], &[1u8][..]),
+            (&[1u8, 0, 2][..], &[1u8][..]),
+            (&[0u8, 1, 1][..], &[1u8][..]),
+        ];
+        let f = EspressoCover::from_cubes(&cubes, 3, 1).unwrap();
+
+        let simplified = esp.try_simplify(&f).unwrap();
+        assert!(simplified.to_cubes(3, 1, CubeType::F).count() < cubes.len());
+        // Same function: both contain the other.
+        assert!(esp.try_verify(&simplified, &f, None).unwrap().is_none());
+        // `f` itself is untouched.
+        assert_eq!(f.to_cubes(3, 1, CubeType::F).count(), cubes.len());
+    }
+
     #[test]
     fn from_cubes_rejects_length_mismatch() {
         // An input slice wider than the declared inputs is rejected (would otherwise write out of the
@@ -2433,6 +3291,9 @@ mod tests {
                         verbose_debug: false,
                         trace: false,
                         summary: false,
+                        // No time limits
+                        deadline: None,
+                        cancellation: None,
                     };
 
                     // Create Espresso instance with config
@@ -2808,6 +3669,72 @@ mod tests {
         assert!(count >= 2, "expected at least 2 cubes, got {count}");
     }
 
+    /// A deadline that has passed or a cancelled token stops the heuristic, exact and signature
+    /// minimisers at their first check with `MinimizationError::Cancelled`, and the thread can go
+    /// on minimising.
+    #[test]
+    fn try_minimize_stops_at_deadline_and_cancellation() {
+        let cubes = [
+            (&[0u8, 1, 0][..], &[1u8][..]),
+            (&[0u8, 1, 1][..], &[1u8][..]),
+            (&[1u8, 1, 0][..], &[1u8][..]),
+        ];
+        let past = EspressoConfig {
+            deadline: Some(Instant::now()),
+            ..EspressoConfig::default()
+        };
+        {
+            let esp = Espresso::new(3, 1, &past);
+            let f = EspressoCover::from_cubes(&cubes, 3, 1).unwrap();
+            assert!(matches!(
+                esp.try_minimize(&f, None, None),
+                Err(MinimizationError::Cancelled { timed_out: true })
+            ));
+            // The interrupted call released its copy of F; the caller's is untouched.
+            assert_eq!(f.to_cubes(3, 1, CubeType::F).count(), 3);
+            assert!(matches!(
+                esp.try_minimize_signature(&f, None, None),
+                Err(MinimizationError::Cancelled { timed_out: true })
+            ));
+        }
+
+        let token = CancellationToken::new();
+        token.cancel();
+        let cancelled = EspressoConfig {
+            cancellation: Some(token),
+            ..EspressoConfig::default()
+        };
+        {
+            let esp = Espresso::new(3, 1, &cancelled);
+            let f = EspressoCover::from_cubes(&cubes, 3, 1).unwrap();
+            assert!(matches!(
+                esp.try_minimize_exact(&f, None, None),
+                Err(MinimizationError::Cancelled { timed_out: false })
+            ));
+            assert!(matches!(
+                esp.try_minimize_signature(&f, None, None),
+                Err(MinimizationError::Cancelled { timed_out: false })
+            ));
+        }
+
+        // A deadline that is not reached changes nothing.
+        let distant = EspressoConfig {
+            deadline: Some(Instant::now() + std::time::Duration::from_secs(3600)),
+            cancellation: Some(CancellationToken::new()),
+            ..EspressoConfig::default()
+        };
+        let esp = Espresso::new(3, 1, &distant);
+        let f = EspressoCover::from_cubes(&cubes, 3, 1).unwrap();
+        let (minimized, _, _) = esp
+            .try_minimize_exact(&f, None, None)
+            .expect("minimisation within the deadline must succeed");
+        assert_eq!(minimized.to_cubes(3, 1, CubeType::F).count(), 2);
+        let (minimized, _, _) = esp
+            .try_minimize_signature(&f, None, None)
+            .expect("minimisation within the deadline must succeed");
+        assert_eq!(minimized.to_cubes(3, 1, CubeType::F).count(), 2);
+    }
+
     /// The infallible `minimize` panics on a fatal condition (its documented `# Panics` contract),
     /// mirroring `test_singleton_conflict_panics` for the instance-conflict case.
     #[test]
@@ -3299,6 +4226,20 @@ mod tests {
 /// // May find better solutions but results vary between runs
 /// ```
 ///
+/// ## Time Limits
+///
+/// ```
+/// use espresso_logic::{CancellationToken, EspressoConfig};
+/// use std::time::{Duration, Instant};
+///
+/// let token = CancellationToken::new();
+/// let config = EspressoConfig {
+///     deadline: Some(Instant::now() + Duration::from_secs(30)), // Give up after 30 s
+///     cancellation: Some(token.clone()), // Or when another thread calls token.cancel()
+///     ..EspressoConfig::default()
+/// };
+/// ```
+///
 /// # Performance Guidelines
 ///
 /// | Problem Size | Recommended Setting | Expected Speedup |
@@ -3403,6 +4344,25 @@ pub struct EspressoConfig {
     ///
     /// **Default:** `false` (deterministic results)
     pub use_random_order: bool,
+
+    /// Give up minimising once this instant has passed
+    ///
+    /// Checked between cubes in the expand, reduce and irredundant passes, in the covering
+    /// problem of exact minimisation and in the signature cube searches. A call still running at
+    /// the deadline stops with [`MinimizationError::Cancelled`] and releases everything it had
+    /// allocated. Only the heuristic, exact and signature minimisers honour it; the other
+    /// algorithms run to completion.
+    ///
+    /// **Default:** `None` (no time limit)
+    pub deadline: Option<Instant>,
+
+    /// Give up minimising once this token is cancelled
+    ///
+    /// Checked at the same points as [`deadline`](Self::deadline), so another thread can stop a
+    /// long minimisation by calling [`CancellationToken::cancel`] on a clone of the token.
+    ///
+    /// **Default:** `None`
+    pub cancellation: Option<CancellationToken>,
 }
 
 impl Default for EspressoConfig {
@@ -3419,6 +4379,8 @@ impl Default for EspressoConfig {
             single_expand: false,
             use_super_gasp: false,
             use_random_order: false,
+            deadline: None,
+            cancellation: None,
         }
     }
 }
@@ -3430,3 +4392,166 @@ impl EspressoConfig {
         Self::default()
     }
 }
+
+/// A flag that stops a running minimisation from another thread
+///
+/// Set as [`EspressoConfig::cancellation`]; clones share the flag, so keep one and hand the
+/// configuration to the minimising thread. Once [`cancel`](Self::cancel) is called, the minimisation
+/// returns [`MinimizationError::Cancelled`] at its next check, as does every later minimisation
+/// using the token.
+///
+/// Two tokens compare equal when they share a flag.
+///
+/// # Examples
+///
+/// ```
+/// use espresso_logic::CancellationToken;
+///
+/// let token = CancellationToken::new();
+/// let handle = token.clone();
+/// assert!(!token.is_cancelled());
+/// handle.cancel();
+/// assert!(token.is_cancelled());
+/// ```
+#[derive(Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    /// Create a token that has not been cancelled
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancel every minimisation using this token (or a clone of it)
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Whether [`cancel`](Self::cancel) has been called
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+impl std::fmt::Debug for CancellationToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CancellationToken")
+            .field("cancelled", &self.is_cancelled())
+            .finish()
+    }
+}
+
+impl PartialEq for CancellationToken {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for CancellationToken {}
+
+impl Hash for CancellationToken {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.0).hash(state);
+    }
+}
+
+/// Strategy for [`Espresso::phase_assignment`] and
+/// [`Cover::assign_output_phase`](crate::Cover::assign_output_phase) — the reference tool's
+/// `-Dopo -S<n>` bits.
+///
+/// The default runs the basic technique: one minimisation of the double-phase function to choose
+/// every output's phase at once, then one under the chosen assignment.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct PhaseStrategy {
+    /// Skip `make_sparse` while minimising the double-phase function (bit 0).
+    pub skip_make_sparse: bool,
+    /// Commit to one output's phase at a time, re-minimising after each: `m + 1` minimisations for
+    /// `m` outputs instead of 2 (bit 1).
+    pub repeated: bool,
+    /// Minimise exactly instead of heuristically (bit 2).
+    pub exact: bool,
+}
+
+impl PhaseStrategy {
+    /// The `opo_strategy` integer `phase_assignment` decodes.
+    fn bits(self) -> c_int {
+        c_int::from(self.skip_make_sparse)
+            | c_int::from(self.repeated) << 1
+            | c_int::from(self.exact) << 2
+    }
+}
+
+/// Strategy for [`Espresso::minimize_single_output`] and
+/// [`Cover::minimize_single_output`](crate::Cover::minimize_single_output) — the reference tool's
+/// `-Dso` and `-Dso_both -S<n>`.
+///
+/// The default is plain `-Dso`: each output's ON-set minimised heuristically.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SingleOutputStrategy {
+    /// Also minimise each output's complement, and implement the output in whichever phase needs
+    /// fewer cubes (`-Dso_both`).
+    pub both_phases: bool,
+    /// Minimise exactly instead of heuristically (`-S1`).
+    pub exact: bool,
+}
+
+/// How [`Espresso::input_pairing`] and [`Cover::find_input_pairing`](crate::Cover::find_input_pairing)
+/// choose which binary inputs to pair into two-bit decoders.
+///
+/// The default is the reference tool's plain `-Dpair`: estimate each pair algebraically.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PairingSearch {
+    /// Score every pair of binary inputs on its own, then take the disjoint pairs with the best
+    /// total score — exhaustively for up to 14 binary inputs, greedily beyond (`-Dpair -S<n>`).
+    /// The paired cover is then minimised heuristically.
+    Estimated(PairingCost),
+    /// Minimise under every pairing and keep the one with the fewest product terms
+    /// (`-Dpairall -S<n>`). The number of pairings grows factorially with the binary inputs.
+    Exhaustive(PairingMinimizer),
+}
+
+impl Default for PairingSearch {
+    fn default() -> Self {
+        PairingSearch::Estimated(PairingCost::default())
+    }
+}
+
+impl PairingSearch {
+    /// Whether the search is `pair_all`, and the strategy number it decodes.
+    fn key(self) -> (bool, c_int) {
+        match self {
+            PairingSearch::Estimated(cost) => (false, cost as c_int),
+            PairingSearch::Exhaustive(minimizer) => (true, minimizer as c_int),
+        }
+    }
+}
+
+/// How [`PairingSearch::Estimated`] scores a single pair: each step is more accurate and more
+/// expensive than the last (T. Sasao's estimate is the first).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum PairingCost {
+    /// Terms saved by algebraic division of the ON-set by the pair (`-S0`).
+    #[default]
+    Algebraic = 0,
+    /// Terms saved by one reduce, expand and irredundant pass over the paired cover (`-S1`).
+    StrongDivision = 1,
+    /// Terms saved by heuristic minimisation of the paired cover (`-S2`).
+    Espresso = 2,
+    /// Terms saved by exact minimisation of the paired cover (`-S3`).
+    Exact = 3,
+}
+
+/// How [`PairingSearch::Exhaustive`] minimises the cover under each pairing.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum PairingMinimizer {
+    /// Heuristic minimisation (`-S0`).
+    #[default]
+    Espresso = 0,
+    /// Exact minimisation (`-S1`).
+    Exact = 1,
+    /// Output phase assignment with the default [`PhaseStrategy`] (`-S2`); the result carries the
+    /// chosen phase.
+    OutputPhase = 2,
+}
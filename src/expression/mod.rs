@@ -98,6 +98,30 @@ impl Default for BoolExpr {
     }
 }
 
+/// Serialises the reverse-Polish token stream itself, so any expression round-trips exactly —
+/// including variable names the text parser would not accept. `a & !b` is
+/// `[{"var":"a"},{"var":"b"},"not","and"]` in JSON.
+#[cfg(feature = "serde")]
+impl serde::Serialize for BoolExpr {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.tokens.iter())
+    }
+}
+
+/// Rejects a token stream that is not a single complete expression.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for BoolExpr {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<BoolExpr, D::Error> {
+        let tokens = Vec::<Token>::deserialize(deserializer)?;
+        if !rpn::is_well_formed(&tokens) {
+            return Err(serde::de::Error::custom(
+                "token stream is not a single complete expression",
+            ));
+        }
+        Ok(BoolExpr::from_tokens(tokens.into()))
+    }
+}
+
 impl BoolExpr {
     /// Create a variable expression with the given name.
     #[must_use]
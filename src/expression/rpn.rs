@@ -22,6 +22,8 @@ use std::sync::Arc;
 /// `String`. There is a single canonical operator set — `&`/`|`/`^`/`!` (AND/OR/XOR/NOT) — even though
 /// the text parser additionally accepts the `*`/`+`/`~` spellings.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
 pub(crate) enum Token {
     /// Push a variable by name.
     Var(Symbol),
@@ -111,3 +113,20 @@ pub(crate) fn fold_postfix<T>(
     }
     stack.pop().expect("postfix program produced no result")
 }
+
+/// Whether `tokens` is a complete postfix program: no operator underflows the value stack, and exactly
+/// one value is left at the end. Every composition helper upholds this by construction; it is checked
+/// only where a token stream arrives from outside, before [`fold_postfix`] would panic on it.
+#[cfg(feature = "serde")]
+pub(crate) fn is_well_formed(tokens: &[Token]) -> bool {
+    let mut depth = 0usize;
+    for token in tokens {
+        depth = match token {
+            Token::Var(_) | Token::Const(_) => depth + 1,
+            Token::Not if depth >= 1 => depth,
+            Token::And | Token::Or | Token::Xor if depth >= 2 => depth - 1,
+            _ => return false,
+        };
+    }
+    depth == 1
+}
@@ -381,3 +381,21 @@ fn sync_context_build_works() {
         .build(&f)
         .equivalent_to(&builder.parse("a ^ b").unwrap()));
 }
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_round_trips_the_token_stream() {
+    // `x[0]` is not a name the text parser accepts, so the encoding must not go through text.
+    let f = expr!("a" & !"b") | BoolExpr::var("x[0]") ^ BoolExpr::constant(true);
+    let json = serde_json::to_string(&f).unwrap();
+    assert_eq!(
+        json,
+        r#"[{"var":"a"},{"var":"b"},"not","and",{"var":"x[0]"},{"const":true},"xor","or"]"#
+    );
+    assert_eq!(serde_json::from_str::<BoolExpr>(&json).unwrap(), f);
+
+    for malformed in ["[]", r#"["not"]"#, r#"[{"var":"a"},{"var":"b"}]"#] {
+        let err = serde_json::from_str::<BoolExpr>(malformed).unwrap_err();
+        assert!(err.to_string().contains("not a single complete expression"));
+    }
+}
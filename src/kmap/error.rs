@@ -0,0 +1,150 @@
+//! Error types for building and writing Karnaugh maps
+
+use std::fmt;
+use std::io;
+use std::sync::Arc;
+
+use crate::cover::IndexOutOfRange;
+
+/// Errors that can occur when building a [`KarnaughMap`](crate::kmap::KarnaughMap) or adding its
+/// groups
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum KMapError {
+    /// The function has more inputs than a map can lay out.
+    TooManyVariables {
+        /// Number of inputs of the function
+        count: usize,
+        /// [`MAX_VARIABLES`](crate::kmap::MAX_VARIABLES)
+        max: usize,
+    },
+    /// The cover has multiple-valued inputs (see [`Cover::mv_layout`](crate::Cover::mv_layout)),
+    /// which a map's binary axes cannot carry.
+    MultipleValuedInputs,
+    /// The requested output is not one of the cover's.
+    OutputOutOfRange(IndexOutOfRange),
+    /// A group cube tests a variable the map does not have.
+    UnknownVariable {
+        /// The variable's name
+        name: Arc<str>,
+    },
+    /// More groups than there are letters to name them.
+    TooManyGroups {
+        /// Number of groups requested
+        count: usize,
+        /// [`MAX_GROUPS`](crate::kmap::MAX_GROUPS)
+        max: usize,
+    },
+}
+
+impl fmt::Display for KMapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KMapError::TooManyVariables { count, max } => write!(
+                f,
+                "a Karnaugh map holds at most {} variables, the function has {}",
+                max, count
+            ),
+            KMapError::MultipleValuedInputs => write!(
+                f,
+                "a Karnaugh map cannot show a cover with multiple-valued inputs"
+            ),
+            KMapError::OutputOutOfRange(e) => write!(f, "output {}", e),
+            KMapError::UnknownVariable { name } => {
+                write!(
+                    f,
+                    "group tests variable '{}', which is not on the map",
+                    name
+                )
+            }
+            KMapError::TooManyGroups { count, max } => write!(
+                f,
+                "a Karnaugh map names at most {} groups, {} were given",
+                max, count
+            ),
+        }
+    }
+}
+
+impl std::error::Error for KMapError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            KMapError::OutputOutOfRange(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<IndexOutOfRange> for KMapError {
+    fn from(err: IndexOutOfRange) -> Self {
+        KMapError::OutputOutOfRange(err)
+    }
+}
+
+/// Errors that can occur when writing a Karnaugh map
+///
+/// This error type is returned by the [`KMapWriter`](crate::KMapWriter) methods
+/// (`write_kmap`, `to_kmap_string`, `to_kmap_file`).
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum KMapWriteError {
+    /// IO error during writing
+    Io(io::Error),
+}
+
+impl fmt::Display for KMapWriteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KMapWriteError::Io(e) => write!(f, "IO error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for KMapWriteError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            KMapWriteError::Io(e) => Some(e),
+        }
+    }
+}
+
+impl From<io::Error> for KMapWriteError {
+    fn from(err: io::Error) -> Self {
+        KMapWriteError::Io(err)
+    }
+}
+
+impl From<KMapWriteError> for io::Error {
+    fn from(err: KMapWriteError) -> Self {
+        match err {
+            // KMapWriteError only contains IO errors, so return it directly
+            KMapWriteError::Io(e) => e,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kmap_error_messages() {
+        let err = KMapError::TooManyVariables { count: 7, max: 6 };
+        assert!(err.to_string().contains("at most 6"));
+        assert!(err.to_string().contains("has 7"));
+        let err = KMapError::UnknownVariable {
+            name: Arc::from("z"),
+        };
+        assert!(err.to_string().contains("'z'"));
+        let err: KMapError = IndexOutOfRange { index: 2, arity: 1 }.into();
+        assert!(err.to_string().starts_with("output index 2"));
+    }
+
+    #[test]
+    fn test_kmap_write_error_round_trips_io_error() {
+        let write_err: KMapWriteError = io::Error::other("disk full").into();
+        assert!(write_err.to_string().contains("disk full"));
+        let io_err: io::Error = write_err.into();
+        assert_eq!(io_err.kind(), io::ErrorKind::Other);
+    }
+}
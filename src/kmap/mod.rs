@@ -0,0 +1,678 @@
+//! Karnaugh map rendering
+//!
+//! This module lays out a small Boolean function as a Karnaugh map, for reviewing logic by eye the
+//! way the C `-Dmap` debug output does, but Gray-coded on both axes so that neighbouring cells
+//! differ in one variable. A [`KarnaughMap`] holds one function of at most [`MAX_VARIABLES`]
+//! inputs.
+//!
+//! - [`KarnaughMap::from_cover`] maps one output of a [`Cover`]: `1` for the ON-set, `0` for the
+//!   OFF-set and `-` for don't-cares.
+//! - [`KarnaughMap::from_bdd`] maps a [`Bdd`] over its support.
+//! - [`KarnaughMap::with_groups`] adds the ON-set cubes of a cover as groupings, typically the
+//!   result of [`Minimizable::minimize`](crate::Minimizable::minimize) or [`Bdd::minimize`].
+//! - [`KMapWriter`] writes a map as plain text or as SVG ([`KMapFormat`]).
+//!
+//! # Layout
+//!
+//! The first half of the variables (rounded down) index the rows and the rest the columns, each
+//! axis in reflected Gray code, first variable most significant. In text, each group is named by a
+//! letter (`A`–`Z`, then `a`–`z`) written in every cell it covers and listed under the map with its
+//! cube. In SVG, each group is a coloured outline; a group that wraps around an edge, or that
+//! spans the two halves of a three-variable axis, is drawn as several outlines in one colour.
+//!
+//! # Quick Example
+//!
+//! ```
+//! use espresso_logic::kmap::{KMapFormat, KarnaughMap};
+//! use espresso_logic::{bdd_builder, KMapWriter};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let builder = bdd_builder!();
+//! let f = builder.parse("a & !b | !b & c | a & !b & c")?;
+//!
+//! // Group the map by the minimised cover.
+//! let map = KarnaughMap::from_bdd(&f)?
+//!     .with_title("f")
+//!     .with_groups(&f.minimize()?, 0)?;
+//! assert_eq!(map.num_groups(), 2);
+//!
+//! let text = map.to_kmap_string(KMapFormat::Text)?;
+//! assert!(text.starts_with("f\na\\bc"));
+//! println!("{}", text);
+//!
+//! let svg = map.to_kmap_string(KMapFormat::Svg)?;
+//! assert!(svg.starts_with("<svg"));
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod error;
+
+pub use error::{KMapError, KMapWriteError};
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::bdd::{Bdd, BddNode, Brand, ManagerCell};
+use crate::cover::{Cover, CubeType, IndexOutOfRange, InputField, Label};
+use crate::Symbol;
+
+/// The most inputs a [`KarnaughMap`] lays out: an 8 × 8 grid
+pub const MAX_VARIABLES: usize = 6;
+
+/// The most groups a [`KarnaughMap`] names, one letter each
+pub const MAX_GROUPS: usize = 52;
+
+/// A Boolean function of a few inputs, laid out as a Karnaugh map
+///
+/// See the [module documentation](self).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KarnaughMap {
+    variables: Vec<Symbol>,
+    /// One value per minterm, numbered with the first variable most significant; `None` is a
+    /// don't-care.
+    cells: Vec<Option<bool>>,
+    /// The groups, in letter order.
+    groups: Vec<Literals>,
+    title: Option<Symbol>,
+}
+
+/// How [`KMapWriter`] draws a map
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum KMapFormat {
+    /// A fixed-width text grid, with groups as letters in their cells
+    #[default]
+    Text,
+    /// A standalone SVG image, with groups as coloured outlines
+    Svg,
+}
+
+/// A cube's literal per variable, `None` where it spans both values.
+type Literals = Vec<Option<bool>>;
+
+/// Whether the cube with these literals, first variable most significant, contains minterm `m`.
+fn contains(literals: &[Option<bool>], m: usize) -> bool {
+    let n = literals.len();
+    literals.iter().enumerate().all(|(i, literal)| {
+        literal.is_none_or(|value| (m >> (n - 1 - i)) & 1 == usize::from(value))
+    })
+}
+
+/// The `position`th code of the reflected Gray code.
+fn gray(position: usize) -> usize {
+    position ^ (position >> 1)
+}
+
+impl KarnaughMap {
+    /// Map one output of a cover
+    ///
+    /// A cell is a don't-care if a D cube covers it, `1` if an F cube does and `0` if an R cube
+    /// does. An uncovered cell is `0`, or a don't-care when the cover type lists its OFF-set (as in
+    /// `.type fr`). An output whose cubes cover its complement (see
+    /// [`Cover::complemented_outputs`]) is mapped as the function it implements.
+    ///
+    /// The inputs are named by their labels, so a positional ([`Anonymous`](crate::Anonymous))
+    /// cover must be [`relabel`](Cover::relabel)led first.
+    ///
+    /// # Errors
+    ///
+    /// [`KMapError::OutputOutOfRange`] if `output` is not one of the cover's outputs,
+    /// [`KMapError::MultipleValuedInputs`] for a cover with multiple-valued inputs, and
+    /// [`KMapError::TooManyVariables`] for more than [`MAX_VARIABLES`] inputs.
+    pub fn from_cover<I: Label + AsRef<str>, O: Label>(
+        cover: &Cover<I, O>,
+        output: usize,
+    ) -> Result<Self, KMapError> {
+        let literals = binary_cubes(cover, output)?;
+        let n = cover.num_inputs();
+        if n > MAX_VARIABLES {
+            return Err(KMapError::TooManyVariables {
+                count: n,
+                max: MAX_VARIABLES,
+            });
+        }
+        let complemented = cover.complemented_outputs().contains(&output);
+        let uncovered = (!cover.cover_type.has_r()).then_some(complemented);
+        let cells = (0..1 << n)
+            .map(|m| {
+                let covered = |set: CubeType| {
+                    literals
+                        .iter()
+                        .any(|(cube_set, cube)| *cube_set == set && contains(cube, m))
+                };
+                if covered(CubeType::D) {
+                    None
+                } else if covered(CubeType::F) {
+                    Some(!complemented)
+                } else if covered(CubeType::R) {
+                    Some(complemented)
+                } else {
+                    uncovered
+                }
+            })
+            .collect();
+        Ok(KarnaughMap {
+            variables: cover
+                .input_labels()
+                .iter()
+                .map(|label| Symbol::from(label.as_ref()))
+                .collect(),
+            cells,
+            groups: Vec::new(),
+            title: None,
+        })
+    }
+
+    /// Map a [`Bdd`] over its support variables, sorted by name
+    ///
+    /// # Errors
+    ///
+    /// [`KMapError::TooManyVariables`] if the function depends on more than [`MAX_VARIABLES`]
+    /// variables.
+    pub fn from_bdd<B: Brand, C: ManagerCell>(bdd: &Bdd<B, C>) -> Result<Self, KMapError> {
+        let mut variables: Vec<Symbol> = bdd.variables().collect();
+        variables.sort();
+        let n = variables.len();
+        if n > MAX_VARIABLES {
+            return Err(KMapError::TooManyVariables {
+                count: n,
+                max: MAX_VARIABLES,
+            });
+        }
+        // The truth table as a bitmask over the minterms, at most 64 of them.
+        let all = u64::MAX >> (64 - (1 << n));
+        let masks: HashMap<&str, u64> = variables
+            .iter()
+            .enumerate()
+            .map(|(i, name)| {
+                let mask = (0..1 << n)
+                    .filter(|m| (m >> (n - 1 - i)) & 1 == 1)
+                    .fold(0, |mask, m| mask | 1 << m);
+                (name.as_ref(), mask)
+            })
+            .collect();
+        let table = bdd.fold(|node| match node {
+            BddNode::Terminal(value) => {
+                if value {
+                    all
+                } else {
+                    0
+                }
+            }
+            BddNode::Decision {
+                variable,
+                low,
+                high,
+            } => {
+                let mask = masks[variable];
+                (high & mask) | (low & !mask)
+            }
+        });
+        Ok(KarnaughMap {
+            cells: (0..1 << n).map(|m| Some((table >> m) & 1 == 1)).collect(),
+            variables,
+            groups: Vec::new(),
+            title: None,
+        })
+    }
+
+    /// Draw the ON-set cubes of one output of `cover` as groups, replacing any earlier ones
+    ///
+    /// The cover's inputs are matched to the map's variables by name; a variable the cover lacks
+    /// is spanned by every group. Cubes that cover no cell (with an empty input field) are skipped.
+    ///
+    /// # Errors
+    ///
+    /// [`KMapError::OutputOutOfRange`] and [`KMapError::MultipleValuedInputs`] as for
+    /// [`from_cover`](Self::from_cover), [`KMapError::UnknownVariable`] if a cube tests a variable
+    /// not on the map, and [`KMapError::TooManyGroups`] for more than [`MAX_GROUPS`] cubes.
+    pub fn with_groups<I: Label + AsRef<str>, O: Label>(
+        mut self,
+        cover: &Cover<I, O>,
+        output: usize,
+    ) -> Result<Self, KMapError> {
+        let position: HashMap<&str, usize> = self
+            .variables
+            .iter()
+            .enumerate()
+            .map(|(i, name)| (name.as_ref(), i))
+            .collect();
+        let labels = cover.input_labels();
+        let mut groups = Vec::new();
+        for (set, literals) in binary_cubes(cover, output)? {
+            if set != CubeType::F {
+                continue;
+            }
+            let mut group = vec![None; self.variables.len()];
+            for (label, literal) in labels.iter().zip(literals) {
+                if let Some(value) = literal {
+                    let i = *position.get(label.as_ref()).ok_or_else(|| {
+                        KMapError::UnknownVariable {
+                            name: Arc::from(label.as_ref()),
+                        }
+                    })?;
+                    group[i] = Some(value);
+                }
+            }
+            groups.push(group);
+        }
+        if groups.len() > MAX_GROUPS {
+            return Err(KMapError::TooManyGroups {
+                count: groups.len(),
+                max: MAX_GROUPS,
+            });
+        }
+        self.groups = groups;
+        Ok(self)
+    }
+
+    /// Give the map a title, written above it
+    #[must_use]
+    pub fn with_title<S: AsRef<str>>(mut self, title: S) -> Self {
+        self.title = Some(Symbol::from(title.as_ref()));
+        self
+    }
+
+    /// The variables, row variables first
+    pub fn variables(&self) -> &[Symbol] {
+        &self.variables
+    }
+
+    /// Number of rows: two to the number of row variables
+    pub fn num_rows(&self) -> usize {
+        1 << self.row_bits()
+    }
+
+    /// Number of columns: two to the number of column variables
+    pub fn num_columns(&self) -> usize {
+        1 << self.column_bits()
+    }
+
+    /// Number of groups drawn
+    pub fn num_groups(&self) -> usize {
+        self.groups.len()
+    }
+
+    /// The value in a cell, `None` for a don't-care
+    ///
+    /// # Panics
+    ///
+    /// If `row` or `column` is out of range.
+    pub fn cell(&self, row: usize, column: usize) -> Option<bool> {
+        assert!(
+            row < self.num_rows() && column < self.num_columns(),
+            "cell ({}, {}) is outside the {} × {} map",
+            row,
+            column,
+            self.num_rows(),
+            self.num_columns()
+        );
+        self.cells[self.minterm(row, column)]
+    }
+
+    fn row_bits(&self) -> usize {
+        self.variables.len() / 2
+    }
+
+    fn column_bits(&self) -> usize {
+        self.variables.len() - self.row_bits()
+    }
+
+    /// The minterm shown in a cell.
+    fn minterm(&self, row: usize, column: usize) -> usize {
+        (gray(row) << self.column_bits()) | gray(column)
+    }
+
+    /// The letters of the groups covering a cell.
+    fn letters(&self, row: usize, column: usize) -> String {
+        let m = self.minterm(row, column);
+        (0..self.groups.len())
+            .filter(|&k| contains(&self.groups[k], m))
+            .map(letter)
+            .collect()
+    }
+
+    /// The cell's value as written: `0`, `1` or `-`.
+    fn symbol(&self, row: usize, column: usize) -> char {
+        match self.cell(row, column) {
+            Some(false) => '0',
+            Some(true) => '1',
+            None => '-',
+        }
+    }
+
+    /// The corner label, row variables then column variables: `ab\cd`, or `x1,x2\x3,x4` when a
+    /// name is longer than one character.
+    fn corner(&self) -> String {
+        let separator = if self.variables.iter().all(|v| v.chars().count() <= 1) {
+            ""
+        } else {
+            ","
+        };
+        let (rows, columns) = self.variables.split_at(self.row_bits());
+        let join = |names: &[Symbol]| -> String {
+            names
+                .iter()
+                .map(|name| name.as_ref())
+                .collect::<Vec<_>>()
+                .join(separator)
+        };
+        format!("{}\\{}", join(rows), join(columns))
+    }
+
+    /// A group's cube in expression syntax: `a & !c`, or `1` for the whole map.
+    fn describe(&self, group: &[Option<bool>]) -> String {
+        let literals: Vec<String> = self
+            .variables
+            .iter()
+            .zip(group)
+            .filter_map(|(name, literal)| {
+                literal.map(|value| {
+                    if value {
+                        name.to_string()
+                    } else {
+                        format!("!{}", name)
+                    }
+                })
+            })
+            .collect();
+        if literals.is_empty() {
+            "1".to_string()
+        } else {
+            literals.join(" & ")
+        }
+    }
+
+    fn write_text<W: Write>(&self, writer: &mut W) -> Result<(), KMapWriteError> {
+        if let Some(title) = &self.title {
+            writeln!(writer, "{}", title)?;
+        }
+        let corner = self.corner();
+        let label_width = corner.chars().count().max(self.row_bits());
+        let letters = (0..self.num_rows())
+            .flat_map(|row| (0..self.num_columns()).map(move |column| (row, column)))
+            .map(|(row, column)| self.letters(row, column).len())
+            .max()
+            .unwrap_or(0);
+        let width = self.column_bits().max(1 + letters) + 2;
+
+        write!(writer, "{:<label_width$}", corner)?;
+        for column in 0..self.num_columns() {
+            write!(writer, "{:>width$}", code(gray(column), self.column_bits()))?;
+        }
+        writeln!(writer)?;
+        for row in 0..self.num_rows() {
+            write!(writer, "{:>label_width$}", code(gray(row), self.row_bits()))?;
+            for column in 0..self.num_columns() {
+                let cell = format!("{}{}", self.symbol(row, column), self.letters(row, column));
+                write!(writer, "{:>width$}", cell)?;
+            }
+            writeln!(writer)?;
+        }
+        for (k, group) in self.groups.iter().enumerate() {
+            writeln!(writer, "{}: {}", letter(k), self.describe(group))?;
+        }
+        Ok(())
+    }
+
+    fn write_svg<W: Write>(&self, writer: &mut W) -> Result<(), KMapWriteError> {
+        const CELL: usize = 40;
+        const MARGIN: usize = 16;
+        const LINE: usize = 24;
+        // Approximate advance of one 14px monospace character.
+        const CHAR: usize = 9;
+
+        let corner = self.corner();
+        let legend: Vec<String> = self
+            .groups
+            .iter()
+            .enumerate()
+            .map(|(k, group)| format!("{}: {}", letter(k), self.describe(group)))
+            .collect();
+        let left = MARGIN + CHAR * corner.chars().count().max(self.row_bits()) + 8;
+        let mut top = MARGIN;
+        if self.title.is_some() {
+            top += LINE;
+        }
+        top += LINE;
+        let grid_width = CELL * self.num_columns();
+        let grid_height = CELL * self.num_rows();
+        let text_width = legend
+            .iter()
+            .map(|line| line.chars().count())
+            .chain(self.title.iter().map(|title| title.chars().count()))
+            .map(|chars| MARGIN + CHAR * chars)
+            .max()
+            .unwrap_or(0);
+        let width = (left + grid_width + MARGIN).max(text_width + MARGIN);
+        let height = top + grid_height + MARGIN + LINE * legend.len();
+
+        writeln!(
+            writer,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" \
+             font-family=\"monospace\" font-size=\"14\">",
+            width, height
+        )?;
+        if let Some(title) = &self.title {
+            writeln!(
+                writer,
+                "  <text x=\"{}\" y=\"{}\">{}</text>",
+                MARGIN,
+                MARGIN + 14,
+                escape(title)
+            )?;
+        }
+        writeln!(
+            writer,
+            "  <text x=\"{}\" y=\"{}\" text-anchor=\"end\">{}</text>",
+            left - 8,
+            top - 8,
+            escape(&corner)
+        )?;
+        for column in 0..self.num_columns() {
+            writeln!(
+                writer,
+                "  <text x=\"{}\" y=\"{}\" text-anchor=\"middle\">{}</text>",
+                left + CELL * column + CELL / 2,
+                top - 8,
+                code(gray(column), self.column_bits())
+            )?;
+        }
+        for row in 0..self.num_rows() {
+            let y = top + CELL * row;
+            writeln!(
+                writer,
+                "  <text x=\"{}\" y=\"{}\" text-anchor=\"end\" dominant-baseline=\"central\">{}</text>",
+                left - 8,
+                y + CELL / 2,
+                code(gray(row), self.row_bits())
+            )?;
+            for column in 0..self.num_columns() {
+                let x = left + CELL * column;
+                writeln!(
+                    writer,
+                    "  <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"none\" stroke=\"black\"/>",
+                    x, y, CELL, CELL
+                )?;
+                writeln!(
+                    writer,
+                    "  <text x=\"{}\" y=\"{}\" text-anchor=\"middle\" dominant-baseline=\"central\">{}</text>",
+                    x + CELL / 2,
+                    y + CELL / 2,
+                    self.symbol(row, column)
+                )?;
+            }
+        }
+
+        for (k, group) in self.groups.iter().enumerate() {
+            let colour = COLOURS[k % COLOURS.len()];
+            // Nested groups stay visible: each is inset a little further than the last.
+            let inset = 3 + 3 * (k % 4);
+            let (row_literals, column_literals) = group.split_at(self.row_bits());
+            let rows = runs(self.num_rows(), |row| contains(row_literals, gray(row)));
+            let columns = runs(self.num_columns(), |column| {
+                contains(column_literals, gray(column))
+            });
+            for &(first_row, end_row) in &rows {
+                for &(first_column, end_column) in &columns {
+                    writeln!(
+                        writer,
+                        "  <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" rx=\"8\" \
+                         fill=\"none\" stroke=\"{}\" stroke-width=\"2\"/>",
+                        left + CELL * first_column + inset,
+                        top + CELL * first_row + inset,
+                        CELL * (end_column - first_column) - 2 * inset,
+                        CELL * (end_row - first_row) - 2 * inset,
+                        colour
+                    )?;
+                }
+            }
+        }
+        for (k, line) in legend.iter().enumerate() {
+            writeln!(
+                writer,
+                "  <text x=\"{}\" y=\"{}\" fill=\"{}\">{}</text>",
+                MARGIN,
+                top + grid_height + MARGIN + LINE * k + 14,
+                COLOURS[k % COLOURS.len()],
+                escape(line)
+            )?;
+        }
+        writeln!(writer, "</svg>")?;
+        Ok(())
+    }
+}
+
+/// Outline colours for the groups, repeating after eight.
+const COLOURS: [&str; 8] = [
+    "#e41a1c", "#377eb8", "#4daf4a", "#984ea3", "#ff7f00", "#a65628", "#f781bf", "#999999",
+];
+
+/// The cubes of a cover asserting `output`, as `(set, literals)` over its inputs; cubes with an
+/// empty input field cover nothing and are left out.
+fn binary_cubes<I: Label, O: Label>(
+    cover: &Cover<I, O>,
+    output: usize,
+) -> Result<Vec<(CubeType, Literals)>, KMapError> {
+    if output >= cover.num_outputs() {
+        return Err(IndexOutOfRange {
+            index: output,
+            arity: cover.num_outputs(),
+        }
+        .into());
+    }
+    if cover.mv_layout().is_some_and(|l| !l.mv_sizes().is_empty()) {
+        return Err(KMapError::MultipleValuedInputs);
+    }
+    Ok(cover
+        .cubes()
+        .filter(|cube| cube.asserts(output))
+        .filter_map(|cube| {
+            let literals = cube
+                .inputs
+                .fields()
+                .map(|field| match field {
+                    InputField::Zero => Some(Some(false)),
+                    InputField::One => Some(Some(true)),
+                    InputField::DontCare => Some(None),
+                    InputField::Empty => None,
+                })
+                .collect::<Option<Vec<_>>>()?;
+            Some((cube.set, literals))
+        })
+        .collect())
+}
+
+/// `value` as `bits` binary digits; empty for an axis without variables.
+fn code(value: usize, bits: usize) -> String {
+    if bits == 0 {
+        String::new()
+    } else {
+        format!("{:0bits$b}", value)
+    }
+}
+
+/// The name of group `k`: `A`–`Z`, then `a`–`z`.
+fn letter(k: usize) -> char {
+    let k = u8::try_from(k).expect("at most MAX_GROUPS groups");
+    if k < 26 {
+        char::from(b'A' + k)
+    } else {
+        char::from(b'a' + k - 26)
+    }
+}
+
+/// The maximal runs `start..end` of consecutive positions in `0..len` satisfying `keep`.
+fn runs(len: usize, keep: impl Fn(usize) -> bool) -> Vec<(usize, usize)> {
+    let mut runs: Vec<(usize, usize)> = Vec::new();
+    for position in (0..len).filter(|&p| keep(p)) {
+        match runs.last_mut() {
+            Some((_, end)) if *end == position => *end += 1,
+            _ => runs.push((position, position + 1)),
+        }
+    }
+    runs
+}
+
+/// Escape text for SVG character data.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Trait for types that can be drawn as a Karnaugh map
+///
+/// Implemented for [`KarnaughMap`]; build one from a cover output or a [`Bdd`] first.
+pub trait KMapWriter {
+    /// Write this map as text or SVG
+    ///
+    /// # Errors
+    ///
+    /// Returns [`KMapWriteError::Io`] if writing fails.
+    fn write_kmap<W: Write>(
+        &self,
+        writer: &mut W,
+        format: KMapFormat,
+    ) -> Result<(), KMapWriteError>;
+
+    /// Convert this map to a string
+    ///
+    /// This is a convenience method that delegates to `write_kmap`.
+    fn to_kmap_string(&self, format: KMapFormat) -> Result<String, KMapWriteError> {
+        let mut buffer = Vec::new();
+        self.write_kmap(&mut buffer, format)?;
+        Ok(String::from_utf8(buffer).expect("Karnaugh maps are built from UTF-8 Rust strings"))
+    }
+
+    /// Write this map to a file
+    fn to_kmap_file<P: AsRef<Path>>(
+        &self,
+        path: P,
+        format: KMapFormat,
+    ) -> Result<(), KMapWriteError> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_kmap(&mut writer, format)?;
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+impl KMapWriter for KarnaughMap {
+    fn write_kmap<W: Write>(
+        &self,
+        writer: &mut W,
+        format: KMapFormat,
+    ) -> Result<(), KMapWriteError> {
+        match format {
+            KMapFormat::Text => self.write_text(writer),
+            KMapFormat::Svg => self.write_svg(writer),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
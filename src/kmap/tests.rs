@@ -0,0 +1,103 @@
+//! Tests for Karnaugh map building and writing.
+
+use super::*;
+
+fn read_cover(text: &str) -> Cover<Symbol, Symbol> {
+    match crate::PlaCover::<Symbol>::from_pla_string(text).unwrap() {
+        crate::PlaCover::InputsOutputsNamed(cover) => cover,
+        other => panic!("expected a named cover, got {other:?}"),
+    }
+}
+
+#[test]
+fn bdd_is_mapped_in_gray_order_with_lettered_groups() {
+    let builder = crate::bdd_builder!();
+    let f = builder.parse("a & !b | !b & c").unwrap();
+    let groups = read_cover(".i 3\n.o 1\n.ilb a b c\n.ob f\n10- 1\n-01 1\n.e\n");
+
+    let map = KarnaughMap::from_bdd(&f)
+        .unwrap()
+        .with_title("f")
+        .with_groups(&groups, 0)
+        .unwrap();
+    assert_eq!(map.variables(), ["a", "b", "c"].map(Symbol::from));
+    assert_eq!((map.num_rows(), map.num_columns()), (2, 4));
+    // Column 2 is `bc = 11`, after `01` in Gray order.
+    assert_eq!(map.cell(1, 1), Some(true));
+    assert_eq!(map.cell(1, 2), Some(false));
+    assert_eq!(
+        map.to_kmap_string(KMapFormat::Text).unwrap(),
+        "f\n\
+         a\\bc   00   01   11   10\n\
+         \x20  0    0   1B    0    0\n\
+         \x20  1   1A  1AB    0    0\n\
+         A: a & !b\n\
+         B: !b & c\n"
+    );
+}
+
+#[test]
+fn cover_cells_follow_the_cover_type() {
+    let fd = read_cover(".i 2\n.o 1\n.ilb x y\n.ob g\n.type fd\n11 1\n0- -\n.e\n");
+    let map = KarnaughMap::from_cover(&fd, 0).unwrap();
+    assert_eq!(
+        map.to_kmap_string(KMapFormat::Text).unwrap(),
+        "x\\y  0  1\n  0  -  -\n  1  0  1\n"
+    );
+
+    // With the OFF-set listed, what no cube covers is a don't-care.
+    let fr = read_cover(".i 2\n.o 1\n.ilb x y\n.ob g\n.type fr\n11 1\n10 0\n.e\n");
+    let map = KarnaughMap::from_cover(&fr, 0).unwrap();
+    assert_eq!(map.cell(0, 0), None);
+    assert_eq!(map.cell(1, 0), Some(false));
+    assert_eq!(map.cell(1, 1), Some(true));
+
+    // Long names are separated in the corner label.
+    let long = read_cover(".i 2\n.o 1\n.ilb x1 x2\n.ob g\n11 1\n.e\n");
+    let text = KarnaughMap::from_cover(&long, 0)
+        .unwrap()
+        .to_kmap_string(KMapFormat::Text)
+        .unwrap();
+    assert!(text.starts_with("x1\\x2  0  1\n"));
+}
+
+#[test]
+fn out_of_reach_functions_are_rejected() {
+    let builder = crate::bdd_builder!();
+    let wide = builder.parse("a & b & c & d & e & f & g").unwrap();
+    assert_eq!(
+        KarnaughMap::from_bdd(&wide),
+        Err(KMapError::TooManyVariables { count: 7, max: 6 })
+    );
+
+    let cover = read_cover(".i 2\n.o 1\n.ilb a b\n.ob f\n11 1\n.e\n");
+    assert!(matches!(
+        KarnaughMap::from_cover(&cover, 1),
+        Err(KMapError::OutputOutOfRange(_))
+    ));
+    let foreign = read_cover(".i 2\n.o 1\n.ilb a z\n.ob f\n1- 1\n-1 1\n.e\n");
+    assert_eq!(
+        KarnaughMap::from_cover(&cover, 0)
+            .unwrap()
+            .with_groups(&foreign, 0),
+        Err(KMapError::UnknownVariable {
+            name: Arc::from("z")
+        })
+    );
+}
+
+#[test]
+fn svg_splits_groups_that_wrap_around() {
+    // `!c` covers columns `00` and `10`, the two edges of the map.
+    let cover = read_cover(".i 3\n.o 1\n.ilb a b c\n.ob f\n--0 1\n.e\n");
+    let map = KarnaughMap::from_cover(&cover, 0)
+        .unwrap()
+        .with_groups(&cover, 0)
+        .unwrap();
+    let svg = map.to_kmap_string(KMapFormat::Svg).unwrap();
+    assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+    assert!(svg.trim_end().ends_with("</svg>"));
+    assert_eq!(svg.matches("stroke=\"black\"").count(), 8);
+    assert_eq!(svg.matches("rx=\"8\"").count(), 2);
+    assert!(svg.contains(">A: !c</text>"));
+}
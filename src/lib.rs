@@ -362,11 +362,15 @@
 extern crate self as espresso_logic;
 
 // Public modules
+pub mod aiger;
 pub mod bdd;
 pub mod cover;
+pub mod dimacs;
+pub mod dot;
 pub mod error;
 pub mod espresso;
 pub mod expression;
+pub mod kmap;
 pub mod symbol;
 /// Raw bindgen-generated FFI bindings to the vendored C Espresso sources.
 ///
@@ -377,18 +381,30 @@ pub mod symbol;
 pub mod sys;
 
 // Re-export high-level public API
+pub use aiger::AIGERWriter;
 pub use bdd::{
     Bdd, BddBuilder, BddNode, BddVariables, Brand, LocalCell, ManagerCell, Scope, ScopedBdd,
     SyncCell,
 };
-pub use cover::pla::{PLAWriter, PlaCover, PlaLabel};
+pub use cover::blif::BLIFWriter;
+pub use cover::eqntott::EqntottWriter;
+pub use cover::hdl::{HDLForm, HDLWriter};
+pub use cover::kiss::{Fsm, StateEncoding};
+pub use cover::pla::{PLAWriter, PlaCover, PlaCubeReader, PlaHeader, PlaLabel};
 pub use cover::{
-    Anonymous, Cover, CoverType, Cube, CubeType, Disagreement, ExpandedMinterms, FieldsIter,
-    InputField, Label, Minimizable, Minterm, MintermIter, NamedLabel, OutputSet, ReconcilableLabel,
-    StringLabel,
+    Anonymous, Cover, CoverType, Cube, CubeType, Disagreement, EssentialPrime, ExpandedMinterms,
+    FieldsIter, InputField, Label, Minimizable, Minterm, MintermIter, MvField, MvLayout,
+    NamedLabel, OutputSet, PairableLabel, ReconcilableLabel, StringLabel, SymbolicLabel,
+    SymbolicVariable, Verdict,
+};
+pub use dimacs::DIMACSWriter;
+pub use dot::DOTWriter;
+pub use espresso::{
+    CancellationToken, EspressoConfig, EspressoCubes, PairingCost, PairingMinimizer, PairingSearch,
+    PhaseStrategy, SingleOutputStrategy,
 };
-pub use espresso::{EspressoConfig, EspressoCubes};
 pub use expression::{BoolExpr, Expr, ExprBuilder, ExprNode, ExprVariables};
+pub use kmap::KMapWriter;
 pub use symbol::Symbol;
 
 /// Build a [`BoolExpr`] from infix Boolean syntax.
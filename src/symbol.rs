@@ -319,6 +319,36 @@ impl fmt::Debug for Symbol {
     }
 }
 
+/// Serialises as a plain string.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Symbol {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// Deserialises from a string, interning it straight from the borrowed input where the format allows.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Symbol {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Symbol, D::Error> {
+        struct SymbolVisitor;
+
+        impl serde::de::Visitor<'_> for SymbolVisitor {
+            type Value = Symbol;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a variable name")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, s: &str) -> Result<Symbol, E> {
+                Ok(Symbol::new(s))
+            }
+        }
+
+        deserializer.deserialize_str(SymbolVisitor)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -548,4 +578,14 @@ mod tests {
             }
         }
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_is_a_plain_string() {
+        for s in ["a", LONG] {
+            let json = serde_json::to_string(&Symbol::new(s)).unwrap();
+            assert_eq!(json, format!("{s:?}"));
+            assert_eq!(serde_json::from_str::<Symbol>(&json).unwrap(), s);
+        }
+    }
 }
@@ -191,6 +191,55 @@ for file in pla/mytest pla/mytest2 pla/mytest3 pla/newtpla1 pla/newapla2 pla/new
 	fi
 done
 
+# Test signature cube minimization (-Dsignature) against the C oracle.
+#
+# The signature algorithm is exact too, but builds its covering table from the essential signature
+# cubes rather than every prime, so it finishes on many of the hard_examples/ that -Dexact cannot.
+# The curated small inputs above are run as well, followed by the hard examples both binaries finish
+# within the timeout (the rest time out under C and so cannot serve as an oracle).
+echo ""
+echo "Testing signature cube minimization (-Dsignature)..."
+echo "─────────────────────────────────────────────────────────────────────"
+
+for file in pla/mytest pla/mytest2 pla/mytest3 pla/newtpla1 pla/newapla2 pla/newbyte \
+	pla/newill pla/newtag pla/newtpla2 pla/newapla1 pla/dc1 pla/newcwp pla/wim pla/check \
+	tlex/con1.pla tlex/xor5.pla tlex/rd53.pla tlex/squar5.pla tlex/inc.pla tlex/misex1.pla; do
+	if [ -f "$file" ]; then
+		basename=$(basename "$file" .pla)
+		run_test "$file" "signature_${basename}" "-Dsignature"
+		run_test "$file" "signature_${basename}_fdr" "-Dsignature -o fdr"
+	fi
+done
+
+for file in hard_examples/{ex4,ibm,misg,mish,misj,shift,signet,ts10,x2dn,x7dn}; do
+	if [ -f "$file" ]; then
+		basename=$(basename "$file")
+		run_test "$file" "signature_hard_${basename}" "-Dsignature"
+	fi
+done
+
+# Test single-output minimization (-Dso, -Dso_both) against the C oracle.
+#
+# Only the ON-set is compared for -Dso_both: C prints the OFF-set as read even for the outputs it
+# implements in complement, where the Rust cover swaps it as -Dopo does.
+if [ -d "tlex" ]; then
+	echo ""
+	echo "Testing single-output minimization (-Dso, -Dso_both)..."
+	echo "─────────────────────────────────────────────────────────────────────"
+	for file in tlex/*.pla; do
+		if [ -f "$file" ]; then
+			basename=$(basename "$file" .pla)
+			# Excluded: the reference C binary crashes/times out on these inputs (no oracle).
+			case "$basename" in
+			o64) continue ;;
+			esac
+			run_test "$file" "so_${basename}" "-Dso"
+			run_test "$file" "so_${basename}_fdr" "-Dso -o fdr"
+			run_test "$file" "so_both_${basename}" "-Dso_both"
+		fi
+	done
+fi
+
 echo ""
 echo "╔════════════════════════════════════════════════════════════════════════╗"
 echo "║                         Test Results                                    ║"
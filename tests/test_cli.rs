@@ -2,9 +2,9 @@
 //!
 //! These drive the *built* binary (`CARGO_BIN_EXE_espresso`) end-to-end via `std::process::Command`,
 //! covering behaviours the shell regression harness does not: the `-O`/`-x`/`-s` flags, error exit
-//! codes, the exact (`-D exact` / `-e`) path, the `echo`/`stats` subcommands, and a Rust-only `-o`
-//! format self-consistency check. The whole file is gated on the `cli` feature, since the binary is
-//! `required-features = ["cli"]`.
+//! codes, the exact (`-D exact` / `-e`), `-D signature`, `-D so -e` and `-D opo` paths, the
+//! `echo`/`stats`/`pair` subcommands, and a Rust-only `-o` format self-consistency check. The whole
+//! file is gated on the `cli` feature, since the binary is `required-features = ["cli"]`.
 #![cfg(feature = "cli")]
 
 use std::fs;
@@ -134,6 +134,59 @@ fn exact_flag_is_alias_for_exact_command() {
     let _ = fs::remove_file(&input);
 }
 
+#[test]
+fn signature_command_runs_and_minimises() {
+    let input = temp_pla("signature", REDUCIBLE);
+    let via_signature = Command::new(ESPRESSO)
+        .args(["-D", "signature"])
+        .arg(&input)
+        .output()
+        .expect("run espresso -D signature");
+    assert!(
+        via_signature.status.success(),
+        "signature exit: {:?}",
+        via_signature.status
+    );
+    let stdout = String::from_utf8_lossy(&via_signature.stdout);
+    // The signature algorithm is exact as well, so !a is again the single cube `0-`.
+    assert!(
+        stdout.contains(".p 1") && stdout.contains("0-"),
+        "signature output is not `0-`:\n{stdout}"
+    );
+    let _ = fs::remove_file(&input);
+}
+
+#[test]
+fn single_output_commands_share_no_terms() {
+    // a·b drives both outputs, so a shared minimisation needs 2 cubes and a per-output one 3.
+    let input = temp_pla("so", ".i 2\n.o 2\n11 11\n00 10\n.e\n");
+    for args in [&["-D", "so"][..], &["-D", "so", "-e"], &["-D", "so_both"]] {
+        let output = Command::new(ESPRESSO)
+            .args(args)
+            .arg(&input)
+            .output()
+            .expect("run espresso -D so");
+        assert!(
+            output.status.success(),
+            "{args:?} exit: {:?}",
+            output.status
+        );
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let cubes: Vec<&str> = stdout
+            .lines()
+            .filter(|l| !l.starts_with(['.', '#']))
+            .collect();
+        assert_eq!(cubes.len(), 3, "{args:?} output:\n{stdout}");
+        assert!(
+            cubes
+                .iter()
+                .all(|c| c.split_whitespace().nth(1).map(|o| o.matches('1').count()) == Some(1)),
+            "{args:?} has a cube asserting several outputs:\n{stdout}"
+        );
+    }
+    let _ = fs::remove_file(&input);
+}
+
 #[test]
 fn echo_passes_pla_through() {
     let input = temp_pla("echo", REDUCIBLE);
@@ -199,3 +252,49 @@ fn output_formats_are_distinct_and_well_formed() {
     assert_ne!(f, fdr, "-o fdr should differ from -o f");
     let _ = fs::remove_file(&input);
 }
+
+#[test]
+fn pair_reports_the_chosen_pairs() {
+    // (a ⊕ c) · (b ⊕ d): both searches pair a with c and b with d, leaving one cube.
+    let input = temp_pla("pair", ".i 4\n.o 1\n0011 1\n0110 1\n1001 1\n1100 1\n.e\n");
+    for command in ["pair", "pairall"] {
+        let output = Command::new(ESPRESSO)
+            .args(["-D", command])
+            .arg(&input)
+            .output()
+            .unwrap_or_else(|_| panic!("run espresso -D {command}"));
+        assert!(output.status.success(), "-D {command} failed");
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        // Like C, the pairs are reported 1-based ahead of the PLA.
+        assert!(
+            stdout.starts_with("# pair is (1 3) (2 4)\n.mv 3 0 4 4 1\n"),
+            "-D {command}:\n{stdout}"
+        );
+        assert!(
+            stdout.contains(".p 1\n 0110 0110 1\n"),
+            "-D {command}:\n{stdout}"
+        );
+    }
+    let _ = fs::remove_file(&input);
+}
+
+#[test]
+fn opo_reports_the_phase_and_complements_outputs() {
+    // f = a + b takes one cube in complement and two as given; g = a·b one cube as given.
+    let input = temp_pla(
+        "opo",
+        ".i 2\n.o 2\n.ilb a b\n.ob f g\n1- 10\n-1 10\n11 01\n.e\n",
+    );
+    let output = Command::new(ESPRESSO)
+        .args(["-D", "opo"])
+        .arg(&input)
+        .output()
+        .expect("run espresso -D opo");
+    assert!(output.status.success(), "-D opo exit: {:?}", output.status);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    // Like C, the chosen phase is reported ahead of the cubes, `0` marking f as complemented.
+    assert!(stdout.contains("#.phase 01\n"), "-D opo:\n{stdout}");
+    // f's single cube covers its complement !a·!b; g keeps its own cube.
+    assert!(stdout.contains(".p 2\n00 10\n11 01\n"), "-D opo:\n{stdout}");
+    let _ = fs::remove_file(&input);
+}